@@ -1,6 +1,7 @@
-use chrono::{Duration, Utc};
-use std::collections::HashMap;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
 
+use crate::config::{DEFAULT_LARGE_METADATA_WARNING_MB, FreshnessThresholds};
 use crate::data::*;
 
 // Industry-standard thresholds based on Netflix, Salesforce, and AWS recommendations
@@ -27,28 +28,112 @@ impl HealthThresholds {
     // Storage growth thresholds (GB per day)
     pub const STORAGE_GROWTH_WARNING: f64 = 100.0;
     pub const STORAGE_GROWTH_CRITICAL: f64 = 500.0;
+
+    // Partition skew thresholds (largest partition bytes / median partition bytes)
+    pub const PARTITION_SKEW_WARNING: f64 = 5.0;
+    pub const PARTITION_SKEW_CRITICAL: f64 = 20.0;
+    pub const HEAVIEST_PARTITIONS_SHOWN: usize = 5;
+
+    // Partition fields on near-unique columns (average records per distinct partition value),
+    // only judged once a field has enough distinct values to matter
+    pub const HIGH_CARDINALITY_MIN_VALUES: usize = 1_000;
+    pub const HIGH_CARDINALITY_RECORDS_WARNING: f64 = 1_000.0;
+    pub const HIGH_CARDINALITY_RECORDS_CRITICAL: f64 = 10.0;
+
+    // Snapshots unreachable from any branch or tag
+    pub const ORPHANED_SNAPSHOTS_WARNING: usize = 5;
+    pub const ORPHANED_SNAPSHOTS_CRITICAL: usize = 50;
+
+    // Metadata document size (in MB) and retained history that bloats it; every commit
+    // rewrites the whole document. The warning size is a setting, see
+    // `AppSettings::large_metadata_warning_mb`.
+    pub const METADATA_SIZE_NOTE_MB: f64 = 10.0;
+    pub const RETAINED_SCHEMAS_NOTE: usize = 100;
+    pub const RETAINED_PARTITION_SPECS_NOTE: usize = 20;
+    pub const SNAPSHOT_LOG_NOTE: usize = 1_000;
 }
 
 pub struct TableAnalytics;
 
 impl TableAnalytics {
     pub fn compute_health_metrics(table: &IcebergTable) -> TableHealthMetrics {
+        Self::compute_health_metrics_for(
+            table,
+            &HealthCategory::ALL,
+            DEFAULT_LARGE_METADATA_WARNING_MB,
+            None,
+        )
+    }
+
+    /// Health metrics scored only on the enabled categories. Alerts from disabled categories
+    /// are dropped and the score is rescaled so the enabled ones span the full 0-100 range.
+    /// Metadata documents over `metadata_warning_mb` raise a warning. Partition health is
+    /// only scored when the current snapshot's `partition_stats` have been read.
+    pub fn compute_health_metrics_for(
+        table: &IcebergTable,
+        enabled: &[HealthCategory],
+        metadata_warning_mb: u64,
+        partition_stats: Option<&[PartitionStats]>,
+    ) -> TableHealthMetrics {
         let file_health = Self::compute_file_health(&table.snapshots);
         let operational_health = Self::compute_operational_health(&table.snapshots);
         let storage_efficiency = Self::compute_storage_efficiency(&table.snapshots);
         let trends = Self::compute_trends(&table.snapshots);
+        let partition_skew = partition_stats.and_then(Self::compute_partition_skew);
 
-        let health_score = Self::compute_overall_health_score(
+        let orphaned_snapshots = Self::find_orphaned_snapshots(table);
+
+        let mut health_score = Self::compute_overall_health_score(
             &file_health,
             &operational_health,
             &storage_efficiency,
             &trends,
+            partition_stats.map(|_| partition_skew.as_ref()),
+            enabled,
         );
+        if orphaned_snapshots.len() > HealthThresholds::ORPHANED_SNAPSHOTS_CRITICAL {
+            health_score = (health_score - 10.0).max(0.0);
+        } else if orphaned_snapshots.len() > HealthThresholds::ORPHANED_SNAPSHOTS_WARNING {
+            health_score = (health_score - 5.0).max(0.0);
+        }
 
-        let alerts = Self::generate_alerts(&file_health, &operational_health, &storage_efficiency);
+        let mut alerts =
+            Self::generate_alerts(&file_health, &operational_health, &storage_efficiency);
+        alerts.extend(Self::large_metadata_alert(table, metadata_warning_mb));
+        alerts.extend(partition_skew.as_ref().and_then(Self::partition_skew_alert));
+        if let Some(stats) = partition_stats {
+            alerts.extend(
+                Self::high_cardinality_partition_fields(stats)
+                    .iter()
+                    .map(Self::high_cardinality_partition_alert),
+            );
+        }
+        alerts.retain(|alert| {
+            alert
+                .category
+                .health_category()
+                .is_none_or(|category| enabled.contains(&category))
+        });
+        if let Some(alert) = Self::orphaned_snapshots_alert(orphaned_snapshots.len()) {
+            alerts.push(alert);
+        }
+        if let Some(alert) = Self::missing_statistics_alert(table) {
+            alerts.push(alert);
+        }
 
         let recommendations = Self::generate_recommendations(&alerts, &trends);
 
+        let snapshots_without_summary = table
+            .snapshots
+            .iter()
+            .filter(|snapshot| snapshot.summary.is_none())
+            .count();
+        if let Some(alert) =
+            Self::missing_summary_alert(snapshots_without_summary, table.snapshots.len())
+        {
+            alerts.push(alert);
+        }
+
         TableHealthMetrics {
             health_score,
             file_health,
@@ -57,7 +142,219 @@ impl TableAnalytics {
             trends,
             alerts,
             recommendations,
+            snapshots_without_summary,
+            orphaned_snapshots,
+            enabled_categories: HealthCategory::ALL
+                .into_iter()
+                .filter(|category| enabled.contains(category))
+                .collect(),
+            partition_skew,
+        }
+    }
+
+    /// IDs of the snapshots reachable from the given heads by following parent links.
+    /// Parents that are missing from `snapshots` (already expired) end the walk.
+    pub fn reachable_snapshot_ids(
+        snapshots: &[Snapshot],
+        heads: impl IntoIterator<Item = u64>,
+    ) -> HashSet<u64> {
+        let parents: HashMap<u64, Option<u64>> = snapshots
+            .iter()
+            .map(|snapshot| (snapshot.snapshot_id, snapshot.parent_snapshot_id))
+            .collect();
+
+        let mut reachable = HashSet::new();
+        for head in heads {
+            let mut next = Some(head);
+            while let Some(id) = next {
+                // Stop at unknown snapshots and at lineage already walked from another head
+                if !parents.contains_key(&id) || !reachable.insert(id) {
+                    break;
+                }
+                next = parents[&id];
+            }
+        }
+        reachable
+    }
+
+    /// Snapshots that no branch or tag (or the current snapshot) can reach, newest first.
+    /// These are left behind by rollbacks or deleted refs until snapshots are expired.
+    pub fn find_orphaned_snapshots(table: &IcebergTable) -> Vec<OrphanedSnapshot> {
+        let heads: Vec<u64> = table
+            .refs
+            .iter()
+            .map(|reference| reference.snapshot_id)
+            .chain(table.current_snapshot_id)
+            .collect();
+        // Without any head every snapshot would look orphaned, which says nothing useful
+        if heads.is_empty() {
+            return Vec::new();
+        }
+
+        let reachable = Self::reachable_snapshot_ids(&table.snapshots, heads);
+        let mut orphaned: Vec<OrphanedSnapshot> = table
+            .snapshots
+            .iter()
+            .filter(|snapshot| !reachable.contains(&snapshot.snapshot_id))
+            .map(|snapshot| OrphanedSnapshot {
+                snapshot_id: snapshot.snapshot_id,
+                timestamp_ms: snapshot.timestamp_ms,
+                operation: snapshot.operation(),
+                added_size_bytes: snapshot
+                    .summary
+                    .as_ref()
+                    .and_then(|summary| summary.added_files_size.as_ref())
+                    .and_then(|size| size.parse().ok()),
+            })
+            .collect();
+        orphaned.sort_by(|a, b| b.timestamp_ms.cmp(&a.timestamp_ms));
+        orphaned
+    }
+
+    fn orphaned_snapshots_alert(count: usize) -> Option<HealthAlert> {
+        let (severity, threshold) = if count > HealthThresholds::ORPHANED_SNAPSHOTS_CRITICAL {
+            (
+                AlertSeverity::Critical,
+                HealthThresholds::ORPHANED_SNAPSHOTS_CRITICAL,
+            )
+        } else if count > HealthThresholds::ORPHANED_SNAPSHOTS_WARNING {
+            (
+                AlertSeverity::Warning,
+                HealthThresholds::ORPHANED_SNAPSHOTS_WARNING,
+            )
+        } else {
+            return None;
+        };
+
+        Some(HealthAlert {
+            severity,
+            category: AlertCategory::OrphanedSnapshots,
+            message: format!(
+                "{} snapshots are not reachable from any branch or tag; consider expiring snapshots",
+                count
+            ),
+            metric_value: count as f64,
+            threshold: threshold as f64,
+            detected_at: Utc::now(),
+        })
+    }
+
+    /// Advise trimming retained metadata once the metadata document is large, or holds enough
+    /// schemas, partition specs or snapshot-log entries to get there. Documents over
+    /// `warning_mb` are a warning rather than a note.
+    pub fn large_metadata_alert(table: &IcebergTable, warning_mb: u64) -> Option<HealthAlert> {
+        let warning_mb = warning_mb as f64;
+        let size_mb = table
+            .metadata_size_bytes
+            .map(|bytes| bytes as f64 / (1024.0 * 1024.0));
+        let mut retained = Vec::new();
+        if table.schemas.len() > HealthThresholds::RETAINED_SCHEMAS_NOTE {
+            retained.push(format!("{} schemas", table.schemas.len()));
+        }
+        if table.partition_specs.len() > HealthThresholds::RETAINED_PARTITION_SPECS_NOTE {
+            retained.push(format!("{} partition specs", table.partition_specs.len()));
+        }
+        if table.snapshot_log_entries > HealthThresholds::SNAPSHOT_LOG_NOTE {
+            retained.push(format!(
+                "{} snapshot-log entries",
+                table.snapshot_log_entries
+            ));
+        }
+
+        let (severity, threshold) = match size_mb {
+            Some(mb) if mb > warning_mb => (AlertSeverity::Warning, warning_mb),
+            Some(mb) if mb > HealthThresholds::METADATA_SIZE_NOTE_MB => {
+                (AlertSeverity::Info, HealthThresholds::METADATA_SIZE_NOTE_MB)
+            }
+            // Only retained history to go on; no size to compare against a threshold
+            _ if !retained.is_empty() => (AlertSeverity::Info, 0.0),
+            _ => return None,
+        };
+
+        let mut message = match size_mb {
+            Some(mb) => format!("metadata.json is {:.1} MB", mb),
+            None => "metadata.json is growing".to_string(),
+        };
+        if !retained.is_empty() {
+            message.push_str(&format!(" ({})", retained.join(", ")));
+        }
+        message.push_str(
+            "; every commit rewrites it. Lower write.metadata.previous-versions-max and expire snapshots to shrink it",
+        );
+
+        Some(HealthAlert {
+            severity,
+            category: AlertCategory::LargeMetadata,
+            message,
+            metric_value: if threshold > 0.0 {
+                size_mb.unwrap_or(0.0)
+            } else {
+                0.0
+            },
+            threshold,
+            detected_at: Utc::now(),
+        })
+    }
+
+    /// Flag tables whose current snapshot has no column statistics, which leaves engines
+    /// planning joins and filters blind. Statistics for an older snapshot are only stale.
+    fn missing_statistics_alert(table: &IcebergTable) -> Option<HealthAlert> {
+        table.current_snapshot_id?;
+        if table.current_statistics().is_some() {
+            return None;
+        }
+
+        let (severity, message) = if table.statistics.is_empty() {
+            (
+                AlertSeverity::Warning,
+                "No column statistics have been computed; query engines fall back to guesses when planning",
+            )
+        } else {
+            (
+                AlertSeverity::Info,
+                "Column statistics exist only for older snapshots and may be stale",
+            )
+        };
+
+        Some(HealthAlert {
+            severity,
+            category: AlertCategory::MissingStatistics,
+            message: message.to_string(),
+            metric_value: 0.0,
+            threshold: 0.0,
+            detected_at: Utc::now(),
+        })
+    }
+
+    /// Info note for snapshots whose summary is missing, so metrics derived from the
+    /// remaining snapshots aren't mistaken for a complete picture
+    fn missing_summary_alert(missing: usize, total: usize) -> Option<HealthAlert> {
+        if missing == 0 {
+            return None;
         }
+
+        let message = if missing == total {
+            format!(
+                "None of the {} snapshots have a summary; file, size and operation metrics are unavailable",
+                total
+            )
+        } else {
+            format!(
+                "{} of {} snapshots have no summary; file, size and operation metrics only cover the other {}",
+                missing,
+                total,
+                total - missing
+            )
+        };
+
+        Some(HealthAlert {
+            severity: AlertSeverity::Info,
+            category: AlertCategory::IncompleteMetadata,
+            message,
+            metric_value: missing as f64,
+            threshold: 0.0,
+            detected_at: Utc::now(),
+        })
     }
 
     fn compute_file_health(snapshots: &[Snapshot]) -> FileHealthMetrics {
@@ -68,16 +365,24 @@ impl TableAnalytics {
         let mut optimal_files = 0u64;
         let mut large_files = 0u64;
 
-        // Analyze the latest snapshot for current state
-        if let Some(latest_snapshot) = snapshots.last() {
-            if let Some(summary) = &latest_snapshot.summary {
-                if let Some(files_str) = &summary.added_data_files {
-                    total_files = files_str.parse().unwrap_or(0);
-                }
-
-                if let Some(size_str) = &summary.total_size {
-                    total_size_bytes = size_str.parse().unwrap_or(0.0);
-                }
+        // Analyze the latest summarized snapshot for current state. Both the file count and
+        // the size are needed; one without the other would skew the average file size.
+        let latest_summary = snapshots
+            .iter()
+            .rev()
+            .find_map(|snapshot| snapshot.summary.as_ref());
+        if let Some(summary) = latest_summary {
+            let files = summary
+                .added_data_files
+                .as_ref()
+                .and_then(|files_str| files_str.parse::<u64>().ok());
+            let size = summary
+                .total_size
+                .as_ref()
+                .and_then(|size_str| size_str.parse::<f64>().ok());
+            if let (Some(files), Some(size)) = (files, size) {
+                total_files = files;
+                total_size_bytes = size;
             }
         }
 
@@ -206,6 +511,8 @@ impl TableAnalytics {
         }
     }
 
+    /// Sizes and operation ratios come from summarized snapshots only; snapshots without a
+    /// summary are left out of both the numerators and the denominators.
     fn compute_storage_efficiency(snapshots: &[Snapshot]) -> StorageEfficiencyMetrics {
         let mut total_size_gb = 0.0;
         let mut delete_operations = 0u32;
@@ -287,66 +594,133 @@ impl TableAnalytics {
         }
     }
 
+    /// `partition_skew` is `None` when the partition stats were not read, leaving partition
+    /// health out of the score, and `Some(None)` when there was no skew to measure.
     fn compute_overall_health_score(
         file_health: &FileHealthMetrics,
         operational_health: &OperationalHealthMetrics,
         storage_efficiency: &StorageEfficiencyMetrics,
         trends: &TrendMetrics,
+        partition_skew: Option<Option<&PartitionSkewMetrics>>,
+        enabled: &[HealthCategory],
     ) -> f64 {
-        let mut score: f64 = 100.0;
-
         // File health penalties
+        let mut file_penalty = 0.0;
         if file_health.small_file_ratio > HealthThresholds::SMALL_FILE_RATIO_CRITICAL {
-            score -= 30.0;
+            file_penalty += 30.0;
         } else if file_health.small_file_ratio > HealthThresholds::SMALL_FILE_RATIO_WARNING {
-            score -= 15.0;
+            file_penalty += 15.0;
+        }
+
+        // Trend bonuses/penalties
+        match trends.file_count_trend {
+            TrendDirection::Improving => file_penalty -= 5.0,
+            TrendDirection::Degrading => file_penalty += 5.0,
+            TrendDirection::Stable => {}
         }
 
         // Operational health penalties
+        let mut operational_penalty = 0.0;
         if operational_health.snapshot_frequency.snapshots_last_hour
             > HealthThresholds::HIGH_FREQUENCY_HOUR_CRITICAL
         {
-            score -= 20.0;
+            operational_penalty += 20.0;
         } else if operational_health.snapshot_frequency.snapshots_last_hour
             > HealthThresholds::HIGH_FREQUENCY_HOUR_WARNING
         {
-            score -= 10.0;
+            operational_penalty += 10.0;
         }
 
         // Compaction penalties
+        let mut compaction_penalty = 0.0;
         if let Some(days_since_compaction) = operational_health.compaction_frequency.days_since_last
         {
             if days_since_compaction > HealthThresholds::COMPACTION_CRITICAL_DAYS {
-                score -= 25.0;
+                compaction_penalty += 25.0;
             } else if days_since_compaction > HealthThresholds::COMPACTION_WARNING_DAYS {
-                score -= 12.0;
+                compaction_penalty += 12.0;
             }
         } else {
             // No compaction data available - apply penalty for lack of monitoring
-            score -= 10.0;
+            compaction_penalty += 10.0;
         }
 
         // Storage growth penalties
+        let mut storage_penalty = 0.0;
         if storage_efficiency.storage_growth_rate_gb_per_day
             > HealthThresholds::STORAGE_GROWTH_CRITICAL
         {
-            score -= 15.0;
+            storage_penalty += 15.0;
         } else if storage_efficiency.storage_growth_rate_gb_per_day
             > HealthThresholds::STORAGE_GROWTH_WARNING
         {
-            score -= 8.0;
+            storage_penalty += 8.0;
         }
 
-        // Trend bonuses/penalties
-        match trends.file_count_trend {
-            TrendDirection::Improving => score += 5.0,
-            TrendDirection::Degrading => score -= 5.0,
-            TrendDirection::Stable => {}
+        // Partition skew penalties
+        let partition_penalty = partition_skew
+            .flatten()
+            .map_or(0.0, Self::partition_skew_penalty);
+        // Unread partition stats leave partition health out of the scale as if disabled
+        let partition_scored =
+            partition_skew.is_some() && enabled.contains(&HealthCategory::Partition);
+        let scored =
+            |category: &HealthCategory| *category != HealthCategory::Partition || partition_scored;
+
+        let penalties = [
+            (HealthCategory::File, file_penalty),
+            (HealthCategory::Operational, operational_penalty),
+            (HealthCategory::Compaction, compaction_penalty),
+            (HealthCategory::Storage, storage_penalty),
+            (HealthCategory::Partition, partition_penalty),
+        ];
+        let enabled_penalty: f64 = penalties
+            .iter()
+            .filter(|(category, _)| enabled.contains(category) && scored(category))
+            .map(|(_, penalty)| penalty)
+            .sum();
+
+        // Scale so the enabled categories can still take the score from 100 down to 0
+        let total_max: f64 = HealthCategory::ALL
+            .iter()
+            .filter(|category| scored(category))
+            .map(Self::max_penalty)
+            .sum();
+        let enabled_max: f64 = HealthCategory::ALL
+            .iter()
+            .filter(|category| enabled.contains(category) && scored(category))
+            .map(Self::max_penalty)
+            .sum();
+        if enabled_max == 0.0 {
+            return 100.0;
         }
 
+        let score = 100.0 - enabled_penalty * (total_max / enabled_max);
         score.max(0.0).min(100.0)
     }
 
+    /// Largest deduction a category can make, used to rescale the score when some are disabled
+    fn max_penalty(category: &HealthCategory) -> f64 {
+        match category {
+            HealthCategory::File => 35.0,
+            HealthCategory::Operational => 20.0,
+            HealthCategory::Storage => 15.0,
+            HealthCategory::Compaction => 25.0,
+            HealthCategory::Partition => 15.0,
+        }
+    }
+
+    /// Points partition skew takes off the score
+    pub fn partition_skew_penalty(skew: &PartitionSkewMetrics) -> f64 {
+        if skew.skew_ratio > HealthThresholds::PARTITION_SKEW_CRITICAL {
+            15.0
+        } else if skew.skew_ratio > HealthThresholds::PARTITION_SKEW_WARNING {
+            8.0
+        } else {
+            0.0
+        }
+    }
+
     fn generate_alerts(
         file_health: &FileHealthMetrics,
         operational_health: &OperationalHealthMetrics,
@@ -471,6 +845,50 @@ impl TableAnalytics {
                         effort_level: MaintenanceEffort::Medium,
                     });
                 }
+                AlertCategory::OrphanedSnapshots => {
+                    recommendations.push(MaintenanceRecommendation {
+                        priority: if alert.severity == AlertSeverity::Critical {
+                            MaintenancePriority::High
+                        } else {
+                            MaintenancePriority::Low
+                        },
+                        action_type: MaintenanceActionType::RetentionPolicy,
+                        description:
+                            "Run expire_snapshots to drop snapshots no branch or tag references"
+                                .to_string(),
+                        estimated_benefit: "Smaller metadata and reclaimable storage".to_string(),
+                        effort_level: MaintenanceEffort::Low,
+                    });
+                }
+                AlertCategory::MissingStatistics => {
+                    recommendations.push(MaintenanceRecommendation {
+                        priority: if alert.severity == AlertSeverity::Warning {
+                            MaintenancePriority::Medium
+                        } else {
+                            MaintenancePriority::Low
+                        },
+                        action_type: MaintenanceActionType::ComputeStatistics,
+                        description:
+                            "Compute table statistics so engines can estimate column cardinality"
+                                .to_string(),
+                        estimated_benefit: "Better join ordering and query plans".to_string(),
+                        effort_level: MaintenanceEffort::Low,
+                    });
+                }
+                AlertCategory::LargeMetadata => {
+                    recommendations.push(MaintenanceRecommendation {
+                        priority: if alert.severity == AlertSeverity::Warning {
+                            MaintenancePriority::Medium
+                        } else {
+                            MaintenancePriority::Low
+                        },
+                        action_type: MaintenanceActionType::MetadataCleanup,
+                        description: "Cap retained metadata versions and expire old snapshots"
+                            .to_string(),
+                        estimated_benefit: "Faster commits and query planning".to_string(),
+                        effort_level: MaintenanceEffort::Low,
+                    });
+                }
                 AlertCategory::HighSnapshotFrequency => {
                     recommendations.push(MaintenanceRecommendation {
                         priority: MaintenancePriority::Medium,
@@ -505,4 +923,2160 @@ impl TableAnalytics {
 
         recommendations
     }
+
+    /// Hours since the newest snapshot was committed, or `None` for a table without snapshots
+    pub fn hours_since_last_snapshot(snapshots: &[Snapshot], now: DateTime<Utc>) -> Option<f64> {
+        snapshots
+            .iter()
+            .map(|snapshot| snapshot.timestamp_ms)
+            .max()
+            .map(|latest| Self::hours_since(latest, now))
+    }
+
+    /// Whole minutes elapsed since `timestamp_ms`, in hours; never negative
+    pub fn hours_since(timestamp_ms: i64, now: DateTime<Utc>) -> f64 {
+        ((now.timestamp_millis() - timestamp_ms).max(0) / 60_000) as f64 / 60.0
+    }
+
+    /// Favorites past their aging or stale threshold, stale first and then longest without a
+    /// snapshot. Tables that could not be checked or have no snapshots are left out.
+    pub fn freshness_breaches(checks: &[FreshnessCheck]) -> Vec<&FreshnessCheck> {
+        let severity = |freshness: Freshness| match freshness {
+            Freshness::Stale => 0,
+            _ => 1,
+        };
+        let mut breaches: Vec<&FreshnessCheck> = checks
+            .iter()
+            .filter(|check| {
+                check.error.is_none()
+                    && matches!(check.freshness, Freshness::Aging | Freshness::Stale)
+            })
+            .collect();
+        breaches.sort_by(|a, b| {
+            severity(a.freshness)
+                .cmp(&severity(b.freshness))
+                .then_with(|| {
+                    b.hours_since_last_snapshot
+                        .partial_cmp(&a.hours_since_last_snapshot)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+        });
+        breaches
+    }
+
+    /// The snapshot that was current at `timestamp_ms`: the newest one committed at or before it
+    pub fn snapshot_active_at(snapshots: &[Snapshot], timestamp_ms: i64) -> Option<&Snapshot> {
+        snapshots
+            .iter()
+            .filter(|snapshot| snapshot.timestamp_ms <= timestamp_ms)
+            .max_by_key(|snapshot| snapshot.timestamp_ms)
+    }
+
+    pub fn classify_freshness(
+        hours_since_last_snapshot: Option<f64>,
+        thresholds: &FreshnessThresholds,
+    ) -> Freshness {
+        match hours_since_last_snapshot {
+            None => Freshness::Unknown,
+            Some(hours) if hours >= thresholds.stale_hours => Freshness::Stale,
+            Some(hours) if hours >= thresholds.aging_hours => Freshness::Aging,
+            Some(_) => Freshness::Fresh,
+        }
+    }
+
+    /// Running record count and table size after each snapshot, keyed by snapshot id and
+    /// computed in commit order. A summary's `total-records` / `total-files-size` is taken as
+    /// authoritative; otherwise the running value moves by added minus deleted, so overwrites
+    /// and deletes shrink it. Snapshots without a summary carry the previous totals forward.
+    pub fn cumulative_totals(snapshots: &[Snapshot]) -> HashMap<u64, CumulativeTotals> {
+        let parse = |value: &Option<String>| value.as_ref().and_then(|v| v.parse::<u64>().ok());
+        let advance = |running: Option<u64>, total, added: Option<u64>, removed: Option<u64>| match (
+            total, added, removed,
+        ) {
+            (Some(total), _, _) => Some(total),
+            (None, None, None) => running,
+            (None, added, removed) => Some(
+                (running.unwrap_or(0) + added.unwrap_or(0)).saturating_sub(removed.unwrap_or(0)),
+            ),
+        };
+
+        let mut ordered: Vec<&Snapshot> = snapshots.iter().collect();
+        ordered.sort_by_key(|s| s.timestamp_ms);
+
+        let mut running = CumulativeTotals::default();
+        let mut totals = HashMap::new();
+        for snapshot in ordered {
+            if let Some(summary) = &snapshot.summary {
+                running = CumulativeTotals {
+                    records: advance(
+                        running.records,
+                        parse(&summary.total_records),
+                        parse(&summary.added_records),
+                        parse(&summary.deleted_records),
+                    ),
+                    size_bytes: advance(
+                        running.size_bytes,
+                        parse(&summary.total_size),
+                        parse(&summary.added_files_size),
+                        parse(&summary.removed_files_size),
+                    ),
+                };
+            }
+            totals.insert(snapshot.snapshot_id, running);
+        }
+        totals
+    }
+
+    /// Snapshots whose `total-records` does not reconcile with the previous snapshot's total
+    /// plus `added-records` minus `deleted-records`. The previous snapshot is the parent.
+    /// Anything that cannot be checked is skipped rather than flagged: a snapshot with no
+    /// parent, a missing total on either side, a parent that has been expired, or a summary with neither an added nor a deleted count (a single
+    /// missing count is read as zero, since Iceberg omits zero-valued counters).
+    pub fn record_count_discrepancies(snapshots: &[Snapshot]) -> Vec<RecordCountDiscrepancy> {
+        let parse = |value: &Option<String>| value.as_ref().and_then(|v| v.parse::<u64>().ok());
+
+        let mut ordered: Vec<&Snapshot> = snapshots.iter().collect();
+        ordered.sort_by_key(|s| (s.timestamp_ms, s.snapshot_id));
+
+        let mut discrepancies = Vec::new();
+        for snapshot in &ordered {
+            // A snapshot without a parent starts from an empty table, whatever came before
+            let Some(parent_id) = snapshot.parent_snapshot_id else {
+                continue;
+            };
+            let previous = ordered.iter().find(|s| s.snapshot_id == parent_id);
+            let (Some(previous), Some(summary)) = (previous, &snapshot.summary) else {
+                continue;
+            };
+            let Some(previous_total) = previous
+                .summary
+                .as_ref()
+                .and_then(|summary| parse(&summary.total_records))
+            else {
+                continue;
+            };
+            let Some(reported) = parse(&summary.total_records) else {
+                continue;
+            };
+            let (added, deleted) = match (
+                parse(&summary.added_records),
+                parse(&summary.deleted_records),
+            ) {
+                (None, None) => continue,
+                (added, deleted) => (added.unwrap_or(0), deleted.unwrap_or(0)),
+            };
+
+            let expected = previous_total as i64 + added as i64 - deleted as i64;
+            if expected != reported as i64 {
+                discrepancies.push(RecordCountDiscrepancy {
+                    snapshot_id: snapshot.snapshot_id,
+                    previous_snapshot_id: previous.snapshot_id,
+                    expected_records: expected,
+                    reported_records: reported,
+                });
+            }
+        }
+        discrepancies
+    }
+
+    /// Group snapshots by the engine that wrote them, busiest writer first. Snapshots with no
+    /// writer information share a single trailing bucket.
+    pub fn writer_groups(snapshots: &[Snapshot]) -> Vec<WriterGroup> {
+        let mut groups: HashMap<Option<String>, WriterGroup> = HashMap::new();
+        for snapshot in snapshots {
+            let writer = snapshot.writer();
+            let group = groups.entry(writer.clone()).or_insert(WriterGroup {
+                writer,
+                snapshot_count: 0,
+                records_added: 0,
+                last_timestamp_ms: i64::MIN,
+            });
+            group.snapshot_count += 1;
+            group.records_added += snapshot
+                .summary
+                .as_ref()
+                .and_then(|s| s.added_records.as_ref())
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(0);
+            group.last_timestamp_ms = group.last_timestamp_ms.max(snapshot.timestamp_ms);
+        }
+
+        let mut groups: Vec<WriterGroup> = groups.into_values().collect();
+        groups.sort_by(|a, b| {
+            a.writer
+                .is_none()
+                .cmp(&b.writer.is_none())
+                .then(b.snapshot_count.cmp(&a.snapshot_count))
+                .then(a.writer.cmp(&b.writer))
+        });
+        groups
+    }
+
+    /// Snapshots committed since `last_seen`, walking back from the current snapshot through
+    /// its parents. When the last seen snapshot was expired the walk stops at snapshots older
+    /// than it instead. `None` when nothing new was committed.
+    pub fn changes_since(
+        snapshots: &[Snapshot],
+        current_snapshot_id: Option<u64>,
+        last_seen: LastSeenSnapshot,
+    ) -> Option<ChangesSinceLastView> {
+        let by_id: HashMap<u64, &Snapshot> = snapshots.iter().map(|s| (s.snapshot_id, s)).collect();
+        let mut new_snapshots = Vec::new();
+        let mut next = current_snapshot_id;
+        while let Some(snapshot) = next.and_then(|id| by_id.get(&id)) {
+            if snapshot.snapshot_id == last_seen.snapshot_id
+                || snapshot.timestamp_ms <= last_seen.timestamp_ms
+                || new_snapshots.len() == snapshots.len()
+            {
+                break;
+            }
+            new_snapshots.push((*snapshot).clone());
+            next = snapshot.parent_snapshot_id;
+        }
+        if new_snapshots.is_empty() {
+            return None;
+        }
+
+        let sum = |field: fn(&Summary) -> &Option<String>| -> u64 {
+            new_snapshots
+                .iter()
+                .filter_map(|s| s.summary.as_ref())
+                .filter_map(|summary| field(summary).as_ref()?.parse::<u64>().ok())
+                .sum()
+        };
+        Some(ChangesSinceLastView {
+            last_seen,
+            records_added: sum(|s| &s.added_records),
+            records_deleted: sum(|s| &s.deleted_records),
+            data_files_added: sum(|s| &s.added_data_files),
+            data_files_removed: sum(|s| &s.deleted_data_files),
+            last_seen_expired: !by_id.contains_key(&last_seen.snapshot_id),
+            new_snapshots,
+        })
+    }
+
+    /// Collect the snapshots committed after `from_snapshot_id` up to and including
+    /// `to_snapshot_id`, oldest first. The window follows parent links back from `to`, so
+    /// commits on other branches are left out and `from` has to be an ancestor of `to`. Only
+    /// snapshot metadata is read; no data is scanned. Summary totals only describe new rows
+    /// when every snapshot in the window is an append, so any overwrite, delete or replace
+    /// yields an explanation instead.
+    pub fn append_window_summary(
+        snapshots: &[Snapshot],
+        from_snapshot_id: u64,
+        to_snapshot_id: u64,
+    ) -> Result<Vec<Snapshot>, String> {
+        let by_id: HashMap<u64, &Snapshot> = snapshots.iter().map(|s| (s.snapshot_id, s)).collect();
+        if !by_id.contains_key(&from_snapshot_id) {
+            return Err(format!("Snapshot {} not found", from_snapshot_id));
+        }
+        let to = by_id
+            .get(&to_snapshot_id)
+            .ok_or_else(|| format!("Snapshot {} not found", to_snapshot_id))?;
+        if from_snapshot_id == to_snapshot_id {
+            return Err("The 'to' snapshot must be newer than the 'from' snapshot".to_string());
+        }
+
+        let mut range: Vec<Snapshot> = Vec::new();
+        let mut next = Some(*to);
+        loop {
+            // A lineage that ends, or loops, without reaching 'from' puts it on another branch
+            // (or its history between the two expired)
+            let Some(snapshot) = next.filter(|_| range.len() < snapshots.len()) else {
+                return Err(format!(
+                    "Snapshot {} is not an ancestor of snapshot {}",
+                    from_snapshot_id, to_snapshot_id
+                ));
+            };
+            if snapshot.snapshot_id == from_snapshot_id {
+                break;
+            }
+            range.push(snapshot.clone());
+            next = snapshot
+                .parent_snapshot_id
+                .and_then(|id| by_id.get(&id).copied());
+        }
+        range.reverse();
+
+        if let Some(blocking) = range.iter().find(|s| s.operation() != "append") {
+            return Err(format!(
+                "Snapshot {} is a '{}' operation; only append-only ranges can be totalled as new rows, because rewritten or deleted rows are not additions",
+                blocking.snapshot_id,
+                blocking.operation()
+            ));
+        }
+
+        Ok(range)
+    }
+
+    /// Measure how unevenly bytes are spread across partitions. Needs at least two
+    /// partitions; an empty median partition is treated as one byte to keep the ratio finite.
+    pub fn compute_partition_skew(stats: &[PartitionStats]) -> Option<PartitionSkewMetrics> {
+        if stats.len() < 2 {
+            return None;
+        }
+
+        let mut sizes: Vec<u64> = stats.iter().map(|s| s.total_size_bytes).collect();
+        sizes.sort_unstable();
+        let n = sizes.len();
+        let median_size_bytes = if n % 2 == 0 {
+            (sizes[n / 2 - 1] + sizes[n / 2]) / 2
+        } else {
+            sizes[n / 2]
+        };
+        let max_size_bytes = sizes[n - 1];
+        let skew_ratio = max_size_bytes as f64 / median_size_bytes.max(1) as f64;
+
+        // Gini over ascending sizes: (2 * sum(i * x_i)) / (n * sum(x)) - (n + 1) / n
+        let total: f64 = sizes.iter().map(|&size| size as f64).sum();
+        let gini_coefficient = if total == 0.0 {
+            0.0
+        } else {
+            let weighted: f64 = sizes
+                .iter()
+                .enumerate()
+                .map(|(i, &size)| (i + 1) as f64 * size as f64)
+                .sum();
+            (2.0 * weighted) / (n as f64 * total) - (n as f64 + 1.0) / n as f64
+        };
+
+        let mut heaviest_partitions = stats.to_vec();
+        heaviest_partitions.sort_by(|a, b| b.total_size_bytes.cmp(&a.total_size_bytes));
+        heaviest_partitions.truncate(HealthThresholds::HEAVIEST_PARTITIONS_SHOWN);
+
+        Some(PartitionSkewMetrics {
+            partition_count: n,
+            median_size_bytes,
+            max_size_bytes,
+            skew_ratio,
+            gini_coefficient,
+            heaviest_partitions,
+        })
+    }
+
+    /// Alert when the largest partition dwarfs the typical one
+    pub fn partition_skew_alert(skew: &PartitionSkewMetrics) -> Option<HealthAlert> {
+        let (severity, threshold) = if skew.skew_ratio > HealthThresholds::PARTITION_SKEW_CRITICAL {
+            (
+                AlertSeverity::Critical,
+                HealthThresholds::PARTITION_SKEW_CRITICAL,
+            )
+        } else if skew.skew_ratio > HealthThresholds::PARTITION_SKEW_WARNING {
+            (
+                AlertSeverity::Warning,
+                HealthThresholds::PARTITION_SKEW_WARNING,
+            )
+        } else {
+            return None;
+        };
+
+        Some(HealthAlert {
+            severity,
+            category: AlertCategory::PartitionSkew,
+            message: format!(
+                "Partition skew: the largest partition is {:.1}x the median partition size",
+                skew.skew_ratio
+            ),
+            metric_value: skew.skew_ratio,
+            threshold,
+            detected_at: Utc::now(),
+        })
+    }
+
+    /// Partition fields whose distinct values are disproportionate to the records they hold,
+    /// e.g. identity partitioning on an id column. Worst offenders first.
+    pub fn high_cardinality_partition_fields(
+        stats: &[PartitionStats],
+    ) -> Vec<HighCardinalityPartitionField> {
+        let total_records: u64 = stats.iter().map(|s| s.record_count).sum();
+        let mut values_by_field: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for stat in stats {
+            for (field, value) in &stat.values {
+                values_by_field
+                    .entry(field.as_str())
+                    .or_default()
+                    .insert(value.as_str());
+            }
+        }
+
+        let mut fields: Vec<HighCardinalityPartitionField> = values_by_field
+            .into_iter()
+            .filter(|(_, values)| values.len() >= HealthThresholds::HIGH_CARDINALITY_MIN_VALUES)
+            .map(|(field, values)| HighCardinalityPartitionField {
+                field: field.to_string(),
+                distinct_values: values.len(),
+                records_per_value: total_records as f64 / values.len() as f64,
+            })
+            .filter(|field| {
+                field.records_per_value < HealthThresholds::HIGH_CARDINALITY_RECORDS_WARNING
+            })
+            .collect();
+        fields.sort_by(|a, b| a.records_per_value.total_cmp(&b.records_per_value));
+        fields
+    }
+
+    /// Alert on a partition field that splits the table into many tiny partitions
+    pub fn high_cardinality_partition_alert(field: &HighCardinalityPartitionField) -> HealthAlert {
+        let (severity, threshold) =
+            if field.records_per_value < HealthThresholds::HIGH_CARDINALITY_RECORDS_CRITICAL {
+                (
+                    AlertSeverity::Critical,
+                    HealthThresholds::HIGH_CARDINALITY_RECORDS_CRITICAL,
+                )
+            } else {
+                (
+                    AlertSeverity::Warning,
+                    HealthThresholds::HIGH_CARDINALITY_RECORDS_WARNING,
+                )
+            };
+
+        HealthAlert {
+            severity,
+            category: AlertCategory::HighCardinalityPartition,
+            message: format!(
+                "High-cardinality partition field '{}': {} distinct values averaging {:.1} records each. Consider a bucket or coarser time transform instead",
+                field.field, field.distinct_values, field.records_per_value
+            ),
+            metric_value: field.records_per_value,
+            threshold,
+            detected_at: Utc::now(),
+        }
+    }
+
+    /// Group live data files by the snapshot that added them, oldest write first. Snapshots
+    /// that have since been expired keep their id but have no time or operation, and sort
+    /// before the ones still in the metadata.
+    pub fn snapshot_contributions(
+        files: &[DataFileOrigin],
+        snapshots: &[Snapshot],
+    ) -> Vec<SnapshotContribution> {
+        let mut by_snapshot: HashMap<Option<u64>, SnapshotContribution> = HashMap::new();
+        for file in files {
+            let contribution = by_snapshot
+                .entry(file.added_snapshot_id)
+                .or_insert_with(|| {
+                    let snapshot = file.added_snapshot_id.and_then(|id| {
+                        snapshots.iter().find(|snapshot| snapshot.snapshot_id == id)
+                    });
+                    SnapshotContribution {
+                        snapshot_id: file.added_snapshot_id,
+                        timestamp_ms: snapshot.map(|snapshot| snapshot.timestamp_ms),
+                        operation: snapshot
+                            .and_then(|snapshot| snapshot.summary.as_ref())
+                            .map(|summary| summary.operation.clone()),
+                        file_count: 0,
+                        record_count: 0,
+                        total_size_bytes: 0,
+                        size_percent: 0.0,
+                    }
+                });
+            contribution.file_count += 1;
+            contribution.record_count += file.record_count;
+            contribution.total_size_bytes += file.file_size_bytes;
+        }
+
+        let table_size_bytes: u64 = files.iter().map(|file| file.file_size_bytes).sum();
+        let mut contributions: Vec<SnapshotContribution> = by_snapshot.into_values().collect();
+        for contribution in &mut contributions {
+            if table_size_bytes > 0 {
+                contribution.size_percent =
+                    contribution.total_size_bytes as f64 / table_size_bytes as f64 * 100.0;
+            }
+        }
+        contributions.sort_by_key(|contribution| {
+            (
+                contribution.timestamp_ms.unwrap_or(i64::MIN),
+                contribution.snapshot_id,
+            )
+        });
+        contributions
+    }
+
+    /// Sum up the live files not written with the current schema; `None` when every file is
+    pub fn schema_drift(
+        stats: &[SchemaFileStats],
+        current_schema_id: i32,
+    ) -> Option<SchemaDriftSummary> {
+        let outdated: Vec<&SchemaFileStats> = stats
+            .iter()
+            .filter(|s| s.schema_id != current_schema_id && s.file_count > 0)
+            .collect();
+        if outdated.is_empty() {
+            return None;
+        }
+
+        let total_size_bytes: u64 = outdated.iter().map(|s| s.total_size_bytes).sum();
+        let table_size_bytes: u64 = stats.iter().map(|s| s.total_size_bytes).sum();
+        Some(SchemaDriftSummary {
+            file_count: outdated.iter().map(|s| s.file_count).sum(),
+            record_count: outdated.iter().map(|s| s.record_count).sum(),
+            total_size_bytes,
+            size_percent: if table_size_bytes == 0 {
+                0.0
+            } else {
+                total_size_bytes as f64 / table_size_bytes as f64 * 100.0
+            },
+        })
+    }
+
+    /// Each field's share of the column data in `stats`, keyed by field id. Structs sum their
+    /// nested fields. Fixed-width columns also get a compression ratio, estimating the
+    /// uncompressed size as their non-null values times the type's plain-encoded width.
+    pub fn column_sizes(
+        stats: &[ColumnSizeStats],
+        fields: &[NestedField],
+    ) -> HashMap<i32, ColumnSize> {
+        let total_bytes: u64 = stats.iter().map(|s| s.compressed_bytes).sum();
+        let by_id: HashMap<i32, &ColumnSizeStats> = stats.iter().map(|s| (s.field_id, s)).collect();
+        let mut sizes = HashMap::new();
+        for field in fields {
+            Self::add_column_size(field, &by_id, total_bytes, &mut sizes);
+        }
+        sizes
+    }
+
+    fn add_column_size(
+        field: &NestedField,
+        by_id: &HashMap<i32, &ColumnSizeStats>,
+        total_bytes: u64,
+        sizes: &mut HashMap<i32, ColumnSize>,
+    ) -> Option<u64> {
+        let (compressed_bytes, compression_ratio) = match &field.field_type {
+            DataType::Struct { fields } => {
+                let nested: Vec<u64> = fields
+                    .iter()
+                    .filter_map(|nested| Self::add_column_size(nested, by_id, total_bytes, sizes))
+                    .collect();
+                if nested.is_empty() {
+                    return None;
+                }
+                (nested.into_iter().sum(), None)
+            }
+            data_type => {
+                let stats = by_id.get(&field.id)?;
+                let ratio = fixed_width_bytes(data_type)
+                    .zip(stats.value_count)
+                    .filter(|_| stats.compressed_bytes > 0)
+                    .map(|(width, values)| {
+                        values.saturating_sub(stats.null_count) as f64 * width
+                            / stats.compressed_bytes as f64
+                    });
+                (stats.compressed_bytes, ratio)
+            }
+        };
+        sizes.insert(
+            field.id,
+            ColumnSize {
+                compressed_bytes,
+                share_percent: if total_bytes == 0 {
+                    0.0
+                } else {
+                    compressed_bytes as f64 / total_bytes as f64 * 100.0
+                },
+                compression_ratio,
+            },
+        );
+        Some(compressed_bytes)
+    }
+
+    /// A compact `lower … upper` hint per field from the bounds in `stats`, keyed by field id.
+    /// Nested struct fields get their own; structs, lists, maps and binary columns, whose
+    /// bounds say nothing readable, get none.
+    pub fn column_ranges(
+        stats: &[ColumnSizeStats],
+        fields: &[NestedField],
+    ) -> HashMap<i32, String> {
+        let by_id: HashMap<i32, &ColumnSizeStats> = stats.iter().map(|s| (s.field_id, s)).collect();
+        let mut ranges = HashMap::new();
+        let mut pending: Vec<&NestedField> = fields.iter().collect();
+        while let Some(field) = pending.pop() {
+            match &field.field_type {
+                DataType::Struct { fields } => pending.extend(fields),
+                DataType::List { .. }
+                | DataType::Map { .. }
+                | DataType::Binary
+                | DataType::Fixed { .. } => {}
+                _ => {
+                    let Some(stats) = by_id.get(&field.id) else {
+                        continue;
+                    };
+                    let (Some(lower), Some(upper)) = (&stats.lower_bound, &stats.upper_bound)
+                    else {
+                        continue;
+                    };
+                    let range = if lower == upper {
+                        lower.clone()
+                    } else {
+                        format!("{} … {}", lower, upper)
+                    };
+                    ranges.insert(field.id, range);
+                }
+            }
+        }
+        ranges
+    }
+
+    /// What changed from `previous` to `current`, two metadata versions of the same table:
+    /// schema, partitioning, sort order, snapshots, branches and tags, properties and location
+    pub fn metadata_changes(
+        previous: &IcebergTable,
+        current: &IcebergTable,
+    ) -> Vec<MetadataChange> {
+        let mut changes = Vec::new();
+        let mut push = |kind: MetadataChangeKind, description: String| {
+            changes.push(MetadataChange { kind, description })
+        };
+
+        // Schema, matched by field id so renames aren't mistaken for drop + add
+        if previous.schema.schema_id != current.schema.schema_id {
+            push(
+                MetadataChangeKind::Schema,
+                format!(
+                    "Current schema changed from {} to {}",
+                    previous.schema.schema_id, current.schema.schema_id
+                ),
+            );
+        }
+        let old_fields = fields_by_id(&previous.schema.fields);
+        let new_fields = fields_by_id(&current.schema.fields);
+        for (id, (path, field)) in &new_fields {
+            let Some((old_path, old_field)) = old_fields.get(id) else {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!("Added column `{}` ({})", path, field.field_type.to_string()),
+                );
+                continue;
+            };
+            if old_path != path {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!("Renamed `{}` to `{}`", old_path, path),
+                );
+            }
+            let (old_type, new_type) = (
+                old_field.field_type.to_string(),
+                field.field_type.to_string(),
+            );
+            if old_type != new_type {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!("Changed `{}` from {} to {}", path, old_type, new_type),
+                );
+            }
+            if old_field.required != field.required {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!(
+                        "`{}` is now {}",
+                        path,
+                        if field.required {
+                            "required"
+                        } else {
+                            "optional"
+                        }
+                    ),
+                );
+            }
+        }
+        for (id, (path, _)) in &old_fields {
+            if !new_fields.contains_key(id) {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!("Dropped column `{}`", path),
+                );
+            }
+        }
+
+        let column = |source_id: i32| {
+            new_fields
+                .get(&source_id)
+                .or_else(|| old_fields.get(&source_id))
+                .map(|(path, _)| path.clone())
+                .unwrap_or_else(|| format!("field {}", source_id))
+        };
+        let transformed = |transform: &PartitionTransform, source_id: i32| match transform {
+            PartitionTransform::Identity => column(source_id),
+            transform => format!("{}({})", transform.to_string(), column(source_id)),
+        };
+
+        let (old_spec, new_spec) = (
+            describe_partition_spec(previous.partition_spec.as_ref(), &column),
+            describe_partition_spec(current.partition_spec.as_ref(), &column),
+        );
+        if old_spec != new_spec {
+            push(
+                MetadataChangeKind::Partitioning,
+                format!("Partitioning changed from {} to {}", old_spec, new_spec),
+            );
+        }
+
+        let describe_sort = |order: Option<&SortOrder>| match order {
+            Some(order) if !order.fields.is_empty() => order
+                .fields
+                .iter()
+                .map(|field| {
+                    let direction = match field.direction {
+                        SortDirection::Ascending => "asc",
+                        SortDirection::Descending => "desc",
+                    };
+                    format!(
+                        "{} {}",
+                        transformed(&field.transform, field.source_id),
+                        direction
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+            _ => "unsorted".to_string(),
+        };
+        let (old_sort, new_sort) = (
+            describe_sort(previous.sort_order.as_ref()),
+            describe_sort(current.sort_order.as_ref()),
+        );
+        if old_sort != new_sort {
+            push(
+                MetadataChangeKind::SortOrder,
+                format!("Sort order changed from {} to {}", old_sort, new_sort),
+            );
+        }
+
+        // Snapshots
+        let old_ids: HashSet<u64> = previous.snapshots.iter().map(|s| s.snapshot_id).collect();
+        let new_ids: HashSet<u64> = current.snapshots.iter().map(|s| s.snapshot_id).collect();
+        let mut added: Vec<&Snapshot> = current
+            .snapshots
+            .iter()
+            .filter(|snapshot| !old_ids.contains(&snapshot.snapshot_id))
+            .collect();
+        added.sort_by_key(|snapshot| snapshot.timestamp_ms);
+        for snapshot in &added {
+            let records = snapshot
+                .summary
+                .as_ref()
+                .and_then(|summary| summary.added_records.as_deref())
+                .filter(|records| *records != "0")
+                .map(|records| format!(", +{} records", records))
+                .unwrap_or_default();
+            push(
+                MetadataChangeKind::Snapshots,
+                format!(
+                    "Committed snapshot {} ({}{})",
+                    snapshot.snapshot_id,
+                    snapshot.operation(),
+                    records
+                ),
+            );
+        }
+        let expired = old_ids.difference(&new_ids).count();
+        if expired > 0 {
+            push(
+                MetadataChangeKind::Snapshots,
+                format!(
+                    "Expired {} snapshot{}",
+                    expired,
+                    if expired == 1 { "" } else { "s" }
+                ),
+            );
+        }
+        // A current snapshot that moved without a new commit is a rollback or cherry-pick
+        let committed_current = current
+            .current_snapshot_id
+            .is_some_and(|id| !old_ids.contains(&id));
+        if previous.current_snapshot_id != current.current_snapshot_id && !committed_current {
+            let describe = |id: Option<u64>| id.map_or("none".to_string(), |id| id.to_string());
+            push(
+                MetadataChangeKind::Snapshots,
+                format!(
+                    "Current snapshot moved from {} to {}",
+                    describe(previous.current_snapshot_id),
+                    describe(current.current_snapshot_id)
+                ),
+            );
+        }
+
+        // Branches and tags; `main` follows the current snapshot reported above
+        let ref_kind = |ref_type: SnapshotRefType| match ref_type {
+            SnapshotRefType::Branch => "branch",
+            SnapshotRefType::Tag => "tag",
+        };
+        for snapshot_ref in &current.refs {
+            match previous
+                .refs
+                .iter()
+                .find(|old| old.name == snapshot_ref.name)
+            {
+                None => push(
+                    MetadataChangeKind::Refs,
+                    format!(
+                        "Created {} `{}` at snapshot {}",
+                        ref_kind(snapshot_ref.ref_type),
+                        snapshot_ref.name,
+                        snapshot_ref.snapshot_id
+                    ),
+                ),
+                Some(old)
+                    if old.snapshot_id != snapshot_ref.snapshot_id
+                        && snapshot_ref.name != "main" =>
+                {
+                    push(
+                        MetadataChangeKind::Refs,
+                        format!(
+                            "Moved {} `{}` from snapshot {} to {}",
+                            ref_kind(snapshot_ref.ref_type),
+                            snapshot_ref.name,
+                            old.snapshot_id,
+                            snapshot_ref.snapshot_id
+                        ),
+                    )
+                }
+                Some(_) => {}
+            }
+        }
+        for old in &previous.refs {
+            if !current.refs.iter().any(|r| r.name == old.name) {
+                push(
+                    MetadataChangeKind::Refs,
+                    format!("Removed {} `{}`", ref_kind(old.ref_type), old.name),
+                );
+            }
+        }
+
+        // Properties, in key order
+        let keys: std::collections::BTreeSet<&String> = previous
+            .properties
+            .keys()
+            .chain(current.properties.keys())
+            .collect();
+        for key in keys {
+            match (previous.properties.get(key), current.properties.get(key)) {
+                (None, Some(value)) => push(
+                    MetadataChangeKind::Properties,
+                    format!("Set `{}` = `{}`", key, value),
+                ),
+                (Some(_), None) => {
+                    push(MetadataChangeKind::Properties, format!("Removed `{}`", key))
+                }
+                (Some(old), Some(value)) if old != value => push(
+                    MetadataChangeKind::Properties,
+                    format!("Changed `{}` from `{}` to `{}`", key, old, value),
+                ),
+                _ => {}
+            }
+        }
+
+        if previous.location != current.location {
+            push(
+                MetadataChangeKind::Location,
+                format!(
+                    "Location changed from {} to {}",
+                    previous.location, current.location
+                ),
+            );
+        }
+
+        changes
+    }
+
+    /// Schema and partitioning differences between the same table in two catalogs, e.g.
+    /// staging and prod. The copies were created separately, so columns are matched by
+    /// dotted path rather than field id, and column order is ignored.
+    pub fn environment_differences(
+        source: &IcebergTable,
+        target: &IcebergTable,
+    ) -> Vec<MetadataChange> {
+        let mut changes = Vec::new();
+        let mut push = |kind: MetadataChangeKind, description: String| {
+            changes.push(MetadataChange { kind, description })
+        };
+
+        let by_path = |table: &IcebergTable| -> std::collections::BTreeMap<String, NestedField> {
+            fields_by_id(&table.schema.fields)
+                .into_values()
+                .map(|(path, field)| (path, field.clone()))
+                .collect()
+        };
+        let (source_fields, target_fields) = (by_path(source), by_path(target));
+        for (path, field) in &source_fields {
+            let Some(target_field) = target_fields.get(path) else {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!("Column `{}` is missing from the target", path),
+                );
+                continue;
+            };
+            let (source_type, target_type) = (
+                field.field_type.to_string(),
+                target_field.field_type.to_string(),
+            );
+            if source_type != target_type {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!(
+                        "`{}` is {} in the source but {} in the target",
+                        path, source_type, target_type
+                    ),
+                );
+            }
+            if field.required != target_field.required {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!(
+                        "`{}` is {} in the target",
+                        path,
+                        if target_field.required {
+                            "required"
+                        } else {
+                            "optional"
+                        }
+                    ),
+                );
+            }
+        }
+        for path in target_fields.keys() {
+            if !source_fields.contains_key(path) {
+                push(
+                    MetadataChangeKind::Schema,
+                    format!("Column `{}` only exists in the target", path),
+                );
+            }
+        }
+
+        let column_of = |table: &IcebergTable| {
+            let fields = fields_by_id(&table.schema.fields)
+                .into_iter()
+                .map(|(id, (path, _))| (id, path))
+                .collect::<std::collections::BTreeMap<i32, String>>();
+            move |source_id: i32| {
+                fields
+                    .get(&source_id)
+                    .cloned()
+                    .unwrap_or_else(|| format!("field {}", source_id))
+            }
+        };
+        let (source_spec, target_spec) = (
+            describe_partition_spec(source.partition_spec.as_ref(), &column_of(source)),
+            describe_partition_spec(target.partition_spec.as_ref(), &column_of(target)),
+        );
+        if source_spec != target_spec {
+            push(
+                MetadataChangeKind::Partitioning,
+                format!(
+                    "Partitioned by {} in the source but {} in the target",
+                    source_spec, target_spec
+                ),
+            );
+        }
+
+        changes
+    }
+}
+
+/// Partition fields as `column` or `transform(column)`, or "unpartitioned"
+fn describe_partition_spec(spec: Option<&PartitionSpec>, column: &dyn Fn(i32) -> String) -> String {
+    match spec {
+        Some(spec) if !spec.fields.is_empty() => spec
+            .fields
+            .iter()
+            .map(|field| match &field.transform {
+                PartitionTransform::Identity => column(field.source_id),
+                transform => format!("{}({})", transform.to_string(), column(field.source_id)),
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => "unpartitioned".to_string(),
+    }
+}
+
+/// Every field by id with its dotted path, descending into structs
+fn fields_by_id(fields: &[NestedField]) -> std::collections::BTreeMap<i32, (String, &NestedField)> {
+    fn collect<'a>(
+        fields: &'a [NestedField],
+        prefix: &str,
+        out: &mut std::collections::BTreeMap<i32, (String, &'a NestedField)>,
+    ) {
+        for field in fields {
+            let path = if prefix.is_empty() {
+                field.name.clone()
+            } else {
+                format!("{}.{}", prefix, field.name)
+            };
+            if let DataType::Struct { fields } = &field.field_type {
+                collect(fields, &path, out);
+            }
+            out.insert(field.id, (path, field));
+        }
+    }
+    let mut out = std::collections::BTreeMap::new();
+    collect(fields, "", &mut out);
+    out
+}
+
+/// Bytes per value of a type's plain Parquet encoding; `None` for variable-width and nested types
+fn fixed_width_bytes(data_type: &DataType) -> Option<f64> {
+    match data_type {
+        DataType::Boolean => Some(0.125),
+        DataType::Integer | DataType::Float | DataType::Date => Some(4.0),
+        DataType::Long
+        | DataType::Double
+        | DataType::Time
+        | DataType::Timestamp
+        | DataType::TimestampTz
+        | DataType::TimestampNs
+        | DataType::TimestampTzNs => Some(8.0),
+        DataType::Uuid => Some(16.0),
+        DataType::Fixed { length } => Some(*length as f64),
+        DataType::Decimal { precision, .. } => Some(match precision {
+            0..=9 => 4.0,
+            10..=18 => 8.0,
+            _ => 16.0,
+        }),
+        DataType::String
+        | DataType::Binary
+        | DataType::Struct { .. }
+        | DataType::List { .. }
+        | DataType::Map { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partition(name: &str, size: u64) -> PartitionStats {
+        PartitionStats {
+            partition: name.to_string(),
+            values: Vec::new(),
+            spec_id: 0,
+            file_count: 1,
+            record_count: size,
+            total_size_bytes: size,
+        }
+    }
+
+    fn summarized_snapshot(id: u64, operation: &str, files: u64, size: u64) -> Snapshot {
+        Snapshot {
+            snapshot_id: id,
+            timestamp_ms: Utc::now().timestamp_millis() - (10 - id as i64) * 86_400_000,
+            summary: Some(Summary {
+                operation: operation.to_string(),
+                added_data_files: Some(files.to_string()),
+                deleted_data_files: None,
+                added_records: None,
+                deleted_records: None,
+                total_records: None,
+                added_files_size: None,
+                removed_files_size: None,
+                total_size: Some(size.to_string()),
+                properties: Default::default(),
+            }),
+            manifest_list: String::new(),
+            schema_id: Some(0),
+            parent_snapshot_id: None,
+        }
+    }
+
+    fn unsummarized_snapshot(id: u64) -> Snapshot {
+        Snapshot {
+            summary: None,
+            ..summarized_snapshot(id, "append", 0, 0)
+        }
+    }
+
+    fn table_with(snapshots: Vec<Snapshot>) -> IcebergTable {
+        let schema = TableSchema {
+            schema_id: 0,
+            fields: vec![],
+        };
+        IcebergTable {
+            name: "orders".to_string(),
+            namespace: "sales".to_string(),
+            catalog_name: "prod".to_string(),
+            location: "s3://bucket/sales/orders".to_string(),
+            schema: schema.clone(),
+            schemas: vec![schema],
+            snapshots,
+            current_snapshot_id: None,
+            properties: HashMap::new(),
+            partition_spec: None,
+            partition_specs: vec![],
+            sort_order: None,
+            refs: vec![],
+            statistics: vec![],
+            partition_statistics: vec![],
+            format_version: Some(2),
+            omitted_snapshots: 0,
+            metadata_size_bytes: None,
+            snapshot_log_entries: 0,
+        }
+    }
+
+    fn child_of(id: u64, parent: Option<u64>) -> Snapshot {
+        Snapshot {
+            parent_snapshot_id: parent,
+            ..summarized_snapshot(id, "append", 1, 1024)
+        }
+    }
+
+    fn branch(name: &str, snapshot_id: u64) -> SnapshotRef {
+        SnapshotRef {
+            name: name.to_string(),
+            snapshot_id,
+            ref_type: SnapshotRefType::Branch,
+        }
+    }
+
+    #[test]
+    fn test_file_health_uses_latest_summarized_snapshot() {
+        let snapshots = vec![
+            summarized_snapshot(1, "append", 4, 4 * 256 * 1024 * 1024),
+            unsummarized_snapshot(2),
+        ];
+
+        let file_health = TableAnalytics::compute_file_health(&snapshots);
+        assert_eq!(file_health.total_files, 4);
+        assert!((file_health.avg_file_size_mb - 256.0).abs() < 1e-9);
+        assert_eq!(file_health.small_file_ratio, 0.0);
+
+        let storage = TableAnalytics::compute_storage_efficiency(&snapshots);
+        assert!((storage.total_size_gb - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_operation_ratios_ignore_unsummarized_snapshots() {
+        let snapshots = vec![
+            summarized_snapshot(1, "append", 1, 1024),
+            unsummarized_snapshot(2),
+            summarized_snapshot(3, "delete", 1, 1024),
+            unsummarized_snapshot(4),
+        ];
+
+        let storage = TableAnalytics::compute_storage_efficiency(&snapshots);
+        assert_eq!(storage.delete_ratio, 0.5);
+        assert_eq!(storage.update_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_missing_summaries_are_reported() {
+        let table = table_with(vec![
+            summarized_snapshot(1, "append", 2, 2 * 128 * 1024 * 1024),
+            unsummarized_snapshot(2),
+            unsummarized_snapshot(3),
+        ]);
+
+        let metrics = TableAnalytics::compute_health_metrics(&table);
+        assert_eq!(metrics.snapshots_without_summary, 2);
+        let note = metrics
+            .alerts
+            .iter()
+            .find(|alert| alert.category == AlertCategory::IncompleteMetadata)
+            .expect("missing summaries should produce a note");
+        assert_eq!(note.severity, AlertSeverity::Info);
+        assert!(note.message.starts_with("2 of 3 snapshots"));
+        assert!(metrics.health_score.is_finite());
+    }
+
+    #[test]
+    fn test_fully_summarized_table_has_no_missing_summary_note() {
+        let table = table_with(vec![summarized_snapshot(1, "append", 1, 1024)]);
+
+        let metrics = TableAnalytics::compute_health_metrics(&table);
+        assert_eq!(metrics.snapshots_without_summary, 0);
+        assert!(
+            metrics
+                .alerts
+                .iter()
+                .all(|alert| alert.category != AlertCategory::IncompleteMetadata)
+        );
+    }
+
+    #[test]
+    fn test_unsummarized_only_table_has_empty_file_metrics() {
+        let table = table_with(vec![unsummarized_snapshot(1), unsummarized_snapshot(2)]);
+
+        let metrics = TableAnalytics::compute_health_metrics(&table);
+        assert_eq!(metrics.file_health.total_files, 0);
+        assert_eq!(metrics.file_health.avg_file_size_mb, 0.0);
+        assert_eq!(metrics.storage_efficiency.delete_ratio, 0.0);
+        assert!(
+            metrics
+                .alerts
+                .iter()
+                .any(|alert| alert.message.starts_with("None of the 2"))
+        );
+    }
+
+    #[test]
+    fn test_reachability_follows_parents() {
+        // 1 <- 2 <- 3 (main), 2 <- 4 (rolled back), 5 has an expired parent
+        let snapshots = vec![
+            child_of(1, None),
+            child_of(2, Some(1)),
+            child_of(3, Some(2)),
+            child_of(4, Some(2)),
+            child_of(5, Some(99)),
+        ];
+
+        let reachable = TableAnalytics::reachable_snapshot_ids(&snapshots, [3]);
+        assert_eq!(reachable, HashSet::from([1, 2, 3]));
+
+        let reachable = TableAnalytics::reachable_snapshot_ids(&snapshots, [3, 4, 5]);
+        assert_eq!(reachable, HashSet::from([1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn test_orphaned_snapshots_after_rollback() {
+        let mut table = table_with(vec![
+            child_of(1, None),
+            child_of(2, Some(1)),
+            child_of(3, Some(2)),
+            child_of(4, Some(3)),
+        ]);
+        // main was rolled back to 2, leaving 3 and 4 behind
+        table.current_snapshot_id = Some(2);
+        table.refs = vec![branch("main", 2)];
+
+        let orphaned = TableAnalytics::find_orphaned_snapshots(&table);
+        let ids: Vec<u64> = orphaned.iter().map(|o| o.snapshot_id).collect();
+        assert_eq!(ids, vec![4, 3]); // newest first
+        assert_eq!(orphaned[0].added_size_bytes, None);
+
+        // A tag keeps its lineage alive
+        table.refs.push(SnapshotRef {
+            name: "audit".to_string(),
+            snapshot_id: 3,
+            ref_type: SnapshotRefType::Tag,
+        });
+        let ids: Vec<u64> = TableAnalytics::find_orphaned_snapshots(&table)
+            .iter()
+            .map(|o| o.snapshot_id)
+            .collect();
+        assert_eq!(ids, vec![4]);
+    }
+
+    #[test]
+    fn test_missing_statistics_alert() {
+        let has_alert = |table: &IcebergTable| {
+            TableAnalytics::compute_health_metrics(table)
+                .alerts
+                .into_iter()
+                .find(|alert| alert.category == AlertCategory::MissingStatistics)
+        };
+        let statistics = |snapshot_id: u64| StatisticsFile {
+            snapshot_id,
+            statistics_path: format!("s3://bucket/stats-{}.puffin", snapshot_id),
+            file_size_in_bytes: 100,
+            blobs: vec![],
+        };
+
+        // Empty tables have nothing to analyze
+        let mut table = table_with(vec![child_of(1, None), child_of(2, Some(1))]);
+        assert!(has_alert(&table).is_none());
+
+        table.current_snapshot_id = Some(2);
+        let alert = has_alert(&table).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Warning);
+        let metrics = TableAnalytics::compute_health_metrics(&table);
+        assert!(
+            metrics
+                .recommendations
+                .iter()
+                .any(|rec| rec.action_type == MaintenanceActionType::ComputeStatistics)
+        );
+
+        table.statistics = vec![statistics(1)];
+        assert_eq!(has_alert(&table).unwrap().severity, AlertSeverity::Info);
+
+        table.statistics.push(statistics(2));
+        assert!(has_alert(&table).is_none());
+    }
+
+    #[test]
+    fn test_snapshot_active_at() {
+        let snapshots: Vec<Snapshot> = [(1, 1_000), (2, 5_000), (3, 3_000)]
+            .into_iter()
+            .map(|(id, timestamp_ms)| Snapshot {
+                timestamp_ms,
+                ..child_of(id, None)
+            })
+            .collect();
+        let active = |ts: i64| {
+            TableAnalytics::snapshot_active_at(&snapshots, ts).map(|snapshot| snapshot.snapshot_id)
+        };
+
+        assert_eq!(active(500), None);
+        assert_eq!(active(1_000), Some(1));
+        assert_eq!(active(4_000), Some(3));
+        assert_eq!(active(9_000), Some(2));
+    }
+
+    #[test]
+    fn test_no_refs_means_no_orphans() {
+        let table = table_with(vec![child_of(1, None), child_of(2, Some(1))]);
+        assert!(TableAnalytics::find_orphaned_snapshots(&table).is_empty());
+    }
+
+    #[test]
+    fn test_many_orphans_raise_an_alert() {
+        let mut snapshots = vec![child_of(1, None)];
+        for id in 2..=(HealthThresholds::ORPHANED_SNAPSHOTS_WARNING as u64 + 2) {
+            snapshots.push(child_of(id, Some(1)));
+        }
+        let mut table = table_with(snapshots);
+        table.current_snapshot_id = Some(1);
+
+        let metrics = TableAnalytics::compute_health_metrics(&table);
+        assert_eq!(
+            metrics.orphaned_snapshots.len(),
+            HealthThresholds::ORPHANED_SNAPSHOTS_WARNING + 1
+        );
+        assert!(
+            metrics
+                .alerts
+                .iter()
+                .any(|alert| alert.category == AlertCategory::OrphanedSnapshots
+                    && alert.severity == AlertSeverity::Warning)
+        );
+    }
+
+    #[test]
+    fn test_disabled_categories_are_not_scored() {
+        // A week-old append-only table: no compaction has ever run
+        let table = table_with(vec![summarized_snapshot(1, "append", 1, 256 * 1024 * 1024)]);
+
+        let all = TableAnalytics::compute_health_metrics(&table);
+        assert_eq!(all.health_score, 90.0);
+        assert_eq!(all.enabled_categories, HealthCategory::all());
+
+        let without_compaction = TableAnalytics::compute_health_metrics_for(
+            &table,
+            &[
+                HealthCategory::File,
+                HealthCategory::Operational,
+                HealthCategory::Storage,
+            ],
+            DEFAULT_LARGE_METADATA_WARNING_MB,
+            None,
+        );
+        assert_eq!(without_compaction.health_score, 100.0);
+        assert!(
+            !without_compaction
+                .enabled_categories
+                .contains(&HealthCategory::Compaction)
+        );
+    }
+
+    #[test]
+    fn test_score_is_renormalized_across_enabled_categories() {
+        let table = table_with(vec![summarized_snapshot(1, "append", 1, 256 * 1024 * 1024)]);
+
+        // Only compaction enabled: its 10 point penalty scales to the full 95 point range
+        let metrics = TableAnalytics::compute_health_metrics_for(
+            &table,
+            &[HealthCategory::Compaction],
+            DEFAULT_LARGE_METADATA_WARNING_MB,
+            None,
+        );
+        assert!((metrics.health_score - (100.0 - 10.0 * 95.0 / 25.0)).abs() < 1e-9);
+
+        let metrics = TableAnalytics::compute_health_metrics_for(
+            &table,
+            &[],
+            DEFAULT_LARGE_METADATA_WARNING_MB,
+            None,
+        );
+        assert_eq!(metrics.health_score, 100.0);
+    }
+
+    #[test]
+    fn test_partition_health_is_scored_once_read() {
+        let table = table_with(vec![summarized_snapshot(1, "append", 1, 256 * 1024 * 1024)]);
+        let hot = vec![
+            partition("a", 10),
+            partition("b", 10),
+            partition("c", 10),
+            partition("hot", 1000),
+        ];
+        let even = vec![partition("a", 100), partition("b", 100)];
+        let metrics = |enabled: &[HealthCategory], stats: Option<&[PartitionStats]>| {
+            TableAnalytics::compute_health_metrics_for(
+                &table,
+                enabled,
+                DEFAULT_LARGE_METADATA_WARNING_MB,
+                stats,
+            )
+        };
+
+        // Without manifests the score is the same as before partition health existed
+        let unread = metrics(&HealthCategory::ALL, None);
+        assert_eq!(unread.health_score, 90.0);
+        assert!(unread.partition_skew.is_none());
+
+        assert_eq!(
+            metrics(&HealthCategory::ALL, Some(&even)).health_score,
+            90.0
+        );
+
+        let skewed = metrics(&HealthCategory::ALL, Some(&hot));
+        assert_eq!(skewed.health_score, 75.0);
+        assert_eq!(skewed.partition_skew.unwrap().skew_ratio, 100.0);
+        assert!(
+            skewed
+                .alerts
+                .iter()
+                .any(|alert| alert.category == AlertCategory::PartitionSkew)
+        );
+
+        // Disabled, the skew neither moves the score nor raises an alert
+        let without_partition = [
+            HealthCategory::File,
+            HealthCategory::Operational,
+            HealthCategory::Storage,
+            HealthCategory::Compaction,
+        ];
+        let disabled = metrics(&without_partition, Some(&hot));
+        assert_eq!(disabled.health_score, 90.0);
+        assert!(
+            disabled
+                .alerts
+                .iter()
+                .all(|alert| alert.category != AlertCategory::PartitionSkew)
+        );
+    }
+
+    #[test]
+    fn test_freshness_classification() {
+        let thresholds = FreshnessThresholds {
+            aging_hours: 24.0,
+            stale_hours: 72.0,
+        };
+
+        assert_eq!(
+            TableAnalytics::classify_freshness(Some(2.0), &thresholds),
+            Freshness::Fresh
+        );
+        assert_eq!(
+            TableAnalytics::classify_freshness(Some(24.0), &thresholds),
+            Freshness::Aging
+        );
+        assert_eq!(
+            TableAnalytics::classify_freshness(Some(100.0), &thresholds),
+            Freshness::Stale
+        );
+        assert_eq!(
+            TableAnalytics::classify_freshness(None, &thresholds),
+            Freshness::Unknown
+        );
+    }
+
+    #[test]
+    fn test_hours_since_last_snapshot_uses_newest() {
+        let now = Utc::now();
+        let mut older = unsummarized_snapshot(1);
+        older.timestamp_ms = (now - Duration::hours(48)).timestamp_millis();
+        let mut newer = unsummarized_snapshot(2);
+        newer.timestamp_ms = (now - Duration::hours(3)).timestamp_millis();
+
+        // Order in the slice doesn't matter
+        let hours = TableAnalytics::hours_since_last_snapshot(&[newer, older], now).unwrap();
+        assert!((hours - 3.0).abs() < 0.1);
+        assert_eq!(TableAnalytics::hours_since_last_snapshot(&[], now), None);
+    }
+
+    #[test]
+    fn test_freshness_breaches_sorted_by_staleness() {
+        let check = |name: &str, hours: Option<f64>, freshness: Freshness| FreshnessCheck {
+            table: FavoriteTable {
+                catalog_name: "prod".to_string(),
+                namespace: "sales".to_string(),
+                table_name: name.to_string(),
+            },
+            hours_since_last_snapshot: hours,
+            freshness,
+            stale_hours: 72.0,
+            error: None,
+            missing: false,
+        };
+        let mut failed = check("failed", None, Freshness::Unknown);
+        failed.error = Some("forbidden".to_string());
+        let checks = vec![
+            check("aging", Some(30.0), Freshness::Aging),
+            check("fresh", Some(1.0), Freshness::Fresh),
+            check("stale", Some(80.0), Freshness::Stale),
+            check("staler", Some(200.0), Freshness::Stale),
+            check("empty", None, Freshness::Unknown),
+            failed,
+        ];
+
+        let names: Vec<&str> = TableAnalytics::freshness_breaches(&checks)
+            .iter()
+            .map(|check| check.table.table_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["staler", "stale", "aging"]);
+    }
+
+    #[test]
+    fn test_snapshot_contributions() {
+        let file = |snapshot_id: Option<u64>, size: u64| DataFileOrigin {
+            file_path: format!("s3://bucket/data/{:?}-{}.parquet", snapshot_id, size),
+            partition: "(unpartitioned)".to_string(),
+            added_snapshot_id: snapshot_id,
+            sequence_number: None,
+            record_count: 10,
+            file_size_bytes: size,
+        };
+        let mut first = summarized_snapshot(1, "append", 0, 0);
+        first.timestamp_ms = 1_000;
+        let mut second = summarized_snapshot(2, "overwrite", 0, 0);
+        second.timestamp_ms = 2_000;
+        let files = vec![
+            file(Some(2), 100),
+            file(Some(1), 200),
+            file(Some(2), 100),
+            file(Some(7), 600),
+        ];
+
+        let contributions = TableAnalytics::snapshot_contributions(&files, &[second, first]);
+        let ids: Vec<Option<u64>> = contributions.iter().map(|c| c.snapshot_id).collect();
+        // The expired snapshot 7 has no timestamp and sorts first
+        assert_eq!(ids, vec![Some(7), Some(1), Some(2)]);
+        assert_eq!(contributions[0].operation, None);
+        assert_eq!(contributions[2].operation.as_deref(), Some("overwrite"));
+        assert_eq!(contributions[2].file_count, 2);
+        assert_eq!(contributions[2].record_count, 20);
+        assert!((contributions[0].size_percent - 60.0).abs() < 1e-9);
+        assert!(TableAnalytics::snapshot_contributions(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_partition_skew_even() {
+        let stats = vec![
+            partition("a", 100),
+            partition("b", 100),
+            partition("c", 100),
+        ];
+        let skew = TableAnalytics::compute_partition_skew(&stats).unwrap();
+        assert_eq!(skew.skew_ratio, 1.0);
+        assert!(skew.gini_coefficient.abs() < 1e-9);
+        assert!(TableAnalytics::partition_skew_alert(&skew).is_none());
+    }
+
+    #[test]
+    fn test_partition_skew_hot_partition() {
+        let stats = vec![
+            partition("a", 10),
+            partition("b", 10),
+            partition("c", 10),
+            partition("hot", 1000),
+        ];
+        let skew = TableAnalytics::compute_partition_skew(&stats).unwrap();
+        assert_eq!(skew.median_size_bytes, 10);
+        assert_eq!(skew.max_size_bytes, 1000);
+        assert_eq!(skew.skew_ratio, 100.0);
+        assert!(skew.gini_coefficient > 0.7);
+        assert_eq!(skew.heaviest_partitions[0].partition, "hot");
+
+        let alert = TableAnalytics::partition_skew_alert(&skew).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert_eq!(alert.category, AlertCategory::PartitionSkew);
+    }
+
+    #[test]
+    fn test_high_cardinality_partition_fields() {
+        // Identity on an id: one record per partition value, next to a low-cardinality region
+        let stats: Vec<PartitionStats> = (0..2_000)
+            .map(|id| {
+                let region = if id % 2 == 0 { "eu" } else { "us" };
+                PartitionStats {
+                    partition: format!("region={}/user_id={}", region, id),
+                    values: vec![
+                        ("region".to_string(), region.to_string()),
+                        ("user_id".to_string(), id.to_string()),
+                    ],
+                    spec_id: 0,
+                    file_count: 1,
+                    record_count: 1,
+                    total_size_bytes: 1_024,
+                }
+            })
+            .collect();
+        let fields = TableAnalytics::high_cardinality_partition_fields(&stats);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field, "user_id");
+        assert_eq!(fields[0].distinct_values, 2_000);
+        assert_eq!(fields[0].records_per_value, 1.0);
+        let alert = TableAnalytics::high_cardinality_partition_alert(&fields[0]);
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert!(alert.message.contains("user_id"));
+
+        // Many partitions are fine when each holds plenty of records
+        let days: Vec<PartitionStats> = (0..1_500)
+            .map(|day| PartitionStats {
+                partition: format!("event_day={}", day),
+                values: vec![("event_day".to_string(), day.to_string())],
+                spec_id: 0,
+                file_count: 4,
+                record_count: 50_000,
+                total_size_bytes: 1_024,
+            })
+            .collect();
+        assert!(TableAnalytics::high_cardinality_partition_fields(&days).is_empty());
+
+        // Unpartitioned tables have no fields to judge
+        assert!(
+            TableAnalytics::high_cardinality_partition_fields(&[partition("(unpartitioned)", 5)])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_large_metadata_alert() {
+        let mut table = table_with(vec![]);
+        table.metadata_size_bytes = Some(2 * 1024 * 1024);
+        assert!(
+            TableAnalytics::large_metadata_alert(&table, DEFAULT_LARGE_METADATA_WARNING_MB)
+                .is_none()
+        );
+
+        table.metadata_size_bytes = Some(80 * 1024 * 1024);
+        let alert = TableAnalytics::large_metadata_alert(&table, DEFAULT_LARGE_METADATA_WARNING_MB)
+            .unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Warning);
+        assert_eq!(alert.metric_value, 80.0);
+        assert!(alert.message.contains("previous-versions-max"));
+
+        // The warning size follows the setting
+        let alert = TableAnalytics::large_metadata_alert(&table, 100).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Info);
+        let alert = TableAnalytics::large_metadata_alert(&table, 20).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Warning);
+        assert_eq!(alert.threshold, 20.0);
+
+        // Retained history alone is worth a note, even on a small document
+        table.metadata_size_bytes = Some(2 * 1024 * 1024);
+        table.snapshot_log_entries = 5_000;
+        let alert = TableAnalytics::large_metadata_alert(&table, DEFAULT_LARGE_METADATA_WARNING_MB)
+            .unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Info);
+        assert_eq!(alert.metric_value, 0.0);
+        assert!(alert.message.contains("5000 snapshot-log entries"));
+
+        let metrics = TableAnalytics::compute_health_metrics(&table);
+        assert!(
+            metrics
+                .recommendations
+                .iter()
+                .any(|rec| rec.action_type == MaintenanceActionType::MetadataCleanup)
+        );
+        let metrics = TableAnalytics::compute_health_metrics_for(
+            &table,
+            &[HealthCategory::File],
+            DEFAULT_LARGE_METADATA_WARNING_MB,
+            None,
+        );
+        assert!(
+            metrics
+                .alerts
+                .iter()
+                .all(|alert| alert.category != AlertCategory::LargeMetadata)
+        );
+    }
+
+    #[test]
+    fn test_append_window_summary() {
+        let child = |id: u64, operation: &str, parent: Option<u64>| Snapshot {
+            parent_snapshot_id: parent,
+            ..summarized_snapshot(id, operation, 1, 0)
+        };
+        // Listed out of commit order; ids follow commit time
+        let snapshots = vec![
+            child(4, "append", Some(3)),
+            child(1, "append", None),
+            child(3, "append", Some(2)),
+            child(2, "append", Some(1)),
+        ];
+        let ids = |range: Result<Vec<Snapshot>, String>| {
+            range
+                .unwrap()
+                .iter()
+                .map(|snapshot| snapshot.snapshot_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            ids(TableAnalytics::append_window_summary(&snapshots, 1, 4)),
+            vec![2, 3, 4]
+        );
+        assert_eq!(
+            ids(TableAnalytics::append_window_summary(&snapshots, 2, 3)),
+            vec![3]
+        );
+        // 'from' must be an ancestor of 'to', and both must exist
+        assert!(TableAnalytics::append_window_summary(&snapshots, 4, 1).is_err());
+        assert!(TableAnalytics::append_window_summary(&snapshots, 2, 2).is_err());
+        assert!(TableAnalytics::append_window_summary(&snapshots, 1, 9).is_err());
+
+        // An overwrite on another branch neither blocks the window nor is counted in it
+        let mut snapshots = snapshots;
+        snapshots.push(child(5, "overwrite", Some(3)));
+        snapshots.push(child(6, "append", Some(4)));
+        snapshots.push(child(7, "append", Some(5)));
+        assert_eq!(
+            ids(TableAnalytics::append_window_summary(&snapshots, 3, 6)),
+            vec![4, 6]
+        );
+        let error = TableAnalytics::append_window_summary(&snapshots, 4, 7).unwrap_err();
+        assert!(error.contains("Snapshot 4 is not an ancestor of snapshot 7"));
+
+        // Any non-append on the lineage rules the range out
+        let error = TableAnalytics::append_window_summary(&snapshots, 3, 7).unwrap_err();
+        assert!(error.contains("Snapshot 5 is a 'overwrite' operation"));
+        // 'from' is exclusive, so a window starting at the overwrite is still append-only
+        assert_eq!(
+            ids(TableAnalytics::append_window_summary(&snapshots, 5, 7)),
+            vec![7]
+        );
+    }
+
+    #[test]
+    fn test_partition_skew_needs_two_partitions() {
+        assert!(TableAnalytics::compute_partition_skew(&[]).is_none());
+        assert!(TableAnalytics::compute_partition_skew(&[partition("only", 5)]).is_none());
+
+        // An empty median partition doesn't divide by zero
+        let stats = vec![partition("a", 0), partition("b", 0), partition("c", 50)];
+        let skew = TableAnalytics::compute_partition_skew(&stats).unwrap();
+        assert_eq!(skew.skew_ratio, 50.0);
+    }
+
+    fn schema_files(schema_id: i32, file_count: u64, size: u64) -> SchemaFileStats {
+        SchemaFileStats {
+            schema_id,
+            file_count,
+            record_count: file_count * 100,
+            total_size_bytes: size,
+        }
+    }
+
+    #[test]
+    fn test_schema_drift() {
+        assert!(TableAnalytics::schema_drift(&[schema_files(2, 4, 400)], 2).is_none());
+        assert!(TableAnalytics::schema_drift(&[], 2).is_none());
+
+        let stats = vec![
+            schema_files(0, 1, 100),
+            schema_files(1, 2, 200),
+            schema_files(2, 5, 700),
+        ];
+        let drift = TableAnalytics::schema_drift(&stats, 2).unwrap();
+        assert_eq!(drift.file_count, 3);
+        assert_eq!(drift.record_count, 300);
+        assert_eq!(drift.total_size_bytes, 300);
+        assert!((drift.size_percent - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_column_sizes() {
+        let column = |field_id, compressed_bytes, value_count, null_count| ColumnSizeStats {
+            field_id,
+            compressed_bytes,
+            value_count,
+            null_count,
+            lower_bound: None,
+            upper_bound: None,
+        };
+        let stats = vec![
+            column(1, 400, Some(100), 0),
+            column(2, 500, None, 0),
+            column(4, 50, Some(100), 50),
+            column(5, 50, Some(100), 0),
+        ];
+        let fields = vec![
+            field(1, "id", DataType::Long),
+            field(2, "payload", DataType::String),
+            field(
+                3,
+                "meta",
+                DataType::Struct {
+                    fields: vec![
+                        field(4, "version", DataType::Integer),
+                        field(5, "source", DataType::String),
+                    ],
+                },
+            ),
+            field(6, "added_later", DataType::Binary),
+        ];
+
+        let sizes = TableAnalytics::column_sizes(&stats, &fields);
+        assert_eq!(sizes.len(), 5);
+        assert!(!sizes.contains_key(&6));
+
+        let id = &sizes[&1];
+        assert_eq!(id.compressed_bytes, 400);
+        assert!((id.share_percent - 40.0).abs() < 1e-9);
+        assert_eq!(id.compression_ratio, Some(2.0));
+
+        // Variable-width columns get no ratio
+        assert_eq!(sizes[&2].compression_ratio, None);
+        assert_eq!(sizes[&5].compression_ratio, None);
+
+        // Nulls don't count towards the uncompressed estimate
+        assert_eq!(sizes[&4].compression_ratio, Some(4.0));
+
+        let meta = &sizes[&3];
+        assert_eq!(meta.compressed_bytes, 100);
+        assert!((meta.share_percent - 10.0).abs() < 1e-9);
+        assert_eq!(meta.compression_ratio, None);
+
+        assert!(TableAnalytics::column_sizes(&[], &fields).is_empty());
+    }
+
+    #[test]
+    fn test_column_ranges() {
+        let bounded = |field_id, lower: Option<&str>, upper: Option<&str>| ColumnSizeStats {
+            field_id,
+            compressed_bytes: 100,
+            value_count: None,
+            null_count: 0,
+            lower_bound: lower.map(str::to_string),
+            upper_bound: upper.map(str::to_string),
+        };
+        let stats = vec![
+            bounded(1, Some("0"), Some("9999")),
+            bounded(2, Some("2021-01-01"), Some("2024-06-30")),
+            bounded(4, Some("\"eu\""), Some("\"eu\"")),
+            bounded(5, Some("0"), None),
+            bounded(6, Some("00"), Some("ff")),
+        ];
+        let fields = vec![
+            field(1, "id", DataType::Integer),
+            field(2, "event_day", DataType::Date),
+            field(
+                3,
+                "meta",
+                DataType::Struct {
+                    fields: vec![
+                        field(4, "region", DataType::String),
+                        field(5, "version", DataType::Integer),
+                    ],
+                },
+            ),
+            field(6, "checksum", DataType::Binary),
+        ];
+
+        let ranges = TableAnalytics::column_ranges(&stats, &fields);
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(ranges[&1], "0 … 9999");
+        assert_eq!(ranges[&2], "2021-01-01 … 2024-06-30");
+        // A single value shows once; a missing bound or a binary column shows nothing
+        assert_eq!(ranges[&4], "\"eu\"");
+        assert!(!ranges.contains_key(&3));
+        assert!(!ranges.contains_key(&5));
+        assert!(!ranges.contains_key(&6));
+    }
+
+    fn delta_snapshot(
+        id: u64,
+        operation: &str,
+        added_records: u64,
+        deleted_records: u64,
+        total_records: Option<u64>,
+    ) -> Snapshot {
+        let mut snapshot = summarized_snapshot(id, operation, 1, 0);
+        let summary = snapshot.summary.as_mut().unwrap();
+        summary.total_size = None;
+        summary.added_records = Some(added_records.to_string());
+        summary.deleted_records = Some(deleted_records.to_string());
+        summary.total_records = total_records.map(|t| t.to_string());
+        summary.added_files_size = Some((added_records * 10).to_string());
+        summary.removed_files_size = Some((deleted_records * 10).to_string());
+        snapshot
+    }
+
+    #[test]
+    fn test_cumulative_totals_follow_commit_order() {
+        // Deliberately out of order; totals must be accumulated oldest first
+        let snapshots = vec![
+            delta_snapshot(3, "overwrite", 20, 50, None),
+            delta_snapshot(1, "append", 100, 0, None),
+            unsummarized_snapshot(4),
+            delta_snapshot(2, "append", 30, 0, None),
+            delta_snapshot(5, "delete", 0, 10, None),
+        ];
+        let totals = TableAnalytics::cumulative_totals(&snapshots);
+
+        let records = |id: u64| totals[&id].records;
+        assert_eq!(records(1), Some(100));
+        assert_eq!(records(2), Some(130));
+        assert_eq!(records(3), Some(100));
+        // No summary: carried forward
+        assert_eq!(records(4), Some(100));
+        assert_eq!(records(5), Some(90));
+        assert_eq!(totals[&5].size_bytes, Some(900));
+    }
+
+    #[test]
+    fn test_cumulative_totals_prefer_reported_total() {
+        let snapshots = vec![
+            delta_snapshot(1, "append", 100, 0, None),
+            delta_snapshot(2, "overwrite", 5, 0, Some(40)),
+            delta_snapshot(3, "append", 10, 0, None),
+        ];
+        let totals = TableAnalytics::cumulative_totals(&snapshots);
+        assert_eq!(totals[&2].records, Some(40));
+        assert_eq!(totals[&3].records, Some(50));
+    }
+
+    #[test]
+    fn test_record_count_discrepancies() {
+        let mut double_write = delta_snapshot(3, "append", 30, 0, Some(190));
+        double_write.parent_snapshot_id = Some(2);
+        let mut no_counts = delta_snapshot(4, "overwrite", 0, 0, Some(500));
+        let summary = no_counts.summary.as_mut().unwrap();
+        summary.added_records = None;
+        summary.deleted_records = None;
+        no_counts.parent_snapshot_id = Some(3);
+        let mut lost_rows = delta_snapshot(5, "overwrite", 10, 20, Some(480));
+        lost_rows.summary.as_mut().unwrap().deleted_records = None;
+        lost_rows.parent_snapshot_id = Some(4);
+        let mut expired_parent = delta_snapshot(6, "append", 1, 0, Some(1));
+        expired_parent.parent_snapshot_id = Some(99);
+        let mut second = delta_snapshot(2, "append", 30, 0, Some(130));
+        second.parent_snapshot_id = Some(1);
+        let mut missing_total = delta_snapshot(8, "append", 5, 0, None);
+        missing_total.parent_snapshot_id = Some(7);
+
+        let snapshots = vec![
+            lost_rows,
+            delta_snapshot(1, "append", 100, 0, Some(100)),
+            double_write,
+            second,
+            no_counts,
+            expired_parent,
+            // A new root after snapshot 6 has no baseline, so 6's total isn't carried over
+            delta_snapshot(7, "append", 5, 0, Some(5)),
+            missing_total,
+        ];
+        let discrepancies = TableAnalytics::record_count_discrepancies(&snapshots);
+
+        assert_eq!(discrepancies.len(), 2);
+        assert_eq!(discrepancies[0].snapshot_id, 3);
+        assert_eq!(discrepancies[0].previous_snapshot_id, 2);
+        assert_eq!(discrepancies[0].expected_records, 160);
+        assert_eq!(discrepancies[0].difference(), 30);
+        assert_eq!(discrepancies[1].snapshot_id, 5);
+        assert_eq!(discrepancies[1].expected_records, 510);
+        assert_eq!(discrepancies[1].difference(), -30);
+    }
+
+    #[test]
+    fn test_writer_groups() {
+        let with_properties = |id: u64, properties: &[(&str, &str)]| {
+            let mut snapshot = delta_snapshot(id, "append", 10, 0, None);
+            snapshot.summary.as_mut().unwrap().properties = properties
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            snapshot
+        };
+        let snapshots = vec![
+            with_properties(1, &[("spark.app.id", "app-1")]),
+            with_properties(2, &[("engine-name", "flink"), ("engine-version", "1.18")]),
+            with_properties(3, &[("engine-name", "spark"), ("spark.app.id", "app-2")]),
+            with_properties(4, &[]),
+            with_properties(5, &[("spark.app.id", "app-3")]),
+            unsummarized_snapshot(6),
+        ];
+
+        assert_eq!(snapshots[1].writer().as_deref(), Some("flink 1.18"));
+        assert_eq!(snapshots[2].writer().as_deref(), Some("spark"));
+
+        let groups = TableAnalytics::writer_groups(&snapshots);
+        let summary: Vec<(Option<&str>, usize)> = groups
+            .iter()
+            .map(|g| (g.writer.as_deref(), g.snapshot_count))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(Some("spark"), 3), (Some("flink 1.18"), 1), (None, 2)]
+        );
+        assert_eq!(groups[0].records_added, 30);
+        assert_eq!(groups[0].last_timestamp_ms, snapshots[4].timestamp_ms);
+    }
+
+    #[test]
+    fn test_changes_since() {
+        let mut snapshots: Vec<Snapshot> = (1..=4)
+            .map(|id| delta_snapshot(id, "append", id * 10, 0, None))
+            .collect();
+        for snapshot in &mut snapshots[1..] {
+            snapshot.parent_snapshot_id = Some(snapshot.snapshot_id - 1);
+        }
+        let seen = |snapshot: &Snapshot| LastSeenSnapshot {
+            snapshot_id: snapshot.snapshot_id,
+            timestamp_ms: snapshot.timestamp_ms,
+        };
+
+        assert!(TableAnalytics::changes_since(&snapshots, Some(4), seen(&snapshots[3])).is_none());
+
+        let changes =
+            TableAnalytics::changes_since(&snapshots, Some(4), seen(&snapshots[1])).unwrap();
+        let ids: Vec<u64> = changes
+            .new_snapshots
+            .iter()
+            .map(|s| s.snapshot_id)
+            .collect();
+        assert_eq!(ids, vec![4, 3]);
+        assert_eq!(changes.records_added, 70);
+        assert_eq!(changes.data_files_added, 2);
+        assert!(!changes.last_seen_expired);
+
+        // Snapshot 2 was expired: everything committed after it still counts
+        let last_seen = seen(&snapshots[1]);
+        snapshots.remove(1);
+        let changes = TableAnalytics::changes_since(&snapshots, Some(4), last_seen).unwrap();
+        assert_eq!(changes.new_snapshots.len(), 2);
+        assert!(changes.last_seen_expired);
+
+        // Rolled back to a snapshot older than the one last seen
+        assert!(TableAnalytics::changes_since(&snapshots, Some(1), last_seen).is_none());
+    }
+
+    fn field(id: i32, name: &str, field_type: DataType) -> NestedField {
+        NestedField {
+            id,
+            name: name.to_string(),
+            required: false,
+            field_type,
+            doc: None,
+            initial_default: None,
+            write_default: None,
+        }
+    }
+
+    #[test]
+    fn test_metadata_changes() {
+        let mut previous = table_with(vec![summarized_snapshot(1, "append", 1, 1024)]);
+        previous.current_snapshot_id = Some(1);
+        previous.schema.fields = vec![
+            field(1, "id", DataType::Integer),
+            field(2, "ts", DataType::Timestamp),
+            field(3, "note", DataType::String),
+        ];
+        previous
+            .properties
+            .insert("owner".to_string(), "sales".to_string());
+        previous
+            .properties
+            .insert("comment".to_string(), "Orders".to_string());
+
+        let mut current = previous.clone();
+        current.schema.schema_id = 1;
+        current.schema.fields = vec![
+            field(1, "id", DataType::Long),
+            field(2, "event_ts", DataType::Timestamp),
+            field(
+                4,
+                "amount",
+                DataType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                },
+            ),
+        ];
+        current.partition_spec = Some(PartitionSpec {
+            spec_id: 1,
+            fields: vec![PartitionField {
+                source_id: 2,
+                field_id: 1000,
+                name: "event_ts_day".to_string(),
+                transform: PartitionTransform::Day,
+            }],
+        });
+        let mut snapshot = summarized_snapshot(2, "append", 1, 2048);
+        snapshot.summary.as_mut().unwrap().added_records = Some("25".to_string());
+        current.snapshots.push(snapshot);
+        current.current_snapshot_id = Some(2);
+        current.refs = vec![branch("audit", 2)];
+        current
+            .properties
+            .insert("owner".to_string(), "finance".to_string());
+        current.properties.remove("comment");
+
+        let descriptions: Vec<String> = TableAnalytics::metadata_changes(&previous, &current)
+            .into_iter()
+            .map(|change| format!("{}: {}", change.kind.label(), change.description))
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                "Schema: Current schema changed from 0 to 1",
+                "Schema: Changed `id` from int to long",
+                "Schema: Renamed `ts` to `event_ts`",
+                "Schema: Added column `amount` (decimal(10, 2))",
+                "Schema: Dropped column `note`",
+                "Partitioning: Partitioning changed from unpartitioned to day(event_ts)",
+                "Snapshots: Committed snapshot 2 (append, +25 records)",
+                "Branches & tags: Created branch `audit` at snapshot 2",
+                "Properties: Removed `comment`",
+                "Properties: Changed `owner` from `sales` to `finance`",
+            ]
+        );
+        assert!(TableAnalytics::metadata_changes(&current, &current).is_empty());
+
+        // Rolling back moves the current snapshot without a new commit
+        let mut rolled_back = current.clone();
+        rolled_back.current_snapshot_id = Some(1);
+        assert_eq!(
+            TableAnalytics::metadata_changes(&current, &rolled_back)[0].description,
+            "Current snapshot moved from 2 to 1"
+        );
+    }
+
+    #[test]
+    fn test_environment_differences() {
+        let mut source = table_with(vec![]);
+        source.schema.fields = vec![
+            field(1, "id", DataType::Long),
+            field(2, "ts", DataType::Timestamp),
+            field(3, "note", DataType::String),
+        ];
+        source.partition_spec = Some(PartitionSpec {
+            spec_id: 0,
+            fields: vec![PartitionField {
+                source_id: 2,
+                field_id: 1000,
+                name: "ts_day".to_string(),
+                transform: PartitionTransform::Day,
+            }],
+        });
+
+        // Same columns under different ids and order still match
+        let mut target = source.clone();
+        target.schema.fields = vec![
+            field(7, "ts", DataType::Timestamp),
+            field(8, "id", DataType::Long),
+            field(9, "note", DataType::String),
+        ];
+        target.partition_spec.as_mut().unwrap().fields[0].source_id = 7;
+        assert!(TableAnalytics::environment_differences(&source, &target).is_empty());
+
+        target.schema.fields = vec![
+            field(7, "ts", DataType::Timestamp),
+            field(8, "id", DataType::Integer),
+            field(10, "region", DataType::String),
+        ];
+        target.partition_spec = None;
+        let descriptions: Vec<String> = TableAnalytics::environment_differences(&source, &target)
+            .into_iter()
+            .map(|change| format!("{}: {}", change.kind.label(), change.description))
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                "Schema: `id` is long in the source but int in the target",
+                "Schema: Column `note` is missing from the target",
+                "Schema: Column `region` only exists in the target",
+                "Partitioning: Partitioned by day(ts) in the source but unpartitioned in the target",
+            ]
+        );
+    }
 }
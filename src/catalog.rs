@@ -1,14 +1,23 @@
 use anyhow::Result;
-use iceberg::table::Table;
+use iceberg::io::FileIO;
+use iceberg::spec::ViewMetadata;
+use iceberg::table::{StaticTable, Table};
 use iceberg::{Catalog, NamespaceIdent, TableIdent};
 use iceberg_catalog_glue::{GlueCatalog, GlueCatalogConfig};
 use iceberg_catalog_rest::{RestCatalog, RestCatalogConfig};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use url::Url;
 
-use crate::config::AppConfig;
+use crate::config::{AppConfig, AppSettings, FreshnessThresholds, ProxySettings};
+use crate::data::{
+    ColumnSizeStats, DataFileOrigin, FavoriteTable, IcebergTable, LastSeenSnapshot, PartitionStats,
+    SchemaFileStats, TableStats, ValidationCheck, ValidationStatus,
+};
+use crate::glue::{GlueConnectOptions, ReadOnlyGlueCatalog};
+use crate::views::RestViewClient;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CatalogType {
@@ -21,17 +30,79 @@ pub struct CatalogConfig {
     pub catalog_type: CatalogType,
     pub name: String,
     pub config: HashMap<String, String>,
+    /// Optional label used to group catalogs (e.g. "prod", "staging") in the navigation pane
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Optional namespace filter applied when listing namespaces, see [`namespace_matches_filter`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace_filter: Option<String>,
+    /// Pinned catalogs connect at startup and are never disconnected for being idle
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct CatalogConnection {
     pub config: CatalogConfig,
     pub catalog: Arc<dyn Catalog>,
+    pub connected_at: chrono::DateTime<chrono::Utc>,
+    /// Region reported by the warehouse bucket. Probed in the background once a Glue catalog
+    /// on AWS S3 has connected, so it stays empty until the probe answers.
+    pub warehouse_bucket_region: Arc<std::sync::OnceLock<String>>,
+    /// When a token obtained by exchange at connect time expires
+    pub token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// View endpoints of a REST catalog; Glue catalogs have none
+    pub views: Option<Arc<RestViewClient>>,
 }
 
+/// A REST catalog client and the view client sharing its credentials
+struct RestConnection {
+    catalog: RestCatalog,
+    views: RestViewClient,
+    /// When a token obtained by exchange at connect time expires
+    token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Exchanged tokens are renewed this long before they expire, so no request races the expiry
+const TOKEN_REFRESH_MARGIN_SECS: i64 = 60;
+
+impl CatalogConnection {
+    /// Whether the connection's exchanged token is expired or about to, so the catalog has to
+    /// reconnect for a new one
+    pub fn token_expired(&self) -> bool {
+        token_expired_at(self.token_expires_at, chrono::Utc::now())
+    }
+
+    /// The warehouse bucket's region when it differs from the Glue region, so every data
+    /// read crosses regions
+    pub fn cross_region_bucket(&self) -> Option<&str> {
+        let bucket_region = self.warehouse_bucket_region.get()?;
+        (self.config.catalog_type == CatalogType::Glue
+            && !bucket_region.eq_ignore_ascii_case(&self.config.glue_region()))
+        .then_some(bucket_region)
+    }
+}
+
+/// Whether a token expiring at `expires_at` is within the refresh margin of `now`. Tokens
+/// without an expiry never expire.
+fn token_expired_at(
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> bool {
+    expires_at.is_some_and(|expires_at| {
+        now + chrono::Duration::seconds(TOKEN_REFRESH_MARGIN_SECS) >= expires_at
+    })
+}
+
+/// Pseudo catalog that tables opened straight from a `metadata.json` file belong to. Their
+/// namespace is the file's directory and their name the file name.
+pub const METADATA_FILE_CATALOG: &str = "Metadata file";
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum TableType {
     Iceberg,
+    /// An Iceberg view, listed by the catalog's view endpoints
+    View,
     Unknown,
 }
 
@@ -43,14 +114,19 @@ pub struct TableReference {
     pub table_type: TableType,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CatalogError {
     ConnectionFailed(String),
     InvalidConfig(String),
     TableNotFound(String),
+    /// A table's metadata could not be loaded, with the cause read from the underlying error
+    TableLoadFailed(String, LoadFailureKind),
+    /// A loaded table's manifests could not be read, classified the same way as a load
+    ManifestReadFailed(String, LoadFailureKind),
     NamespaceNotFound(String),
     AuthenticationFailed(String),
     NetworkError(String),
+    ProxyError(String),
 }
 
 impl std::fmt::Display for CatalogError {
@@ -59,15 +135,178 @@ impl std::fmt::Display for CatalogError {
             CatalogError::ConnectionFailed(msg) => write!(f, "Connection failed: {}", msg),
             CatalogError::InvalidConfig(msg) => write!(f, "Invalid configuration: {}", msg),
             CatalogError::TableNotFound(msg) => write!(f, "Table not found: {}", msg),
+            CatalogError::TableLoadFailed(msg, _) => write!(f, "Table load failed: {}", msg),
+            CatalogError::ManifestReadFailed(msg, _) => write!(f, "Manifest read failed: {}", msg),
             CatalogError::NamespaceNotFound(msg) => write!(f, "Namespace not found: {}", msg),
             CatalogError::AuthenticationFailed(msg) => write!(f, "Authentication failed: {}", msg),
             CatalogError::NetworkError(msg) => write!(f, "Network error: {}", msg),
+            CatalogError::ProxyError(msg) => write!(f, "Proxy error: {}", msg),
         }
     }
 }
 
 impl std::error::Error for CatalogError {}
 
+impl CatalogError {
+    /// The underlying message, without the variant's prefix
+    pub fn message(&self) -> &str {
+        match self {
+            CatalogError::ConnectionFailed(msg)
+            | CatalogError::InvalidConfig(msg)
+            | CatalogError::TableNotFound(msg)
+            | CatalogError::NamespaceNotFound(msg)
+            | CatalogError::AuthenticationFailed(msg)
+            | CatalogError::NetworkError(msg)
+            | CatalogError::ProxyError(msg)
+            | CatalogError::TableLoadFailed(msg, _)
+            | CatalogError::ManifestReadFailed(msg, _) => msg,
+        }
+    }
+
+    /// Why a table or its manifests failed to load; other errors are [`LoadFailureKind::Other`]
+    pub fn load_failure_kind(&self) -> LoadFailureKind {
+        match self {
+            CatalogError::TableLoadFailed(_, kind) | CatalogError::ManifestReadFailed(_, kind) => {
+                *kind
+            }
+            _ => LoadFailureKind::Other,
+        }
+    }
+}
+
+/// Why a listed table failed to load, for triaging a broken-tables scan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LoadFailureKind {
+    MissingMetadata,
+    AccessDenied,
+    ParseError,
+    Other,
+}
+
+impl LoadFailureKind {
+    /// Classify a load error by its kind and the storage or parser errors it wraps. Messages
+    /// are never inspected: they carry table names and paths that would be misread as causes.
+    pub fn from_error(error: &iceberg::Error) -> Self {
+        if matches!(error.kind(), iceberg::ErrorKind::DataInvalid) {
+            return LoadFailureKind::ParseError;
+        }
+        let mut source = std::error::Error::source(error);
+        while let Some(cause) = source {
+            if let Some(io_error) = cause.downcast_ref::<opendal::Error>() {
+                match io_error.kind() {
+                    opendal::ErrorKind::NotFound => return LoadFailureKind::MissingMetadata,
+                    opendal::ErrorKind::PermissionDenied => return LoadFailureKind::AccessDenied,
+                    _ => {}
+                }
+            }
+            if let Some(http_error) = cause.downcast_ref::<reqwest::Error>() {
+                match http_error.status().map(|status| status.as_u16()) {
+                    Some(401 | 403) => return LoadFailureKind::AccessDenied,
+                    Some(404) => return LoadFailureKind::MissingMetadata,
+                    _ => {}
+                }
+            }
+            if cause.downcast_ref::<serde_json::Error>().is_some() {
+                return LoadFailureKind::ParseError;
+            }
+            source = cause.source();
+        }
+        LoadFailureKind::Other
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LoadFailureKind::MissingMetadata => "Missing metadata",
+            LoadFailureKind::AccessDenied => "Access denied",
+            LoadFailureKind::ParseError => "Unreadable metadata",
+            LoadFailureKind::Other => "Other error",
+        }
+    }
+}
+
+/// Why a saved catalog was disconnected without the user asking. Each one reconnects on next
+/// use rather than surfacing a dead connection's error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoDisconnect {
+    /// No list or load for longer than the idle auto-disconnect limit
+    Idle,
+    /// The computer slept, so sockets and tokens are probably stale
+    Suspended,
+    /// A request failed because the connection had been dropped
+    ConnectionLost,
+}
+
+impl AutoDisconnect {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AutoDisconnect::Idle => "Disconnected after being idle; reconnects on next use",
+            AutoDisconnect::Suspended => {
+                "Disconnected while the computer was asleep; reconnects on next use"
+            }
+            AutoDisconnect::ConnectionLost => "Connection dropped; reconnects on next use",
+        }
+    }
+}
+
+/// Wall-clock time beyond the expected tick that counts as the computer having slept
+pub const SLEEP_GAP_THRESHOLD: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Whether the gap between two wall-clock readings taken `tick` apart means the machine was
+/// suspended in between. The monotonic clock can stop during sleep, so wall-clock time is used.
+pub fn slept_between(
+    previous: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+    tick: std::time::Duration,
+) -> bool {
+    (now - previous)
+        .to_std()
+        .is_ok_and(|gap| gap > tick + SLEEP_GAP_THRESHOLD)
+}
+
+/// Whether an error means the underlying connection was dead (reset, closed or a broken
+/// pipe) rather than the request itself failing, so reconnecting and retrying can succeed
+pub fn is_stale_connection_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    [
+        "connection reset",
+        "connection closed",
+        "connection aborted",
+        "broken pipe",
+        "econnreset",
+        "epipe",
+        "connection was closed",
+        "channel closed",
+        "incompletemessage",
+        "incomplete message",
+    ]
+    .iter()
+    .any(|marker| message.contains(marker))
+}
+
+/// Whether a storage error means the credentials used for it have expired, e.g. scoped
+/// credentials a REST catalog vended with the table, so reloading the table can fix it
+pub fn is_expired_credentials_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    [
+        "expiredtoken",
+        "token has expired",
+        "token is expired",
+        "credentials have expired",
+        "authenticationexpired",
+    ]
+    .iter()
+    .any(|marker| message.contains(marker))
+}
+
+fn manifest_read_error(e: anyhow::Error) -> CatalogError {
+    let kind = e
+        .downcast_ref::<iceberg::Error>()
+        .map_or(LoadFailureKind::Other, LoadFailureKind::from_error);
+    let error = format!("Failed to read manifests: {:#}", e);
+    log::error!("{}", error);
+    CatalogError::ManifestReadFailed(error, kind)
+}
+
 impl CatalogConfig {
     pub fn new_rest(name: String, uri: String) -> Self {
         let mut config = HashMap::new();
@@ -77,6 +316,9 @@ impl CatalogConfig {
             catalog_type: CatalogType::Rest,
             name,
             config,
+            group: None,
+            namespace_filter: None,
+            pinned: false,
         }
     }
 
@@ -91,21 +333,229 @@ impl CatalogConfig {
             catalog_type: CatalogType::Glue,
             name,
             config,
+            group: None,
+            namespace_filter: None,
+            pinned: false,
+        }
+    }
+}
+
+/// Caps how many catalog requests are in flight at once, shared by every list/load operation
+struct RequestLimiter {
+    max_concurrent: usize,
+    semaphore: Arc<tokio::sync::Semaphore>,
+    queued: Arc<AtomicUsize>,
+}
+
+impl RequestLimiter {
+    fn new(max_concurrent: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        Self {
+            max_concurrent,
+            semaphore: Arc::new(tokio::sync::Semaphore::new(max_concurrent)),
+            queued: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    async fn acquire(&self) -> Result<tokio::sync::OwnedSemaphorePermit, CatalogError> {
+        self.queued.fetch_add(1, Ordering::SeqCst);
+        let permit = self.semaphore.clone().acquire_owned().await;
+        self.queued.fetch_sub(1, Ordering::SeqCst);
+        permit.map_err(|e| CatalogError::NetworkError(format!("Request limiter closed: {}", e)))
+    }
+}
+
+/// Snapshot of catalog request activity for the UI
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RequestActivity {
+    pub in_flight: usize,
+    pub queued: usize,
+}
+
+/// Catalog requests tracked by the request metrics panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CatalogOperation {
+    ListNamespaces,
+    ListTables,
+    LoadTable,
+    /// Cheap existence probe before opening a table from a possibly stale reference
+    TableExists,
+    /// Reading manifests for partition or schema file statistics
+    Scan,
+    ListViews,
+    LoadView,
+}
+
+impl CatalogOperation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CatalogOperation::ListNamespaces => "list_namespaces",
+            CatalogOperation::ListTables => "list_tables",
+            CatalogOperation::LoadTable => "load_table",
+            CatalogOperation::TableExists => "table_exists",
+            CatalogOperation::Scan => "scan",
+            CatalogOperation::ListViews => "list_views",
+            CatalogOperation::LoadView => "load_view",
         }
     }
 }
 
+/// Latencies kept per catalog and operation; older samples are dropped from percentiles
+const METRICS_SAMPLE_LIMIT: usize = 500;
+
+#[derive(Debug, Default)]
+struct OperationSamples {
+    requests: usize,
+    errors: usize,
+    latencies_ms: std::collections::VecDeque<u64>,
+}
+
+/// Request counts and latency percentiles for one operation against one catalog
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationMetrics {
+    pub catalog_name: String,
+    pub operation: CatalogOperation,
+    pub requests: usize,
+    pub errors: usize,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+}
+
+/// Nearest-rank percentile of unsorted samples, 0 when there are none
+pub fn latency_percentile(samples: &[u64], percentile: f64) -> u64 {
+    if samples.is_empty() {
+        return 0;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    let rank = ((percentile / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// A table as currently loaded and as of the metadata file before the current one
+pub struct MetadataVersions {
+    pub current: Table,
+    pub previous: Option<PreviousMetadata>,
+}
+
+/// An earlier metadata file from a table's metadata log
+pub struct PreviousMetadata {
+    pub location: String,
+    pub timestamp_ms: i64,
+    pub table: Table,
+}
+
 pub struct CatalogManager {
     connections: Vec<CatalogConnection>,
     config: AppConfig,
+    request_limiter: RequestLimiter,
+    /// Catalogs whose namespace filter is temporarily bypassed ("show all")
+    unfiltered_catalogs: HashSet<String>,
+    /// Storage properties (e.g. S3 credentials) for opened metadata files, by file location
+    metadata_file_props: HashMap<String, HashMap<String, String>>,
+    /// Request samples by catalog and operation, recorded while enabled in settings
+    request_metrics: std::sync::Mutex<HashMap<(String, CatalogOperation), OperationSamples>>,
+    /// Last list or load per catalog, for the idle auto-disconnect
+    last_activity: std::sync::Mutex<HashMap<String, std::time::Instant>>,
+    /// Catalogs disconnected automatically, and why; they reconnect on next use
+    auto_disconnected: HashMap<String, AutoDisconnect>,
+    /// Catalogs whose connection was found dead by a request. Set through a shared borrow, as
+    /// other requests may still hold the manager; the next connect check replaces them.
+    stale_connections: std::sync::Mutex<HashSet<String>>,
+    /// Per-catalog locks serializing on-demand connects, see [`CatalogManager::connect_lock`]
+    connect_locks: std::sync::Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
 }
 
 impl CatalogManager {
     pub fn new() -> Self {
         let config = AppConfig::load().unwrap_or_default();
+        let request_limiter = RequestLimiter::new(config.settings.max_concurrent_requests);
         Self {
             connections: Vec::new(),
             config,
+            request_limiter,
+            unfiltered_catalogs: HashSet::new(),
+            metadata_file_props: HashMap::new(),
+            request_metrics: std::sync::Mutex::new(HashMap::new()),
+            last_activity: std::sync::Mutex::new(HashMap::new()),
+            auto_disconnected: HashMap::new(),
+            stale_connections: std::sync::Mutex::new(HashSet::new()),
+            connect_locks: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How many catalog requests are running and how many are waiting for a free slot
+    pub fn request_activity(&self) -> RequestActivity {
+        let limiter = &self.request_limiter;
+        RequestActivity {
+            in_flight: limiter
+                .max_concurrent
+                .saturating_sub(limiter.semaphore.available_permits()),
+            queued: limiter.queued.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Record one catalog request, if request metrics are enabled
+    fn record_request(
+        &self,
+        catalog_name: &str,
+        operation: CatalogOperation,
+        started: std::time::Instant,
+        succeeded: bool,
+    ) {
+        if !self.config.settings.collect_request_metrics {
+            return;
+        }
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        let Ok(mut metrics) = self.request_metrics.lock() else {
+            return;
+        };
+        let samples = metrics
+            .entry((catalog_name.to_string(), operation))
+            .or_default();
+        samples.requests += 1;
+        if !succeeded {
+            samples.errors += 1;
+        }
+        if samples.latencies_ms.len() == METRICS_SAMPLE_LIMIT {
+            samples.latencies_ms.pop_front();
+        }
+        samples.latencies_ms.push_back(elapsed_ms);
+    }
+
+    /// Note activity on a catalog so the idle auto-disconnect leaves it alone
+    fn touch(&self, catalog_name: &str) {
+        if let Ok(mut activity) = self.last_activity.lock() {
+            activity.insert(catalog_name.to_string(), std::time::Instant::now());
+        }
+    }
+
+    /// Recorded request metrics, sorted by catalog then operation
+    pub fn request_metrics(&self) -> Vec<OperationMetrics> {
+        let Ok(metrics) = self.request_metrics.lock() else {
+            return Vec::new();
+        };
+        let mut rows: Vec<OperationMetrics> = metrics
+            .iter()
+            .map(|((catalog_name, operation), samples)| {
+                let latencies: Vec<u64> = samples.latencies_ms.iter().copied().collect();
+                OperationMetrics {
+                    catalog_name: catalog_name.clone(),
+                    operation: *operation,
+                    requests: samples.requests,
+                    errors: samples.errors,
+                    p50_ms: latency_percentile(&latencies, 50.0),
+                    p95_ms: latency_percentile(&latencies, 95.0),
+                }
+            })
+            .collect();
+        rows.sort_by(|a, b| (&a.catalog_name, a.operation).cmp(&(&b.catalog_name, b.operation)));
+        rows
+    }
+
+    pub fn reset_request_metrics(&self) {
+        if let Ok(mut metrics) = self.request_metrics.lock() {
+            metrics.clear();
         }
     }
 
@@ -114,26 +564,63 @@ impl CatalogManager {
         &self.config.catalogs
     }
 
+    /// The warehouse configured for a catalog, whether or not it is currently connected
+    pub fn catalog_warehouse(&self, catalog_name: &str) -> Option<String> {
+        self.get_connection(catalog_name)
+            .map(|connection| &connection.config)
+            .or_else(|| self.config.get_catalog(catalog_name))
+            .and_then(|config| config.config.get("warehouse").cloned())
+    }
+
     /// Check if a catalog name is unique
     pub fn is_catalog_name_unique(&self, name: &str) -> bool {
         self.config.is_name_unique(name)
     }
 
-    pub async fn connect_catalog(&mut self, config: CatalogConfig) -> Result<(), CatalogError> {
-        let catalog: Arc<dyn Catalog> = match config.catalog_type {
-            CatalogType::Rest => self.create_rest_catalog(&config).await?,
-            CatalogType::Glue => self.create_glue_catalog(&config).await?,
-        };
+    /// Get the application settings
+    pub fn settings(&self) -> &AppSettings {
+        &self.config.settings
+    }
 
-        let connection = CatalogConnection {
-            config: config.clone(),
-            catalog,
-        };
+    /// Replace and persist the application settings
+    pub fn update_settings(&mut self, settings: AppSettings) -> Result<(), CatalogError> {
+        // Requests already holding a slot finish against the old limit
+        if settings.max_concurrent_requests != self.config.settings.max_concurrent_requests {
+            self.request_limiter = RequestLimiter::new(settings.max_concurrent_requests);
+        }
+        self.config.settings = settings;
+        self.config.save().map_err(|e| {
+            let error = format!("Failed to save settings: {}", e);
+            log::error!("{}", error);
+            CatalogError::InvalidConfig(error)
+        })
+    }
 
-        // Remove existing connection with same name
-        self.connections
-            .retain(|conn| conn.config.name != config.name);
-        self.connections.push(connection);
+    /// Proxy to use for a catalog's outbound traffic, if any
+    fn resolve_proxy(&self, config: &CatalogConfig) -> Option<ProxySettings> {
+        ProxySettings::resolve(&self.config.settings.proxy, config, |name| {
+            std::env::var(name).ok()
+        })
+    }
+
+    /// Proxy for requests outside any catalog, e.g. the update check
+    pub fn global_proxy(&self) -> Option<ProxySettings> {
+        ProxySettings::resolve_global(&self.config.settings.proxy, |name| std::env::var(name).ok())
+    }
+
+    /// Proxy a catalog connection is built with. Glue only swaps in its own client for a
+    /// proxy set in Hielo; one merely exported in the environment leaves it on `GlueCatalog`.
+    fn connection_proxy(&self, config: &CatalogConfig) -> Option<ProxySettings> {
+        match config.catalog_type {
+            CatalogType::Rest => self.resolve_proxy(config),
+            CatalogType::Glue => ProxySettings::configured(&self.config.settings.proxy, config),
+        }
+    }
+
+    pub async fn connect_catalog(&mut self, config: CatalogConfig) -> Result<(), CatalogError> {
+        let proxy = self.connection_proxy(&config);
+        let connection = Self::build_connection(config.clone(), proxy).await?;
+        self.install_connection(connection);
 
         // Save catalog configuration to persistent config
         if let Err(e) = self.config.add_catalog(config) {
@@ -151,10 +638,68 @@ impl CatalogManager {
         Ok(())
     }
 
+    /// Build the clients for a catalog. Doesn't borrow the manager, so the token exchange and
+    /// connect can run without holding it; store the result with
+    /// [`CatalogManager::install_connection`].
+    async fn build_connection(
+        config: CatalogConfig,
+        proxy: Option<ProxySettings>,
+    ) -> Result<CatalogConnection, CatalogError> {
+        let (catalog, views, token_expires_at): (Arc<dyn Catalog>, _, _) = match config.catalog_type
+        {
+            CatalogType::Rest => {
+                let rest = Self::create_rest_catalog(&config, proxy.as_ref()).await?;
+                (
+                    Arc::new(rest.catalog),
+                    Some(Arc::new(rest.views)),
+                    rest.token_expires_at,
+                )
+            }
+            CatalogType::Glue => (
+                Self::create_glue_catalog(&config, proxy.as_ref()).await?,
+                None,
+                None,
+            ),
+        };
+        Ok(CatalogConnection {
+            config,
+            catalog,
+            connected_at: chrono::Utc::now(),
+            warehouse_bucket_region: Arc::new(std::sync::OnceLock::new()),
+            token_expires_at,
+            views,
+        })
+    }
+
+    /// Store a built connection, replacing any existing one for the catalog
+    pub fn install_connection(&mut self, connection: CatalogConnection) {
+        // The probe can take seconds, so it runs in the background
+        if let Some(probe) = self.warehouse_bucket_region_probe(&connection.config) {
+            let region = connection.warehouse_bucket_region.clone();
+            tokio::spawn(async move {
+                if let Some(bucket_region) = probe.await {
+                    let _ = region.set(bucket_region);
+                }
+            });
+        }
+
+        let catalog_name = connection.config.name.clone();
+        self.connections
+            .retain(|conn| conn.config.name != catalog_name);
+        self.connections.push(connection);
+        self.auto_disconnected.remove(&catalog_name);
+        if let Ok(mut stale) = self.stale_connections.lock() {
+            stale.remove(&catalog_name);
+        }
+        self.touch(&catalog_name);
+    }
+
+    /// Build a REST catalog client, exchanging the subject token first when the catalog uses
+    /// token exchange
     async fn create_rest_catalog(
-        &self,
         config: &CatalogConfig,
-    ) -> Result<Arc<dyn Catalog>, CatalogError> {
+        proxy: Option<&ProxySettings>,
+    ) -> Result<RestConnection, CatalogError> {
         let uri = config.config.get("uri").ok_or_else(|| {
             CatalogError::InvalidConfig("URI is required for REST catalog".to_string())
         })?;
@@ -170,24 +715,67 @@ impl CatalogManager {
             props.insert("warehouse".to_string(), warehouse.clone());
         }
 
-        // Add optional auth token
-        if let Some(token) = config.config.get("auth_token") {
-            props.insert("token".to_string(), token.clone());
+        let auth = RestAuthOptions::from_catalog_config(config);
+        for (key, value) in auth.catalog_props() {
+            props.insert(key.to_string(), value);
+        }
+        for (key, value) in S3StorageOptions::from_catalog_config(config).file_io_props() {
+            props.insert(key.to_string(), value);
+        }
+        // The catalog returns the credentials in each loadTable response's config, which the
+        // REST client hands to the table's FileIO ahead of any locally configured ones
+        if config.requests_vended_credentials() {
+            props.insert(
+                ACCESS_DELEGATION_PROP.to_string(),
+                "vended-credentials".to_string(),
+            );
+        }
+
+        if let Some(proxy) = proxy {
+            check_proxy_reachable(proxy).await?;
+        }
+        let client = build_http_client(proxy, &TlsOptions::from_catalog_config(config))?;
+
+        let mut token_expires_at = None;
+        let mut token = props.get("token").cloned();
+        if auth.method == RestAuthMethod::TokenExchange {
+            let exchanged = exchange_token(&client, &auth.token_endpoint(uri), &auth).await?;
+            log::info!(
+                "Exchanged subject token for catalog '{}' (expires in {:?}s)",
+                config.name,
+                exchanged.expires_in
+            );
+            token_expires_at = exchanged
+                .expires_in
+                .map(|seconds| chrono::Utc::now() + chrono::Duration::seconds(seconds as i64));
+            token = Some(exchanged.access_token.clone());
+            props.insert("token".to_string(), exchanged.access_token);
         }
 
+        let views = RestViewClient::new(
+            client.clone(),
+            uri.clone(),
+            config.config.get("warehouse").cloned(),
+            auth,
+            token,
+        );
+
         let rest_config = RestCatalogConfig::builder()
             .uri(uri.clone())
             .props(props)
+            .client(Some(client))
             .build();
 
-        let catalog = RestCatalog::new(rest_config);
-
-        Ok(Arc::new(catalog))
+        Ok(RestConnection {
+            catalog: RestCatalog::new(rest_config),
+            views,
+            token_expires_at,
+        })
     }
 
     async fn create_glue_catalog(
-        &self,
         config: &CatalogConfig,
+        proxy: Option<&ProxySettings>,
     ) -> Result<Arc<dyn Catalog>, CatalogError> {
         let warehouse = config.config.get("warehouse").ok_or_else(|| {
             CatalogError::InvalidConfig("Warehouse is required for Glue catalog".to_string())
@@ -197,12 +785,7 @@ impl CatalogManager {
         props.insert("warehouse".to_string(), warehouse.clone());
 
         // Region is required for Glue catalog - ensure it's always present
-        let region = config
-            .config
-            .get("region")
-            .cloned()
-            .unwrap_or_else(|| "us-east-1".to_string());
-        props.insert("region".to_string(), region);
+        props.insert("region".to_string(), config.glue_region());
 
         if let Some(profile) = config.config.get("profile") {
             props.insert("profile".to_string(), profile.clone());
@@ -211,6 +794,10 @@ impl CatalogManager {
         if let Some(endpoint) = config.config.get("endpoint_url") {
             props.insert("endpoint_url".to_string(), endpoint.clone());
         }
+        // Glue's own endpoint and region above; these only steer the S3 client reading files
+        for (key, value) in S3StorageOptions::from_catalog_config(config).file_io_props() {
+            props.insert(key.to_string(), value);
+        }
 
         let glue_config = GlueCatalogConfig::builder()
             .warehouse(warehouse.clone())
@@ -236,6 +823,36 @@ impl CatalogManager {
             log::warn!("No region found in Glue catalog configuration!");
         }
 
+        if TlsOptions::from_catalog_config(config) != TlsOptions::default() {
+            log::warn!("Custom TLS options only apply to REST catalogs and are ignored for Glue");
+        }
+
+        // GlueCatalog builds its own SDK client, so catalogs with a proxy set in Hielo use one
+        // whose HTTP client carries it
+        if let Some(proxy) = proxy {
+            check_proxy_reachable(proxy).await?;
+            log::info!("Routing Glue traffic through proxy: {}", proxy.url);
+            let options = GlueConnectOptions {
+                warehouse,
+                region: config.glue_region(),
+                profile: config.config.get("profile").map(String::as_str),
+                endpoint_url: config.config.get("endpoint_url").map(String::as_str),
+                file_io_props: S3StorageOptions::from_catalog_config(config)
+                    .file_io_props()
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect(),
+            };
+            let catalog = ReadOnlyGlueCatalog::new(options, proxy)
+                .await
+                .map_err(|e| {
+                    let error = format!("Failed to create Glue catalog: {}", e);
+                    log::error!("{}", error);
+                    CatalogError::ConnectionFailed(error)
+                })?;
+            return Ok(Arc::new(catalog));
+        }
+
         let catalog = GlueCatalog::new(glue_config).await.map_err(|e| {
             let error = format!("Failed to create Glue catalog: {}", e);
             log::error!("{}", error);
@@ -245,35 +862,72 @@ impl CatalogManager {
         Ok(Arc::new(catalog))
     }
 
+    /// Ask AWS S3 which region the warehouse bucket lives in. Best effort: custom endpoints,
+    /// non-S3 warehouses and failed requests give `None`. The returned future doesn't borrow
+    /// the manager, so it can run after the connection is stored.
+    fn warehouse_bucket_region_probe(
+        &self,
+        config: &CatalogConfig,
+    ) -> Option<impl Future<Output = Option<String>> + Send + 'static> {
+        if config.catalog_type != CatalogType::Glue
+            || !S3StorageOptions::from_catalog_config(config)
+                .endpoint
+                .trim()
+                .is_empty()
+        {
+            return None;
+        }
+        let bucket = s3_bucket_name(config.config.get("warehouse")?)?;
+        let client =
+            build_http_client(self.resolve_proxy(config).as_ref(), &TlsOptions::default()).ok()?;
+        Some(async move {
+            // Path-style against the global endpoint works for any bucket name; S3 names the
+            // bucket's region in a header even when the request itself is refused
+            let response = client
+                .head(format!("https://s3.amazonaws.com/{}", bucket))
+                .timeout(std::time::Duration::from_secs(5))
+                .send()
+                .await
+                .map_err(|e| log::warn!("Could not probe region of bucket '{}': {}", bucket, e))
+                .ok()?;
+            let region = response
+                .headers()
+                .get("x-amz-bucket-region")?
+                .to_str()
+                .ok()?
+                .to_string();
+            log::info!("Warehouse bucket '{}' is in region {}", bucket, region);
+            Some(region)
+        })
+    }
+
     pub async fn list_namespaces(&self, catalog_name: &str) -> Result<Vec<String>, CatalogError> {
         log::info!("Listing namespaces for catalog: '{}'", catalog_name);
 
-        let connection = self
-            .connections
-            .iter()
-            .find(|conn| conn.config.name == catalog_name)
-            .ok_or_else(|| {
-                let error = format!("Catalog '{}' not found", catalog_name);
-                log::error!("{}", error);
-                CatalogError::ConnectionFailed(error)
-            })?;
+        let connection = self.usable_connection(catalog_name)?;
+        self.touch(catalog_name);
 
         log::info!(
             "Found catalog connection, catalog type: {:?}",
             connection.config.catalog_type
         );
 
-        let namespaces = connection
-            .catalog
-            .list_namespaces(None)
-            .await
-            .map_err(|e| {
-                let error = format!("Failed to list namespaces: {}", e);
-                log::error!("{}", error);
-                CatalogError::NetworkError(error)
-            })?;
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let namespaces = connection.catalog.list_namespaces(None).await;
+        self.record_request(
+            catalog_name,
+            CatalogOperation::ListNamespaces,
+            started,
+            namespaces.is_ok(),
+        );
+        let namespaces = namespaces.map_err(|e| {
+            let error = format!("Failed to list namespaces: {}", e);
+            log::error!("{}", error);
+            CatalogError::NetworkError(error)
+        })?;
 
-        let namespace_strings: Vec<String> = namespaces
+        let mut namespace_strings: Vec<String> = namespaces
             .into_iter()
             .map(|ns| {
                 let ns_string = ns.to_string();
@@ -282,6 +936,31 @@ impl CatalogManager {
             })
             .collect();
 
+        let duplicates = dedup_names(&mut namespace_strings, |ns| ns.as_str());
+        if duplicates > 0 {
+            log::warn!(
+                "Catalog '{}' listed {} namespace(s) more than once; showing each once",
+                catalog_name,
+                duplicates
+            );
+        }
+
+        let active_filter = connection
+            .config
+            .namespace_filter
+            .as_deref()
+            .filter(|_| !self.unfiltered_catalogs.contains(catalog_name));
+        if let Some(filter) = active_filter {
+            let total = namespace_strings.len();
+            namespace_strings.retain(|ns| namespace_matches_filter(ns, filter));
+            log::info!(
+                "Namespace filter '{}' kept {} of {} namespaces",
+                filter,
+                namespace_strings.len(),
+                total
+            );
+        }
+
         log::info!("Returning {} namespaces", namespace_strings.len());
         Ok(namespace_strings)
     }
@@ -297,15 +976,8 @@ impl CatalogManager {
             namespace
         );
 
-        let connection = self
-            .connections
-            .iter()
-            .find(|conn| conn.config.name == catalog_name)
-            .ok_or_else(|| {
-                let error = format!("Catalog '{}' not found", catalog_name);
-                log::error!("{}", error);
-                CatalogError::ConnectionFailed(error)
-            })?;
+        let connection = self.usable_connection(catalog_name)?;
+        self.touch(catalog_name);
 
         log::info!(
             "Found catalog connection, catalog type: {:?}",
@@ -321,15 +993,24 @@ impl CatalogManager {
 
         log::info!("Created namespace identifier: {:?}", namespace_ident);
 
-        let table_idents = connection
-            .catalog
-            .list_tables(&namespace_ident)
-            .await
-            .map_err(|e| {
+        let mut table_idents = {
+            let _permit = self.request_limiter.acquire().await?;
+            let started = std::time::Instant::now();
+            let table_idents = connection.catalog.list_tables(&namespace_ident).await;
+            self.record_request(
+                catalog_name,
+                CatalogOperation::ListTables,
+                started,
+                table_idents.is_ok(),
+            );
+            table_idents.map_err(|e| {
                 let error = format!("Failed to list tables in namespace '{}': {}", namespace, e);
                 log::error!("{}", error);
                 CatalogError::NetworkError(error)
-            })?;
+            })?
+        };
+
+        warn_about_duplicate_tables(&mut table_idents, namespace);
 
         log::info!(
             "Found {} table identifiers in namespace '{}'",
@@ -337,14 +1018,50 @@ impl CatalogManager {
             namespace
         );
 
+        // Catalogs without view endpoints, or that fail to list views, still list their tables
+        let view_names = match &connection.views {
+            Some(views) => {
+                let _permit = self.request_limiter.acquire().await?;
+                let started = std::time::Instant::now();
+                let view_names = views.list_views(namespace).await;
+                self.record_request(
+                    catalog_name,
+                    CatalogOperation::ListViews,
+                    started,
+                    view_names.is_ok(),
+                );
+                view_names.unwrap_or_else(|e| {
+                    log::warn!("Failed to list views in namespace '{}': {}", namespace, e);
+                    Vec::new()
+                })
+            }
+            None => Vec::new(),
+        };
+        log::info!(
+            "Found {} views in namespace '{}'",
+            view_names.len(),
+            namespace
+        );
+
         let mut table_refs: Vec<TableReference> = Vec::new();
 
+        // Some catalogs list views among their tables too; those aren't loaded as tables
+        table_idents.retain(|ident| !view_names.iter().any(|name| name == ident.name()));
         for ident in table_idents {
             let table_name = ident.name().to_string();
             let full_name = format!("{}.{}", namespace, table_name);
 
             // Try to load the table to determine if it's an Iceberg table
-            let table_type = match connection.catalog.load_table(&ident).await {
+            let _permit = self.request_limiter.acquire().await?;
+            let started = std::time::Instant::now();
+            let loaded = connection.catalog.load_table(&ident).await;
+            self.record_request(
+                catalog_name,
+                CatalogOperation::LoadTable,
+                started,
+                loaded.is_ok(),
+            );
+            let table_type = match loaded {
                 Ok(_) => {
                     log::info!("✅ Iceberg table detected: {}", full_name);
                     TableType::Iceberg
@@ -374,10 +1091,59 @@ impl CatalogManager {
             table_refs.push(table_ref);
         }
 
+        for view_name in view_names {
+            log::info!("👁️ Iceberg view detected: {}.{}", namespace, view_name);
+            table_refs.push(TableReference {
+                namespace: namespace.to_string(),
+                full_name: format!("{}.{}", namespace, view_name),
+                name: view_name,
+                table_type: TableType::View,
+            });
+        }
+
         log::info!("Returning {} table references", table_refs.len());
         Ok(table_refs)
     }
 
+    /// Table names in a namespace, without loading each table the way [`Self::list_tables`] does
+    pub async fn list_table_names(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+    ) -> Result<Vec<String>, CatalogError> {
+        let connection = self.usable_connection(catalog_name)?;
+        self.touch(catalog_name);
+
+        let namespace_ident =
+            NamespaceIdent::from_vec(vec![namespace.to_string()]).map_err(|e| {
+                let error = format!("Invalid namespace '{}': {}", namespace, e);
+                log::error!("{}", error);
+                CatalogError::InvalidConfig(error)
+            })?;
+
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let table_idents = connection.catalog.list_tables(&namespace_ident).await;
+        self.record_request(
+            catalog_name,
+            CatalogOperation::ListTables,
+            started,
+            table_idents.is_ok(),
+        );
+        let mut table_idents = table_idents.map_err(|e| {
+            let error = format!("Failed to list tables in namespace '{}': {}", namespace, e);
+            log::error!("{}", error);
+            CatalogError::NetworkError(error)
+        })?;
+
+        warn_about_duplicate_tables(&mut table_idents, namespace);
+
+        Ok(table_idents
+            .into_iter()
+            .map(|ident| ident.name().to_string())
+            .collect())
+    }
+
     pub async fn load_table(
         &self,
         catalog_name: &str,
@@ -390,16 +1156,12 @@ impl CatalogManager {
             namespace,
             table_name
         );
+        if catalog_name == METADATA_FILE_CATALOG {
+            return self.load_metadata_file(namespace, table_name).await;
+        }
 
-        let connection = self
-            .connections
-            .iter()
-            .find(|conn| conn.config.name == catalog_name)
-            .ok_or_else(|| {
-                let error = format!("Catalog '{}' not found", catalog_name);
-                log::error!("{}", error);
-                CatalogError::ConnectionFailed(error)
-            })?;
+        let connection = self.usable_connection(catalog_name)?;
+        self.touch(catalog_name);
 
         log::info!(
             "Found catalog connection, type: {:?}, config: {:?}",
@@ -415,35 +1177,908 @@ impl CatalogManager {
 
         log::info!("Table identifier created: {:?}", table_ident);
 
-        let table = connection
-            .catalog
-            .load_table(&table_ident)
-            .await
-            .map_err(|e| {
-                let error = format!("Failed to load table '{}': {}", table_ident, e);
-                log::error!("{}", error);
-                CatalogError::TableNotFound(error)
-            })?;
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let table = connection.catalog.load_table(&table_ident).await;
+        self.record_request(
+            catalog_name,
+            CatalogOperation::LoadTable,
+            started,
+            table.is_ok(),
+        );
+        let table = table.map_err(|e| {
+            let error = format!("Failed to load table '{}': {}", table_ident, e);
+            log::error!("{}", error);
+            CatalogError::TableLoadFailed(error, LoadFailureKind::from_error(&e))
+        })?;
 
         log::info!("Table loaded successfully: {}", table_ident);
         Ok(table)
     }
 
-    pub fn get_connections(&self) -> &[CatalogConnection] {
-        &self.connections
-    }
+    /// Load a view's metadata from the catalog's view endpoints
+    pub async fn load_view(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        view_name: &str,
+    ) -> Result<ViewMetadata, CatalogError> {
+        let connection = self.usable_connection(catalog_name)?;
+        self.touch(catalog_name);
+        let views = connection.views.as_ref().ok_or_else(|| {
+            CatalogError::InvalidConfig(format!(
+                "Catalog '{}' does not serve Iceberg views",
+                catalog_name
+            ))
+        })?;
 
-    pub fn remove_connection(&mut self, catalog_name: &str) -> bool {
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let view = views.load_view(namespace, view_name).await;
+        self.record_request(
+            catalog_name,
+            CatalogOperation::LoadView,
+            started,
+            view.is_ok(),
+        );
+        let view = view.inspect_err(|e| log::error!("{}", e))?;
+
+        log::info!("View loaded successfully: {}.{}", namespace, view_name);
+        Ok(view)
+    }
+
+    /// Check whether a table still exists without loading its metadata, so references
+    /// kept across sessions (such as favorites) can be flagged once the table is dropped
+    pub async fn table_exists(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<bool, CatalogError> {
+        if catalog_name == METADATA_FILE_CATALOG {
+            return Ok(true);
+        }
+
+        let connection = self.usable_connection(catalog_name)?;
+        self.touch(catalog_name);
+
+        let table_ident = TableIdent::from_strs(vec![namespace, table_name]).map_err(|e| {
+            let error = format!("Invalid table identifier: {}", e);
+            log::error!("{}", error);
+            CatalogError::InvalidConfig(error)
+        })?;
+
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let exists = connection.catalog.table_exists(&table_ident).await;
+        self.record_request(
+            catalog_name,
+            CatalogOperation::TableExists,
+            started,
+            exists.is_ok(),
+        );
+        exists.map_err(|e| {
+            let error = format!("Failed to check table '{}': {}", table_ident, e);
+            log::error!("{}", error);
+            CatalogError::ConnectionFailed(error)
+        })
+    }
+
+    /// Check a batch of `(namespace, table)` references against one catalog, returning
+    /// those that no longer exist. References that cannot be checked are left out.
+    pub async fn missing_tables(
+        &self,
+        catalog_name: &str,
+        tables: &[(String, String)],
+    ) -> Vec<(String, String)> {
+        let mut missing = Vec::new();
+        for (namespace, table_name) in tables {
+            if let Ok(false) = self.table_exists(catalog_name, namespace, table_name).await {
+                missing.push((namespace.clone(), table_name.clone()));
+            }
+        }
+        missing
+    }
+
+    /// Remember a local or object-store `metadata.json` location and the storage properties
+    /// needed to read it. Returns the `(namespace, table)` pair to load it under
+    /// [`METADATA_FILE_CATALOG`].
+    pub fn register_metadata_file(
+        &mut self,
+        location: &str,
+        props: HashMap<String, String>,
+    ) -> Result<(String, String), CatalogError> {
+        let location = location.trim().trim_end_matches('/');
+        let (directory, file_name) = split_metadata_location(location).ok_or_else(|| {
+            CatalogError::InvalidConfig(format!(
+                "'{}' is not a path to a metadata JSON file",
+                location
+            ))
+        })?;
+        self.metadata_file_props.insert(location.to_string(), props);
+        Ok((directory.to_string(), file_name.to_string()))
+    }
+
+    /// Read a table straight from its metadata file, bypassing any catalog
+    async fn load_metadata_file(
+        &self,
+        directory: &str,
+        file_name: &str,
+    ) -> Result<Table, CatalogError> {
+        let location = format!("{}/{}", directory, file_name);
+        let props = self
+            .metadata_file_props
+            .get(&location)
+            .cloned()
+            .unwrap_or_default();
+
+        let file_io = FileIO::from_path(&location)
+            .and_then(|builder| builder.with_props(props).build())
+            .map_err(|e| {
+                let error = format!("Cannot read '{}': {}", location, e);
+                log::error!("{}", error);
+                CatalogError::InvalidConfig(error)
+            })?;
+        let table_ident = TableIdent::from_strs(["metadata_file", file_name]).map_err(|e| {
+            let error = format!("Invalid table identifier: {}", e);
+            log::error!("{}", error);
+            CatalogError::InvalidConfig(error)
+        })?;
+
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let table = StaticTable::from_metadata_file(&location, table_ident, file_io).await;
+        self.record_request(
+            METADATA_FILE_CATALOG,
+            CatalogOperation::LoadTable,
+            started,
+            table.is_ok(),
+        );
+        let table = table.map_err(|e| {
+            let error = format!("Failed to load metadata file '{}': {}", location, e);
+            log::error!("{}", error);
+            CatalogError::TableLoadFailed(error, LoadFailureKind::from_error(&e))
+        })?;
+
+        log::info!("Metadata file loaded: {}", location);
+        Ok(table.into_table())
+    }
+
+    /// Load a table together with the version before its current metadata file, read from
+    /// the metadata log. `previous` is `None` for a table with a single metadata version.
+    pub async fn load_metadata_versions(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<MetadataVersions, CatalogError> {
+        let current = self.load_table(catalog_name, namespace, table_name).await?;
+        let Some(entry) = current.metadata().metadata_log().last().cloned() else {
+            return Ok(MetadataVersions {
+                current,
+                previous: None,
+            });
+        };
+
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let previous = StaticTable::from_metadata_file(
+            &entry.metadata_file,
+            current.identifier().clone(),
+            current.file_io().clone(),
+        )
+        .await;
+        self.record_request(
+            catalog_name,
+            CatalogOperation::LoadTable,
+            started,
+            previous.is_ok(),
+        );
+        let previous = previous.map_err(|e| {
+            let error = format!(
+                "Failed to read previous metadata file '{}': {}",
+                entry.metadata_file, e
+            );
+            log::error!("{}", error);
+            CatalogError::NetworkError(error)
+        })?;
+
+        Ok(MetadataVersions {
+            current,
+            previous: Some(PreviousMetadata {
+                location: entry.metadata_file,
+                timestamp_ms: entry.timestamp_ms,
+                table: previous.into_table(),
+            }),
+        })
+    }
+
+    /// Load a table and summarise its size and freshness for listings
+    pub async fn table_stats(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<TableStats, CatalogError> {
+        let table = self.load_table(catalog_name, namespace, table_name).await?;
+        Ok(crate::iceberg_adapter::table_stats(&table))
+    }
+
+    /// Load a table and aggregate its current data files per partition from the manifests
+    pub async fn partition_stats(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<Vec<PartitionStats>, CatalogError> {
+        self.scan_table(catalog_name, namespace, table_name, |table| async move {
+            crate::iceberg_adapter::partition_stats(&table).await
+        })
+        .await
+    }
+
+    /// Aggregate the current data files of a table the caller already loaded, for reports
+    /// that need its metadata too. Expired credentials aren't retried; reload the table instead.
+    pub async fn loaded_partition_stats(
+        &self,
+        catalog_name: &str,
+        table: &Table,
+    ) -> Result<Vec<PartitionStats>, CatalogError> {
+        let _permit = self.request_limiter.acquire().await?;
+        let started = std::time::Instant::now();
+        let result = crate::iceberg_adapter::partition_stats(table).await;
+        self.record_request(
+            catalog_name,
+            CatalogOperation::Scan,
+            started,
+            result.is_ok(),
+        );
+        result.map_err(manifest_read_error)
+    }
+
+    /// Load a table and aggregate its current data files by the schema id they were written with
+    pub async fn schema_file_stats(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<Vec<SchemaFileStats>, CatalogError> {
+        self.scan_table(catalog_name, namespace, table_name, |table| async move {
+            crate::iceberg_adapter::schema_file_stats(&table).await
+        })
+        .await
+    }
+
+    /// Load a table and sum the on-disk size of each column across its current data files
+    pub async fn column_size_stats(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<Vec<ColumnSizeStats>, CatalogError> {
+        self.scan_table(catalog_name, namespace, table_name, |table| async move {
+            crate::iceberg_adapter::column_size_stats(&table).await
+        })
+        .await
+    }
+
+    /// Load a table and list its current data files with the snapshot that added each one
+    pub async fn data_file_origins(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<Vec<DataFileOrigin>, CatalogError> {
+        self.scan_table(catalog_name, namespace, table_name, |table| async move {
+            crate::iceberg_adapter::data_file_origins(&table).await
+        })
+        .await
+    }
+
+    /// Load a table and run the metadata/data consistency checks on its current snapshot.
+    /// Failed checks are part of the result; only a table that can't be loaded is an error.
+    pub async fn validate_table(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<Vec<ValidationCheck>, CatalogError> {
+        let table = self.load_table(catalog_name, namespace, table_name).await?;
+        let started = std::time::Instant::now();
+        // Each read holds its own permit, released before a reload takes one
+        let mut checks = {
+            let _permit = self.request_limiter.acquire().await?;
+            crate::iceberg_adapter::validate_table(&table).await
+        };
+        let credentials_expired = checks.iter().any(|check| {
+            check.status == ValidationStatus::Failed && is_expired_credentials_error(&check.detail)
+        });
+        if credentials_expired {
+            log::warn!(
+                "Storage credentials for '{}.{}' expired during validation; reloading the table",
+                namespace,
+                table_name
+            );
+            let table = self.load_table(catalog_name, namespace, table_name).await?;
+            let _permit = self.request_limiter.acquire().await?;
+            checks = crate::iceberg_adapter::validate_table(&table).await;
+        }
+        self.record_request(catalog_name, CatalogOperation::Scan, started, true);
+        Ok(checks)
+    }
+
+    /// Load a table and read its manifests with `read`. Credentials vended with the table can
+    /// expire before the read is done; the table is then reloaded, which vends fresh ones, and
+    /// the read retried once.
+    async fn scan_table<T, F, Fut>(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        read: F,
+    ) -> Result<T, CatalogError>
+    where
+        F: Fn(Table) -> Fut,
+        Fut: std::future::Future<Output = anyhow::Result<T>>,
+    {
+        let table = self.load_table(catalog_name, namespace, table_name).await?;
+        let started = std::time::Instant::now();
+        // Each read holds its own permit, released before a reload takes one
+        let mut result = {
+            let _permit = self.request_limiter.acquire().await?;
+            read(table).await
+        };
+        if let Err(e) = &result {
+            if is_expired_credentials_error(&format!("{:#}", e)) {
+                log::warn!(
+                    "Storage credentials for '{}.{}' expired ({:#}); reloading the table",
+                    namespace,
+                    table_name,
+                    e
+                );
+                let table = self.load_table(catalog_name, namespace, table_name).await?;
+                let _permit = self.request_limiter.acquire().await?;
+                result = read(table).await;
+            }
+        }
+        self.record_request(
+            catalog_name,
+            CatalogOperation::Scan,
+            started,
+            result.is_ok(),
+        );
+        result.map_err(manifest_read_error)
+    }
+
+    pub fn get_connections(&self) -> &[CatalogConnection] {
+        &self.connections
+    }
+
+    /// Get the active connection for a catalog, if it is connected
+    pub fn get_connection(&self, catalog_name: &str) -> Option<&CatalogConnection> {
+        self.connections
+            .iter()
+            .find(|conn| conn.config.name == catalog_name)
+    }
+
+    /// The connection every catalog request goes through. A connection whose exchanged token
+    /// has expired is refused rather than sending a request the server will reject; callers
+    /// reconnect it through [`CatalogManager::connector`].
+    fn usable_connection(&self, catalog_name: &str) -> Result<&CatalogConnection, CatalogError> {
+        let connection = self.get_connection(catalog_name).ok_or_else(|| {
+            let error = format!("Catalog '{}' not found", catalog_name);
+            log::error!("{}", error);
+            CatalogError::ConnectionFailed(error)
+        })?;
+        if connection.token_expired() {
+            let error = format!(
+                "Token for catalog '{}' has expired; reconnect to get a new one",
+                catalog_name
+            );
+            log::warn!("{}", error);
+            return Err(CatalogError::AuthenticationFailed(error));
+        }
+        Ok(connection)
+    }
+
+    pub fn remove_connection(&mut self, catalog_name: &str) -> bool {
         let initial_len = self.connections.len();
         self.connections
             .retain(|conn| conn.config.name != catalog_name);
+        self.auto_disconnected.remove(catalog_name);
+        if let Ok(mut activity) = self.last_activity.lock() {
+            activity.remove(catalog_name);
+        }
+        if let Ok(mut stale) = self.stale_connections.lock() {
+            stale.remove(catalog_name);
+        }
         self.connections.len() < initial_len
     }
 
+    /// The connect for a saved catalog that [needs one](CatalogManager::needs_connect), e.g.
+    /// after an idle disconnect or once its exchanged token expired. The future doesn't borrow
+    /// the manager, so the token exchange runs without holding it; pass its result to
+    /// [`CatalogManager::finish_connect`]. `None` when no connect is needed and for catalogs
+    /// without a saved config, like opened metadata files, which are left to the caller.
+    pub fn connector(
+        &self,
+        catalog_name: &str,
+    ) -> Option<impl Future<Output = Result<CatalogConnection, CatalogError>> + 'static> {
+        if !self.needs_connect(catalog_name) {
+            return None;
+        }
+        let config = self.config.get_catalog(catalog_name)?.clone();
+        match self.get_connection(catalog_name) {
+            Some(connection) if connection.token_expired() => log::info!(
+                "Token for catalog '{}' expired; exchanging a new one",
+                catalog_name
+            ),
+            Some(_) => log::info!("Replacing dropped connection to catalog '{}'", catalog_name),
+            None => log::info!("Connecting catalog '{}' on demand", catalog_name),
+        }
+        let proxy = self.connection_proxy(&config);
+        Some(Self::build_connection(config, proxy))
+    }
+
+    /// Store the outcome of a [`CatalogManager::connector`] connect. A dead connection that
+    /// couldn't be replaced is dropped, so the catalog shows as disconnected.
+    pub fn finish_connect(
+        &mut self,
+        catalog_name: &str,
+        connected: Result<CatalogConnection, CatalogError>,
+    ) -> Result<(), CatalogError> {
+        match connected {
+            Ok(connection) => {
+                self.install_connection(connection);
+                Ok(())
+            }
+            Err(e) => {
+                let stale = self
+                    .stale_connections
+                    .lock()
+                    .is_ok_and(|stale| stale.contains(catalog_name));
+                if stale {
+                    self.auto_disconnect(catalog_name, AutoDisconnect::ConnectionLost);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Lock held while a catalog connects on demand, so concurrent requests wait for one
+    /// connect (and token exchange) instead of each starting their own
+    pub fn connect_lock(&self, catalog_name: &str) -> Arc<tokio::sync::Mutex<()>> {
+        match self.connect_locks.lock() {
+            Ok(mut locks) => locks.entry(catalog_name.to_string()).or_default().clone(),
+            Err(_) => Arc::default(),
+        }
+    }
+
+    /// Whether the catalog has to connect before its next request: it isn't connected, its
+    /// exchanged token has expired, or a request found its connection dead
+    pub fn needs_connect(&self, catalog_name: &str) -> bool {
+        let Some(connection) = self.get_connection(catalog_name) else {
+            return true;
+        };
+        connection.token_expired()
+            || self
+                .stale_connections
+                .lock()
+                .is_ok_and(|stale| stale.contains(catalog_name))
+    }
+
+    /// Flag a connection a request found dead (e.g. after the laptop slept), so the next
+    /// connect check replaces it. Takes `&self`, so it works while other requests are running.
+    pub fn mark_connection_stale(&self, catalog_name: &str) {
+        if let Ok(mut stale) = self.stale_connections.lock() {
+            stale.insert(catalog_name.to_string());
+        }
+    }
+
+    /// Close a catalog's connection at the user's request, keeping its saved config. The next
+    /// list or load reconnects it. Returns whether it was connected.
+    pub fn disconnect_catalog(&mut self, catalog_name: &str) -> bool {
+        let disconnected = self.remove_connection(catalog_name);
+        if disconnected {
+            log::info!("Disconnected catalog '{}'", catalog_name);
+        }
+        disconnected
+    }
+
+    /// Connected catalogs, other than pinned ones, without a list or load for longer than the
+    /// auto-disconnect limit. Empty when the setting is off or any request is still running.
+    pub fn idle_catalogs(&self) -> Vec<String> {
+        let Some(minutes) = self.config.settings.idle_disconnect_minutes else {
+            return Vec::new();
+        };
+        if self.request_activity().in_flight > 0 {
+            return Vec::new();
+        }
+        let limit = std::time::Duration::from_secs(minutes * 60);
+        let Ok(activity) = self.last_activity.lock() else {
+            return Vec::new();
+        };
+        self.connections
+            .iter()
+            .filter(|conn| !conn.config.pinned)
+            .filter(|conn| match activity.get(&conn.config.name) {
+                Some(last) => last.elapsed() >= limit,
+                None => (chrono::Utc::now() - conn.connected_at)
+                    .to_std()
+                    .is_ok_and(|age| age >= limit),
+            })
+            .map(|conn| conn.config.name.clone())
+            .collect()
+    }
+
+    /// Tear down idle connections, dropping their clients and cached tokens while keeping
+    /// the saved config. Returns the catalogs that were disconnected.
+    pub fn disconnect_idle(&mut self) -> Vec<String> {
+        let idle = self.idle_catalogs();
+        for catalog_name in &idle {
+            log::info!("Disconnecting idle catalog '{}'", catalog_name);
+            self.auto_disconnect(catalog_name, AutoDisconnect::Idle);
+        }
+        idle
+    }
+
+    /// Drop a saved catalog's connection so the next list or load reconnects it. Catalogs
+    /// without a saved config (opened metadata files) can't reconnect and are left alone.
+    pub fn auto_disconnect(&mut self, catalog_name: &str, reason: AutoDisconnect) -> bool {
+        if self.config.get_catalog(catalog_name).is_none() || !self.remove_connection(catalog_name)
+        {
+            return false;
+        }
+        self.auto_disconnected
+            .insert(catalog_name.to_string(), reason);
+        true
+    }
+
+    /// Drop every reconnectable connection after the computer woke from sleep. Returns the
+    /// catalogs that were disconnected; empty while any request is still running.
+    pub fn disconnect_after_sleep(&mut self) -> Vec<String> {
+        if self.request_activity().in_flight > 0 {
+            return Vec::new();
+        }
+        let connected: Vec<String> = self
+            .connections
+            .iter()
+            .map(|conn| conn.config.name.clone())
+            .collect();
+        connected
+            .into_iter()
+            .filter(|catalog_name| self.auto_disconnect(catalog_name, AutoDisconnect::Suspended))
+            .collect()
+    }
+
+    /// Why the catalog was disconnected automatically, if it was and hasn't been used since
+    pub fn auto_disconnect_reason(&self, catalog_name: &str) -> Option<AutoDisconnect> {
+        self.auto_disconnected.get(catalog_name).copied()
+    }
+
+    /// Assign a catalog to a navigation group, or clear its group with `None`
+    pub fn set_catalog_group(
+        &mut self,
+        catalog_name: &str,
+        group: Option<String>,
+    ) -> Result<(), CatalogError> {
+        let group = group
+            .map(|g| g.trim().to_string())
+            .filter(|g| !g.is_empty());
+
+        let Some(mut catalog) = self.config.get_catalog(catalog_name).cloned() else {
+            return Err(CatalogError::InvalidConfig(format!(
+                "Catalog '{}' not found",
+                catalog_name
+            )));
+        };
+        catalog.group = group.clone();
+        if let Err(e) = self.config.update_catalog(catalog) {
+            log::error!("Failed to save catalog group: {}", e);
+            return Err(CatalogError::InvalidConfig(format!(
+                "Failed to save catalog group: {}",
+                e
+            )));
+        }
+
+        if let Some(connection) = self
+            .connections
+            .iter_mut()
+            .find(|conn| conn.config.name == catalog_name)
+        {
+            connection.config.group = group;
+        }
+        Ok(())
+    }
+
+    /// Pin or unpin a saved catalog, see [`CatalogConfig::pinned`]
+    pub fn set_catalog_pinned(
+        &mut self,
+        catalog_name: &str,
+        pinned: bool,
+    ) -> Result<(), CatalogError> {
+        let Some(mut catalog) = self.config.get_catalog(catalog_name).cloned() else {
+            return Err(CatalogError::InvalidConfig(format!(
+                "Catalog '{}' not found",
+                catalog_name
+            )));
+        };
+        catalog.pinned = pinned;
+        if let Err(e) = self.config.update_catalog(catalog) {
+            log::error!("Failed to save catalog pin: {}", e);
+            return Err(CatalogError::InvalidConfig(format!(
+                "Failed to save catalog pin: {}",
+                e
+            )));
+        }
+
+        if let Some(connection) = self
+            .connections
+            .iter_mut()
+            .find(|conn| conn.config.name == catalog_name)
+        {
+            connection.config.pinned = pinned;
+        }
+        Ok(())
+    }
+
+    /// Point a REST catalog at another warehouse behind the same endpoint and remember it.
+    /// A connected catalog reconnects, so the server's config for that warehouse (e.g. its
+    /// `prefix`) applies to the following requests.
+    pub async fn switch_warehouse(
+        &mut self,
+        catalog_name: &str,
+        warehouse: &str,
+    ) -> Result<(), CatalogError> {
+        let warehouse = warehouse.trim();
+        if warehouse.is_empty() || warehouse.contains(',') {
+            return Err(CatalogError::InvalidConfig(
+                "Enter a single warehouse name or location".to_string(),
+            ));
+        }
+        let Some(mut catalog) = self.config.get_catalog(catalog_name).cloned() else {
+            return Err(CatalogError::InvalidConfig(format!(
+                "Catalog '{}' not found",
+                catalog_name
+            )));
+        };
+        let mut warehouses = catalog.known_warehouses();
+        if !warehouses.iter().any(|known| known == warehouse) {
+            warehouses.push(warehouse.to_string());
+        }
+        catalog
+            .config
+            .insert(WAREHOUSES_KEY.to_string(), warehouses.join(","));
+        catalog
+            .config
+            .insert("warehouse".to_string(), warehouse.to_string());
+
+        // Only a warehouse the catalog could connect to is saved; a failed switch leaves the
+        // previous one in place
+        if self.get_connection(catalog_name).is_some() {
+            log::info!(
+                "Reconnecting catalog '{}' to warehouse '{}'",
+                catalog_name,
+                warehouse
+            );
+            self.connect_catalog(catalog.clone()).await?;
+        }
+        if let Err(e) = self.config.update_catalog(catalog) {
+            log::error!("Failed to save catalog warehouse: {}", e);
+            return Err(CatalogError::InvalidConfig(format!(
+                "Failed to save catalog warehouse: {}",
+                e
+            )));
+        }
+        Ok(())
+    }
+
+    /// Saved catalogs to connect eagerly at startup
+    pub fn pinned_catalogs(&self) -> Vec<String> {
+        self.config
+            .catalogs
+            .iter()
+            .filter(|catalog| catalog.pinned)
+            .map(|catalog| catalog.name.clone())
+            .collect()
+    }
+
+    /// Set or clear (`None`) the namespace filter for a catalog
+    pub fn set_namespace_filter(
+        &mut self,
+        catalog_name: &str,
+        filter: Option<String>,
+    ) -> Result<(), CatalogError> {
+        let filter = filter
+            .map(|f| f.trim().to_string())
+            .filter(|f| !f.is_empty());
+
+        let Some(mut catalog) = self.config.get_catalog(catalog_name).cloned() else {
+            return Err(CatalogError::InvalidConfig(format!(
+                "Catalog '{}' not found",
+                catalog_name
+            )));
+        };
+        catalog.namespace_filter = filter.clone();
+        if let Err(e) = self.config.update_catalog(catalog) {
+            log::error!("Failed to save namespace filter: {}", e);
+            return Err(CatalogError::InvalidConfig(format!(
+                "Failed to save namespace filter: {}",
+                e
+            )));
+        }
+
+        if let Some(connection) = self
+            .connections
+            .iter_mut()
+            .find(|conn| conn.config.name == catalog_name)
+        {
+            connection.config.namespace_filter = filter;
+        }
+        Ok(())
+    }
+
+    /// Temporarily bypass a catalog's namespace filter. Not persisted.
+    pub fn set_show_all_namespaces(&mut self, catalog_name: &str, show_all: bool) {
+        if show_all {
+            self.unfiltered_catalogs.insert(catalog_name.to_string());
+        } else {
+            self.unfiltered_catalogs.remove(catalog_name);
+        }
+    }
+
+    pub fn is_showing_all_namespaces(&self, catalog_name: &str) -> bool {
+        self.unfiltered_catalogs.contains(catalog_name)
+    }
+
+    /// Offer a newly connected catalog's endpoint on the connect forms next time
+    pub fn remember_recent_endpoint(&mut self, config: &CatalogConfig) -> Result<(), CatalogError> {
+        let mut settings = self.config.settings.clone();
+        settings.recent_endpoints.remember(config);
+        self.update_settings(settings)
+    }
+
+    pub fn is_favorite_table(&self, table: &FavoriteTable) -> bool {
+        self.config.settings.favorite_tables.contains(table)
+    }
+
+    /// Star or unstar a table for the freshness dashboard
+    pub fn toggle_favorite_table(&mut self, table: FavoriteTable) -> Result<(), CatalogError> {
+        let mut settings = self.config.settings.clone();
+        if settings.favorite_tables.contains(&table) {
+            settings
+                .favorite_tables
+                .retain(|favorite| favorite != &table);
+        } else {
+            settings.favorite_tables.push(table);
+        }
+        self.update_settings(settings)
+    }
+
+    /// Current snapshot of a table the last time it was viewed, by [`crate::config::table_key`]
+    pub fn last_seen_snapshot(&self, table_key: &str) -> Option<LastSeenSnapshot> {
+        self.config
+            .settings
+            .last_seen_snapshots
+            .get(table_key)
+            .copied()
+    }
+
+    /// Remember the table's current snapshot as seen. Settings are only written when it moved.
+    pub fn mark_table_seen(&mut self, table: &IcebergTable) -> Result<(), CatalogError> {
+        let Some(current) = table.current_snapshot() else {
+            return Ok(());
+        };
+        let seen = LastSeenSnapshot {
+            snapshot_id: current.snapshot_id,
+            timestamp_ms: current.timestamp_ms,
+        };
+        let key = crate::config::table_key(&table.catalog_name, &table.namespace, &table.name);
+        if self.last_seen_snapshot(&key) == Some(seen) {
+            return Ok(());
+        }
+        let mut settings = self.config.settings.clone();
+        settings.last_seen_snapshots.insert(key, seen);
+        self.update_settings(settings)
+    }
+
+    /// Remember whether a navigation group is collapsed across restarts
+    pub fn set_group_collapsed(
+        &mut self,
+        group: &str,
+        collapsed: bool,
+    ) -> Result<(), CatalogError> {
+        let mut settings = self.config.settings.clone();
+        settings.collapsed_catalog_groups.retain(|g| g != group);
+        if collapsed {
+            settings.collapsed_catalog_groups.push(group.to_string());
+        }
+        self.update_settings(settings)
+    }
+
+    /// Override the freshness thresholds for one table, or fall back to the global default with `None`
+    pub fn set_table_freshness_thresholds(
+        &mut self,
+        table_key: &str,
+        thresholds: Option<FreshnessThresholds>,
+    ) -> Result<(), CatalogError> {
+        let mut settings = self.config.settings.clone();
+        match thresholds {
+            Some(thresholds) => {
+                settings
+                    .table_freshness_thresholds
+                    .insert(table_key.to_string(), thresholds);
+            }
+            None => {
+                settings.table_freshness_thresholds.remove(table_key);
+            }
+        }
+        self.update_settings(settings)
+    }
+
+    /// Rename a saved catalog. A live connection is kept and rekeyed rather than reconnected.
+    pub fn rename_catalog(&mut self, old_name: &str, new_name: &str) -> Result<(), CatalogError> {
+        let new_name = new_name.trim();
+        if new_name.is_empty() {
+            return Err(CatalogError::InvalidConfig(
+                "Catalog name cannot be empty".to_string(),
+            ));
+        }
+        if new_name == old_name {
+            return Ok(());
+        }
+
+        if let Err(e) = self.config.rename_catalog(old_name, new_name) {
+            log::error!("Failed to rename catalog {}: {}", old_name, e);
+            return Err(CatalogError::InvalidConfig(format!(
+                "Failed to rename catalog: {}",
+                e
+            )));
+        }
+
+        if let Some(connection) = self
+            .connections
+            .iter_mut()
+            .find(|conn| conn.config.name == old_name)
+        {
+            connection.config.name = new_name.to_string();
+        }
+        if self.unfiltered_catalogs.remove(old_name) {
+            self.unfiltered_catalogs.insert(new_name.to_string());
+        }
+        if let Some(reason) = self.auto_disconnected.remove(old_name) {
+            self.auto_disconnected.insert(new_name.to_string(), reason);
+        }
+        if let Ok(mut activity) = self.last_activity.lock() {
+            if let Some(last) = activity.remove(old_name) {
+                activity.insert(new_name.to_string(), last);
+            }
+        }
+        if let Ok(mut metrics) = self.request_metrics.lock() {
+            *metrics = metrics
+                .drain()
+                .map(|((catalog_name, operation), samples)| {
+                    let catalog_name = if catalog_name == old_name {
+                        new_name.to_string()
+                    } else {
+                        catalog_name
+                    };
+                    ((catalog_name, operation), samples)
+                })
+                .collect();
+        }
+
+        log::info!("Renamed catalog {} to {}", old_name, new_name);
+        Ok(())
+    }
+
     /// Delete a catalog - removes both the connection and the saved configuration
     pub fn delete_catalog(&mut self, catalog_name: &str) -> Result<(), CatalogError> {
         // Remove from active connections
         self.remove_connection(catalog_name);
+        if let Ok(mut metrics) = self.request_metrics.lock() {
+            metrics.retain(|(name, _), _| name != catalog_name);
+        }
 
         // Remove from saved configuration
         if let Err(e) = self.config.remove_catalog(catalog_name) {
@@ -459,6 +2094,623 @@ impl CatalogManager {
     }
 }
 
+/// Split a metadata file location into its directory and `*.json` file name
+fn split_metadata_location(location: &str) -> Option<(&str, &str)> {
+    let (directory, file_name) = location.rsplit_once('/')?;
+    (!directory.is_empty() && file_name.ends_with(".json")).then_some((directory, file_name))
+}
+
+/// Drop tables a catalog listed twice and log names that only differ by case, which a
+/// case-insensitive catalog may treat as one table
+fn warn_about_duplicate_tables(table_idents: &mut Vec<TableIdent>, namespace: &str) {
+    let duplicates = dedup_names(table_idents, |ident| ident.name());
+    if duplicates > 0 {
+        log::warn!(
+            "Namespace '{}' listed {} table(s) more than once; showing each once",
+            namespace,
+            duplicates
+        );
+    }
+    for group in case_variant_groups(table_idents.iter().map(|ident| ident.name())) {
+        log::warn!(
+            "Tables in namespace '{}' differ only by case: {}",
+            namespace,
+            group.join(", ")
+        );
+    }
+}
+
+/// Drop repeated names, keeping the first of each, and return how many were dropped.
+/// Some catalogs list the same table or namespace twice.
+pub fn dedup_names<T>(items: &mut Vec<T>, name: impl Fn(&T) -> &str) -> usize {
+    let before = items.len();
+    let mut seen = HashSet::new();
+    items.retain(|item| seen.insert(name(item).to_string()));
+    before - items.len()
+}
+
+/// Names that differ only by case, grouped and sorted. Case-insensitive catalogs may resolve
+/// every name in a group to the same table.
+pub fn case_variant_groups<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<Vec<String>> {
+    let mut groups: std::collections::BTreeMap<String, Vec<String>> =
+        std::collections::BTreeMap::new();
+    for name in names {
+        let group = groups.entry(name.to_lowercase()).or_default();
+        if !group.iter().any(|existing| existing == name) {
+            group.push(name.to_string());
+        }
+    }
+    groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .map(|mut group| {
+            group.sort();
+            group
+        })
+        .collect()
+}
+
+/// Whether `namespace` passes a namespace filter. The filter is a comma-separated list of
+/// patterns; a pattern containing `*` or `?` is matched as a glob against the whole name,
+/// anything else as a prefix. An empty filter matches everything.
+pub fn namespace_matches_filter(namespace: &str, filter: &str) -> bool {
+    let mut patterns = filter
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .peekable();
+    if patterns.peek().is_none() {
+        return true;
+    }
+
+    patterns.any(|pattern| {
+        if pattern.contains(['*', '?']) {
+            let pattern: Vec<char> = pattern.chars().collect();
+            let text: Vec<char> = namespace.chars().collect();
+            glob_matches(&pattern, &text)
+        } else {
+            namespace.starts_with(pattern)
+        }
+    })
+}
+
+fn glob_matches(pattern: &[char], text: &[char]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` seen and the text index it is currently absorbing up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+// Per-catalog TLS keys stored in `CatalogConfig::config`
+pub const TLS_CA_BUNDLE_KEY: &str = "tls_ca_bundle";
+pub const TLS_SKIP_VERIFY_KEY: &str = "tls_skip_verify";
+
+/// TLS options for a catalog's HTTP client
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TlsOptions {
+    pub ca_bundle_path: Option<String>,
+    pub skip_verify: bool,
+}
+
+impl TlsOptions {
+    pub fn from_catalog_config(config: &CatalogConfig) -> Self {
+        Self {
+            ca_bundle_path: config
+                .config
+                .get(TLS_CA_BUNDLE_KEY)
+                .filter(|path| !path.trim().is_empty())
+                .cloned(),
+            skip_verify: config
+                .config
+                .get(TLS_SKIP_VERIFY_KEY)
+                .is_some_and(|value| value == "true"),
+        }
+    }
+}
+
+impl CatalogConfig {
+    /// Whether certificate verification has been switched off for this catalog
+    pub fn tls_verification_disabled(&self) -> bool {
+        TlsOptions::from_catalog_config(self).skip_verify
+    }
+}
+
+/// Per-catalog key stored in `CatalogConfig::config`; `"true"` asks a REST catalog for vended
+/// storage credentials
+pub const VENDED_CREDENTIALS_KEY: &str = "vended_credentials";
+/// REST client property sending the `X-Iceberg-Access-Delegation` header on every request
+const ACCESS_DELEGATION_PROP: &str = "header.X-Iceberg-Access-Delegation";
+
+impl CatalogConfig {
+    /// Whether to ask the catalog for scoped storage credentials with each table it loads.
+    /// Opt-in, so catalogs saved before the option existed keep connecting as they did; the
+    /// connect form turns it on for new REST catalogs.
+    pub fn requests_vended_credentials(&self) -> bool {
+        self.catalog_type == CatalogType::Rest
+            && self
+                .config
+                .get(VENDED_CREDENTIALS_KEY)
+                .is_some_and(|value| value == "true")
+    }
+
+    /// Warehouses this REST catalog switches between: the remembered list followed by the
+    /// active warehouse if it isn't in it, without duplicates
+    pub fn known_warehouses(&self) -> Vec<String> {
+        let listed = self
+            .config
+            .get(WAREHOUSES_KEY)
+            .map(|list| list.split(',').collect::<Vec<_>>())
+            .unwrap_or_default();
+        let active = self.config.get("warehouse").map(String::as_str);
+        let mut warehouses: Vec<String> = Vec::new();
+        for warehouse in listed.into_iter().chain(active) {
+            let warehouse = warehouse.trim();
+            if !warehouse.is_empty() && !warehouses.iter().any(|known| known == warehouse) {
+                warehouses.push(warehouse.to_string());
+            }
+        }
+        warehouses
+    }
+
+    /// Region a Glue catalog talks to, `us-east-1` unless one is configured
+    pub fn glue_region(&self) -> String {
+        self.config
+            .get("region")
+            .map(|region| region.trim())
+            .filter(|region| !region.is_empty())
+            .unwrap_or(DEFAULT_GLUE_REGION)
+            .to_string()
+    }
+}
+
+const DEFAULT_GLUE_REGION: &str = "us-east-1";
+
+/// Comma-separated warehouses a REST catalog has been switched between. The active one is
+/// still the `warehouse` key, so connecting needs nothing else.
+pub const WAREHOUSES_KEY: &str = "warehouses";
+
+// Per-catalog object store keys stored in `CatalogConfig::config`
+pub const S3_ENDPOINT_KEY: &str = "s3_endpoint";
+pub const S3_REGION_KEY: &str = "s3_region";
+pub const S3_PATH_STYLE_KEY: &str = "s3_path_style_access";
+
+// REST catalog authentication keys stored in `CatalogConfig::config`. Catalogs saved before
+// the method was chosen explicitly only have `auth_token`, if anything.
+pub const REST_AUTH_METHOD_KEY: &str = "auth_method";
+pub const AUTH_TOKEN_KEY: &str = "auth_token";
+pub const OAUTH_CLIENT_ID_KEY: &str = "client_id";
+pub const OAUTH_CLIENT_SECRET_KEY: &str = "client_secret";
+pub const OAUTH_SERVER_URI_KEY: &str = "oauth2_server_uri";
+pub const OAUTH_SCOPE_KEY: &str = "scope";
+pub const SUBJECT_TOKEN_KEY: &str = "subject_token";
+/// RFC 8693 `subject_token_type`, named without "token" so it isn't masked as a secret
+pub const SUBJECT_TYPE_KEY: &str = "subject_type";
+
+pub const DEFAULT_SUBJECT_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:jwt";
+const TOKEN_EXCHANGE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
+
+/// How Hielo authenticates to a REST catalog
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum RestAuthMethod {
+    #[default]
+    None,
+    StaticToken,
+    /// OAuth2 client credentials, handled by the REST client against the token endpoint
+    ClientCredentials,
+    /// RFC 8693 token exchange of an identity provider's token for a catalog-scoped one
+    TokenExchange,
+}
+
+impl RestAuthMethod {
+    pub const ALL: [RestAuthMethod; 4] = [
+        RestAuthMethod::None,
+        RestAuthMethod::StaticToken,
+        RestAuthMethod::ClientCredentials,
+        RestAuthMethod::TokenExchange,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RestAuthMethod::None => "None",
+            RestAuthMethod::StaticToken => "Static token",
+            RestAuthMethod::ClientCredentials => "OAuth2 client credentials",
+            RestAuthMethod::TokenExchange => "OAuth2 token exchange",
+        }
+    }
+
+    /// Value stored under [`REST_AUTH_METHOD_KEY`]
+    pub fn key(&self) -> &'static str {
+        match self {
+            RestAuthMethod::None => "none",
+            RestAuthMethod::StaticToken => "token",
+            RestAuthMethod::ClientCredentials => "client_credentials",
+            RestAuthMethod::TokenExchange => "token_exchange",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|method| method.key() == key)
+    }
+}
+
+/// Credentials for a REST catalog. Only the fields of the chosen method are saved.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RestAuthOptions {
+    pub method: RestAuthMethod,
+    pub token: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// Token endpoint for client credentials and token exchange; the catalog's
+    /// `/v1/oauth/tokens` when empty
+    pub server_uri: String,
+    pub scope: String,
+    pub subject_token: String,
+    pub subject_token_type: String,
+}
+
+impl RestAuthOptions {
+    pub fn from_catalog_config(config: &CatalogConfig) -> Self {
+        let value = |key: &str| config.config.get(key).cloned().unwrap_or_default();
+        let token = value(AUTH_TOKEN_KEY);
+        let method = match config.config.get(REST_AUTH_METHOD_KEY) {
+            Some(key) => RestAuthMethod::from_key(key).unwrap_or_default(),
+            None if !token.is_empty() => RestAuthMethod::StaticToken,
+            None => RestAuthMethod::None,
+        };
+        Self {
+            method,
+            token,
+            client_id: value(OAUTH_CLIENT_ID_KEY),
+            client_secret: value(OAUTH_CLIENT_SECRET_KEY),
+            server_uri: value(OAUTH_SERVER_URI_KEY),
+            scope: value(OAUTH_SCOPE_KEY),
+            subject_token: value(SUBJECT_TOKEN_KEY),
+            subject_token_type: value(SUBJECT_TYPE_KEY),
+        }
+    }
+
+    /// Store the chosen method and its fields on a catalog config, skipping empty values
+    pub fn insert_into(&self, config: &mut HashMap<String, String>) {
+        let fields: Vec<(&str, &str)> = match self.method {
+            RestAuthMethod::None => vec![],
+            RestAuthMethod::StaticToken => vec![(AUTH_TOKEN_KEY, self.token.trim())],
+            RestAuthMethod::ClientCredentials => vec![
+                (OAUTH_CLIENT_ID_KEY, self.client_id.trim()),
+                (OAUTH_CLIENT_SECRET_KEY, self.client_secret.trim()),
+                (OAUTH_SERVER_URI_KEY, self.server_uri.trim()),
+                (OAUTH_SCOPE_KEY, self.scope.trim()),
+            ],
+            RestAuthMethod::TokenExchange => vec![
+                (SUBJECT_TOKEN_KEY, self.subject_token.trim()),
+                (SUBJECT_TYPE_KEY, self.subject_token_type.trim()),
+                (OAUTH_SERVER_URI_KEY, self.server_uri.trim()),
+                (OAUTH_SCOPE_KEY, self.scope.trim()),
+                (OAUTH_CLIENT_ID_KEY, self.client_id.trim()),
+                (OAUTH_CLIENT_SECRET_KEY, self.client_secret.trim()),
+            ],
+        };
+        config.insert(
+            REST_AUTH_METHOD_KEY.to_string(),
+            self.method.key().to_string(),
+        );
+        for (key, value) in fields {
+            if !value.is_empty() {
+                config.insert(key.to_string(), value.to_string());
+            }
+        }
+    }
+
+    /// Why these options can't connect yet, if a required field is missing
+    pub fn missing_field(&self) -> Option<&'static str> {
+        match self.method {
+            RestAuthMethod::None => None,
+            RestAuthMethod::StaticToken => self.token.trim().is_empty().then_some("token"),
+            RestAuthMethod::ClientCredentials => {
+                if self.client_id.trim().is_empty() {
+                    Some("client ID")
+                } else {
+                    self.client_secret
+                        .trim()
+                        .is_empty()
+                        .then_some("client secret")
+                }
+            }
+            RestAuthMethod::TokenExchange => self
+                .subject_token
+                .trim()
+                .is_empty()
+                .then_some("subject token"),
+        }
+    }
+
+    /// REST client properties for the static token or client credentials. Token exchange
+    /// happens before the client is built, see [`exchange_token`].
+    fn catalog_props(&self) -> Vec<(&'static str, String)> {
+        let mut props = Vec::new();
+        match self.method {
+            RestAuthMethod::StaticToken if !self.token.trim().is_empty() => {
+                props.push(("token", self.token.trim().to_string()));
+            }
+            RestAuthMethod::ClientCredentials => {
+                props.push((
+                    "credential",
+                    format!("{}:{}", self.client_id.trim(), self.client_secret.trim()),
+                ));
+                if !self.server_uri.trim().is_empty() {
+                    props.push(("oauth2-server-uri", self.server_uri.trim().to_string()));
+                }
+                if !self.scope.trim().is_empty() {
+                    props.push(("scope", self.scope.trim().to_string()));
+                }
+            }
+            _ => {}
+        }
+        props
+    }
+
+    /// Token endpoint for a catalog at `catalog_uri`
+    pub fn token_endpoint(&self, catalog_uri: &str) -> String {
+        match self.server_uri.trim() {
+            "" => format!("{}/v1/oauth/tokens", catalog_uri.trim_end_matches('/')),
+            server_uri => server_uri.to_string(),
+        }
+    }
+}
+
+/// Successful token endpoint response (RFC 8693 section 2.2.1)
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// Lifetime in seconds, if the server says
+    expires_in: Option<u64>,
+}
+
+/// Exchange the configured subject token for a catalog access token. The client ID and
+/// secret, when set, authenticate the exchange with HTTP basic auth.
+async fn exchange_token(
+    client: &reqwest::Client,
+    endpoint: &str,
+    auth: &RestAuthOptions,
+) -> Result<TokenResponse, CatalogError> {
+    let subject_token_type = match auth.subject_token_type.trim() {
+        "" => DEFAULT_SUBJECT_TOKEN_TYPE,
+        token_type => token_type,
+    };
+    let mut form = vec![
+        ("grant_type", TOKEN_EXCHANGE_GRANT_TYPE),
+        ("subject_token", auth.subject_token.trim()),
+        ("subject_token_type", subject_token_type),
+        ("requested_token_type", ACCESS_TOKEN_TYPE),
+    ];
+    if !auth.scope.trim().is_empty() {
+        form.push(("scope", auth.scope.trim()));
+    }
+
+    let mut request = client
+        .post(endpoint)
+        .timeout(std::time::Duration::from_secs(30))
+        .form(&form);
+    if !auth.client_id.trim().is_empty() {
+        request = request.basic_auth(auth.client_id.trim(), Some(auth.client_secret.trim()));
+    }
+    let response = request.send().await.map_err(|e| {
+        CatalogError::NetworkError(format!("Token exchange with {} failed: {}", endpoint, e))
+    })?;
+    let status = response.status();
+    let body = response.text().await.map_err(|e| {
+        CatalogError::ConnectionFailed(format!("Failed to read token exchange response: {}", e))
+    })?;
+    if !status.is_success() {
+        return Err(CatalogError::AuthenticationFailed(format!(
+            "Token exchange with {} was rejected ({}): {}",
+            endpoint, status, body
+        )));
+    }
+    serde_json::from_str(&body).map_err(|e| {
+        CatalogError::ConnectionFailed(format!("Unexpected token exchange response: {}", e))
+    })
+}
+
+/// Where table data lives when it isn't on AWS S3 (MinIO, Ceph, R2...), or lives in another
+/// region than the catalog. Empty fields leave the FileIO defaults in place.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct S3StorageOptions {
+    pub endpoint: String,
+    pub region: String,
+    pub path_style_access: bool,
+}
+
+impl S3StorageOptions {
+    pub fn from_catalog_config(config: &CatalogConfig) -> Self {
+        let value = |key: &str| config.config.get(key).cloned().unwrap_or_default();
+        Self {
+            endpoint: value(S3_ENDPOINT_KEY),
+            region: value(S3_REGION_KEY),
+            path_style_access: value(S3_PATH_STYLE_KEY) == "true",
+        }
+    }
+
+    /// Store these options on a catalog config, skipping empty values
+    pub fn insert_into(&self, config: &mut HashMap<String, String>) {
+        for (key, value) in [
+            (S3_ENDPOINT_KEY, self.endpoint.trim()),
+            (S3_REGION_KEY, self.region.trim()),
+        ] {
+            if !value.is_empty() {
+                config.insert(key.to_string(), value.to_string());
+            }
+        }
+        if self.path_style_access {
+            config.insert(S3_PATH_STYLE_KEY.to_string(), "true".to_string());
+        }
+    }
+
+    /// The FileIO properties the catalog hands to its S3 client for metadata and data files
+    fn file_io_props(&self) -> Vec<(&'static str, String)> {
+        let mut props = Vec::new();
+        if !self.endpoint.trim().is_empty() {
+            props.push(("s3.endpoint", self.endpoint.trim().to_string()));
+        }
+        if !self.region.trim().is_empty() {
+            props.push(("s3.region", self.region.trim().to_string()));
+        }
+        if self.path_style_access {
+            props.push(("s3.path-style-access", "true".to_string()));
+        }
+        props
+    }
+}
+
+/// Where a table's location sits relative to its catalog's warehouse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WarehouseRelation {
+    UnderWarehouse,
+    /// Stored outside the managed prefix, e.g. a registered or misconfigured table
+    ExternalToWarehouse,
+}
+
+impl WarehouseRelation {
+    /// Compare a table location with a warehouse URI. Warehouses that are names rather than
+    /// storage URIs (as some REST catalogs use) give `None`, as does an empty location.
+    pub fn classify(warehouse: &str, location: &str) -> Option<Self> {
+        let warehouse = normalize_storage_uri(warehouse)?;
+        let location = normalize_storage_uri(location)?;
+        let under = location == warehouse || location.starts_with(&format!("{}/", warehouse));
+        Some(if under {
+            WarehouseRelation::UnderWarehouse
+        } else {
+            WarehouseRelation::ExternalToWarehouse
+        })
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WarehouseRelation::UnderWarehouse => "Under warehouse",
+            WarehouseRelation::ExternalToWarehouse => "External to warehouse",
+        }
+    }
+}
+
+/// Trim trailing slashes and fold the Hadoop `s3a`/`s3n` schemes into `s3`, so equivalent
+/// URIs compare equal. Values without a scheme aren't storage URIs.
+fn normalize_storage_uri(uri: &str) -> Option<String> {
+    let uri = uri.trim().trim_end_matches('/');
+    let (scheme, rest) = uri.split_once("://")?;
+    let scheme = match scheme.to_ascii_lowercase().as_str() {
+        "s3a" | "s3n" => "s3".to_string(),
+        other => other.to_string(),
+    };
+    Some(format!("{}://{}", scheme, rest))
+}
+
+/// Bucket name of an `s3://` (or `s3a`/`s3n`) location
+fn s3_bucket_name(location: &str) -> Option<String> {
+    let uri = normalize_storage_uri(location)?;
+    let rest = uri.strip_prefix("s3://")?;
+    let bucket = rest.split('/').next().unwrap_or_default();
+    (!bucket.is_empty()).then(|| bucket.to_string())
+}
+
+/// Build the HTTP client used for REST catalog traffic, routed through the proxy if one is set.
+/// Without an explicit proxy reqwest still honours the system proxy environment variables.
+pub fn build_http_client(
+    proxy: Option<&ProxySettings>,
+    tls: &TlsOptions,
+) -> Result<reqwest::Client, CatalogError> {
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(path) = &tls.ca_bundle_path {
+        let pem = std::fs::read(path).map_err(|e| {
+            CatalogError::InvalidConfig(format!("Failed to read CA bundle '{}': {}", path, e))
+        })?;
+        let certificates = reqwest::Certificate::from_pem_bundle(&pem).map_err(|e| {
+            CatalogError::InvalidConfig(format!("Invalid CA bundle '{}': {}", path, e))
+        })?;
+        log::info!(
+            "Trusting {} certificate(s) from {}",
+            certificates.len(),
+            path
+        );
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    if tls.skip_verify {
+        log::warn!("TLS certificate verification is DISABLED for this catalog");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    if let Some(proxy) = proxy {
+        let mut reqwest_proxy = reqwest::Proxy::all(proxy.url.as_str()).map_err(|e| {
+            CatalogError::ProxyError(format!("Invalid proxy URL '{}': {}", proxy.url, e))
+        })?;
+        if !proxy.username.is_empty() {
+            reqwest_proxy = reqwest_proxy.basic_auth(&proxy.username, &proxy.password);
+        }
+        if !proxy.no_proxy.is_empty() {
+            reqwest_proxy = reqwest_proxy.no_proxy(reqwest::NoProxy::from_string(&proxy.no_proxy));
+        }
+        builder = builder.proxy(reqwest_proxy);
+    }
+
+    builder.build().map_err(|e| {
+        let error = format!("Failed to build HTTP client: {}", e);
+        log::error!("{}", error);
+        CatalogError::ConnectionFailed(error)
+    })
+}
+
+/// Fail fast with a proxy-specific error when the proxy itself cannot be reached, so it is
+/// not mistaken for the catalog being down
+async fn check_proxy_reachable(proxy: &ProxySettings) -> Result<(), CatalogError> {
+    let url = Url::parse(&proxy.url).map_err(|e| {
+        CatalogError::ProxyError(format!("Invalid proxy URL '{}': {}", proxy.url, e))
+    })?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| CatalogError::ProxyError(format!("Proxy URL '{}' has no host", proxy.url)))?
+        .to_string();
+    let port = url.port_or_known_default().unwrap_or(8080);
+
+    match tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        tokio::net::TcpStream::connect((host.as_str(), port)),
+    )
+    .await
+    {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => {
+            let error = format!("Cannot reach proxy {}:{}: {}", host, port, e);
+            log::error!("{}", error);
+            Err(CatalogError::ProxyError(error))
+        }
+        Err(_) => {
+            let error = format!("Timed out connecting to proxy {}:{}", host, port);
+            log::error!("{}", error);
+            Err(CatalogError::ProxyError(error))
+        }
+    }
+}
+
 // Test connection function
 pub async fn test_catalog_connection(config: &CatalogConfig) -> Result<String, CatalogError> {
     let mut manager = CatalogManager::new();
@@ -472,3 +2724,350 @@ pub async fn test_catalog_connection(config: &CatalogConfig) -> Result<String, C
         namespaces.len()
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespace_filter_prefix() {
+        assert!(namespace_matches_filter("team_a_sales", "team_a"));
+        assert!(!namespace_matches_filter("team_b_sales", "team_a"));
+        assert!(namespace_matches_filter("anything", ""));
+        assert!(namespace_matches_filter("anything", " , "));
+    }
+
+    #[test]
+    fn test_namespace_filter_glob() {
+        assert!(namespace_matches_filter("analytics.team_a", "*.team_a"));
+        assert!(namespace_matches_filter("team_a_raw", "team_?_*"));
+        assert!(!namespace_matches_filter("team_ab", "team_?"));
+        assert!(!namespace_matches_filter("other.team_a.raw", "*.team_a"));
+        assert!(namespace_matches_filter("a**b", "a*b"));
+    }
+
+    #[test]
+    fn test_namespace_filter_multiple_patterns() {
+        let filter = "finance, *_shared";
+        assert!(namespace_matches_filter("finance_reporting", filter));
+        assert!(namespace_matches_filter("marketing_shared", filter));
+        assert!(!namespace_matches_filter("marketing", filter));
+    }
+
+    #[test]
+    fn test_is_stale_connection_error() {
+        assert!(is_stale_connection_error(
+            "Failed to load table: error sending request: connection reset by peer (os error 104)"
+        ));
+        assert!(is_stale_connection_error("io error: Broken pipe"));
+        assert!(!is_stale_connection_error(
+            "Failed to load table: 404 NoSuchTableException"
+        ));
+        assert!(!is_stale_connection_error("connection refused"));
+    }
+
+    #[test]
+    fn test_is_expired_credentials_error() {
+        assert!(is_expired_credentials_error(
+            "Could not read manifest 's3://bucket/m.avro': Unexpected => S3Error { code: \"ExpiredToken\", message: \"The provided token has expired.\" }"
+        ));
+        assert!(is_expired_credentials_error(
+            "Server-side credentials have expired"
+        ));
+        assert!(!is_expired_credentials_error(
+            "Could not read manifest 's3://bucket/m.avro': AccessDenied"
+        ));
+    }
+
+    #[test]
+    fn test_token_expired_at() {
+        let now = chrono::Utc::now();
+        let expires_in = |seconds: i64| Some(now + chrono::Duration::seconds(seconds));
+
+        assert!(!token_expired_at(None, now));
+        assert!(!token_expired_at(expires_in(3_600), now));
+        assert!(!token_expired_at(
+            expires_in(TOKEN_REFRESH_MARGIN_SECS + 1),
+            now
+        ));
+        // Within the margin the token is renewed before a request can race the expiry
+        assert!(token_expired_at(expires_in(TOKEN_REFRESH_MARGIN_SECS), now));
+        assert!(token_expired_at(expires_in(1), now));
+        assert!(token_expired_at(expires_in(-10), now));
+    }
+
+    #[test]
+    fn test_requests_vended_credentials() {
+        // Saved catalogs without the key don't start sending the delegation header
+        let mut rest = CatalogConfig::new_rest("rest".to_string(), "http://localhost".to_string());
+        assert!(!rest.requests_vended_credentials());
+        rest.config
+            .insert(VENDED_CREDENTIALS_KEY.to_string(), "true".to_string());
+        assert!(rest.requests_vended_credentials());
+        rest.config
+            .insert(VENDED_CREDENTIALS_KEY.to_string(), "false".to_string());
+        assert!(!rest.requests_vended_credentials());
+
+        let glue = CatalogConfig {
+            catalog_type: CatalogType::Glue,
+            ..CatalogConfig::new_rest("glue".to_string(), String::new())
+        };
+        assert!(!glue.requests_vended_credentials());
+    }
+
+    #[test]
+    fn test_known_warehouses() {
+        let mut rest = CatalogConfig::new_rest("rest".to_string(), "http://localhost".to_string());
+        assert!(rest.known_warehouses().is_empty());
+
+        rest.config
+            .insert("warehouse".to_string(), "analytics".to_string());
+        assert_eq!(rest.known_warehouses(), vec!["analytics"]);
+
+        rest.config.insert(
+            WAREHOUSES_KEY.to_string(),
+            "sandbox, analytics,,sandbox".to_string(),
+        );
+        assert_eq!(rest.known_warehouses(), vec!["sandbox", "analytics"]);
+    }
+
+    #[test]
+    fn test_rest_auth_options() {
+        // Catalogs saved with just a token keep using it
+        let mut legacy =
+            CatalogConfig::new_rest("rest".to_string(), "http://localhost".to_string());
+        assert_eq!(
+            RestAuthOptions::from_catalog_config(&legacy).method,
+            RestAuthMethod::None
+        );
+        legacy
+            .config
+            .insert(AUTH_TOKEN_KEY.to_string(), "abc".to_string());
+        let auth = RestAuthOptions::from_catalog_config(&legacy);
+        assert_eq!(auth.method, RestAuthMethod::StaticToken);
+        assert_eq!(auth.catalog_props(), vec![("token", "abc".to_string())]);
+
+        // Only the chosen method's fields are saved, and they round-trip
+        let exchange = RestAuthOptions {
+            method: RestAuthMethod::TokenExchange,
+            token: "stale".to_string(),
+            subject_token: " eyJ.idp.jwt ".to_string(),
+            server_uri: "https://idp.example.com/token".to_string(),
+            ..Default::default()
+        };
+        let mut config = CatalogConfig::new_rest("sso".to_string(), "http://localhost".to_string());
+        exchange.insert_into(&mut config.config);
+        assert!(!config.config.contains_key(AUTH_TOKEN_KEY));
+        let restored = RestAuthOptions::from_catalog_config(&config);
+        assert_eq!(restored.method, RestAuthMethod::TokenExchange);
+        assert_eq!(restored.subject_token, "eyJ.idp.jwt");
+        assert_eq!(
+            restored.token_endpoint("http://localhost"),
+            "https://idp.example.com/token"
+        );
+        // Exchange happens at connect time, not through client properties
+        assert!(restored.catalog_props().is_empty());
+        assert_eq!(restored.missing_field(), None);
+
+        let credentials = RestAuthOptions {
+            method: RestAuthMethod::ClientCredentials,
+            client_id: "hielo".to_string(),
+            client_secret: "s3cret".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            credentials.catalog_props(),
+            vec![("credential", "hielo:s3cret".to_string())]
+        );
+        assert_eq!(
+            credentials.token_endpoint("http://localhost:8181/"),
+            "http://localhost:8181/v1/oauth/tokens"
+        );
+        assert_eq!(
+            RestAuthOptions {
+                client_secret: String::new(),
+                ..credentials
+            }
+            .missing_field(),
+            Some("client secret")
+        );
+    }
+
+    #[test]
+    fn test_glue_region_and_bucket_name() {
+        let glue = |region: Option<&str>| {
+            CatalogConfig::new_glue(
+                "glue".to_string(),
+                "s3://lake/warehouse".to_string(),
+                region.map(str::to_string),
+            )
+        };
+        assert_eq!(glue(None).glue_region(), "us-east-1");
+        assert_eq!(glue(Some(" ")).glue_region(), "us-east-1");
+        assert_eq!(glue(Some("eu-west-1")).glue_region(), "eu-west-1");
+
+        assert_eq!(
+            s3_bucket_name("s3://lake/warehouse/"),
+            Some("lake".to_string())
+        );
+        assert_eq!(s3_bucket_name("s3a://lake"), Some("lake".to_string()));
+        assert_eq!(s3_bucket_name("gs://lake/warehouse"), None);
+        assert_eq!(s3_bucket_name("warehouse"), None);
+        assert_eq!(s3_bucket_name("s3:///warehouse"), None);
+    }
+
+    #[test]
+    fn test_slept_between() {
+        let tick = std::time::Duration::from_secs(15);
+        let start = chrono::Utc::now();
+        let after = |secs: i64| start + chrono::Duration::seconds(secs);
+        assert!(!slept_between(start, after(16), tick));
+        assert!(!slept_between(start, after(130), tick));
+        assert!(slept_between(start, after(3_600), tick));
+        // A clock set backwards is not a sleep
+        assert!(!slept_between(start, after(-3_600), tick));
+    }
+
+    #[test]
+    fn test_classify_load_error() {
+        let io_error = |kind| {
+            iceberg::Error::new(
+                iceberg::ErrorKind::Unexpected,
+                "Failure in doing io operation",
+            )
+            .with_source(opendal::Error::new(
+                kind,
+                "s3://warehouse/permissions/metadata/00404-not_found.metadata.json",
+            ))
+        };
+        assert_eq!(
+            LoadFailureKind::from_error(&io_error(opendal::ErrorKind::PermissionDenied)),
+            LoadFailureKind::AccessDenied
+        );
+        assert_eq!(
+            LoadFailureKind::from_error(&io_error(opendal::ErrorKind::NotFound)),
+            LoadFailureKind::MissingMetadata
+        );
+        assert_eq!(
+            LoadFailureKind::from_error(&iceberg::Error::new(
+                iceberg::ErrorKind::DataInvalid,
+                "Failed to parse metadata: expected value at line 1"
+            )),
+            LoadFailureKind::ParseError
+        );
+        // Names that look like causes don't decide the kind
+        assert_eq!(
+            LoadFailureKind::from_error(&io_error(opendal::ErrorKind::Unexpected)),
+            LoadFailureKind::Other
+        );
+        assert_eq!(
+            LoadFailureKind::from_error(&iceberg::Error::new(
+                iceberg::ErrorKind::Unexpected,
+                "Failed to load 'parsed_events.permissions': connection reset"
+            )),
+            LoadFailureKind::Other
+        );
+    }
+
+    #[test]
+    fn test_split_metadata_location() {
+        assert_eq!(
+            split_metadata_location("s3://bucket/db/orders/metadata/00003-ab.metadata.json"),
+            Some(("s3://bucket/db/orders/metadata", "00003-ab.metadata.json"))
+        );
+        assert_eq!(
+            split_metadata_location("/tmp/v1.metadata.json"),
+            Some(("/tmp", "v1.metadata.json"))
+        );
+        assert_eq!(split_metadata_location("/v1.metadata.json"), None);
+        assert_eq!(split_metadata_location("s3://bucket/db/orders"), None);
+        assert_eq!(split_metadata_location("v1.metadata.json"), None);
+    }
+
+    #[test]
+    fn test_dedup_names() {
+        let mut names = vec![
+            "orders".to_string(),
+            "Orders".to_string(),
+            "orders".to_string(),
+            "users".to_string(),
+        ];
+        assert_eq!(dedup_names(&mut names, |name| name.as_str()), 1);
+        assert_eq!(names, vec!["orders", "Orders", "users"]);
+        assert_eq!(dedup_names(&mut names, |name| name.as_str()), 0);
+    }
+
+    #[test]
+    fn test_case_variant_groups() {
+        let names = [
+            "orders", "users", "Orders", "ORDERS", "orders", "Users", "items",
+        ];
+        assert_eq!(
+            case_variant_groups(names),
+            vec![
+                vec![
+                    "ORDERS".to_string(),
+                    "Orders".to_string(),
+                    "orders".to_string()
+                ],
+                vec!["Users".to_string(), "users".to_string()],
+            ]
+        );
+        // Exact repeats alone are not case variants
+        assert!(case_variant_groups(["orders", "orders"]).is_empty());
+    }
+
+    #[test]
+    fn test_latency_percentile() {
+        assert_eq!(latency_percentile(&[], 50.0), 0);
+        assert_eq!(latency_percentile(&[42], 95.0), 42);
+
+        let samples: Vec<u64> = (1..=100).rev().collect();
+        assert_eq!(latency_percentile(&samples, 50.0), 50);
+        assert_eq!(latency_percentile(&samples, 95.0), 95);
+        assert_eq!(latency_percentile(&samples, 100.0), 100);
+        assert_eq!(latency_percentile(&[30, 10, 20], 50.0), 20);
+    }
+
+    #[test]
+    fn test_s3_storage_options() {
+        let options = S3StorageOptions {
+            endpoint: " http://localhost:9000 ".to_string(),
+            region: String::new(),
+            path_style_access: true,
+        };
+        let mut catalog =
+            CatalogConfig::new_rest("minio".to_string(), "http://localhost:8181".to_string());
+        options.insert_into(&mut catalog.config);
+        assert!(!catalog.config.contains_key(S3_REGION_KEY));
+
+        let restored = S3StorageOptions::from_catalog_config(&catalog);
+        assert_eq!(
+            restored.file_io_props(),
+            vec![
+                ("s3.endpoint", "http://localhost:9000".to_string()),
+                ("s3.path-style-access", "true".to_string()),
+            ]
+        );
+        assert!(S3StorageOptions::default().file_io_props().is_empty());
+    }
+
+    #[test]
+    fn test_warehouse_relation() {
+        assert_eq!(
+            WarehouseRelation::classify(
+                "s3://lake/warehouse/",
+                "s3a://lake/warehouse/sales/orders"
+            ),
+            Some(WarehouseRelation::UnderWarehouse)
+        );
+        assert_eq!(
+            WarehouseRelation::classify("s3://lake/warehouse", "s3://lake/warehouse-old/orders"),
+            Some(WarehouseRelation::ExternalToWarehouse)
+        );
+        assert_eq!(
+            WarehouseRelation::classify("analytics", "s3://lake/warehouse/orders"),
+            None
+        );
+    }
+}
@@ -1,7 +1,25 @@
-use crate::catalog::{CatalogConfig, CatalogManager, CatalogType, TableReference, TableType};
+use crate::catalog::{
+    CatalogConfig, CatalogManager, CatalogType, DEFAULT_SUBJECT_TOKEN_TYPE, RestAuthMethod,
+    RestAuthOptions, S3StorageOptions, TLS_CA_BUNDLE_KEY, TLS_SKIP_VERIFY_KEY, TableReference,
+    TableType, VENDED_CREDENTIALS_KEY,
+};
+use crate::components::format_bytes;
+use crate::config::ProxySettings;
+use crate::crawl::{CrawlRun, crawl};
+use crate::data::TableStats;
+use crate::strings::{Msg, t};
+use dioxus::html::HasFileData;
 use dioxus::prelude::*;
 use std::collections::HashMap;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TableSortColumn {
+    Name,
+    Records,
+    Size,
+    LastModified,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ConnectionStatus {
     Disconnected,
@@ -10,7 +28,7 @@ pub enum ConnectionStatus {
     Error(String),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CatalogFormType {
     Rest,
     Glue,
@@ -27,6 +45,7 @@ pub fn CatalogConnectionScreen(
     catalog_manager: Signal<CatalogManager>,
     on_catalog_connected: EventHandler<()>,
     on_table_selected: EventHandler<(String, String, String)>, // (catalog_name, namespace, table_name)
+    on_view_selected: EventHandler<(String, String, String)>,
 ) -> Element {
     let mut selected_catalog_type = use_signal(|| CatalogFormType::Rest);
     let connection_status = use_signal(|| ConnectionStatus::Disconnected);
@@ -48,7 +67,7 @@ pub fn CatalogConnectionScreen(
                         class: "flex justify-between items-center py-6",
                         h1 {
                             class: "text-3xl font-bold text-gray-900",
-                            "🧊 Hielo - Connect to Catalog"
+                            {format!("🧊 Hielo - {}", t(Msg::ConnectToCatalog))}
                         }
                     }
                 }
@@ -148,6 +167,7 @@ pub fn CatalogConnectionScreen(
                         selected_namespace: selected_namespace,
                         tables: tables,
                         on_table_selected,
+                        on_view_selected,
                         loading_namespaces: false, // Connection screen doesn't show namespace loading
                     }
                 }
@@ -166,7 +186,13 @@ fn RestCatalogForm(
     let mut catalog_name = use_signal(|| "rest-catalog".to_string());
     let mut uri = use_signal(|| "".to_string());
     let mut warehouse = use_signal(|| "".to_string());
-    let mut auth_token = use_signal(|| "".to_string());
+    let auth = use_signal(RestAuthOptions::default);
+    let proxy_override = use_signal(ProxySettings::default);
+    let s3_storage = use_signal(S3StorageOptions::default);
+    let recent = catalog_manager.read().settings().recent_endpoints.clone();
+    let mut ca_bundle_path = use_signal(String::new);
+    let mut skip_tls_verify = use_signal(|| false);
+    let mut vended_credentials = use_signal(|| true);
 
     let connect = move |_| async move {
         connection_status.set(ConnectionStatus::Connecting);
@@ -182,20 +208,43 @@ fn RestCatalogForm(
             )));
             return;
         }
+        if let Some(field) = auth.read().missing_field() {
+            connection_status.set(ConnectionStatus::Error(format!(
+                "Enter the {} for {} authentication.",
+                field,
+                auth.read().method.label()
+            )));
+            return;
+        }
 
         let mut config = HashMap::new();
         config.insert("uri".to_string(), uri());
         if !warehouse().is_empty() {
             config.insert("warehouse".to_string(), warehouse());
         }
-        if !auth_token().is_empty() {
-            config.insert("auth_token".to_string(), auth_token());
+        auth.read().insert_into(&mut config);
+        proxy_override.read().insert_into(&mut config);
+        s3_storage.read().insert_into(&mut config);
+        if !ca_bundle_path().trim().is_empty() {
+            config.insert(
+                TLS_CA_BUNDLE_KEY.to_string(),
+                ca_bundle_path().trim().to_string(),
+            );
+        }
+        if skip_tls_verify() {
+            config.insert(TLS_SKIP_VERIFY_KEY.to_string(), "true".to_string());
+        }
+        if vended_credentials() {
+            config.insert(VENDED_CREDENTIALS_KEY.to_string(), "true".to_string());
         }
 
         let catalog_config = CatalogConfig {
             catalog_type: CatalogType::Rest,
             name: catalog_name(),
             config,
+            group: None,
+            namespace_filter: None,
+            pinned: false,
         };
 
         let connection_result = catalog_manager
@@ -205,6 +254,12 @@ fn RestCatalogForm(
         match connection_result {
             Ok(()) => {
                 connection_status.set(ConnectionStatus::Connected);
+                if let Err(e) = catalog_manager
+                    .write()
+                    .remember_recent_endpoint(&catalog_config)
+                {
+                    log::warn!("Failed to remember recent endpoint: {}", e);
+                }
                 // Load namespaces
                 match catalog_manager
                     .read()
@@ -228,10 +283,7 @@ fn RestCatalogForm(
             class: "space-y-4",
 
             div {
-                label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "Catalog Name"
-                }
+                HelpLabel { form_type: CatalogFormType::Rest, field: "name", text: "Catalog Name" }
                 input {
                     r#type: "text",
                     value: "{catalog_name}",
@@ -239,27 +291,25 @@ fn RestCatalogForm(
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
                     placeholder: "my-rest-catalog"
                 }
+                HelpHint { form_type: CatalogFormType::Rest, field: "name" }
             }
 
             div {
-                label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "REST Endpoint URI *"
-                }
+                HelpLabel { form_type: CatalogFormType::Rest, field: "uri", text: "REST Endpoint URI *" }
                 input {
                     r#type: "url",
                     value: "{uri}",
+                    list: "recent-rest-uris",
                     oninput: move |evt| uri.set(evt.value()),
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
                     placeholder: "http://localhost:8181"
                 }
+                RecentEndpointOptions { id: "recent-rest-uris", values: recent.rest_uris.clone() }
+                HelpHint { form_type: CatalogFormType::Rest, field: "uri" }
             }
 
             div {
-                label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "Warehouse Location (Optional)"
-                }
+                HelpLabel { form_type: CatalogFormType::Rest, field: "warehouse", text: "Warehouse Location (Optional)" }
                 input {
                     r#type: "text",
                     value: "{warehouse}",
@@ -267,19 +317,65 @@ fn RestCatalogForm(
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
                     placeholder: "s3://my-bucket/warehouse/"
                 }
+                HelpHint { form_type: CatalogFormType::Rest, field: "warehouse" }
             }
 
+            RestAuthSection { auth: auth }
+
+            PrerequisitesSection { form_type: CatalogFormType::Rest }
+
             div {
                 label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "Auth Token (Optional)"
+                    class: "flex items-center text-sm font-medium text-gray-700",
+                    input {
+                        r#type: "checkbox",
+                        checked: vended_credentials(),
+                        onchange: move |evt| vended_credentials.set(evt.checked()),
+                        class: "mr-2"
+                    }
+                    "Use storage credentials vended by the catalog"
                 }
+                p {
+                    class: "mt-1 text-xs text-gray-500",
+                    "Asks the catalog for scoped credentials with each table, so Hielo can read metadata and manifests without its own S3 keys. Catalogs that don't vend credentials ignore this and your local AWS credentials are used."
+                }
+            }
+
+            S3StorageSection { options: s3_storage }
+
+            ProxyOverrideSection { proxy: proxy_override }
+
+            div {
+                HelpLabel { form_type: CatalogFormType::Rest, field: "ca_bundle", text: "Custom CA Bundle (Optional)" }
                 input {
-                    r#type: "password",
-                    value: "{auth_token}",
-                    oninput: move |evt| auth_token.set(evt.value()),
+                    r#type: "text",
+                    value: "{ca_bundle_path}",
+                    oninput: move |evt| ca_bundle_path.set(evt.value()),
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
-                    placeholder: "Bearer token or API key"
+                    placeholder: "/etc/ssl/certs/internal-ca.pem"
+                }
+                HelpHint { form_type: CatalogFormType::Rest, field: "ca_bundle" }
+            }
+
+            div {
+                class: format!("p-3 rounded-md border {}",
+                    if skip_tls_verify() { "bg-red-50 border-red-300" } else { "border-gray-200" }
+                ),
+                label {
+                    class: "flex items-center text-sm font-medium text-gray-700",
+                    input {
+                        r#type: "checkbox",
+                        checked: skip_tls_verify(),
+                        onchange: move |evt| skip_tls_verify.set(evt.checked()),
+                        class: "mr-2"
+                    }
+                    "Skip TLS certificate verification (insecure, development only)"
+                }
+                if skip_tls_verify() {
+                    p {
+                        class: "mt-2 text-xs text-red-700 font-medium",
+                        "⚠️ Certificates will not be checked. Anyone on the network path can impersonate this catalog and read your credentials. Never use this for production catalogs."
+                    }
                 }
             }
 
@@ -315,6 +411,9 @@ fn GlueCatalogForm(
     let mut warehouse = use_signal(|| "".to_string());
     let mut region = use_signal(|| "us-east-1".to_string());
     let mut profile = use_signal(|| "".to_string());
+    let proxy_override = use_signal(ProxySettings::default);
+    let s3_storage = use_signal(S3StorageOptions::default);
+    let recent = catalog_manager.read().settings().recent_endpoints.clone();
 
     let connect = move |_| async move {
         connection_status.set(ConnectionStatus::Connecting);
@@ -337,11 +436,16 @@ fn GlueCatalogForm(
         if !profile().is_empty() {
             config.insert("profile".to_string(), profile());
         }
+        proxy_override.read().insert_into(&mut config);
+        s3_storage.read().insert_into(&mut config);
 
         let catalog_config = CatalogConfig {
             catalog_type: CatalogType::Glue,
             name: catalog_name(),
             config,
+            group: None,
+            namespace_filter: None,
+            pinned: false,
         };
 
         let connection_result = catalog_manager
@@ -351,6 +455,12 @@ fn GlueCatalogForm(
         match connection_result {
             Ok(()) => {
                 connection_status.set(ConnectionStatus::Connected);
+                if let Err(e) = catalog_manager
+                    .write()
+                    .remember_recent_endpoint(&catalog_config)
+                {
+                    log::warn!("Failed to remember recent endpoint: {}", e);
+                }
                 // Load namespaces
                 match catalog_manager
                     .read()
@@ -374,10 +484,7 @@ fn GlueCatalogForm(
             class: "space-y-4",
 
             div {
-                label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "Catalog Name"
-                }
+                HelpLabel { form_type: CatalogFormType::Glue, field: "name", text: "Catalog Name" }
                 input {
                     r#type: "text",
                     value: "{catalog_name}",
@@ -385,41 +492,39 @@ fn GlueCatalogForm(
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
                     placeholder: "my-glue-catalog"
                 }
+                HelpHint { form_type: CatalogFormType::Glue, field: "name" }
             }
 
             div {
-                label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "S3 Warehouse Location *"
-                }
+                HelpLabel { form_type: CatalogFormType::Glue, field: "warehouse", text: "S3 Warehouse Location *" }
                 input {
                     r#type: "text",
                     value: "{warehouse}",
+                    list: "recent-glue-warehouses",
                     oninput: move |evt| warehouse.set(evt.value()),
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
                     placeholder: "s3://my-bucket/warehouse/"
                 }
+                RecentEndpointOptions { id: "recent-glue-warehouses", values: recent.glue_warehouses.clone() }
+                HelpHint { form_type: CatalogFormType::Glue, field: "warehouse" }
             }
 
             div {
-                label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "AWS Region"
-                }
+                HelpLabel { form_type: CatalogFormType::Glue, field: "region", text: "AWS Region" }
                 input {
                     r#type: "text",
                     value: "{region}",
+                    list: "recent-glue-regions",
                     oninput: move |evt| region.set(evt.value()),
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
                     placeholder: "us-east-1"
                 }
+                RecentEndpointOptions { id: "recent-glue-regions", values: recent.glue_regions.clone() }
+                HelpHint { form_type: CatalogFormType::Glue, field: "region" }
             }
 
             div {
-                label {
-                    class: "block text-sm font-medium text-gray-700",
-                    "AWS Profile (Optional)"
-                }
+                HelpLabel { form_type: CatalogFormType::Glue, field: "profile", text: "AWS Profile (Optional)" }
                 input {
                     r#type: "text",
                     value: "{profile}",
@@ -427,12 +532,15 @@ fn GlueCatalogForm(
                     class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500",
                     placeholder: "default"
                 }
-                p {
-                    class: "mt-1 text-xs text-gray-500",
-                    "Leave empty to use default AWS credentials"
-                }
+                HelpHint { form_type: CatalogFormType::Glue, field: "profile" }
             }
 
+            PrerequisitesSection { form_type: CatalogFormType::Glue }
+
+            S3StorageSection { options: s3_storage }
+
+            ProxyOverrideSection { proxy: proxy_override }
+
             button {
                 onclick: connect,
                 disabled: warehouse().is_empty() || matches!(connection_status(), ConnectionStatus::Connecting),
@@ -454,6 +562,487 @@ fn GlueCatalogForm(
     }
 }
 
+/// Inline guidance for one catalog form field
+struct FieldHelp {
+    form_type: CatalogFormType,
+    field: &'static str,
+    /// Short line shown under the input
+    hint: &'static str,
+    /// Longer explanation shown on the "?" tooltip
+    details: &'static str,
+}
+
+/// Help text for every catalog form field, keyed by catalog type and field
+const FIELD_HELP: &[FieldHelp] = &[
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "name",
+        hint: "A unique label for this connection in Hielo",
+        details: "Only used inside Hielo to tell catalogs apart. It is not sent to the catalog server.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "uri",
+        hint: "Base URL of the Iceberg REST catalog, without the /v1 suffix",
+        details: "The server must implement the Iceberg REST catalog API. Hielo calls {uri}/v1/config first, then lists namespaces. Examples: http://localhost:8181, https://catalog.example.com/api/catalog",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "warehouse",
+        hint: "Warehouse identifier the server expects, if any",
+        details: "Sent as the 'warehouse' parameter of the config request. Depending on the server this is a storage location (s3://bucket/path), a warehouse name (Polaris, Lakekeeper) or an account-scoped ID (Tabular). Leave empty if the server has a single default warehouse.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "auth_method",
+        hint: "How Hielo proves its identity to the catalog",
+        details: "Static token sends a fixed Bearer token. Client credentials fetch a token from the OAuth2 token endpoint with a client ID and secret. Token exchange (RFC 8693) trades a token from your identity provider, e.g. an SSO-issued JWT, for a catalog-scoped one and exchanges it again when it expires.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "oauth2_server_uri",
+        hint: "Leave empty to use the catalog's own {uri}/v1/oauth/tokens endpoint",
+        details: "Identity providers and catalogs such as Polaris or Unity Catalog often issue tokens from a separate URL. It must accept form-encoded token requests.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "subject_token",
+        hint: "The token from your identity provider that is exchanged for a catalog token",
+        details: "Usually a JWT from your SSO login, e.g. the output of your IdP's CLI. It is stored like other secrets and re-exchanged each time the catalog token expires; replace it here once it expires itself.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "auth_token",
+        hint: "Sent as a Bearer token on every request",
+        details: "Paste the token only, without the 'Bearer ' prefix. Tokens are stored in ~/.hielo/config.json and are never shown in the UI after saving.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Rest,
+        field: "ca_bundle",
+        hint: "PEM file with the CA certificate(s) that signed the catalog's TLS certificate",
+        details: "Needed when the catalog uses a certificate from a private or corporate CA. The file may contain several certificates; all are trusted in addition to the system roots.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Glue,
+        field: "name",
+        hint: "A unique label for this connection in Hielo",
+        details: "Only used inside Hielo to tell catalogs apart. It does not need to match anything in AWS.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Glue,
+        field: "warehouse",
+        hint: "S3 location under which the Glue tables store their data",
+        details: "Use the s3:// form, e.g. s3://my-bucket/warehouse/. Hielo reads table metadata files from S3, so the credentials must be allowed to read this location.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Glue,
+        field: "region",
+        hint: "Region of the Glue Data Catalog, e.g. us-east-1",
+        details: "Glue catalogs are regional. Use the region where your databases live; tables in other regions will not be listed.",
+    },
+    FieldHelp {
+        form_type: CatalogFormType::Glue,
+        field: "profile",
+        hint: "Leave empty to use default AWS credentials",
+        details: "Name of a profile in ~/.aws/config. Without one the standard chain applies: AWS_* environment variables, the default profile, SSO, then instance or container credentials.",
+    },
+];
+
+impl CatalogFormType {
+    fn field_help(&self, field: &str) -> Option<&'static FieldHelp> {
+        FIELD_HELP
+            .iter()
+            .find(|help| help.form_type == *self && help.field == field)
+    }
+
+    /// What has to be in place before a connection can succeed
+    fn prerequisites(&self) -> &'static [&'static str] {
+        match self {
+            CatalogFormType::Rest => &[
+                "The catalog server is reachable from this machine (check VPNs and proxies)",
+                "The token, if required, can list namespaces and load tables",
+                "For tables on S3/GCS/ADLS, the server vends credentials or the environment provides them",
+            ],
+            CatalogFormType::Glue => &[
+                "AWS credentials are available (profile, environment variables or SSO)",
+                "IAM permissions: glue:GetDatabases, glue:GetDatabase, glue:GetTables, glue:GetTable",
+                "IAM permissions: s3:GetObject and s3:ListBucket on the warehouse location",
+                "Tables are registered in Glue with table_type=ICEBERG",
+            ],
+        }
+    }
+
+    fn docs_link(&self) -> (&'static str, &'static str) {
+        match self {
+            CatalogFormType::Rest => (
+                "Iceberg REST catalog specification",
+                "https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml",
+            ),
+            CatalogFormType::Glue => (
+                "Using Iceberg with AWS Glue",
+                "https://iceberg.apache.org/docs/latest/aws/",
+            ),
+        }
+    }
+}
+
+/// Form label with a "?" tooltip explaining the field
+#[component]
+fn HelpLabel(form_type: CatalogFormType, field: &'static str, text: &'static str) -> Element {
+    let details = form_type.field_help(field).map(|help| help.details);
+
+    rsx! {
+        label {
+            class: "flex items-center text-sm font-medium text-gray-700",
+            "{text}"
+            if let Some(details) = details {
+                span {
+                    class: "ml-1 inline-flex items-center justify-center w-4 h-4 rounded-full bg-gray-200 text-gray-600 text-xs cursor-help",
+                    title: "{details}",
+                    "?"
+                }
+            }
+        }
+    }
+}
+
+/// One-line helper text under a form input
+#[component]
+fn HelpHint(form_type: CatalogFormType, field: &'static str) -> Element {
+    match form_type.field_help(field) {
+        Some(help) => rsx! {
+            p {
+                class: "mt-1 text-xs text-gray-500",
+                "{help.hint}"
+            }
+        },
+        None => rsx! {},
+    }
+}
+
+/// Authentication method picker for REST catalogs, showing only the chosen method's fields
+#[component]
+fn RestAuthSection(auth: Signal<RestAuthOptions>) -> Element {
+    let method = auth.read().method;
+    let input_class = "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500";
+
+    rsx! {
+        div {
+            class: "space-y-3",
+            div {
+                HelpLabel { form_type: CatalogFormType::Rest, field: "auth_method", text: "Authentication" }
+                select {
+                    class: "{input_class}",
+                    onchange: move |evt| {
+                        if let Some(selected) = RestAuthMethod::ALL
+                            .into_iter()
+                            .find(|m| m.key() == evt.value())
+                        {
+                            auth.with_mut(|a| a.method = selected);
+                        }
+                    },
+                    for option_method in RestAuthMethod::ALL {
+                        option {
+                            value: option_method.key(),
+                            selected: method == option_method,
+                            "{option_method.label()}"
+                        }
+                    }
+                }
+                HelpHint { form_type: CatalogFormType::Rest, field: "auth_method" }
+            }
+
+            match method {
+                RestAuthMethod::None => rsx! {},
+                RestAuthMethod::StaticToken => rsx! {
+                    div {
+                        HelpLabel { form_type: CatalogFormType::Rest, field: "auth_token", text: "Auth Token" }
+                        input {
+                            r#type: "password",
+                            value: "{auth.read().token}",
+                            oninput: move |evt| auth.with_mut(|a| a.token = evt.value()),
+                            class: "{input_class}",
+                            placeholder: "Bearer token or API key"
+                        }
+                        HelpHint { form_type: CatalogFormType::Rest, field: "auth_token" }
+                    }
+                },
+                RestAuthMethod::TokenExchange => rsx! {
+                    div {
+                        HelpLabel { form_type: CatalogFormType::Rest, field: "subject_token", text: "Subject Token *" }
+                        input {
+                            r#type: "password",
+                            value: "{auth.read().subject_token}",
+                            oninput: move |evt| auth.with_mut(|a| a.subject_token = evt.value()),
+                            class: "{input_class}",
+                            placeholder: "eyJhbGciOi..."
+                        }
+                        HelpHint { form_type: CatalogFormType::Rest, field: "subject_token" }
+                    }
+                    div {
+                        label { class: "block text-sm font-medium text-gray-700", "Subject Token Type" }
+                        input {
+                            r#type: "text",
+                            value: "{auth.read().subject_token_type}",
+                            oninput: move |evt| auth.with_mut(|a| a.subject_token_type = evt.value()),
+                            class: "{input_class}",
+                            placeholder: DEFAULT_SUBJECT_TOKEN_TYPE
+                        }
+                    }
+                    OAuthClientFields { auth: auth, optional: true }
+                },
+                RestAuthMethod::ClientCredentials => rsx! {
+                    OAuthClientFields { auth: auth, optional: false }
+                },
+            }
+        }
+    }
+}
+
+/// Token endpoint, scope and client ID/secret shared by the OAuth2 methods. Token exchange
+/// only sends the client credentials when the identity provider asks for them.
+#[component]
+fn OAuthClientFields(auth: Signal<RestAuthOptions>, optional: bool) -> Element {
+    let input_class = "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500";
+    let client_suffix = if optional { " (Optional)" } else { " *" };
+
+    rsx! {
+        div {
+            HelpLabel { form_type: CatalogFormType::Rest, field: "oauth2_server_uri", text: "Token Endpoint (Optional)" }
+            input {
+                r#type: "url",
+                value: "{auth.read().server_uri}",
+                oninput: move |evt| auth.with_mut(|a| a.server_uri = evt.value()),
+                class: "{input_class}",
+                placeholder: "https://idp.example.com/oauth2/token"
+            }
+            HelpHint { form_type: CatalogFormType::Rest, field: "oauth2_server_uri" }
+        }
+        div {
+            class: "grid grid-cols-2 gap-3",
+            div {
+                label { class: "block text-sm font-medium text-gray-700", "Client ID{client_suffix}" }
+                input {
+                    r#type: "text",
+                    value: "{auth.read().client_id}",
+                    oninput: move |evt| auth.with_mut(|a| a.client_id = evt.value()),
+                    class: "{input_class}"
+                }
+            }
+            div {
+                label { class: "block text-sm font-medium text-gray-700", "Client Secret{client_suffix}" }
+                input {
+                    r#type: "password",
+                    value: "{auth.read().client_secret}",
+                    oninput: move |evt| auth.with_mut(|a| a.client_secret = evt.value()),
+                    class: "{input_class}"
+                }
+            }
+        }
+        div {
+            label { class: "block text-sm font-medium text-gray-700", "Scope (Optional)" }
+            input {
+                r#type: "text",
+                value: "{auth.read().scope}",
+                oninput: move |evt| auth.with_mut(|a| a.scope = evt.value()),
+                class: "{input_class}",
+                placeholder: "PRINCIPAL_ROLE:ALL"
+            }
+        }
+    }
+}
+
+/// Collapsible checklist of what a catalog type needs before connecting
+/// Suggestions for a connect form field from recently used endpoints
+#[component]
+fn RecentEndpointOptions(id: &'static str, values: Vec<String>) -> Element {
+    rsx! {
+        datalist {
+            id: id,
+            for value in values {
+                option { value: "{value}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn PrerequisitesSection(form_type: CatalogFormType) -> Element {
+    let mut expanded = use_signal(|| false);
+    let (docs_label, docs_url) = form_type.docs_link();
+
+    rsx! {
+        div {
+            class: "border border-gray-200 rounded-md",
+            button {
+                r#type: "button",
+                onclick: move |_| expanded.set(!expanded()),
+                class: "w-full flex items-center justify-between px-3 py-2 text-sm text-gray-700 hover:bg-gray-50",
+                span { "Required permissions & prerequisites" }
+                span { class: "text-gray-400", if expanded() { "▲" } else { "▼" } }
+            }
+            if expanded() {
+                div {
+                    class: "px-3 pb-3 text-xs text-gray-600",
+                    ul {
+                        class: "list-disc pl-5 space-y-1",
+                        for item in form_type.prerequisites().iter() {
+                            li { "{item}" }
+                        }
+                    }
+                    a {
+                        href: docs_url,
+                        target: "_blank",
+                        class: "inline-block mt-2 text-blue-600 hover:text-blue-800 underline",
+                        "{docs_label} ↗"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Collapsible per-catalog proxy override; left empty, the global proxy setting applies
+/// Custom S3 endpoint, region and path-style addressing for S3-compatible storage like MinIO
+#[component]
+fn S3StorageSection(options: Signal<S3StorageOptions>) -> Element {
+    let mut expanded = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "border border-gray-200 rounded-md",
+            button {
+                r#type: "button",
+                onclick: move |_| expanded.set(!expanded()),
+                class: "w-full flex items-center justify-between px-3 py-2 text-sm text-gray-700 hover:bg-gray-50",
+                span { "S3-compatible storage (Optional)" }
+                span { class: "text-gray-400", if expanded() { "▲" } else { "▼" } }
+            }
+            if expanded() {
+                div {
+                    class: "px-3 pb-3 space-y-2",
+                    p {
+                        class: "text-xs text-gray-500",
+                        "For table files on MinIO, Ceph, R2 or another S3-compatible store, or in a different region than the catalog. Leave empty for AWS S3."
+                    }
+                    div {
+                        label { class: "block text-xs font-medium text-gray-700", "S3 endpoint URL" }
+                        input {
+                            r#type: "url",
+                            value: "{options.read().endpoint}",
+                            oninput: move |evt| options.with_mut(|o| o.endpoint = evt.value()),
+                            class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm text-sm focus:ring-blue-500 focus:border-blue-500",
+                            placeholder: "http://localhost:9000"
+                        }
+                    }
+                    div {
+                        label { class: "block text-xs font-medium text-gray-700", "S3 region override" }
+                        input {
+                            r#type: "text",
+                            value: "{options.read().region}",
+                            oninput: move |evt| options.with_mut(|o| o.region = evt.value()),
+                            class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm text-sm focus:ring-blue-500 focus:border-blue-500",
+                            placeholder: "us-east-1"
+                        }
+                    }
+                    label {
+                        class: "flex items-center text-sm text-gray-700",
+                        input {
+                            r#type: "checkbox",
+                            checked: options.read().path_style_access,
+                            onchange: move |evt| options.with_mut(|o| o.path_style_access = evt.checked()),
+                            class: "mr-2"
+                        }
+                        "Path-style access (bucket in the path instead of the host name; required by most MinIO setups)"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProxyOverrideSection(proxy: Signal<ProxySettings>) -> Element {
+    let mut expanded = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "border border-gray-200 rounded-md",
+            button {
+                r#type: "button",
+                onclick: move |_| expanded.set(!expanded()),
+                class: "w-full flex items-center justify-between px-3 py-2 text-sm text-gray-700 hover:bg-gray-50",
+                span { "Proxy override (Optional)" }
+                span { class: "text-gray-400", if expanded() { "▲" } else { "▼" } }
+            }
+            if expanded() {
+                div {
+                    class: "px-3 pb-3",
+                    p {
+                        class: "text-xs text-gray-500 mb-2",
+                        "Overrides the global proxy from Settings for this catalog only."
+                    }
+                    ProxySettingsFields { proxy: proxy }
+                }
+            }
+        }
+    }
+}
+
+/// Proxy URL, no-proxy list and credentials, shared by the settings panel and catalog forms
+#[component]
+pub fn ProxySettingsFields(proxy: Signal<ProxySettings>) -> Element {
+    rsx! {
+        div {
+            class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+            div {
+                class: "sm:col-span-2",
+                label { class: "block text-xs font-medium text-gray-700", "Proxy URL" }
+                input {
+                    r#type: "text",
+                    value: "{proxy.read().url}",
+                    oninput: move |evt| proxy.with_mut(|p| p.url = evt.value()),
+                    class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm text-sm focus:ring-blue-500 focus:border-blue-500",
+                    placeholder: "http://proxy.corp.example:3128"
+                }
+            }
+            div {
+                class: "sm:col-span-2",
+                label { class: "block text-xs font-medium text-gray-700", "No-proxy hosts" }
+                input {
+                    r#type: "text",
+                    value: "{proxy.read().no_proxy}",
+                    oninput: move |evt| proxy.with_mut(|p| p.no_proxy = evt.value()),
+                    class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm text-sm focus:ring-blue-500 focus:border-blue-500",
+                    placeholder: "localhost,127.0.0.1,.internal.example"
+                }
+            }
+            p {
+                class: "sm:col-span-2 text-xs text-gray-500",
+                "Covers catalog requests only. Table files (metadata and manifests on S3 or other object storage) are read directly, or through HTTPS_PROXY when it is exported in the environment."
+            }
+            div {
+                label { class: "block text-xs font-medium text-gray-700", "Proxy username" }
+                input {
+                    r#type: "text",
+                    value: "{proxy.read().username}",
+                    oninput: move |evt| proxy.with_mut(|p| p.username = evt.value()),
+                    class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm text-sm focus:ring-blue-500 focus:border-blue-500",
+                }
+            }
+            div {
+                label { class: "block text-xs font-medium text-gray-700", "Proxy password" }
+                input {
+                    r#type: "password",
+                    value: "{proxy.read().password}",
+                    oninput: move |evt| proxy.with_mut(|p| p.password = evt.value()),
+                    class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm text-sm focus:ring-blue-500 focus:border-blue-500",
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn ConnectionStatusDisplay(status: ConnectionStatus) -> Element {
     match status {
@@ -546,6 +1135,15 @@ fn ConnectionStatusDisplay(status: ConnectionStatus) -> Element {
     }
 }
 
+/// Name of the first connected catalog, read without holding the manager across a request
+fn first_catalog_name(catalog_manager: Signal<CatalogManager>) -> Option<String> {
+    catalog_manager
+        .read()
+        .get_connections()
+        .first()
+        .map(|connection| connection.config.name.clone())
+}
+
 #[component]
 fn TableBrowser(
     catalog_manager: Signal<CatalogManager>,
@@ -553,23 +1151,31 @@ fn TableBrowser(
     selected_namespace: Signal<Option<String>>,
     tables: Signal<Vec<TableReference>>,
     on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
     loading_namespaces: bool,
 ) -> Element {
     let mut loading_tables = use_signal(|| false);
     let load_tables = move |namespace: String| async move {
         loading_tables.set(true);
         // Get the first catalog connection (assuming single connection for now)
-        if let Some(connection) = catalog_manager.read().get_connections().first() {
+        let catalog_name = first_catalog_name(catalog_manager);
+        if let Some(catalog_name) = catalog_name {
             log::info!(
                 "Loading tables for namespace: {} from catalog: {}",
                 namespace,
-                connection.config.name
+                catalog_name
             );
-            match catalog_manager
-                .read()
-                .list_tables(&connection.config.name, &namespace)
-                .await
+            let listed = match crate::ensure_catalog_connected(catalog_manager, &catalog_name).await
             {
+                Ok(()) => {
+                    catalog_manager
+                        .read()
+                        .list_tables(&catalog_name, &namespace)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            match listed {
                 Ok(table_list) => {
                     log::info!(
                         "Successfully loaded {} tables for namespace: {}",
@@ -618,7 +1224,7 @@ fn TableBrowser(
                             }
                             span {
                                 class: "text-sm text-gray-600",
-                                "Loading namespaces..."
+                                {t(Msg::LoadingNamespaces)}
                             }
                         }
                     } else if namespaces.is_empty() {
@@ -626,7 +1232,7 @@ fn TableBrowser(
                             class: "text-center py-8",
                             p {
                                 class: "text-sm text-gray-500 italic",
-                                "No namespaces found in this catalog"
+                                {t(Msg::NoNamespacesInCatalog)}
                             }
                         }
                     } else {
@@ -674,13 +1280,13 @@ fn TableBrowser(
                                 }
                                 span {
                                     class: "text-sm text-gray-600",
-                                    "Loading tables..."
+                                    {t(Msg::LoadingTables)}
                                 }
                             }
                         } else if tables().is_empty() {
                             p {
                                 class: "text-sm text-gray-500 italic",
-                                "No tables found in this namespace"
+                                {t(Msg::NoTablesInNamespace)}
                             }
                         } else {
                             div {
@@ -688,22 +1294,26 @@ fn TableBrowser(
                                 for table in tables() {
                                     button {
                                         onclick: move |_| {
-                                            // Only allow clicking on Iceberg tables
-                                            if table.table_type == TableType::Iceberg {
-                                                if let Some(connection) = catalog_manager.read().get_connections().first() {
-                                                    on_table_selected.call((
-                                                        connection.config.name.clone(),
-                                                        table.namespace.clone(),
-                                                        table.name.clone()
-                                                    ));
+                                            // Only Iceberg tables and views can be opened
+                                            if let Some(connection) = catalog_manager.read().get_connections().first() {
+                                                let selected = (
+                                                    connection.config.name.clone(),
+                                                    table.namespace.clone(),
+                                                    table.name.clone()
+                                                );
+                                                match table.table_type {
+                                                    TableType::Iceberg => on_table_selected.call(selected),
+                                                    TableType::View => on_view_selected.call(selected),
+                                                    TableType::Unknown => {}
                                                 }
                                             }
                                         },
-                                        disabled: table.table_type != TableType::Iceberg,
+                                        disabled: table.table_type == TableType::Unknown,
                                         class: format!(
                                             "text-left px-4 py-3 border rounded-md transition-colors {}",
                                             match table.table_type {
                                                 TableType::Iceberg => "border-gray-300 hover:border-blue-400 hover:bg-blue-50 cursor-pointer",
+                                                TableType::View => "border-gray-300 hover:border-purple-400 hover:bg-purple-50 cursor-pointer",
                                                 TableType::Unknown => "border-gray-200 bg-gray-50 cursor-not-allowed opacity-60"
                                             }
                                         ),
@@ -714,12 +1324,13 @@ fn TableBrowser(
                                                     class: format!(
                                                         "text-sm font-medium {}",
                                                         match table.table_type {
-                                                            TableType::Iceberg => "text-gray-900",
+                                                            TableType::Iceberg | TableType::View => "text-gray-900",
                                                             TableType::Unknown => "text-gray-500"
                                                         }
                                                     ),
                                                     match table.table_type {
                                                         TableType::Iceberg => format!("🧊 {}", table.name),
+                                                        TableType::View => format!("👁️ {}", table.name),
                                                         TableType::Unknown => format!("📄 {}", table.name)
                                                     }
                                                 }
@@ -727,14 +1338,14 @@ fn TableBrowser(
                                                     class: "text-xs text-gray-500",
                                                     "{table.full_name}"
                                                 }
-                                                if table.table_type != TableType::Iceberg {
+                                                if table.table_type == TableType::Unknown {
                                                     p {
                                                         class: "text-xs text-gray-400 italic mt-1",
                                                         "Not an Iceberg table"
                                                     }
                                                 }
                                             }
-                                            if table.table_type == TableType::Iceberg {
+                                            if table.table_type != TableType::Unknown {
                                                 svg {
                                                     class: "h-5 w-5 text-gray-400",
                                                     fill: "none",
@@ -857,7 +1468,7 @@ fn SavedCatalogsSection(
                                 if matches!(connection_status(), ConnectionStatus::Connecting) {
                                     "Connecting..."
                                 } else {
-                                    "Connect"
+                                    {t(Msg::Connect)}
                                 }
                             }
                         }
@@ -872,6 +1483,7 @@ fn SavedCatalogsSection(
 pub fn CatalogBrowser(
     catalog_manager: Signal<CatalogManager>,
     on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
     on_home_requested: EventHandler<()>,
 ) -> Element {
     let mut current_view = use_signal(|| NavigationView::Namespaces);
@@ -884,12 +1496,13 @@ pub fn CatalogBrowser(
     use_effect(move || {
         spawn(async move {
             loading.set(true);
-            if let Some(connection) = catalog_manager.read().get_connections().first() {
-                match catalog_manager
-                    .read()
-                    .list_namespaces(&connection.config.name)
-                    .await
-                {
+            if let Some(catalog_name) = first_catalog_name(catalog_manager) {
+                let listed =
+                    match crate::ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                        Ok(()) => catalog_manager.read().list_namespaces(&catalog_name).await,
+                        Err(e) => Err(e),
+                    };
+                match listed {
                     Ok(ns) => {
                         namespaces.set(ns);
                         loading.set(false);
@@ -913,12 +1526,18 @@ pub fn CatalogBrowser(
         search_query.set(String::new()); // Clear search when navigating
         spawn(async move {
             loading.set(true);
-            if let Some(connection) = catalog_manager.read().get_connections().first() {
-                match catalog_manager
-                    .read()
-                    .list_tables(&connection.config.name, &namespace)
-                    .await
-                {
+            if let Some(catalog_name) = first_catalog_name(catalog_manager) {
+                let listed =
+                    match crate::ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                        Ok(()) => {
+                            catalog_manager
+                                .read()
+                                .list_tables(&catalog_name, &namespace)
+                                .await
+                        }
+                        Err(e) => Err(e),
+                    };
+                match listed {
                     Ok(table_list) => {
                         tables.set(table_list);
                         loading.set(false);
@@ -973,12 +1592,19 @@ pub fn CatalogBrowser(
                             },
                             NavigationView::Tables { namespace } => rsx! {
                                 TableExplorerView {
+                                    catalog_name: catalog_manager
+                                        .read()
+                                        .get_connections()
+                                        .first()
+                                        .map(|connection| connection.config.name.clone())
+                                        .unwrap_or_default(),
                                     namespace: namespace,
                                     tables: tables(),
                                     search_query: search_query(),
                                     on_search_change: move |query: String| search_query.set(query),
                                     catalog_manager: catalog_manager,
-                                    on_table_selected: on_table_selected
+                                    on_table_selected: on_table_selected,
+                                    on_view_selected: on_view_selected
                                 }
                             }
                         }
@@ -1100,7 +1726,7 @@ fn LoadingView() -> Element {
                 }
                 p {
                     class: "text-sm text-gray-600",
-                    "Loading..."
+                    {t(Msg::Loading)}
                 }
             }
         }
@@ -1186,7 +1812,7 @@ fn NamespaceExplorerView(
                     }
                     p {
                         class: "text-sm text-gray-500",
-                        "No namespaces found in this catalog"
+                        {t(Msg::NoNamespacesInCatalog)}
                     }
                 }
             } else {
@@ -1229,14 +1855,17 @@ fn NamespaceExplorerView(
     }
 }
 
+/// Tables of one namespace as cards or a sortable details table
 #[component]
-fn TableExplorerView(
+pub fn TableExplorerView(
+    catalog_name: String,
     namespace: String,
     tables: Vec<TableReference>,
     search_query: String,
     on_search_change: EventHandler<String>,
     catalog_manager: Signal<CatalogManager>,
     on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
 ) -> Element {
     // Filter tables based on search query
     let all_tables = &tables;
@@ -1251,17 +1880,108 @@ fn TableExplorerView(
             .collect()
     };
 
-    let iceberg_tables: Vec<_> = filtered_tables
+    let mut iceberg_tables: Vec<_> = filtered_tables
         .iter()
         .filter(|t| t.table_type == TableType::Iceberg)
         .copied()
         .collect();
+    let views: Vec<_> = filtered_tables
+        .iter()
+        .filter(|t| t.table_type == TableType::View)
+        .copied()
+        .collect();
     let other_tables: Vec<_> = filtered_tables
         .iter()
-        .filter(|t| t.table_type != TableType::Iceberg)
+        .filter(|t| t.table_type == TableType::Unknown)
         .copied()
         .collect();
 
+    // Details layout: per-table stats, fetched lazily and cached by full table name
+    let mut details_layout = use_signal(|| false);
+    let mut table_stats = use_signal(HashMap::<String, Result<TableStats, String>>::new);
+    let stats_generation = use_signal(|| 0usize);
+    let mut sort_column = use_signal(|| TableSortColumn::Name);
+    let mut sort_descending = use_signal(|| false);
+
+    let fetch_table_stats = {
+        let catalog_name = catalog_name.clone();
+        move |tables_to_fetch: Vec<TableReference>| {
+            let uncached: Vec<TableReference> = tables_to_fetch
+                .into_iter()
+                .filter(|table| !table_stats.peek().contains_key(&table.full_name))
+                .collect();
+            let catalog_name = catalog_name.clone();
+            crawl(
+                catalog_manager,
+                CrawlRun::start(stats_generation),
+                uncached,
+                move |table: TableReference| {
+                    let catalog_name = catalog_name.clone();
+                    async move {
+                        let result =
+                            match crate::ensure_catalog_connected(catalog_manager, &catalog_name)
+                                .await
+                            {
+                                Ok(()) => {
+                                    catalog_manager
+                                        .read()
+                                        .table_stats(&catalog_name, &table.namespace, &table.name)
+                                        .await
+                                }
+                                Err(e) => Err(e),
+                            }
+                            .map_err(|e| e.to_string());
+                        (table.full_name, result)
+                    }
+                },
+                move |(full_name, result): (String, Result<TableStats, String>)| {
+                    table_stats.write().insert(full_name, result);
+                },
+            );
+        }
+    };
+
+    if details_layout() {
+        let stats = table_stats.read();
+        let stat =
+            |table: &TableReference| stats.get(&table.full_name).and_then(|r| r.as_ref().ok());
+        iceberg_tables.sort_by(|a, b| match sort_column() {
+            TableSortColumn::Name => a.name.cmp(&b.name),
+            TableSortColumn::Records => stat(a)
+                .and_then(|s| s.total_records)
+                .cmp(&stat(b).and_then(|s| s.total_records)),
+            TableSortColumn::Size => stat(a)
+                .and_then(|s| s.total_size_bytes)
+                .cmp(&stat(b).and_then(|s| s.total_size_bytes)),
+            TableSortColumn::LastModified => stat(a)
+                .map(|s| s.last_updated_ms)
+                .cmp(&stat(b).map(|s| s.last_updated_ms)),
+        });
+        if sort_descending() {
+            iceberg_tables.reverse();
+        }
+    }
+
+    let mut sort_by = move |column: TableSortColumn| {
+        if sort_column() == column {
+            sort_descending.set(!sort_descending());
+        } else {
+            sort_column.set(column);
+            sort_descending.set(column != TableSortColumn::Name);
+        }
+    };
+    let sort_indicator = move |column: TableSortColumn| {
+        if sort_column() != column {
+            ""
+        } else if sort_descending() {
+            " ▼"
+        } else {
+            " ▲"
+        }
+    };
+    let visible_iceberg_tables: Vec<TableReference> =
+        iceberg_tables.iter().map(|t| (*t).clone()).collect();
+
     rsx! {
         div {
             class: "space-y-4",
@@ -1278,12 +1998,33 @@ fn TableExplorerView(
                         }
                     }
                 }
-                span {
-                    class: "text-sm text-gray-500",
-                    if !query_clone.is_empty() && filtered_tables.len() != all_tables.len() {
-                        "{filtered_tables.len()} of {all_tables.len()} items"
-                    } else {
-                        "({filtered_tables.len()} items)"
+                div {
+                    class: "flex items-center space-x-3",
+                    span {
+                        class: "text-sm text-gray-500",
+                        if !query_clone.is_empty() && filtered_tables.len() != all_tables.len() {
+                            "{filtered_tables.len()} of {all_tables.len()} items"
+                        } else {
+                            "({filtered_tables.len()} items)"
+                        }
+                    }
+                    div {
+                        class: "flex border border-gray-300 rounded-md overflow-hidden text-sm",
+                        button {
+                            onclick: move |_| details_layout.set(false),
+                            class: if !details_layout() { "px-3 py-1 bg-blue-600 text-white" } else { "px-3 py-1 bg-white text-gray-600 hover:bg-gray-50" },
+                            title: "Card layout",
+                            "▦ Cards"
+                        }
+                        button {
+                            onclick: move |_| {
+                                details_layout.set(true);
+                                fetch_table_stats(visible_iceberg_tables.clone());
+                            },
+                            class: if details_layout() { "px-3 py-1 bg-blue-600 text-white" } else { "px-3 py-1 bg-white text-gray-600 hover:bg-gray-50" },
+                            title: "Details layout with record counts, sizes and last-modified times",
+                            "☰ Details"
+                        }
                     }
                 }
             }
@@ -1321,7 +2062,7 @@ fn TableExplorerView(
                     }
                     p {
                         class: "text-sm text-gray-500",
-                        "No tables found in this namespace"
+                        {t(Msg::NoTablesInNamespace)}
                     }
                 }
             } else {
@@ -1339,6 +2080,97 @@ fn TableExplorerView(
                                     "({iceberg_tables.len()})"
                                 }
                             }
+                            if details_layout() {
+                                div {
+                                    class: "overflow-x-auto border border-gray-200 rounded-lg",
+                                    table {
+                                        class: "min-w-full divide-y divide-gray-200",
+                                        thead {
+                                            class: "bg-gray-50",
+                                            tr {
+                                                th {
+                                                    class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:text-gray-700",
+                                                    onclick: move |_| sort_by(TableSortColumn::Name),
+                                                    "Table{sort_indicator(TableSortColumn::Name)}"
+                                                }
+                                                th {
+                                                    class: "px-4 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:text-gray-700",
+                                                    onclick: move |_| sort_by(TableSortColumn::Records),
+                                                    "Records{sort_indicator(TableSortColumn::Records)}"
+                                                }
+                                                th {
+                                                    class: "px-4 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:text-gray-700",
+                                                    onclick: move |_| sort_by(TableSortColumn::Size),
+                                                    "Data Size{sort_indicator(TableSortColumn::Size)}"
+                                                }
+                                                th {
+                                                    class: "px-4 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:text-gray-700",
+                                                    onclick: move |_| sort_by(TableSortColumn::LastModified),
+                                                    "Last Modified{sort_indicator(TableSortColumn::LastModified)}"
+                                                }
+                                            }
+                                        }
+                                        tbody {
+                                            class: "bg-white divide-y divide-gray-200",
+                                            for table in iceberg_tables.iter() {
+                                                {
+                                                    let stats = table_stats.read().get(&table.full_name).cloned();
+                                                    let table_clone = (*table).clone();
+                                                    rsx! {
+                                                        tr {
+                                                            class: "hover:bg-blue-50 cursor-pointer",
+                                                            onclick: {
+                                                                let catalog_name = catalog_name.clone();
+                                                                move |_| {
+                                                                    on_table_selected.call((
+                                                                        catalog_name.clone(),
+                                                                        table_clone.namespace.clone(),
+                                                                        table_clone.name.clone()
+                                                                    ));
+                                                                }
+                                                            },
+                                                            td { class: "px-4 py-2 text-sm font-medium text-gray-900", "🧊 {table.name}" }
+                                                            {match stats {
+                                                                Some(Ok(stats)) => rsx! {
+                                                                    td {
+                                                                        class: "px-4 py-2 text-sm text-gray-700 text-right",
+                                                                        {stats.total_records.map(|r| r.to_string()).unwrap_or_else(|| "—".to_string())}
+                                                                    }
+                                                                    td {
+                                                                        class: "px-4 py-2 text-sm text-gray-700 text-right",
+                                                                        {stats.total_size_bytes.map(format_bytes).unwrap_or_else(|| "—".to_string())}
+                                                                    }
+                                                                    td {
+                                                                        class: "px-4 py-2 text-sm text-gray-700 text-right",
+                                                                        {chrono::DateTime::from_timestamp_millis(stats.last_updated_ms)
+                                                                            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                                                                            .unwrap_or_else(|| "—".to_string())}
+                                                                    }
+                                                                },
+                                                                Some(Err(e)) => rsx! {
+                                                                    td {
+                                                                        class: "px-4 py-2 text-xs text-red-600 text-right",
+                                                                        colspan: "3",
+                                                                        title: "{e}",
+                                                                        {t(Msg::FailedToLoad)}
+                                                                    }
+                                                                },
+                                                                None => rsx! {
+                                                                    td {
+                                                                        class: "px-4 py-2 text-xs text-gray-400 text-right",
+                                                                        colspan: "3",
+                                                                        {t(Msg::Loading)}
+                                                                    }
+                                                                },
+                                                            }}
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            } else {
                             div {
                                 class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-3",
                                 for table in iceberg_tables {
@@ -1347,14 +2179,13 @@ fn TableExplorerView(
                                         button {
                                             onclick: {
                                                 let table_clone = table.clone();
+                                                let catalog_name = catalog_name.clone();
                                                 move |_| {
-                                                    if let Some(connection) = catalog_manager.read().get_connections().first() {
-                                                        on_table_selected.call((
-                                                            connection.config.name.clone(),
-                                                            table_clone.namespace.clone(),
-                                                            table_clone.name.clone()
-                                                        ));
-                                                    }
+                                                    on_table_selected.call((
+                                                        catalog_name.clone(),
+                                                        table_clone.namespace.clone(),
+                                                        table_clone.name.clone()
+                                                    ));
                                                 }
                                             },
                                             class: "w-full p-4 text-left border border-gray-200 rounded-lg hover:border-blue-300 hover:bg-blue-50 transition-all duration-200 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent",
@@ -1381,6 +2212,62 @@ fn TableExplorerView(
                                     }
                                 }
                             }
+                            }
+                        }
+                    }
+
+                    // Views Section
+                    if !views.is_empty() {
+                        div {
+                            h4 {
+                                class: "text-md font-medium text-gray-900 mb-3 flex items-center",
+                                "👁️ Views"
+                                span {
+                                    class: "ml-2 text-sm text-gray-500 font-normal",
+                                    "({views.len()})"
+                                }
+                            }
+                            div {
+                                class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-3",
+                                for view in views {
+                                    div {
+                                        class: "group relative",
+                                        button {
+                                            onclick: {
+                                                let view_clone = view.clone();
+                                                let catalog_name = catalog_name.clone();
+                                                move |_| {
+                                                    on_view_selected.call((
+                                                        catalog_name.clone(),
+                                                        view_clone.namespace.clone(),
+                                                        view_clone.name.clone()
+                                                    ));
+                                                }
+                                            },
+                                            class: "w-full p-4 text-left border border-gray-200 rounded-lg hover:border-purple-300 hover:bg-purple-50 transition-all duration-200 focus:outline-none focus:ring-2 focus:ring-purple-500 focus:border-transparent",
+                                            div {
+                                                class: "flex flex-col items-center space-y-2",
+                                                div {
+                                                    class: "text-3xl",
+                                                    "👁️"
+                                                }
+                                                div {
+                                                    class: "text-center",
+                                                    p {
+                                                        class: "text-sm font-medium text-gray-900 truncate",
+                                                        title: "{view.name}",
+                                                        "{view.name}"
+                                                    }
+                                                    p {
+                                                        class: "text-xs text-gray-500 mt-1",
+                                                        "Iceberg View"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
 
@@ -1484,3 +2371,123 @@ fn SearchInput(
         }
     }
 }
+
+/// Path of the first file dropped onto a drop target, if any
+pub fn dropped_file_path(evt: &DragEvent) -> Option<String> {
+    evt.files()
+        .and_then(|files| files.files().into_iter().next())
+}
+
+/// Ask for a `metadata.json` location (local path or `s3://` URI, with optional S3
+/// credentials) to open as a table without a catalog
+#[component]
+pub fn OpenMetadataFileDialog(
+    on_open: EventHandler<(String, HashMap<String, String>)>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut location = use_signal(String::new);
+    let mut region = use_signal(String::new);
+    let mut endpoint = use_signal(String::new);
+    let mut access_key_id = use_signal(String::new);
+    let mut secret_access_key = use_signal(String::new);
+    let is_s3 = location().trim().starts_with("s3://") || location().trim().starts_with("s3a://");
+
+    let submit = move |_| {
+        let mut props = HashMap::new();
+        if is_s3 {
+            for (key, value) in [
+                ("s3.region", region()),
+                ("s3.endpoint", endpoint()),
+                ("s3.access-key-id", access_key_id()),
+                ("s3.secret-access-key", secret_access_key()),
+            ] {
+                if !value.trim().is_empty() {
+                    props.insert(key.to_string(), value.trim().to_string());
+                }
+            }
+        }
+        on_open.call((location().trim().to_string(), props));
+    };
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-lg w-full mx-4 p-6",
+                onclick: |e| e.stop_propagation(),
+                ondragover: move |evt| evt.prevent_default(),
+                ondrop: move |evt| {
+                    evt.prevent_default();
+                    if let Some(path) = dropped_file_path(&evt) {
+                        location.set(path);
+                    }
+                },
+
+                h3 {
+                    class: "text-lg font-medium text-gray-900 mb-1",
+                    "📄 Open metadata file"
+                }
+                p {
+                    class: "text-sm text-gray-500 mb-4",
+                    "Open a table from its metadata JSON without a catalog. Drop a file here or enter a local path or s3:// URI."
+                }
+                label {
+                    class: "block text-sm font-medium text-gray-700",
+                    "Metadata file location"
+                }
+                input {
+                    r#type: "text",
+                    value: "{location}",
+                    oninput: move |evt| location.set(evt.value()),
+                    class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500 font-mono text-sm",
+                    placeholder: "s3://bucket/db/table/metadata/00003-....metadata.json"
+                }
+                if is_s3 {
+                    div {
+                        class: "mt-4 grid grid-cols-2 gap-3",
+                        for (label_text, mut field, input_type) in [
+                            ("Region", region, "text"),
+                            ("Endpoint (optional)", endpoint, "text"),
+                            ("Access key ID", access_key_id, "text"),
+                            ("Secret access key", secret_access_key, "password"),
+                        ] {
+                            div {
+                                label {
+                                    class: "block text-xs font-medium text-gray-700",
+                                    "{label_text}"
+                                }
+                                input {
+                                    r#type: input_type,
+                                    value: "{field}",
+                                    oninput: move |evt| field.set(evt.value()),
+                                    class: "mt-1 block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500 text-sm",
+                                }
+                            }
+                        }
+                    }
+                    p {
+                        class: "mt-2 text-xs text-gray-500",
+                        "Leave the credentials empty to use the default AWS credential chain."
+                    }
+                }
+                div {
+                    class: "mt-6 flex justify-end space-x-3",
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "px-4 py-2 text-sm text-gray-700 border border-gray-300 rounded-md hover:bg-gray-50",
+                        {t(Msg::Cancel)}
+                    }
+                    button {
+                        onclick: submit,
+                        disabled: location().trim().is_empty(),
+                        class: "px-4 py-2 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:bg-gray-300",
+                        "Open"
+                    }
+                }
+            }
+        }
+    }
+}
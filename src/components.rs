@@ -1,10 +1,66 @@
-use crate::analytics::TableAnalytics;
+use crate::analytics::{HealthThresholds, TableAnalytics};
+use crate::catalog::{CatalogError, CatalogManager, LoadFailureKind, WarehouseRelation};
+use crate::config::{FreshnessThresholds, is_sensitive_classification};
 use crate::data::{
-    AlertSeverity, DataType, IcebergTable, NestedField, PartitionField, Snapshot,
-    TableHealthMetrics,
+    AlertCategory, AlertSeverity, ChangesSinceLastView, ColumnSize, ColumnSizeStats,
+    DataFileOrigin, DataType, Freshness, HealthCategory, IcebergTable, IcebergView,
+    LastMetadataChange, MaintenanceActionType, NestedField, NullOrder, PartitionField,
+    PartitionStats, RecordCountDiscrepancy, SchemaFileStats, Snapshot, SortDirection,
+    TableHealthMetrics, TableSchema, ValidationCheck, ValidationStatus, ViewVersion,
 };
+use crate::export::{
+    PartitionSpecFormat, SchemaFormat, SqlDialect, generate_ddl, local_directory,
+    maintenance_command, reveal_in_file_browser, save_to_downloads, snapshot_timeline_svg,
+};
+use crate::strings::{Msg, t, tf};
+use crate::toast::{ToastKind, notify};
 use dioxus::prelude::*;
 
+/// Human-readable byte size (binary units)
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Copy text to the system clipboard via the webview
+pub fn copy_to_clipboard(text: &str) {
+    let literal = serde_json::to_string(text).unwrap_or_default();
+    document::eval(&format!("navigator.clipboard.writeText({});", literal));
+}
+
+/// Text currently on the system clipboard, if it can be read
+pub async fn read_clipboard() -> Option<String> {
+    document::eval("return await navigator.clipboard.readText();")
+        .join::<String>()
+        .await
+        .ok()
+}
+
+// Pinned (sticky) leading columns for wide tables. The ID column gets a fixed width so the
+// column after it knows where to stick.
+const PINNED_COLUMN: &str = "sticky left-0 z-10 border-r border-gray-200";
+const PINNED_ID_COLUMN_STYLE: &str = "min-width: 6rem; width: 6rem;";
+const PINNED_AFTER_ID_COLUMN: &str = "sticky z-10 border-r border-gray-200";
+const PINNED_AFTER_ID_COLUMN_STYLE: &str = "left: 6rem;";
+
+/// Badge colors for writers in the snapshot timeline, assigned busiest writer first
+const WRITER_COLORS: [&str; 4] = [
+    "bg-indigo-100 text-indigo-800",
+    "bg-pink-100 text-pink-800",
+    "bg-orange-100 text-orange-800",
+    "bg-purple-100 text-purple-800",
+];
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct SnapshotFilters {
     pub operation_types: Vec<String>, // Selected operation types
@@ -14,6 +70,8 @@ pub struct SnapshotFilters {
     pub records_added_max: Option<u64>,
     pub date_start: Option<String>, // ISO date string
     pub date_end: Option<String>,   // ISO date string
+    /// Snapshot id (or id prefix), or a date/time to find the snapshot active then
+    pub search: String,
 }
 
 impl Default for SnapshotFilters {
@@ -30,6 +88,7 @@ impl Default for SnapshotFilters {
             records_added_max: None,
             date_start: None,
             date_end: None,
+            search: String::new(),
         }
     }
 }
@@ -225,8 +284,78 @@ fn DateRangeFilter(
     }
 }
 
+/// Placeholder for a table tab whose metadata is still being fetched
+#[component]
+pub fn TableLoadingSkeleton(namespace: String, table_name: String) -> Element {
+    rsx! {
+        div {
+            class: "h-full flex flex-col",
+
+            // Sub-tab bar placeholder
+            div {
+                class: "flex items-center justify-between border-b border-gray-200 bg-gray-50 px-6 py-2",
+                div {
+                    class: "flex space-x-4 animate-pulse",
+                    for _ in 0..5 {
+                        div { class: "h-4 w-20 bg-gray-200 rounded" }
+                    }
+                }
+                div {
+                    class: "flex items-center text-sm text-gray-500",
+                    div {
+                        class: "animate-spin rounded-full h-4 w-4 border-b-2 border-blue-600 mr-2"
+                    }
+                    "Loading {namespace}.{table_name}..."
+                }
+            }
+
+            div {
+                class: "flex-1 overflow-auto p-6 space-y-6 animate-pulse",
+
+                // Overview card
+                div {
+                    class: "bg-white shadow rounded-lg px-4 py-5 sm:p-6",
+                    div { class: "h-5 w-40 bg-gray-200 rounded mb-6" }
+                    div {
+                        class: "grid grid-cols-1 gap-x-4 gap-y-6 sm:grid-cols-2",
+                        for _ in 0..6 {
+                            div {
+                                div { class: "h-3 w-24 bg-gray-200 rounded mb-2" }
+                                div { class: "h-4 w-48 bg-gray-100 rounded" }
+                            }
+                        }
+                    }
+                }
+
+                // Schema / snapshot rows
+                div {
+                    class: "bg-white shadow rounded-lg px-4 py-5 sm:p-6 space-y-3",
+                    div { class: "h-5 w-32 bg-gray-200 rounded mb-4" }
+                    for _ in 0..8 {
+                        div {
+                            class: "flex space-x-4",
+                            div { class: "h-4 w-12 bg-gray-100 rounded" }
+                            div { class: "h-4 w-48 bg-gray-200 rounded" }
+                            div { class: "h-4 w-32 bg-gray-100 rounded" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
-pub fn TableOverviewTab(table: IcebergTable) -> Element {
+pub fn TableOverviewTab(
+    table: IcebergTable,
+    // The catalog's configured warehouse, if it has one
+    warehouse: Option<String>,
+    catalog_manager: Signal<CatalogManager>,
+) -> Element {
+    let warehouse_relation = warehouse
+        .as_deref()
+        .and_then(|warehouse| WarehouseRelation::classify(warehouse, &table.location));
+
     rsx! {
         div {
             class: "space-y-6",
@@ -236,12 +365,44 @@ pub fn TableOverviewTab(table: IcebergTable) -> Element {
                 class: "bg-white shadow rounded-lg",
                 div {
                     class: "px-4 py-5 sm:p-6",
-                    h3 {
-                        class: "text-lg leading-6 font-medium text-gray-900 mb-4",
-                        "Table Overview"
+                    div {
+                        class: "flex items-center mb-4 space-x-3",
+                        h3 {
+                            class: "text-lg leading-6 font-medium text-gray-900",
+                            {t(Msg::TableOverview)}
+                        }
+                        if let Some(version) = table.format_version {
+                            span {
+                                class: if version == 1 { "inline-flex px-2 py-1 text-xs font-semibold rounded-full bg-yellow-100 text-yellow-800" } else { "inline-flex px-2 py-1 text-xs font-semibold rounded-full bg-blue-100 text-blue-800" },
+                                title: "Iceberg table format version",
+                                "Format v{version}"
+                            }
+                        }
                     }
                     dl {
                         class: "grid grid-cols-1 gap-x-4 gap-y-6 sm:grid-cols-2",
+                        if let Some(version) = table.format_version {
+                            div {
+                                class: "sm:col-span-2",
+                                dt {
+                                    class: "text-sm font-medium text-gray-500",
+                                    "Format version"
+                                }
+                                dd {
+                                    class: "mt-1 text-sm text-gray-900",
+                                    span { class: "font-semibold", "v{version}" }
+                                    if let Some(note) = table.format_version_note() {
+                                        span { class: "text-gray-500", ": {note}" }
+                                    }
+                                }
+                                if version == 1 {
+                                    dd {
+                                        class: "mt-1 text-xs text-yellow-700",
+                                        "⚠️ Still on v1. Upgrading to v2 (set the table property format-version to 2) enables row-level deletes and merge-on-read; check that every reader and writer supports v2 first."
+                                    }
+                                }
+                            }
+                        }
                         div {
                             dt {
                                 class: "text-sm font-medium text-gray-500",
@@ -268,8 +429,50 @@ pub fn TableOverviewTab(table: IcebergTable) -> Element {
                                 "Location"
                             }
                             dd {
-                                class: "mt-1 text-sm text-gray-900 font-mono break-all",
-                                "{table.location}"
+                                class: "mt-1 flex items-start text-sm text-gray-900 font-mono",
+                                span {
+                                    class: "break-all select-all cursor-text",
+                                    "{table.location}"
+                                }
+                                button {
+                                    onclick: {
+                                        let location = table.location.clone();
+                                        move |_| {
+                                            copy_to_clipboard(&location);
+                                            notify(ToastKind::Success, t(Msg::LocationCopied));
+                                        }
+                                    },
+                                    class: "ml-2 text-xs text-gray-400 hover:text-gray-600 flex-shrink-0",
+                                    title: "Copy location",
+                                    "📋"
+                                }
+                                if let Some(directory) = local_directory(&table.location) {
+                                    button {
+                                        onclick: move |_| {
+                                            if let Err(e) = reveal_in_file_browser(&directory) {
+                                                log::error!("Failed to open {}: {}", directory.display(), e);
+                                            }
+                                        },
+                                        class: "ml-2 text-xs text-gray-400 hover:text-gray-600 flex-shrink-0",
+                                        title: "Show in file browser",
+                                        "📂"
+                                    }
+                                }
+                            }
+                            if let (Some(warehouse), Some(relation)) = (warehouse.clone(), warehouse_relation) {
+                                dd {
+                                    class: "mt-1 text-xs",
+                                    title: "Catalog warehouse: {warehouse}",
+                                    match relation {
+                                        WarehouseRelation::UnderWarehouse => rsx! {
+                                            span { class: "text-gray-500", "✓ {relation.label()} " }
+                                        },
+                                        WarehouseRelation::ExternalToWarehouse => rsx! {
+                                            span { class: "text-yellow-700", "⚠️ {relation.label()} " }
+                                        },
+                                    }
+                                    span { class: "font-mono text-gray-400 break-all", "{warehouse}" }
+                                }
                             }
                         }
                         div {
@@ -299,7 +502,7 @@ pub fn TableOverviewTab(table: IcebergTable) -> Element {
                             }
                             dd {
                                 class: "mt-1 text-sm text-gray-900",
-                                "{table.snapshots.len()}"
+                                "{table.total_snapshot_count()}"
                             }
                         }
                     }
@@ -313,7 +516,7 @@ pub fn TableOverviewTab(table: IcebergTable) -> Element {
                     class: "px-4 py-5 sm:p-6",
                     h3 {
                         class: "text-lg leading-6 font-medium text-gray-900 mb-4",
-                        "Table Properties"
+                        {t(Msg::TableProperties)}
                     }
                     div {
                         class: "overflow-x-auto",
@@ -323,7 +526,7 @@ pub fn TableOverviewTab(table: IcebergTable) -> Element {
                                 class: "bg-gray-50",
                                 tr {
                                     th {
-                                        class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                        class: "{PINNED_COLUMN} bg-gray-50 px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
                                         "Property"
                                     }
                                     th {
@@ -337,12 +540,12 @@ pub fn TableOverviewTab(table: IcebergTable) -> Element {
                                 for (key, value) in &table.properties {
                                     tr {
                                         td {
-                                            class: "px-6 py-4 whitespace-nowrap text-sm font-medium text-gray-900 font-mono",
+                                            class: "{PINNED_COLUMN} bg-white px-6 py-4 whitespace-nowrap text-sm font-medium text-gray-900 font-mono",
                                             "{key}"
                                         }
                                         td {
-                                            class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500 font-mono",
-                                            "{value}"
+                                            class: "px-6 py-4 text-sm text-gray-500 font-mono",
+                                            PropertyValue { value: value.clone() }
                                         }
                                     }
                                 }
@@ -351,12 +554,454 @@ pub fn TableOverviewTab(table: IcebergTable) -> Element {
                     }
                 }
             }
+
+            TableDdlCard { table: table.clone() }
+
+            TableValidationCard { table: table.clone(), catalog_manager }
+        }
+    }
+}
+
+/// On-demand integrity check of the current snapshot: referenced schema and partition spec
+/// ids exist, the manifest list and manifests are readable, and summary totals match them
+#[component]
+fn TableValidationCard(table: IcebergTable, catalog_manager: Signal<CatalogManager>) -> Element {
+    let mut validating = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+    // Checks together with the snapshot they were run against
+    let mut results = use_signal(|| Option::<(Option<u64>, Vec<ValidationCheck>)>::None);
+
+    let checks = results()
+        .filter(|(snapshot_id, _)| *snapshot_id == table.current_snapshot_id)
+        .map(|(_, checks)| checks);
+    let failures = checks.as_ref().map_or(0, |checks| {
+        checks
+            .iter()
+            .filter(|check| check.status == ValidationStatus::Failed)
+            .count()
+    });
+
+    let validate = {
+        let catalog_name = table.catalog_name.clone();
+        let namespace = table.namespace.clone();
+        let table_name = table.name.clone();
+        let snapshot_id = table.current_snapshot_id;
+        move |_: MouseEvent| {
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            let table_name = table_name.clone();
+            spawn(async move {
+                validating.set(true);
+                error.set(None);
+                let result = catalog_manager
+                    .read()
+                    .validate_table(&catalog_name, &namespace, &table_name)
+                    .await;
+                match result {
+                    Ok(checks) => results.set(Some((snapshot_id, checks))),
+                    Err(e) => error.set(Some(e.message().to_string())),
+                }
+                validating.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "bg-white shadow rounded-lg",
+            div {
+                class: "px-4 py-5 sm:p-6",
+                div {
+                    class: "flex items-center justify-between mb-2",
+                    h3 {
+                        class: "text-lg leading-6 font-medium text-gray-900",
+                        "Validate Table"
+                    }
+                    button {
+                        onclick: validate,
+                        disabled: validating(),
+                        class: "px-3 py-1 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:bg-gray-300",
+                        if validating() {
+                            "Validating..."
+                        } else if checks.is_some() {
+                            "Run again"
+                        } else {
+                            "Validate"
+                        }
+                    }
+                }
+                p {
+                    class: "text-sm text-gray-500 mb-4",
+                    "Checks that the current snapshot's schema, partition spec, manifest list and manifests exist and are readable, and that the summary's file and record totals match the manifests."
+                }
+                if let Some(error) = error() {
+                    p { class: "text-sm text-red-600", "{error}" }
+                }
+                if let Some(checks) = checks {
+                    p {
+                        class: if failures == 0 { "text-sm font-medium text-green-700 mb-2" } else { "text-sm font-medium text-red-700 mb-2" },
+                        if failures == 0 {
+                            "No problems found"
+                        } else {
+                            "{failures} of {checks.len()} checks failed"
+                        }
+                    }
+                    ul {
+                        class: "divide-y divide-gray-200",
+                        for check in checks {
+                            li {
+                                key: "{check.name}",
+                                class: "py-2 flex items-start text-sm",
+                                span {
+                                    class: "mr-2",
+                                    match check.status {
+                                        ValidationStatus::Passed => "✅",
+                                        ValidationStatus::Failed => "❌",
+                                        ValidationStatus::Skipped => "➖",
+                                    }
+                                }
+                                div {
+                                    class: "min-w-0",
+                                    div { class: "font-medium text-gray-900", "{check.name}" }
+                                    div {
+                                        class: if check.status == ValidationStatus::Failed { "text-xs text-red-700 break-all" } else { "text-xs text-gray-500 break-all" },
+                                        "{check.detail}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Values longer than this, or spanning several lines, are shown collapsed
+const PROPERTY_PREVIEW_CHARS: usize = 120;
+const PROPERTY_PREVIEW_LINES: usize = 3;
+
+/// The value pretty-printed, if it is a JSON object or array
+fn pretty_json(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if !(trimmed.starts_with('{') || trimmed.starts_with('[')) {
+        return None;
+    }
+    serde_json::from_str::<serde_json::Value>(trimmed)
+        .ok()
+        .and_then(|json| serde_json::to_string_pretty(&json).ok())
+}
+
+/// A table property value. Short values render inline; long, multi-line or JSON values go
+/// in a monospace block clipped to a few lines, with show more, copy and, for JSON,
+/// pretty-printing.
+#[component]
+fn PropertyValue(value: String) -> Element {
+    let mut expanded = use_signal(|| false);
+    let mut pretty = use_signal(|| true);
+    let pretty_value = pretty_json(&value);
+    let char_count = value.chars().count();
+    let is_large = pretty_value.is_some()
+        || char_count > PROPERTY_PREVIEW_CHARS
+        || value.lines().count() > PROPERTY_PREVIEW_LINES;
+
+    if !is_large {
+        return rsx! {
+            span { class: "whitespace-nowrap", "{value}" }
+        };
+    }
+
+    let shown = match &pretty_value {
+        Some(json) if pretty() => json.clone(),
+        _ => value.clone(),
+    };
+
+    rsx! {
+        div {
+            class: "max-w-3xl",
+            pre {
+                class: format!(
+                    "whitespace-pre-wrap break-all text-xs bg-gray-50 border border-gray-200 rounded p-2 {}",
+                    if expanded() { "max-h-96 overflow-auto" } else { "max-h-16 overflow-hidden" }
+                ),
+                "{shown}"
+            }
+            div {
+                class: "mt-1 flex items-center space-x-3 text-xs font-sans",
+                button {
+                    onclick: move |_| expanded.set(!expanded()),
+                    class: "text-blue-600 hover:text-blue-800",
+                    if expanded() { "Show less" } else { "Show more" }
+                }
+                if pretty_value.is_some() {
+                    button {
+                        onclick: move |_| pretty.set(!pretty()),
+                        class: "text-blue-600 hover:text-blue-800",
+                        if pretty() { "Show raw" } else { "Pretty-print JSON" }
+                    }
+                }
+                button {
+                    onclick: move |_| {
+                        copy_to_clipboard(&value);
+                        notify(ToastKind::Success, t(Msg::ValueCopied));
+                    },
+                    class: "text-gray-500 hover:text-gray-700",
+                    title: "Copy the raw value",
+                    "📋 Copy"
+                }
+                span { class: "text-gray-400", "{char_count} chars" }
+            }
+        }
+    }
+}
+
+/// "Show command" toggle under a maintenance recommendation, revealing the engine procedure
+/// call that carries it out. Copy-only: Hielo never runs it.
+#[component]
+fn MaintenanceCommand(action_type: MaintenanceActionType, table: IcebergTable) -> Element {
+    let mut expanded = use_signal(|| false);
+    let mut dialect = use_signal(|| SqlDialect::Spark);
+    let mut copied = use_signal(|| false);
+    let Some(command) = maintenance_command(&action_type, &table, dialect()) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            class: "mt-2",
+            button {
+                onclick: move |_| expanded.set(!expanded()),
+                class: "text-xs text-blue-600 hover:text-blue-800",
+                if expanded() { "▾ Hide command" } else { "▸ Show command" }
+            }
+            if expanded() {
+                div {
+                    class: "mt-2 bg-white border border-gray-200 rounded-md p-2",
+                    div {
+                        class: "flex items-center justify-between mb-2",
+                        select {
+                            class: "px-2 py-1 text-xs border border-gray-300 rounded-md bg-white",
+                            onchange: move |evt| {
+                                if let Some(selected) = SqlDialect::ALL
+                                    .into_iter()
+                                    .find(|d| d.label() == evt.value())
+                                {
+                                    dialect.set(selected);
+                                    copied.set(false);
+                                }
+                            },
+                            for option_dialect in SqlDialect::ALL {
+                                option {
+                                    value: option_dialect.label(),
+                                    selected: dialect() == option_dialect,
+                                    "{option_dialect.label()}"
+                                }
+                            }
+                        }
+                        button {
+                            onclick: {
+                                let command = command.clone();
+                                move |_| {
+                                    copy_to_clipboard(&command);
+                                    copied.set(true);
+                                }
+                            },
+                            class: "px-2 py-1 text-xs text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                            if copied() { "✓ Copied" } else { "📋 Copy" }
+                        }
+                    }
+                    pre {
+                        class: "text-xs font-mono bg-gray-50 rounded p-2 overflow-x-auto select-all",
+                        "{command}"
+                    }
+                    p {
+                        class: "mt-1 text-xs text-gray-500",
+                        "Review and run this in your query engine; the catalog name may differ there."
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `CREATE TABLE` statement for the table in a chosen SQL dialect
+#[component]
+pub fn TableDdlCard(table: IcebergTable) -> Element {
+    let mut dialect = use_signal(|| SqlDialect::Spark);
+    let mut copied = use_signal(|| false);
+    let ddl = generate_ddl(&table, dialect());
+
+    rsx! {
+        div {
+            class: "bg-white shadow rounded-lg",
+            div {
+                class: "px-4 py-5 sm:p-6",
+                div {
+                    class: "flex items-center justify-between mb-4",
+                    h3 {
+                        class: "text-lg leading-6 font-medium text-gray-900",
+                        "DDL"
+                    }
+                    div {
+                        class: "flex items-center space-x-2",
+                        select {
+                            class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                            onchange: move |evt| {
+                                if let Some(selected) = SqlDialect::ALL
+                                    .into_iter()
+                                    .find(|d| d.label() == evt.value())
+                                {
+                                    dialect.set(selected);
+                                    copied.set(false);
+                                }
+                            },
+                            for option_dialect in SqlDialect::ALL {
+                                option {
+                                    value: option_dialect.label(),
+                                    selected: dialect() == option_dialect,
+                                    "{option_dialect.label()}"
+                                }
+                            }
+                        }
+                        button {
+                            onclick: {
+                                let ddl = ddl.clone();
+                                move |_| {
+                                    copy_to_clipboard(&ddl);
+                                    copied.set(true);
+                                }
+                            },
+                            class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                            title: "Copy the CREATE TABLE statement",
+                            if copied() { "✓ Copied" } else { "📋 Copy DDL" }
+                        }
+                    }
+                }
+                pre {
+                    class: "text-xs font-mono bg-gray-50 border border-gray-200 rounded-md p-3 overflow-x-auto select-all",
+                    "{ddl}"
+                }
+                p {
+                    class: "mt-2 text-xs text-gray-500",
+                    "Generated from the current schema and partition spec. Types without an equivalent in the chosen engine fall back to the closest match."
+                }
+            }
         }
     }
 }
 
 #[component]
-pub fn TableSchemaTab(table: IcebergTable) -> Element {
+pub fn TableSchemaTab(
+    table: IcebergTable,
+    catalog_manager: Signal<CatalogManager>,
+    // Manifest scans keyed by `catalog.namespace.table@snapshot_id`, kept across tab switches
+    schema_file_stats_cache: Signal<std::collections::HashMap<String, Vec<SchemaFileStats>>>,
+    // Per-column sizes, keyed the same way
+    column_size_cache: Signal<std::collections::HashMap<String, Vec<ColumnSizeStats>>>,
+    // Field to scroll to and highlight, e.g. from a partition or sort field link; consumed here
+    focus_field: Signal<Option<i32>>,
+) -> Element {
+    let mut matrix_search = use_signal(String::new);
+    let column_ndv = table.column_ndv();
+    let column_classifications = catalog_manager
+        .read()
+        .settings()
+        .column_classifications(&table.properties, &table.schema.fields);
+    let mut analyzing_sizes = use_signal(|| false);
+    let mut column_size_error = use_signal(|| Option::<String>::None);
+    let mut sort_by_size = use_signal(|| false);
+    let size_cache_key = table.current_snapshot_id.map(|snapshot_id| {
+        format!(
+            "{}.{}.{}@{}",
+            table.catalog_name, table.namespace, table.name, snapshot_id
+        )
+    });
+    let column_sizes = size_cache_key
+        .as_ref()
+        .and_then(|key| column_size_cache.read().get(key).cloned())
+        .map(|stats| TableAnalytics::column_sizes(&stats, &table.schema.fields))
+        .unwrap_or_default();
+    let column_ranges = size_cache_key
+        .as_ref()
+        .and_then(|key| column_size_cache.read().get(key).cloned())
+        .map(|stats| TableAnalytics::column_ranges(&stats, &table.schema.fields))
+        .unwrap_or_default();
+    let schema_fields = if sort_by_size() && !column_sizes.is_empty() {
+        fields_by_size(&table.schema.fields, &column_sizes)
+    } else {
+        table.schema.fields.clone()
+    };
+    let analyze_sizes = {
+        let catalog_name = table.catalog_name.clone();
+        let namespace = table.namespace.clone();
+        let table_name = table.name.clone();
+        let size_cache_key = size_cache_key.clone();
+        move |_: MouseEvent| {
+            let Some(cache_key) = size_cache_key.clone() else {
+                return;
+            };
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            let table_name = table_name.clone();
+            spawn(async move {
+                analyzing_sizes.set(true);
+                column_size_error.set(None);
+                let result = catalog_manager
+                    .read()
+                    .column_size_stats(&catalog_name, &namespace, &table_name)
+                    .await;
+                match result {
+                    Ok(stats) => {
+                        column_size_cache.write().insert(cache_key, stats);
+                    }
+                    Err(e) => column_size_error.set(Some(e.message().to_string())),
+                }
+                analyzing_sizes.set(false);
+            });
+        }
+    };
+    // Struct fields whose children are hidden, by field id
+    let mut collapsed_structs = use_signal(std::collections::HashSet::<i32>::new);
+    let struct_ids = struct_field_ids(&table.schema.fields);
+    // Format for copying or saving the schema
+    let mut schema_format = use_signal(|| SchemaFormat::Markdown);
+
+    // Take the requested field, expanding the structs around it so its row is rendered
+    let mut highlighted_field = use_signal(|| Option::<i32>::None);
+    let lineage_fields = table.schema.fields.clone();
+    use_effect(move || {
+        let Some(field_id) = focus_field() else {
+            return;
+        };
+        focus_field.set(None);
+        if let Some(lineage) = schema_field_lineage(&lineage_fields, field_id) {
+            collapsed_structs.with_mut(|collapsed| {
+                for field in lineage {
+                    collapsed.remove(&field.id);
+                }
+            });
+        }
+        highlighted_field.set(Some(field_id));
+    });
+    // Scroll once the highlighted row has rendered
+    use_effect(move || {
+        if let Some(field_id) = highlighted_field() {
+            document::eval(&format!(
+                "document.getElementById('schema-field-{}')?.scrollIntoView({{ block: 'center', behavior: 'smooth' }});",
+                field_id
+            ));
+        }
+    });
+
+    // Bring the first matching row of the comparison matrix into view once it has rendered
+    use_effect(move || {
+        if !matrix_search().trim().is_empty() {
+            document::eval(
+                "document.querySelector('.schema-match')?.scrollIntoView({ block: 'center', behavior: 'smooth' });",
+            );
+        }
+    });
+
     rsx! {
         div {
             class: "space-y-6",
@@ -366,9 +1011,63 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                 class: "bg-white shadow rounded-lg",
                 div {
                     class: "px-4 py-5 sm:p-6",
-                    h3 {
-                        class: "text-lg leading-6 font-medium text-gray-900 mb-4",
-                        "Current Schema (ID: {table.schema.schema_id})"
+                    div {
+                        class: "flex items-center justify-between mb-4",
+                        h3 {
+                            class: "text-lg leading-6 font-medium text-gray-900",
+                            "Current Schema (ID: {table.schema.schema_id})"
+                        }
+                        div {
+                            class: "flex items-center space-x-2",
+                            select {
+                                class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                                title: "Markdown for wikis and design docs; Avro (.avsc) and Arrow JSON for serialization tools",
+                                onchange: move |evt| {
+                                    if let Some(selected) = SchemaFormat::ALL
+                                        .into_iter()
+                                        .find(|f| f.label() == evt.value())
+                                    {
+                                        schema_format.set(selected);
+                                    }
+                                },
+                                for option_format in SchemaFormat::ALL {
+                                    option {
+                                        value: option_format.label(),
+                                        selected: schema_format() == option_format,
+                                        "{option_format.label()}"
+                                    }
+                                }
+                            }
+                            button {
+                                onclick: {
+                                    let table = table.clone();
+                                    move |_| {
+                                        let format = schema_format();
+                                        copy_to_clipboard(&format.render(&table));
+                                        notify(ToastKind::Success, tf(Msg::SchemaCopiedAs, &[&format.label()]));
+                                    }
+                                },
+                                class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                title: "Copy the schema in the selected format",
+                                "📋 Copy"
+                            }
+                            button {
+                                onclick: {
+                                    let table = table.clone();
+                                    move |_| {
+                                        let format = schema_format();
+                                        let saved = save_to_downloads(&format.file_name(&table), &format.render(&table));
+                                        match saved {
+                                            Ok(path) => notify(ToastKind::Success, tf(Msg::SavedFile, &[&path.display()])),
+                                            Err(e) => notify(ToastKind::Error, format!("{}: {}", t(Msg::FailedToSaveSchema), e)),
+                                        }
+                                    }
+                                },
+                                class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                title: "Save the schema in the selected format to your downloads folder",
+                                "💾 Save"
+                            }
+                        }
                     }
                     div {
                         class: "mb-4",
@@ -397,18 +1096,68 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                         }
                     }
                     div {
-                        class: "overflow-x-auto",
-                        table {
-                            class: "min-w-full divide-y divide-gray-200",
-                            thead {
-                                class: "bg-gray-50",
-                                tr {
-                                    th {
-                                        class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                        "ID"
+                        class: "flex items-center justify-between mb-2 text-xs",
+                        div {
+                            class: "flex items-center space-x-3",
+                            if size_cache_key.is_some() {
+                                if column_sizes.is_empty() {
+                                    button {
+                                        onclick: analyze_sizes,
+                                        disabled: analyzing_sizes(),
+                                        class: "text-blue-600 hover:text-blue-800 disabled:text-gray-400",
+                                        title: "Sum each column's on-disk size and collect its value range across the current snapshot's data files, from the sizes and bounds recorded in its manifests",
+                                        if analyzing_sizes() { "Reading manifests..." } else { "Analyze column sizes and ranges" }
                                     }
-                                    th {
-                                        class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                } else {
+                                    label {
+                                        class: "flex items-center space-x-1 text-gray-600",
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: sort_by_size(),
+                                            onchange: move |evt| sort_by_size.set(evt.checked()),
+                                        }
+                                        span { "Sort by size" }
+                                    }
+                                }
+                            }
+                            if let Some(error) = column_size_error() {
+                                span { class: "text-red-600 truncate max-w-md", title: "{error}", "{error}" }
+                            }
+                        }
+                        if !struct_ids.is_empty() {
+                            div {
+                                class: "flex space-x-3",
+                                button {
+                                    onclick: move |_| collapsed_structs.write().clear(),
+                                    class: "text-blue-600 hover:text-blue-800",
+                                    "Expand all"
+                                }
+                                button {
+                                    onclick: {
+                                        let struct_ids = struct_ids.clone();
+                                        move |_| collapsed_structs.set(struct_ids.clone())
+                                    },
+                                    class: "text-blue-600 hover:text-blue-800",
+                                    "Collapse all"
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        class: "overflow-x-auto",
+                        table {
+                            class: "min-w-full divide-y divide-gray-200",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th {
+                                        class: "{PINNED_COLUMN} bg-gray-50 px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                        style: PINNED_ID_COLUMN_STYLE,
+                                        "ID"
+                                    }
+                                    th {
+                                        class: "{PINNED_AFTER_ID_COLUMN} bg-gray-50 px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                        style: PINNED_AFTER_ID_COLUMN_STYLE,
                                         "Name"
                                     }
                                     th {
@@ -419,6 +1168,31 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                                         class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
                                         "Required"
                                     }
+                                    th {
+                                        class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                        "Defaults"
+                                    }
+                                    if !column_ndv.is_empty() {
+                                        th {
+                                            class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            title: "Approximate distinct values, from the current snapshot's statistics file",
+                                            "Distinct (≈)"
+                                        }
+                                    }
+                                    if !column_ranges.is_empty() {
+                                        th {
+                                            class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            title: "Smallest and largest value across the current snapshot's data files, from manifest bounds. String bounds may be truncated.",
+                                            "Range"
+                                        }
+                                    }
+                                    if !column_sizes.is_empty() {
+                                        th {
+                                            class: "px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            title: "Compressed on-disk size across the current snapshot's data files and its share of all column data. Fixed-width columns also show an estimated compression ratio.",
+                                            "Size"
+                                        }
+                                    }
                                     th {
                                         class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
                                         "Description"
@@ -427,8 +1201,17 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                             }
                             tbody {
                                 class: "bg-white divide-y divide-gray-200",
-                                for field in &table.schema.fields {
-                                    SchemaFieldRow { field: field.clone(), depth: 0 }
+                                for field in &schema_fields {
+                                    SchemaFieldRow {
+                                        field: field.clone(),
+                                        depth: 0,
+                                        column_ndv: column_ndv.clone(),
+                                        column_sizes: column_sizes.clone(),
+                                        column_ranges: column_ranges.clone(),
+                                        column_classifications: column_classifications.clone(),
+                                        collapsed_structs: collapsed_structs,
+                                        highlighted_field: highlighted_field()
+                                    }
                                 }
                             }
                         }
@@ -436,6 +1219,8 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                 }
             }
 
+            TableStatisticsCard { table: table.clone() }
+
             // Schema Evolution (if multiple schemas exist)
             if table.schemas.len() > 1 {
                 div {
@@ -494,9 +1279,19 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
 
                         // Schema comparison
                         div {
-                            h4 {
-                                class: "text-md font-medium text-gray-900 mb-3",
-                                "Schema Comparison"
+                            div {
+                                class: "flex items-center justify-between mb-3",
+                                h4 {
+                                    class: "text-md font-medium text-gray-900",
+                                    "Schema Comparison"
+                                }
+                                input {
+                                    r#type: "text",
+                                    class: "w-64 px-3 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500",
+                                    placeholder: "Find field by name or ID...",
+                                    value: "{matrix_search}",
+                                    oninput: move |evt| matrix_search.set(evt.value()),
+                                }
                             }
                             div {
                                 class: "overflow-x-auto",
@@ -506,11 +1301,13 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                                         class: "bg-gray-50",
                                         tr {
                                             th {
-                                                class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                                class: "{PINNED_COLUMN} bg-gray-50 px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                                style: PINNED_ID_COLUMN_STYLE,
                                                 "Field ID"
                                             }
                                             th {
-                                                class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                                class: "{PINNED_AFTER_ID_COLUMN} bg-gray-50 px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                                style: PINNED_AFTER_ID_COLUMN_STYLE,
                                                 "Field Name"
                                             }
                                             for schema in &table.schemas {
@@ -540,6 +1337,9 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                                             }
                                             let mut sorted_field_ids: Vec<_> = all_field_ids.into_iter().collect();
                                             sorted_field_ids.sort();
+                                            let query = matrix_search().trim().to_lowercase();
+                                            let searching = !query.is_empty();
+                                            sorted_field_ids.retain(|id| field_matches_query(&table.schemas, *id, &query));
 
                                             rsx! {
                                                 for field_id in sorted_field_ids {
@@ -553,19 +1353,33 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
 
                                                         rsx! {
                                                             tr {
+                                                                class: if searching { "schema-match bg-yellow-50" } else { "" },
                                                                 td {
-                                                                    class: "px-6 py-4 whitespace-nowrap text-sm font-medium text-gray-900",
+                                                                    class: format!(
+                                                                        "{} {} px-6 py-4 whitespace-nowrap text-sm font-medium text-gray-900",
+                                                                        PINNED_COLUMN,
+                                                                        if searching { "bg-yellow-50" } else { "bg-white" }
+                                                                    ),
+                                                                    style: PINNED_ID_COLUMN_STYLE,
                                                                     "{field_id}"
                                                                 }
                                                                 td {
-                                                                    class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900",
+                                                                    class: format!(
+                                                                        "{} {} px-6 py-4 whitespace-nowrap text-sm text-gray-900 {}",
+                                                                        PINNED_AFTER_ID_COLUMN,
+                                                                        if searching { "bg-yellow-50" } else { "bg-white" },
+                                                                        if searching { "font-semibold" } else { "" }
+                                                                    ),
+                                                                    style: PINNED_AFTER_ID_COLUMN_STYLE,
                                                                     "{field_name}"
                                                                 }
                                                                 for schema in &table.schemas {
                                                                     td {
                                                                         class: format!(
                                                                             "px-6 py-4 whitespace-nowrap text-sm {}",
-                                                                            if schema.schema_id == table.schema.schema_id {
+                                                                            if searching && schema.fields.iter().any(|f| f.id == field_id) {
+                                                                                "bg-yellow-100"
+                                                                            } else if schema.schema_id == table.schema.schema_id {
                                                                                 "bg-blue-50"
                                                                             } else {
                                                                                 ""
@@ -611,26 +1425,1053 @@ pub fn TableSchemaTab(table: IcebergTable) -> Element {
                     }
                 }
             }
+
+            if table.schemas.len() > 1 {
+                SchemaFileStatsCard {
+                    table: table.clone(),
+                    catalog_manager,
+                    cache: schema_file_stats_cache,
+                }
+            }
+        }
+    }
+}
+
+/// Failure to read a table's manifests, with a hint on what to check and a way to retry
+#[component]
+fn ManifestReadError(error: CatalogError, on_retry: EventHandler<MouseEvent>) -> Element {
+    let hint = match error.load_failure_kind() {
+        LoadFailureKind::AccessDenied => {
+            "The catalog returned the table, but the storage credentials can't read its files. Check the object store permissions for this path."
+        }
+        LoadFailureKind::MissingMetadata => {
+            "A manifest referenced by the current snapshot is missing, for example after an orphan file cleanup or a partial copy."
+        }
+        LoadFailureKind::ParseError => {
+            "A manifest exists but couldn't be decoded. It may be truncated or written by an incompatible engine."
+        }
+        LoadFailureKind::Other => {
+            "Check the network connection and the object store settings for this catalog."
+        }
+    };
+    let message = error.message();
+
+    rsx! {
+        div {
+            class: "border-l-4 border-red-400 bg-red-50 p-3 text-sm",
+            div { class: "font-medium text-red-800", {t(Msg::ManifestReadFailed)} }
+            div { class: "mt-1 font-mono text-xs text-red-700 break-all", "{message}" }
+            p { class: "mt-2 text-red-700", "{hint}" }
+            button {
+                onclick: move |evt| on_retry.call(evt),
+                class: "mt-2 px-3 py-1 text-sm font-medium text-red-700 bg-white border border-red-300 rounded-md hover:bg-red-100",
+                {t(Msg::Retry)}
+            }
+        }
+    }
+}
+
+/// Diff between the table's current metadata file and the one before it, read from the
+/// metadata log on request
+#[component]
+fn LastMetadataChangeCard(table: IcebergTable, catalog_manager: Signal<CatalogManager>) -> Element {
+    let mut loading = use_signal(|| false);
+    let mut result = use_signal(|| Option::<Result<Option<LastMetadataChange>, String>>::None);
+
+    let load = {
+        let catalog_name = table.catalog_name.clone();
+        let namespace = table.namespace.clone();
+        let table_name = table.name.clone();
+        move |_: MouseEvent| {
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            let table_name = table_name.clone();
+            spawn(async move {
+                loading.set(true);
+                let versions = catalog_manager
+                    .read()
+                    .load_metadata_versions(&catalog_name, &namespace, &table_name)
+                    .await
+                    .map_err(|e| e.message().to_string());
+                let convert = |table: &iceberg::table::Table| {
+                    crate::iceberg_adapter::convert_iceberg_table(
+                        table,
+                        namespace.clone(),
+                        catalog_name.clone(),
+                    )
+                    .map_err(|e| format!("Failed to convert table metadata: {}", e))
+                };
+                let change = versions.and_then(|versions| {
+                    let Some(previous) = versions.previous else {
+                        return Ok(None);
+                    };
+                    let changes = TableAnalytics::metadata_changes(
+                        &convert(&previous.table)?,
+                        &convert(&versions.current)?,
+                    );
+                    Ok(Some(LastMetadataChange {
+                        previous_location: previous.location,
+                        previous_timestamp_ms: previous.timestamp_ms,
+                        changes,
+                    }))
+                });
+                result.set(Some(change));
+                loading.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "bg-white shadow rounded-lg",
+            div {
+                class: "px-4 py-5 sm:p-6",
+                div {
+                    class: "flex items-center justify-between mb-2",
+                    h3 {
+                        class: "text-lg leading-6 font-medium text-gray-900",
+                        "Last Metadata Change"
+                    }
+                    button {
+                        onclick: load,
+                        disabled: loading(),
+                        class: "px-3 py-1 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:bg-gray-300",
+                        if loading() {
+                            "Reading metadata..."
+                        } else if result().is_some() {
+                            "Compare again"
+                        } else {
+                            "Compare with previous version"
+                        }
+                    }
+                }
+                p {
+                    class: "text-sm text-gray-500 mb-4",
+                    "What the most recent commit changed, comparing the current metadata file with the previous entry in the metadata log."
+                }
+                {match result() {
+                    None => rsx! {},
+                    Some(Err(error)) => rsx! {
+                        p { class: "text-sm text-red-600 break-all", "{error}" }
+                    },
+                    Some(Ok(None)) => rsx! {
+                        p { class: "text-sm text-gray-600", "The metadata log is empty, so there is no earlier version to compare with." }
+                    },
+                    Some(Ok(Some(change))) => rsx! {
+                        div {
+                            class: "text-xs text-gray-500 mb-3",
+                            "Previous version: "
+                            span { class: "font-mono break-all", "{change.previous_location}" }
+                            {format!(
+                                " (replaced {})",
+                                chrono::DateTime::from_timestamp_millis(change.previous_timestamp_ms)
+                                    .map(|at| at.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                                    .unwrap_or_else(|| "at an unknown time".to_string())
+                            )}
+                        }
+                        if change.changes.is_empty() {
+                            p { class: "text-sm text-gray-600", "No schema, partitioning, sort order, snapshot, branch, property or location changes." }
+                        } else {
+                            ul {
+                                class: "space-y-1",
+                                for metadata_change in change.changes.iter() {
+                                    li {
+                                        class: "flex items-start text-sm",
+                                        span {
+                                            class: "flex-shrink-0 w-32 text-xs font-medium text-gray-500 pt-0.5",
+                                            "{metadata_change.kind.label()}"
+                                        }
+                                        span { class: "text-gray-900", "{metadata_change.description}" }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }}
+            }
+        }
+    }
+}
+
+/// Files listed under a selected write before the rest are summarised
+const BLAME_FILES_SHOWN: usize = 200;
+
+/// Which historical write added each live data file, read from the manifests on demand and
+/// grouped by adding snapshot. Selecting a write lists its files.
+#[component]
+fn DataFileBlameCard(
+    table: IcebergTable,
+    catalog_manager: Signal<CatalogManager>,
+    cache: Signal<std::collections::HashMap<String, Vec<DataFileOrigin>>>,
+) -> Element {
+    let mut analyzing = use_signal(|| false);
+    let mut error = use_signal(|| Option::<CatalogError>::None);
+    let mut selected = use_signal(|| Option::<Option<u64>>::None);
+    let mut path_filter = use_signal(String::new);
+
+    let Some(snapshot_id) = table.current_snapshot_id else {
+        return rsx! {};
+    };
+    let cache_key = format!(
+        "{}.{}.{}@{}",
+        table.catalog_name, table.namespace, table.name, snapshot_id
+    );
+    let files = cache.read().get(&cache_key).cloned();
+    let contributions = files
+        .as_ref()
+        .map(|files| TableAnalytics::snapshot_contributions(files, &table.snapshots));
+
+    let analyze = {
+        let catalog_name = table.catalog_name.clone();
+        let namespace = table.namespace.clone();
+        let table_name = table.name.clone();
+        move |_: MouseEvent| {
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            let table_name = table_name.clone();
+            let cache_key = cache_key.clone();
+            spawn(async move {
+                analyzing.set(true);
+                error.set(None);
+                let result = catalog_manager
+                    .read()
+                    .data_file_origins(&catalog_name, &namespace, &table_name)
+                    .await;
+                match result {
+                    Ok(origins) => {
+                        cache.write().insert(cache_key, origins);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                analyzing.set(false);
+            });
+        }
+    };
+
+    let selected_files: Vec<DataFileOrigin> = match (selected(), &files) {
+        (Some(snapshot), Some(files)) => files
+            .iter()
+            .filter(|file| file.added_snapshot_id == snapshot)
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    };
+    let path_query = path_filter().trim().to_lowercase();
+    let shown_files: Vec<&DataFileOrigin> = selected_files
+        .iter()
+        .filter(|file| path_query.is_empty() || file.file_path.to_lowercase().contains(&path_query))
+        .collect();
+
+    rsx! {
+        div {
+            class: "bg-white shadow rounded-lg",
+            div {
+                class: "px-4 py-5 sm:p-6",
+                div {
+                    class: "flex items-center justify-between mb-2",
+                    h3 {
+                        class: "text-lg leading-6 font-medium text-gray-900",
+                        "Data File Blame"
+                    }
+                    if files.is_none() && error().is_none() {
+                        button {
+                            onclick: analyze.clone(),
+                            disabled: analyzing(),
+                            class: "px-3 py-1 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:bg-gray-300",
+                            if analyzing() { "Reading manifests..." } else { "Analyze files" }
+                        }
+                    }
+                }
+                p {
+                    class: "text-sm text-gray-500 mb-4",
+                    "Which write added each live file of snapshot {snapshot_id}, and how much of the current data each write still contributes. Old appends that still hold many small files were never compacted."
+                }
+                if let Some(error) = error() {
+                    ManifestReadError { error, on_retry: analyze }
+                }
+                if let Some(contributions) = contributions {
+                    if contributions.is_empty() {
+                        p { class: "text-sm text-gray-500", "The current snapshot has no live data files." }
+                    } else {
+                        table {
+                            class: "min-w-full divide-y divide-gray-200",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Added by" }
+                                    th { class: "px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Committed" }
+                                    th { class: "px-4 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Files" }
+                                    th { class: "px-4 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Records" }
+                                    th { class: "px-4 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Size" }
+                                    th { class: "px-4 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Share" }
+                                }
+                            }
+                            tbody {
+                                class: "bg-white divide-y divide-gray-200",
+                                for contribution in contributions {
+                                    {
+                                        let id = contribution.snapshot_id;
+                                        let is_selected = selected() == Some(id);
+                                        rsx! {
+                                            tr {
+                                                key: "{id:?}",
+                                                onclick: move |_| selected.set(if is_selected { None } else { Some(id) }),
+                                                class: if is_selected { "bg-blue-50 cursor-pointer" } else { "hover:bg-gray-50 cursor-pointer" },
+                                                title: "Show this write's files",
+                                                td {
+                                                    class: "px-4 py-2 text-sm text-gray-900",
+                                                    span {
+                                                        class: "font-mono",
+                                                        {id.map_or("unknown snapshot".to_string(), |id| id.to_string())}
+                                                    }
+                                                    if let Some(operation) = &contribution.operation {
+                                                        span { class: "ml-2 text-xs text-gray-500", "{operation}" }
+                                                    } else if id.is_some() {
+                                                        span { class: "ml-2 text-xs text-yellow-700", "expired" }
+                                                    }
+                                                }
+                                                td {
+                                                    class: "px-4 py-2 text-sm text-gray-500",
+                                                    {contribution
+                                                        .timestamp_ms
+                                                        .and_then(chrono::DateTime::from_timestamp_millis)
+                                                        .map_or("—".to_string(), |at| at.format("%Y-%m-%d %H:%M UTC").to_string())}
+                                                }
+                                                td { class: "px-4 py-2 text-sm text-gray-900 text-right", "{contribution.file_count}" }
+                                                td { class: "px-4 py-2 text-sm text-gray-900 text-right", "{contribution.record_count}" }
+                                                td { class: "px-4 py-2 text-sm text-gray-900 text-right", {format_bytes(contribution.total_size_bytes)} }
+                                                td { class: "px-4 py-2 text-sm text-gray-900 text-right", {format!("{:.1}%", contribution.size_percent)} }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if !selected_files.is_empty() {
+                            div {
+                                class: "mt-4 border-t border-gray-200 pt-4",
+                                div {
+                                    class: "flex items-center justify-between mb-2",
+                                    h4 {
+                                        class: "text-sm font-medium text-gray-900",
+                                        if path_query.is_empty() {
+                                            {format!("{} file(s) added by this write", selected_files.len())}
+                                        } else {
+                                            {format!("{} of {} file(s) added by this write", shown_files.len(), selected_files.len())}
+                                        }
+                                    }
+                                    input {
+                                        r#type: "text",
+                                        class: "w-64 px-3 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500",
+                                        placeholder: "Find file by path...",
+                                        value: "{path_filter}",
+                                        oninput: move |evt| path_filter.set(evt.value()),
+                                    }
+                                }
+                                if shown_files.is_empty() {
+                                    p { class: "text-xs text-gray-500", "No file path contains \"{path_filter().trim()}\"." }
+                                }
+                                div {
+                                    class: "max-h-96 overflow-y-auto divide-y divide-gray-100",
+                                    for file in shown_files.iter().take(BLAME_FILES_SHOWN) {
+                                        div {
+                                            key: "{file.file_path}",
+                                            class: "flex items-center justify-between py-1 text-xs",
+                                            div {
+                                                class: "min-w-0",
+                                                div {
+                                                    class: "flex items-start font-mono text-gray-900",
+                                                    span { class: "break-all select-all cursor-text", "{file.file_path}" }
+                                                    button {
+                                                        onclick: {
+                                                            let file_path = file.file_path.clone();
+                                                            move |_| {
+                                                                copy_to_clipboard(&file_path);
+                                                                notify(ToastKind::Success, t(Msg::FilePathCopied));
+                                                            }
+                                                        },
+                                                        class: "ml-2 text-gray-400 hover:text-gray-600 flex-shrink-0",
+                                                        title: "Copy file path",
+                                                        "📋"
+                                                    }
+                                                }
+                                                p {
+                                                    class: "text-gray-500",
+                                                    "{file.partition}"
+                                                    if let Some(sequence_number) = file.sequence_number {
+                                                        " · seq {sequence_number}"
+                                                    }
+                                                }
+                                            }
+                                            div {
+                                                class: "ml-4 flex-shrink-0 text-right text-gray-700",
+                                                p { "{file.record_count} rows" }
+                                                p { {format_bytes(file.file_size_bytes)} }
+                                            }
+                                        }
+                                    }
+                                }
+                                if shown_files.len() > BLAME_FILES_SHOWN {
+                                    p {
+                                        class: "mt-2 text-xs text-gray-500",
+                                        {format!("Showing the first {} of {} files", BLAME_FILES_SHOWN, shown_files.len())}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Live data files of the current snapshot grouped by the schema they were written with,
+/// read from the manifests on demand. Files on an older schema are highlighted.
+#[component]
+fn SchemaFileStatsCard(
+    table: IcebergTable,
+    catalog_manager: Signal<CatalogManager>,
+    cache: Signal<std::collections::HashMap<String, Vec<SchemaFileStats>>>,
+) -> Element {
+    let mut analyzing = use_signal(|| false);
+    let mut error = use_signal(|| Option::<CatalogError>::None);
+
+    let Some(snapshot_id) = table.current_snapshot_id else {
+        return rsx! {};
+    };
+    let cache_key = format!(
+        "{}.{}.{}@{}",
+        table.catalog_name, table.namespace, table.name, snapshot_id
+    );
+    let stats = cache.read().get(&cache_key).cloned();
+    let current_schema_id = table.schema.schema_id;
+    let drift = stats
+        .as_ref()
+        .and_then(|stats| TableAnalytics::schema_drift(stats, current_schema_id));
+
+    let analyze = {
+        let catalog_name = table.catalog_name.clone();
+        let namespace = table.namespace.clone();
+        let table_name = table.name.clone();
+        move |_: MouseEvent| {
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            let table_name = table_name.clone();
+            let cache_key = cache_key.clone();
+            spawn(async move {
+                analyzing.set(true);
+                error.set(None);
+                let result = catalog_manager
+                    .read()
+                    .schema_file_stats(&catalog_name, &namespace, &table_name)
+                    .await;
+                match result {
+                    Ok(stats) => {
+                        cache.write().insert(cache_key, stats);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                analyzing.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "bg-white shadow rounded-lg",
+            div {
+                class: "px-4 py-5 sm:p-6",
+                div {
+                    class: "flex items-center justify-between mb-2",
+                    h3 {
+                        class: "text-lg leading-6 font-medium text-gray-900",
+                        "Data Files by Schema"
+                    }
+                    if stats.is_none() && error().is_none() {
+                        button {
+                            onclick: analyze.clone(),
+                            disabled: analyzing(),
+                            class: "px-3 py-1 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:bg-gray-300",
+                            if analyzing() { "Reading manifests..." } else { "Analyze files" }
+                        }
+                    }
+                }
+                p {
+                    class: "text-sm text-gray-500 mb-4",
+                    "Which schema the live files of snapshot {snapshot_id} were written with. Files on an older schema are read through schema evolution and may be worth rewriting."
+                }
+                if let Some(error) = error() {
+                    ManifestReadError { error, on_retry: analyze }
+                }
+                if let Some(stats) = stats {
+                    if let Some(drift) = drift {
+                        div {
+                            class: "border-l-4 border-yellow-400 bg-yellow-50 p-3 mb-4 text-sm text-yellow-800",
+                            {format!(
+                                "{} files ({}, {:.1}% of live bytes) were written before the current schema {}",
+                                drift.file_count,
+                                format_bytes(drift.total_size_bytes),
+                                drift.size_percent,
+                                current_schema_id
+                            )}
+                        }
+                    } else if !stats.is_empty() {
+                        div {
+                            class: "border-l-4 border-green-400 bg-green-50 p-3 mb-4 text-sm text-green-800",
+                            "All live data files are on the current schema."
+                        }
+                    }
+                    if stats.is_empty() {
+                        p { class: "text-sm text-gray-500", "The current snapshot has no live data files." }
+                    } else {
+                        table {
+                            class: "min-w-full divide-y divide-gray-200",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Schema" }
+                                    th { class: "px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Files" }
+                                    th { class: "px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Records" }
+                                    th { class: "px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Size" }
+                                }
+                            }
+                            tbody {
+                                class: "bg-white divide-y divide-gray-200",
+                                for entry in stats {
+                                    tr {
+                                        key: "{entry.schema_id}",
+                                        class: if entry.schema_id == current_schema_id { "" } else { "bg-yellow-50" },
+                                        td {
+                                            class: "px-6 py-2 text-sm text-gray-900",
+                                            "Schema {entry.schema_id}"
+                                            if entry.schema_id == current_schema_id {
+                                                span {
+                                                    class: "ml-2 inline-flex px-2 py-0.5 text-xs font-semibold rounded-full bg-blue-100 text-blue-800",
+                                                    "CURRENT"
+                                                }
+                                            }
+                                        }
+                                        td { class: "px-6 py-2 text-sm text-gray-900 text-right", "{entry.file_count}" }
+                                        td { class: "px-6 py-2 text-sm text-gray-900 text-right", "{entry.record_count}" }
+                                        td { class: "px-6 py-2 text-sm text-gray-900 text-right", {format_bytes(entry.total_size_bytes)} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whether a field ID matches the matrix search, by ID or by any name it has had across schemas
+fn field_matches_query(schemas: &[TableSchema], field_id: i32, query: &str) -> bool {
+    query.is_empty()
+        || field_id.to_string() == query
+        || schemas
+            .iter()
+            .flat_map(|s| &s.fields)
+            .any(|f| f.id == field_id && f.name.to_lowercase().contains(query))
+}
+
+/// Statistics and partition statistics files registered for the table
+#[component]
+pub fn TableStatisticsCard(table: IcebergTable) -> Element {
+    let current_statistics = table.current_statistics().cloned();
+    let current_partition_statistics = table
+        .partition_statistics
+        .iter()
+        .find(|file| Some(file.snapshot_id) == table.current_snapshot_id)
+        .cloned();
+    let field_name = |field_id: i32| {
+        table
+            .schema
+            .fields
+            .iter()
+            .find(|field| field.id == field_id)
+            .map_or(field_id.to_string(), |field| field.name.clone())
+    };
+
+    rsx! {
+        div {
+            class: "bg-white shadow rounded-lg",
+            div {
+                class: "px-4 py-5 sm:p-6",
+                h3 {
+                    class: "text-lg leading-6 font-medium text-gray-900 mb-2",
+                    "Statistics"
+                }
+                p {
+                    class: "text-sm text-gray-500 mb-4",
+                    "{table.statistics.len()} statistics file(s) and {table.partition_statistics.len()} partition statistics file(s) across all snapshots."
+                }
+                if let Some(file) = current_statistics {
+                    div {
+                        class: "mb-4",
+                        div {
+                            class: "flex items-start text-xs font-mono text-gray-500 mb-2",
+                            span { class: "mr-1 flex-shrink-0", "Current snapshot:" }
+                            span { class: "break-all select-all", "{file.statistics_path}" }
+                            span { class: "ml-2 flex-shrink-0", {format!("({})", format_bytes(file.file_size_in_bytes))} }
+                        }
+                        if file.blobs.is_empty() {
+                            p { class: "text-sm text-gray-500", "The file lists no blobs." }
+                        } else {
+                            table {
+                                class: "min-w-full divide-y divide-gray-200 text-sm",
+                                thead {
+                                    class: "bg-gray-50",
+                                    tr {
+                                        th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Blob type" }
+                                        th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Columns" }
+                                        th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Distinct (≈)" }
+                                    }
+                                }
+                                tbody {
+                                    class: "bg-white divide-y divide-gray-200",
+                                    for blob in file.blobs.iter() {
+                                        tr {
+                                            td { class: "px-4 py-2 font-mono text-xs text-gray-900", "{blob.blob_type}" }
+                                            td {
+                                                class: "px-4 py-2 text-gray-900",
+                                                {blob.field_ids.iter().map(|id| field_name(*id)).collect::<Vec<_>>().join(", ")}
+                                            }
+                                            td {
+                                                class: "px-4 py-2 text-gray-900",
+                                                {blob.ndv().map_or("—".to_string(), |ndv| ndv.to_string())}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    p {
+                        class: "text-sm text-gray-500 mb-4",
+                        "No statistics file for the current snapshot. Engines write one when statistics are computed, e.g. Spark's compute_table_stats procedure or Trino's ANALYZE."
+                    }
+                }
+                if let Some(file) = current_partition_statistics {
+                    div {
+                        class: "flex items-start text-xs font-mono text-gray-500",
+                        span { class: "mr-1 flex-shrink-0", "Partition statistics:" }
+                        span { class: "break-all select-all", "{file.statistics_path}" }
+                        span { class: "ml-2 flex-shrink-0", {format!("({})", format_bytes(file.file_size_in_bytes))} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A view's definition, read-only: the SQL of a version in each dialect it was written
+/// for, the schema that version produces, and every version the view has had
+#[component]
+pub fn ViewDefinitionTab(view: IcebergView) -> Element {
+    let current_version_id = view.current_version_id;
+    // The current version until an earlier one is picked from the history
+    let mut shown_version_id = use_signal(|| current_version_id);
+    let shown_id = shown_version_id();
+    let shown = view
+        .versions
+        .iter()
+        .find(|version| version.version_id == shown_id)
+        .cloned();
+    let shown_is_current = shown_id == current_version_id;
+    let schema = shown
+        .as_ref()
+        .and_then(|version| view.schema(version.schema_id))
+        .cloned();
+    let schema_fields = schema
+        .as_ref()
+        .map(|schema| view_schema_rows(&schema.fields, 0))
+        .unwrap_or_default();
+    let newest_first: Vec<ViewVersion> = view.versions.iter().rev().cloned().collect();
+    let mut properties: Vec<(String, String)> = view
+        .properties
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    properties.sort();
+
+    rsx! {
+        div {
+            class: "space-y-6",
+
+            div {
+                class: "bg-white shadow rounded-lg",
+                div {
+                    class: "px-4 py-5 sm:p-6",
+                    div {
+                        class: "flex items-center mb-4 space-x-3",
+                        h3 {
+                            class: "text-lg leading-6 font-medium text-gray-900",
+                            "👁️ {view.name}"
+                        }
+                        span {
+                            class: "inline-flex px-2 py-1 text-xs font-semibold rounded-full bg-purple-100 text-purple-800",
+                            title: "Views are shown read-only",
+                            "View"
+                        }
+                    }
+                    dl {
+                        class: "grid grid-cols-1 gap-x-4 gap-y-6 sm:grid-cols-2",
+                        div {
+                            dt { class: "text-sm font-medium text-gray-500", "Namespace" }
+                            dd { class: "mt-1 text-sm text-gray-900 font-mono", "{view.namespace}" }
+                        }
+                        div {
+                            dt { class: "text-sm font-medium text-gray-500", "Current version" }
+                            dd {
+                                class: "mt-1 text-sm text-gray-900",
+                                "v{current_version_id} of {view.versions.len()}"
+                            }
+                        }
+                        div {
+                            class: "sm:col-span-2",
+                            dt { class: "text-sm font-medium text-gray-500", "Location" }
+                            dd { class: "mt-1 text-sm text-gray-900 font-mono break-all", "{view.location}" }
+                        }
+                    }
+                }
+            }
+
+            // SQL of the shown version, one block per dialect
+            div {
+                class: "bg-white shadow rounded-lg",
+                div {
+                    class: "px-4 py-5 sm:p-6",
+                    div {
+                        class: "flex items-center justify-between mb-4",
+                        h3 {
+                            class: "text-lg leading-6 font-medium text-gray-900",
+                            if shown_is_current {
+                                "SQL"
+                            } else {
+                                "SQL of version {shown_id}"
+                            }
+                        }
+                        if !shown_is_current {
+                            button {
+                                onclick: move |_| shown_version_id.set(current_version_id),
+                                class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                "Show current version"
+                            }
+                        }
+                    }
+                    if let Some(version) = shown.clone() {
+                        p {
+                            class: "mb-4 text-xs text-gray-500",
+                            {
+                                let catalog = version
+                                    .default_catalog
+                                    .as_ref()
+                                    .map(|catalog| format!("{}.", catalog))
+                                    .unwrap_or_default();
+                                format!(
+                                    "Unqualified names resolve in {}{}",
+                                    catalog, version.default_namespace
+                                )
+                            }
+                        }
+                        if version.representations.is_empty() {
+                            p {
+                                class: "text-sm text-gray-500 italic",
+                                "This version has no SQL representation"
+                            }
+                        }
+                        for representation in version.representations.clone() {
+                            div {
+                                class: "mb-4",
+                                div {
+                                    class: "flex items-center justify-between mb-1",
+                                    span {
+                                        class: "inline-flex px-2 py-1 text-xs font-semibold rounded-full bg-blue-100 text-blue-800",
+                                        "{representation.dialect}"
+                                    }
+                                    button {
+                                        onclick: {
+                                            let representation = representation.clone();
+                                            move |_| {
+                                                copy_to_clipboard(&representation.sql);
+                                                notify(
+                                                    ToastKind::Success,
+                                                    tf(Msg::SqlCopied, &[&representation.dialect]),
+                                                );
+                                            }
+                                        },
+                                        class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                        title: "Copy the {representation.dialect} SQL",
+                                        "📋 Copy SQL"
+                                    }
+                                }
+                                pre {
+                                    class: "text-xs font-mono bg-gray-50 border border-gray-200 rounded-md p-3 overflow-x-auto select-all whitespace-pre-wrap",
+                                    "{representation.sql}"
+                                }
+                            }
+                        }
+                    } else {
+                        p {
+                            class: "text-sm text-gray-500 italic",
+                            "The view's metadata doesn't include version {shown_id}"
+                        }
+                    }
+                }
+            }
+
+            // Columns the shown version produces
+            div {
+                class: "bg-white shadow rounded-lg",
+                div {
+                    class: "px-4 py-5 sm:p-6",
+                    h3 {
+                        class: "text-lg leading-6 font-medium text-gray-900 mb-4",
+                        match &schema {
+                            Some(schema) => format!("Schema {}", schema.schema_id),
+                            None => "Schema".to_string(),
+                        }
+                    }
+                    if schema_fields.is_empty() {
+                        p {
+                            class: "text-sm text-gray-500 italic",
+                            "No schema recorded for this version"
+                        }
+                    } else {
+                        table {
+                            class: "min-w-full divide-y divide-gray-200",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    for heading in ["ID", "Name", "Type", "Required", "Doc"] {
+                                        th {
+                                            class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            "{heading}"
+                                        }
+                                    }
+                                }
+                            }
+                            tbody {
+                                class: "bg-white divide-y divide-gray-200",
+                                for (depth, field) in schema_fields {
+                                    tr {
+                                        td { class: "px-6 py-2 whitespace-nowrap text-sm text-gray-900", "{field.id}" }
+                                        td {
+                                            class: format!("px-6 py-2 whitespace-nowrap text-sm text-gray-900 pl-{}", 6 + depth * 4),
+                                            "{field.name}"
+                                        }
+                                        td {
+                                            class: "px-6 py-2 whitespace-nowrap text-sm text-gray-500",
+                                            span {
+                                                class: "inline-flex px-2 py-1 text-xs font-semibold rounded-full bg-blue-100 text-blue-800",
+                                                {field.field_type.to_string()}
+                                            }
+                                        }
+                                        td {
+                                            class: "px-6 py-2 whitespace-nowrap text-sm text-gray-500",
+                                            if field.required { "Yes" } else { "No" }
+                                        }
+                                        td {
+                                            class: "px-6 py-2 text-sm text-gray-500",
+                                            {field.doc.clone().unwrap_or_default()}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Every version, newest first
+            div {
+                class: "bg-white shadow rounded-lg",
+                div {
+                    class: "px-4 py-5 sm:p-6",
+                    h3 {
+                        class: "text-lg leading-6 font-medium text-gray-900 mb-4",
+                        "Version History"
+                    }
+                    table {
+                        class: "min-w-full divide-y divide-gray-200",
+                        thead {
+                            class: "bg-gray-50",
+                            tr {
+                                for heading in ["Version", "Committed", "Schema", "Dialects", "Engine", ""] {
+                                    th {
+                                        class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                        "{heading}"
+                                    }
+                                }
+                            }
+                        }
+                        tbody {
+                            class: "bg-white divide-y divide-gray-200",
+                            for version in newest_first {
+                                tr {
+                                    class: if version.version_id == shown_id { "bg-blue-50" } else { "" },
+                                    td {
+                                        class: "px-6 py-2 whitespace-nowrap text-sm text-gray-900",
+                                        "v{version.version_id}"
+                                        if version.version_id == current_version_id {
+                                            span {
+                                                class: "ml-2 inline-flex px-2 py-0.5 text-xs font-semibold rounded-full bg-green-100 text-green-800",
+                                                "current"
+                                            }
+                                        }
+                                    }
+                                    td {
+                                        class: "px-6 py-2 whitespace-nowrap text-sm text-gray-500",
+                                        {version.timestamp().format("%Y-%m-%d %H:%M:%S UTC").to_string()}
+                                    }
+                                    td { class: "px-6 py-2 whitespace-nowrap text-sm text-gray-500", "{version.schema_id}" }
+                                    td {
+                                        class: "px-6 py-2 text-sm text-gray-500",
+                                        {version.representations.iter().map(|r| r.dialect.as_str()).collect::<Vec<_>>().join(", ")}
+                                    }
+                                    td {
+                                        class: "px-6 py-2 whitespace-nowrap text-sm text-gray-500",
+                                        {view_version_engine(&version)}
+                                    }
+                                    td {
+                                        class: "px-6 py-2 whitespace-nowrap text-right text-sm",
+                                        if version.version_id != shown_id {
+                                            button {
+                                                onclick: move |_| shown_version_id.set(version.version_id),
+                                                class: "text-blue-600 hover:text-blue-800",
+                                                "Show SQL"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !properties.is_empty() {
+                div {
+                    class: "bg-white shadow rounded-lg",
+                    div {
+                        class: "px-4 py-5 sm:p-6",
+                        h3 {
+                            class: "text-lg leading-6 font-medium text-gray-900 mb-4",
+                            "View Properties"
+                        }
+                        dl {
+                            class: "grid grid-cols-1 gap-x-4 gap-y-4 sm:grid-cols-2",
+                            for (key, value) in properties {
+                                div {
+                                    dt { class: "text-sm font-medium text-gray-500 font-mono", "{key}" }
+                                    dd { class: "mt-1 text-sm text-gray-900 break-all", "{value}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Fields of a view schema with their nesting depth, struct members under their parent
+fn view_schema_rows(fields: &[NestedField], depth: usize) -> Vec<(usize, NestedField)> {
+    let mut rows = Vec::new();
+    for field in fields {
+        rows.push((depth, field.clone()));
+        if let DataType::Struct { fields: nested } = &field.field_type {
+            rows.extend(view_schema_rows(nested, depth + 1));
         }
     }
+    rows
+}
+
+/// Engine that committed a view version, from the summary keys engines write
+fn view_version_engine(version: &ViewVersion) -> String {
+    match (
+        version.summary.get("engine-name"),
+        version.summary.get("engine-version"),
+    ) {
+        (Some(name), Some(engine_version)) => format!("{} {}", name, engine_version),
+        (Some(name), None) => name.clone(),
+        _ => "—".to_string(),
+    }
 }
 
 #[component]
-pub fn SchemaFieldRow(field: NestedField, depth: usize) -> Element {
+pub fn SchemaFieldRow(
+    field: NestedField,
+    depth: usize,
+    // Approximate distinct counts by field id; the column is hidden when empty
+    column_ndv: std::collections::HashMap<i32, u64>,
+    // On-disk sizes by field id; the column is hidden when empty
+    column_sizes: std::collections::HashMap<i32, ColumnSize>,
+    // `lower … upper` hints from manifest bounds by field id; the column is hidden when empty
+    column_ranges: std::collections::HashMap<i32, String>,
+    // Classification tags from table properties by field id, shown as badges by the name
+    column_classifications: std::collections::HashMap<i32, Vec<String>>,
+    collapsed_structs: Signal<std::collections::HashSet<i32>>,
+    // Field shown highlighted, e.g. after following a partition field link
+    highlighted_field: Option<i32>,
+) -> Element {
     let indent_class = format!("pl-{}", depth * 4);
+    let is_struct = matches!(field.field_type, DataType::Struct { .. });
+    let is_collapsed = collapsed_structs.read().contains(&field.id);
+    let field_id = field.id;
+    // Pinned cells need an opaque background of their own
+    let row_bg = if highlighted_field == Some(field_id) {
+        "bg-yellow-50"
+    } else {
+        "bg-white"
+    };
 
     rsx! {
         tr {
+            id: "schema-field-{field_id}",
+            class: "{row_bg}",
             td {
-                class: "px-6 py-4 whitespace-nowrap text-sm font-medium text-gray-900",
+                class: "{PINNED_COLUMN} {row_bg} px-6 py-4 whitespace-nowrap text-sm font-medium text-gray-900",
+                style: PINNED_ID_COLUMN_STYLE,
                 "{field.id}"
             }
             td {
-                class: format!("px-6 py-4 whitespace-nowrap text-sm text-gray-900 {}", indent_class),
+                class: format!("{} {} px-6 py-4 whitespace-nowrap text-sm text-gray-900 {}", PINNED_AFTER_ID_COLUMN, row_bg, indent_class),
+                style: PINNED_AFTER_ID_COLUMN_STYLE,
+                if is_struct {
+                    button {
+                        onclick: move |_| {
+                            collapsed_structs.with_mut(|collapsed| {
+                                if !collapsed.remove(&field_id) {
+                                    collapsed.insert(field_id);
+                                }
+                            });
+                        },
+                        class: "mr-1 text-gray-400 hover:text-gray-700",
+                        title: if is_collapsed { "Show nested fields" } else { "Hide nested fields" },
+                        if is_collapsed { "▶" } else { "▼" }
+                    }
+                }
                 span {
                     class: if depth > 0 { "text-gray-600" } else { "font-medium" },
                     "{field.name}"
                 }
+                if let Some(tags) = column_classifications.get(&field_id) {
+                    for tag in tags.iter() {
+                        span {
+                            class: if is_sensitive_classification(tag) {
+                                "ml-2 inline-flex px-2 py-0.5 text-xs font-semibold uppercase rounded-full bg-red-100 text-red-800"
+                            } else {
+                                "ml-2 inline-flex px-2 py-0.5 text-xs font-semibold uppercase rounded-full bg-gray-100 text-gray-700"
+                            },
+                            title: "Classified by table property",
+                            "{tag}"
+                        }
+                    }
+                }
             }
             td {
                 class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
@@ -653,19 +2494,118 @@ pub fn SchemaFieldRow(field: NestedField, depth: usize) -> Element {
                     }
                 }
             }
+            td {
+                class: "px-6 py-4 text-sm text-gray-500",
+                if field.initial_default.is_none() && field.write_default.is_none() {
+                    "—"
+                }
+                if let Some(initial) = &field.initial_default {
+                    div {
+                        title: "Value read for rows written before this field was added",
+                        span { class: "text-xs text-gray-400", "initial: " }
+                        span { class: "font-mono text-gray-900", "{initial}" }
+                    }
+                }
+                if let Some(write) = &field.write_default {
+                    div {
+                        title: "Value written when a writer does not supply this field",
+                        span { class: "text-xs text-gray-400", "write: " }
+                        span { class: "font-mono text-gray-900", "{write}" }
+                    }
+                }
+            }
+            if !column_ndv.is_empty() {
+                td {
+                    class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900",
+                    {column_ndv.get(&field.id).map_or("—".to_string(), |ndv| ndv.to_string())}
+                }
+            }
+            if !column_ranges.is_empty() {
+                td {
+                    class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900 font-mono max-w-xs truncate",
+                    title: column_ranges.get(&field.id).cloned().unwrap_or_default(),
+                    {column_ranges.get(&field.id).cloned().unwrap_or_else(|| "—".to_string())}
+                }
+            }
+            if !column_sizes.is_empty() {
+                td {
+                    class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900 text-right",
+                    if let Some(size) = column_sizes.get(&field.id) {
+                        div { {format_bytes(size.compressed_bytes)} }
+                        div {
+                            class: "text-xs text-gray-500",
+                            {format!("{:.1}%", size.share_percent)}
+                            if let Some(ratio) = size.compression_ratio {
+                                span {
+                                    class: "ml-1",
+                                    title: "Estimated uncompressed size over compressed size",
+                                    {format!("· {:.1}×", ratio)}
+                                }
+                            }
+                        }
+                    } else {
+                        "—"
+                    }
+                }
+            }
             td {
                 class: "px-6 py-4 text-sm text-gray-500",
                 {field.doc.unwrap_or_else(|| "—".to_string())}
             }
         }
 
-        // Render nested fields for struct types
+        // Render nested fields for struct types, unless collapsed
         if let DataType::Struct { fields } = &field.field_type {
-            for nested_field in fields {
-                SchemaFieldRow { field: nested_field.clone(), depth: depth + 1 }
+            if !is_collapsed {
+                for nested_field in fields {
+                    SchemaFieldRow {
+                        field: nested_field.clone(),
+                        depth: depth + 1,
+                        column_ndv: column_ndv.clone(),
+                        column_sizes: column_sizes.clone(),
+                        column_ranges: column_ranges.clone(),
+                        column_classifications: column_classifications.clone(),
+                        collapsed_structs: collapsed_structs,
+                        highlighted_field
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `fields` largest first by on-disk size, nested struct fields too. Fields without a size keep
+/// their order after the rest.
+fn fields_by_size(
+    fields: &[NestedField],
+    sizes: &std::collections::HashMap<i32, ColumnSize>,
+) -> Vec<NestedField> {
+    let mut sorted: Vec<NestedField> = fields
+        .iter()
+        .map(|field| {
+            let mut field = field.clone();
+            if let DataType::Struct { fields } = &mut field.field_type {
+                *fields = fields_by_size(fields, sizes);
             }
+            field
+        })
+        .collect();
+    sorted.sort_by_key(|field| {
+        std::cmp::Reverse(sizes.get(&field.id).map(|size| size.compressed_bytes))
+    });
+    sorted
+}
+
+/// Ids of every struct field in the schema, including structs nested in other structs
+fn struct_field_ids(fields: &[NestedField]) -> std::collections::HashSet<i32> {
+    let mut ids = std::collections::HashSet::new();
+    for field in fields {
+        if let DataType::Struct { fields: nested } = &field.field_type {
+            ids.insert(field.id);
+            ids.extend(struct_field_ids(nested));
         }
     }
+    ids
 }
 
 fn is_filtered(filters: &SnapshotFilters) -> bool {
@@ -675,7 +2615,8 @@ fn is_filtered(filters: &SnapshotFilters) -> bool {
     filters.records_added_min.is_some() ||
     filters.records_added_max.is_some() ||
     filters.date_start.is_some() ||
-    filters.date_end.is_some()
+    filters.date_end.is_some() ||
+    !filters.search.trim().is_empty()
 }
 
 fn get_active_filter_count(filters: &SnapshotFilters) -> usize {
@@ -692,15 +2633,96 @@ fn get_active_filter_count(filters: &SnapshotFilters) -> usize {
     if filters.date_start.is_some() || filters.date_end.is_some() {
         count += 1;
     }
+    if !filters.search.trim().is_empty() {
+        count += 1;
+    }
     count
 }
 
+/// What the snapshot search box is looking for
+#[derive(Debug, Clone, PartialEq)]
+enum SnapshotSearch {
+    /// Digits match snapshot ids by prefix
+    IdPrefix(String),
+    /// A date or date-time (UTC) finds the snapshot active at that moment
+    ActiveAt(i64),
+}
+
+/// Parse the snapshot search box; `None` when the text is neither an id nor a date
+fn parse_snapshot_search(query: &str) -> Option<SnapshotSearch> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+    if query.chars().all(|c| c.is_ascii_digit()) {
+        return Some(SnapshotSearch::IdPrefix(query.to_string()));
+    }
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(query) {
+        return Some(SnapshotSearch::ActiveAt(datetime.timestamp_millis()));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(datetime) = chrono::NaiveDateTime::parse_from_str(query, format) {
+            return Some(SnapshotSearch::ActiveAt(
+                datetime.and_utc().timestamp_millis(),
+            ));
+        }
+    }
+    // A bare date means the end of that day
+    chrono::NaiveDate::parse_from_str(query, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .map(|datetime| SnapshotSearch::ActiveAt(datetime.and_utc().timestamp_millis()))
+}
+
 fn apply_snapshot_filters(snapshots: &[Snapshot], filters: &SnapshotFilters) -> Vec<Snapshot> {
+    // A time search pins the one snapshot active then, found before the other filters apply
+    let search = parse_snapshot_search(&filters.search);
+    let active_snapshot_id = match &search {
+        Some(SnapshotSearch::ActiveAt(timestamp_ms)) => Some(
+            TableAnalytics::snapshot_active_at(snapshots, *timestamp_ms)
+                .map(|snapshot| snapshot.snapshot_id),
+        ),
+        _ => None,
+    };
+
     snapshots
         .iter()
         .filter(|snapshot| {
+            match (&search, active_snapshot_id) {
+                (Some(SnapshotSearch::IdPrefix(prefix)), _) => {
+                    if !snapshot
+                        .snapshot_id
+                        .to_string()
+                        .starts_with(prefix.as_str())
+                    {
+                        return false;
+                    }
+                }
+                (Some(SnapshotSearch::ActiveAt(_)), Some(active_id)) => {
+                    if active_id != Some(snapshot.snapshot_id) {
+                        return false;
+                    }
+                }
+                // Unparseable text matches nothing rather than silently showing everything
+                (None, _) if !filters.search.trim().is_empty() => return false,
+                _ => {}
+            }
+
+            // Snapshots without a summary have no known operation or counts. Keep them
+            // unless a filter that needs those values is active.
+            if snapshot.summary.is_none() {
+                let needs_summary = filters.operation_types.len() < 3
+                    || filters.files_added_min.is_some()
+                    || filters.files_added_max.is_some()
+                    || filters.records_added_min.is_some()
+                    || filters.records_added_max.is_some();
+                if needs_summary {
+                    return false;
+                }
+            }
+
             // Filter by operation type
-            if !filters.operation_types.is_empty() {
+            if snapshot.summary.is_some() && !filters.operation_types.is_empty() {
                 let operation = snapshot.operation();
                 if !filters.operation_types.contains(&operation) {
                     return false;
@@ -785,8 +2807,43 @@ fn apply_snapshot_filters(snapshots: &[Snapshot], filters: &SnapshotFilters) ->
         .collect()
 }
 
+/// What the snapshot tab's health metrics depend on; a change recomputes them
+#[derive(Debug, Clone, PartialEq)]
+struct HealthMetricsKey {
+    table: String,
+    current_snapshot_id: Option<u64>,
+    snapshot_count: usize,
+    enabled_categories: Vec<HealthCategory>,
+    metadata_warning_mb: u64,
+    partition_stats_read: bool,
+}
+
+fn log_health_summary(table: &str, health_metrics: &TableHealthMetrics) {
+    tracing::info!(
+        "Table health for {}: Score={:.1}, Files={} ({:.1}% small), Activity={}/hr, Storage={:.1}GB ({:+.1}GB/day), Alerts={}",
+        table,
+        health_metrics.health_score,
+        health_metrics.file_health.total_files,
+        health_metrics.file_health.small_file_ratio * 100.0,
+        health_metrics
+            .operational_health
+            .snapshot_frequency
+            .snapshots_last_hour,
+        health_metrics.storage_efficiency.total_size_gb,
+        health_metrics
+            .storage_efficiency
+            .storage_growth_rate_gb_per_day,
+        health_metrics.alerts.len()
+    );
+}
+
 #[component]
-pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
+pub fn SnapshotTimelineTab(
+    table: IcebergTable,
+    catalog_manager: Signal<CatalogManager>,
+    // Manifest reads keyed by `catalog.namespace.table@snapshot_id`, kept across tab switches
+    data_file_origins_cache: Signal<std::collections::HashMap<String, Vec<DataFileOrigin>>>,
+) -> Element {
     let mut filters = use_signal(SnapshotFilters::default);
     let mut show_filters = use_signal(|| false);
 
@@ -797,8 +2854,87 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
     // Apply filters to snapshots
     let filtered_snapshots = apply_snapshot_filters(&sorted_snapshots, &filters());
 
-    // Compute health metrics - Analytics engine is active!
-    let health_metrics = TableAnalytics::compute_health_metrics(&table);
+    // Partition health needs the manifests, so they are only read on request. The stats are
+    // kept with the snapshot they were read at and ignored once a refresh moves past it.
+    let mut partition_stats =
+        use_signal(|| Option::<(Option<u64>, Result<Vec<PartitionStats>, CatalogError>)>::None);
+    let current_partition_stats = partition_stats
+        .read()
+        .as_ref()
+        .filter(|(snapshot_id, _)| *snapshot_id == table.current_snapshot_id)
+        .map(|(_, result)| result.clone());
+    let mut analyzing_partitions = use_signal(|| false);
+
+    // Health metrics are computed once per table, snapshot, category selection and partition
+    // stats, not on every render
+    let (enabled_categories, log_health_metrics, metadata_warning_mb) = {
+        let manager = catalog_manager.read();
+        let settings = manager.settings();
+        (
+            settings.enabled_health_categories.clone(),
+            settings.log_health_metrics,
+            settings.large_metadata_warning_mb,
+        )
+    };
+    let health_cache = use_hook(|| {
+        std::rc::Rc::new(std::cell::RefCell::new(
+            Option::<(HealthMetricsKey, TableHealthMetrics)>::None,
+        ))
+    });
+    let health_key = HealthMetricsKey {
+        table: table.qualified_name(),
+        current_snapshot_id: table.current_snapshot_id,
+        snapshot_count: table.snapshots.len(),
+        enabled_categories: enabled_categories.clone(),
+        metadata_warning_mb,
+        partition_stats_read: matches!(current_partition_stats, Some(Ok(_))),
+    };
+    let cached = health_cache
+        .borrow()
+        .as_ref()
+        .filter(|(key, _)| *key == health_key)
+        .map(|(_, metrics)| metrics.clone());
+    let health_metrics = match cached {
+        Some(metrics) => metrics,
+        None => {
+            let metrics = TableAnalytics::compute_health_metrics_for(
+                &table,
+                &enabled_categories,
+                metadata_warning_mb,
+                current_partition_stats
+                    .as_ref()
+                    .and_then(|result| result.as_ref().ok())
+                    .map(Vec::as_slice),
+            );
+            if log_health_metrics {
+                log_health_summary(&health_key.table, &metrics);
+            }
+            *health_cache.borrow_mut() = Some((health_key, metrics.clone()));
+            metrics
+        }
+    };
+
+    let partition_skew = health_metrics.partition_skew.clone();
+    let analyze_partitions = {
+        let catalog_name = table.catalog_name.clone();
+        let namespace = table.namespace.clone();
+        let table_name = table.name.clone();
+        let snapshot_id = table.current_snapshot_id;
+        move |_: MouseEvent| {
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            let table_name = table_name.clone();
+            spawn(async move {
+                analyzing_partitions.set(true);
+                let result = catalog_manager
+                    .read()
+                    .partition_stats(&catalog_name, &namespace, &table_name)
+                    .await;
+                partition_stats.set(Some((snapshot_id, result)));
+                analyzing_partitions.set(false);
+            });
+        }
+    };
 
     // Health section collapsed state
     let mut health_collapsed = use_signal(|| true);
@@ -806,22 +2942,59 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
     // Loading state for snapshots
     let mut snapshots_loading = use_signal(|| false);
 
-    // Log health metrics to console for demo (in production this would be displayed in UI)
-    tracing::info!(
-        "Table Health Analytics: Score={:.1}, Files={} ({:.1}% small), Activity={}/hr, Storage={:.1}GB ({:+.1}GB/day), Alerts={}",
-        health_metrics.health_score,
-        health_metrics.file_health.total_files,
-        health_metrics.file_health.small_file_ratio * 100.0,
-        health_metrics
-            .operational_health
-            .snapshot_frequency
-            .snapshots_last_hour,
-        health_metrics.storage_efficiency.total_size_gb,
-        health_metrics
-            .storage_efficiency
-            .storage_growth_rate_gb_per_day,
-        health_metrics.alerts.len()
-    );
+    // Snapshots whose full summary is expanded
+    let mut expanded_summaries = use_signal(std::collections::HashSet::<u64>::new);
+
+    // Show running table totals instead of per-snapshot deltas. Totals always come from the
+    // full history, so filtering the list doesn't change them.
+    let mut show_cumulative = use_signal(|| false);
+    let cumulative_totals = TableAnalytics::cumulative_totals(&table.snapshots);
+
+    // Color-code snapshots by the engine that wrote them, with a legend of writers
+    let mut color_by_writer = use_signal(|| false);
+    let writer_groups = TableAnalytics::writer_groups(&table.snapshots);
+    let writer_color = {
+        let writers: Vec<Option<String>> = writer_groups.iter().map(|g| g.writer.clone()).collect();
+        move |writer: &Option<String>| match writers.iter().position(|w| w == writer) {
+            Some(index) if writer.is_some() => WRITER_COLORS[index % WRITER_COLORS.len()],
+            _ => "bg-gray-100 text-gray-700",
+        }
+    };
+
+    // Snapshots whose total-records doesn't add up, flagged inline on their timeline entry
+    let record_count_discrepancies: std::collections::HashMap<u64, RecordCountDiscrepancy> =
+        TableAnalytics::record_count_discrepancies(&table.snapshots)
+            .into_iter()
+            .map(|discrepancy| (discrepancy.snapshot_id, discrepancy))
+            .collect();
+
+    let export_timeline_image = {
+        let snapshots = filtered_snapshots.clone();
+        let title = format!("{}.{} snapshot history", table.namespace, table.name);
+        let file_name = format!("{}.{}-timeline.svg", table.namespace, table.name);
+        move |_| {
+            let svg = snapshot_timeline_svg(&title, &snapshots);
+            match save_to_downloads(&file_name, &svg) {
+                Ok(path) => notify(ToastKind::Success, tf(Msg::SavedFile, &[&path.display()])),
+                Err(e) => notify(
+                    ToastKind::Error,
+                    format!("{}: {}", t(Msg::FailedToExportTimelineImage), e),
+                ),
+            }
+        }
+    };
+
+    // Append window selection
+    let mut append_from = use_signal(|| None::<u64>);
+    let mut append_to = use_signal(|| None::<u64>);
+    let append_window = match (append_from(), append_to()) {
+        (Some(from), Some(to)) => Some(TableAnalytics::append_window_summary(
+            &table.snapshots,
+            from,
+            to,
+        )),
+        _ => None,
+    };
 
     rsx! {
         div {
@@ -883,9 +3056,24 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                         div {
                             class: "text-sm text-blue-800",
                             "Health score starts at 100 and deducts points for issues: High small file ratio (-30), "
-                            "Excessive snapshots (-20), Missing compaction (-25), High storage growth (-15). "
+                            "Excessive snapshots (-20), Missing compaction (-25), High storage growth (-15), "
+                            "Skewed partitions (-15, once the manifests are analyzed). "
                             "Based on Netflix, Salesforce, and AWS production best practices."
                         }
+                        if health_metrics.enabled_categories.len() < HealthCategory::ALL.len() {
+                            div {
+                                class: "mt-2 text-xs text-blue-700",
+                                {format!(
+                                    "Disabled in Settings: {}. Deductions from the remaining categories are scaled up so the score still spans 0-100.",
+                                    HealthCategory::ALL
+                                        .iter()
+                                        .filter(|category| !health_metrics.enabled_categories.contains(category))
+                                        .map(|category| category.label())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                )}
+                            }
+                        }
                     }
 
                     // Health Categories Grid
@@ -893,77 +3081,137 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                         class: "grid grid-cols-1 lg:grid-cols-2 gap-6 mb-6",
 
                         // File Health Category
-                        HealthCategoryCard {
-                            title: "📁 File Health".to_string(),
-                            score_impact: if health_metrics.file_health.small_file_ratio > 0.5 { -30.0 }
-                                         else if health_metrics.file_health.small_file_ratio > 0.3 { -15.0 }
-                                         else { 0.0 },
-                            status: if health_metrics.file_health.small_file_ratio > 0.5 { "Critical".to_string() }
-                                   else if health_metrics.file_health.small_file_ratio > 0.3 { "Warning".to_string() }
-                                   else { "Good".to_string() },
-                            metrics: vec![
-                                format!("Total Files: {}", health_metrics.file_health.total_files),
-                                format!("Small Files: {} ({:.1}%)",
-                                    health_metrics.file_health.small_files_count,
-                                    health_metrics.file_health.small_file_ratio * 100.0),
-                                format!("Average Size: {:.1} MB", health_metrics.file_health.avg_file_size_mb),
-                            ],
-                            explanation: "Small files (<64MB) hurt query performance. Keep small file ratio under 30%".to_string()
+                        if health_metrics.enabled_categories.contains(&HealthCategory::File) {
+                            HealthCategoryCard {
+                                title: "📁 File Health".to_string(),
+                                score_impact: if health_metrics.file_health.small_file_ratio > 0.5 { -30.0 }
+                                             else if health_metrics.file_health.small_file_ratio > 0.3 { -15.0 }
+                                             else { 0.0 },
+                                status: if health_metrics.file_health.small_file_ratio > 0.5 { "Critical".to_string() }
+                                       else if health_metrics.file_health.small_file_ratio > 0.3 { "Warning".to_string() }
+                                       else { "Good".to_string() },
+                                metrics: vec![
+                                    format!("Total Files: {}", health_metrics.file_health.total_files),
+                                    format!("Small Files: {} ({:.1}%)",
+                                        health_metrics.file_health.small_files_count,
+                                        health_metrics.file_health.small_file_ratio * 100.0),
+                                    format!("Average Size: {:.1} MB", health_metrics.file_health.avg_file_size_mb),
+                                ],
+                                explanation: "Small files (<64MB) hurt query performance. Keep small file ratio under 30%".to_string()
+                            }
                         }
 
                         // Operational Health Category
-                        HealthCategoryCard {
-                            title: "⚡ Operational Health".to_string(),
-                            score_impact: if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 20 { -20.0 }
-                                         else if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 10 { -10.0 }
-                                         else { 0.0 },
-                            status: if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 20 { "Critical".to_string() }
-                                   else if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 10 { "Warning".to_string() }
-                                   else { "Good".to_string() },
-                            metrics: vec![
-                                format!("Snapshots/hour: {}", health_metrics.operational_health.snapshot_frequency.snapshots_last_hour),
-                                format!("Snapshots/day: {}", health_metrics.operational_health.snapshot_frequency.snapshots_last_day),
-                                if let Some(hours) = health_metrics.operational_health.time_since_last_compaction_hours {
-                                    if hours < 24.0 { format!("Last Compaction: {:.1}h ago", hours) }
-                                    else { format!("Last Compaction: {:.1}d ago", hours / 24.0) }
-                                } else { "Last Compaction: Unknown".to_string() }
-                            ],
-                            explanation: "High snapshot frequency (>10/hr) indicates inefficient write patterns".to_string()
+                        if health_metrics.enabled_categories.contains(&HealthCategory::Operational) {
+                            HealthCategoryCard {
+                                title: "⚡ Operational Health".to_string(),
+                                score_impact: if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 20 { -20.0 }
+                                             else if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 10 { -10.0 }
+                                             else { 0.0 },
+                                status: if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 20 { "Critical".to_string() }
+                                       else if health_metrics.operational_health.snapshot_frequency.snapshots_last_hour > 10 { "Warning".to_string() }
+                                       else { "Good".to_string() },
+                                metrics: vec![
+                                    format!("Snapshots/hour: {}", health_metrics.operational_health.snapshot_frequency.snapshots_last_hour),
+                                    format!("Snapshots/day: {}", health_metrics.operational_health.snapshot_frequency.snapshots_last_day),
+                                    if let Some(hours) = health_metrics.operational_health.time_since_last_compaction_hours {
+                                        if hours < 24.0 { format!("Last Compaction: {:.1}h ago", hours) }
+                                        else { format!("Last Compaction: {:.1}d ago", hours / 24.0) }
+                                    } else { "Last Compaction: Unknown".to_string() }
+                                ],
+                                explanation: "High snapshot frequency (>10/hr) indicates inefficient write patterns".to_string()
+                            }
                         }
 
                         // Storage Efficiency Category
-                        HealthCategoryCard {
-                            title: "💾 Storage Efficiency".to_string(),
-                            score_impact: if health_metrics.storage_efficiency.storage_growth_rate_gb_per_day > 500.0 { -15.0 }
-                                         else if health_metrics.storage_efficiency.storage_growth_rate_gb_per_day > 100.0 { -8.0 }
-                                         else { 0.0 },
-                            status: if health_metrics.storage_efficiency.storage_growth_rate_gb_per_day > 500.0 { "Warning".to_string() }
-                                   else { "Good".to_string() },
-                            metrics: vec![
-                                format!("Total Size: {:.1} GB", health_metrics.storage_efficiency.total_size_gb),
-                                format!("Growth Rate: {:+.1} GB/day", health_metrics.storage_efficiency.storage_growth_rate_gb_per_day),
-                                format!("Data Freshness: {:.1}h", health_metrics.storage_efficiency.data_freshness_hours),
-                            ],
-                            explanation: "Monitor storage growth and data freshness for cost optimization".to_string()
+                        if health_metrics.enabled_categories.contains(&HealthCategory::Storage) {
+                            HealthCategoryCard {
+                                title: "💾 Storage Efficiency".to_string(),
+                                score_impact: if health_metrics.storage_efficiency.storage_growth_rate_gb_per_day > 500.0 { -15.0 }
+                                             else if health_metrics.storage_efficiency.storage_growth_rate_gb_per_day > 100.0 { -8.0 }
+                                             else { 0.0 },
+                                status: if health_metrics.storage_efficiency.storage_growth_rate_gb_per_day > 500.0 { "Warning".to_string() }
+                                       else { "Good".to_string() },
+                                metrics: vec![
+                                    format!("Total Size: {:.1} GB", health_metrics.storage_efficiency.total_size_gb),
+                                    format!("Growth Rate: {:+.1} GB/day", health_metrics.storage_efficiency.storage_growth_rate_gb_per_day),
+                                    format!("Data Freshness: {:.1}h", health_metrics.storage_efficiency.data_freshness_hours),
+                                ],
+                                explanation: "Monitor storage growth and data freshness for cost optimization".to_string()
+                            }
                         }
 
                         // Compaction Health Category
-                        HealthCategoryCard {
-                            title: "🔧 Compaction Health".to_string(),
-                            score_impact: if let Some(days) = health_metrics.operational_health.compaction_frequency.days_since_last {
-                                             if days > 14.0 { -25.0 } else if days > 7.0 { -12.0 } else { 0.0 }
-                                         } else { -10.0 },
-                            status: if let Some(days) = health_metrics.operational_health.compaction_frequency.days_since_last {
-                                       if days > 14.0 { "Critical".to_string() } else if days > 7.0 { "Warning".to_string() } else { "Good".to_string() }
-                                   } else { "Warning".to_string() },
-                            metrics: vec![
-                                if let Some(days) = health_metrics.operational_health.compaction_frequency.days_since_last {
-                                    format!("Days Since Last: {:.1}", days)
-                                } else { "Days Since Last: Unknown".to_string() },
-                                format!("Compactions/week: {}", health_metrics.operational_health.compaction_frequency.compactions_last_week),
-                                format!("Avg Frequency: {:.1} days", health_metrics.operational_health.compaction_frequency.avg_compaction_frequency_days),
-                            ],
-                            explanation: "Regular compaction (weekly) maintains query performance and reduces metadata overhead".to_string()
+                        if health_metrics.enabled_categories.contains(&HealthCategory::Compaction) {
+                            HealthCategoryCard {
+                                title: "🔧 Compaction Health".to_string(),
+                                score_impact: if let Some(days) = health_metrics.operational_health.compaction_frequency.days_since_last {
+                                                 if days > 14.0 { -25.0 } else if days > 7.0 { -12.0 } else { 0.0 }
+                                             } else { -10.0 },
+                                status: if let Some(days) = health_metrics.operational_health.compaction_frequency.days_since_last {
+                                           if days > 14.0 { "Critical".to_string() } else if days > 7.0 { "Warning".to_string() } else { "Good".to_string() }
+                                       } else { "Warning".to_string() },
+                                metrics: vec![
+                                    if let Some(days) = health_metrics.operational_health.compaction_frequency.days_since_last {
+                                        format!("Days Since Last: {:.1}", days)
+                                    } else { "Days Since Last: Unknown".to_string() },
+                                    format!("Compactions/week: {}", health_metrics.operational_health.compaction_frequency.compactions_last_week),
+                                    format!("Avg Frequency: {:.1} days", health_metrics.operational_health.compaction_frequency.avg_compaction_frequency_days),
+                                ],
+                                explanation: "Regular compaction (weekly) maintains query performance and reduces metadata overhead".to_string()
+                            }
+                        }
+
+                        // Partition Health Category (reads manifests on demand)
+                        if health_metrics.enabled_categories.contains(&HealthCategory::Partition) {
+                            if let Some(skew) = partition_skew.as_ref() {
+                                HealthCategoryCard {
+                                    title: "🧩 Partition Health".to_string(),
+                                    score_impact: -TableAnalytics::partition_skew_penalty(skew),
+                                    status: if skew.skew_ratio > HealthThresholds::PARTITION_SKEW_CRITICAL { "Critical".to_string() }
+                                           else if skew.skew_ratio > HealthThresholds::PARTITION_SKEW_WARNING { "Warning".to_string() }
+                                           else { "Good".to_string() },
+                                    metrics: vec![
+                                        format!("Partitions: {}", skew.partition_count),
+                                        format!("Skew (max/median): {:.1}x", skew.skew_ratio),
+                                        format!("Gini coefficient: {:.2}", skew.gini_coefficient),
+                                        format!("Largest: {} | Median: {}", format_bytes(skew.max_size_bytes), format_bytes(skew.median_size_bytes)),
+                                    ],
+                                    explanation: format!(
+                                        "Partitions over {:.0}x the median size create hot spots for query engines. Consider a finer or different partition transform",
+                                        HealthThresholds::PARTITION_SKEW_WARNING
+                                    )
+                                }
+                            } else {
+                                div {
+                                    class: "border border-gray-200 rounded-lg p-4",
+                                    h5 { class: "font-medium text-gray-900 mb-3", "🧩 Partition Health" }
+                                    {match current_partition_stats.clone() {
+                                        None => rsx! {
+                                            p { class: "text-sm text-gray-600 mb-3", "Reads the current snapshot's manifests to measure how evenly data is spread across partitions." }
+                                            button {
+                                                onclick: analyze_partitions,
+                                                disabled: analyzing_partitions(),
+                                                class: "px-3 py-1 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:bg-gray-300",
+                                                if analyzing_partitions() { "Reading manifests..." } else { "Analyze partitions" }
+                                            }
+                                        },
+                                        Some(Err(error)) => rsx! {
+                                            if analyzing_partitions() {
+                                                p { class: "text-sm text-gray-600", "Reading manifests..." }
+                                            } else {
+                                                ManifestReadError { error, on_retry: analyze_partitions }
+                                            }
+                                        },
+                                        Some(Ok(stats)) if stats.is_empty() => rsx! {
+                                            p { class: "text-sm text-gray-600", "The current snapshot has no live data files yet, so there are no partitions to compare." }
+                                        },
+                                        Some(Ok(_)) => rsx! {
+                                            p { class: "text-sm text-gray-600", "Fewer than two partitions with live data; skew does not apply." }
+                                        },
+                                    }}
+                                }
+                            }
                         }
                     }
 
@@ -994,7 +3242,7 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                                         },
                                         "{alert.message}"
                                     }
-                                    if alert.metric_value != 0.0 {
+                                    if alert.metric_value != 0.0 && alert.category != AlertCategory::IncompleteMetadata {
                                         div {
                                             class: match alert.severity {
                                                 crate::data::AlertSeverity::Critical | crate::data::AlertSeverity::Emergency =>
@@ -1007,6 +3255,77 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                                             "Current: {alert.metric_value:.1} | Threshold: {alert.threshold:.1}"
                                         }
                                     }
+                                    if alert.category == AlertCategory::PartitionSkew {
+                                        if let Some(skew) = partition_skew.as_ref() {
+                                            div {
+                                                class: "mt-2 text-xs text-gray-700",
+                                                div { class: "font-medium mb-1", "Heaviest partitions:" }
+                                                for heavy in skew.heaviest_partitions.iter() {
+                                                    div {
+                                                        class: "flex justify-between font-mono",
+                                                        span { class: "truncate mr-4 select-all", "{heavy.partition}" }
+                                                        span { class: "flex-shrink-0", {format!("{} · {} files", format_bytes(heavy.total_size_bytes), heavy.file_count)} }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Orphaned snapshots
+                    if !health_metrics.orphaned_snapshots.is_empty() {
+                        div {
+                            class: "border-t border-gray-200 pt-6 mt-6",
+                            h4 { class: "text-lg font-medium text-gray-900 mb-1", "🧟 Potentially orphaned snapshots" }
+                            p {
+                                class: "text-sm text-gray-500 mb-4",
+                                {format!(
+                                    "{} snapshot(s) can't be reached from any branch or tag. They keep their data files alive until snapshots are expired.",
+                                    health_metrics.orphaned_snapshots.len()
+                                )}
+                            }
+                            div {
+                                class: "overflow-x-auto max-h-64 overflow-y-auto border border-gray-200 rounded-md",
+                                table {
+                                    class: "min-w-full divide-y divide-gray-200 text-sm",
+                                    thead {
+                                        class: "bg-gray-50",
+                                        tr {
+                                            th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase", "Snapshot" }
+                                            th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase", "Operation" }
+                                            th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase", "Age" }
+                                            th { class: "px-4 py-2 text-right text-xs font-medium text-gray-500 uppercase", "Added size" }
+                                        }
+                                    }
+                                    tbody {
+                                        class: "bg-white divide-y divide-gray-200",
+                                        for orphan in health_metrics.orphaned_snapshots.iter() {
+                                            tr {
+                                                td { class: "px-4 py-2 font-mono text-gray-900 select-all", "{orphan.snapshot_id}" }
+                                                td { class: "px-4 py-2 text-gray-700", "{orphan.operation}" }
+                                                td {
+                                                    class: "px-4 py-2 text-gray-700",
+                                                    {
+                                                        let age_hours = chrono::Utc::now()
+                                                            .signed_duration_since(
+                                                                chrono::DateTime::from_timestamp_millis(orphan.timestamp_ms).unwrap_or_else(chrono::Utc::now),
+                                                            )
+                                                            .num_minutes()
+                                                            .max(0) as f64
+                                                            / 60.0;
+                                                        format_age_hours(age_hours)
+                                                    }
+                                                }
+                                                td {
+                                                    class: "px-4 py-2 text-right text-gray-700",
+                                                    {orphan.added_size_bytes.map(format_bytes).unwrap_or_else(|| "—".to_string())}
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -1056,6 +3375,10 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                                         },
                                         "Benefit: {recommendation.estimated_benefit} | Effort: {recommendation.effort_level:?}"
                                     }
+                                    MaintenanceCommand {
+                                        action_type: recommendation.action_type.clone(),
+                                        table: table.clone(),
+                                    }
                                 }
                             }
                         }
@@ -1069,7 +3392,7 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                 class: "flex items-center justify-between",
                 h3 {
                     class: "text-lg leading-6 font-medium text-gray-900",
-                    "Snapshot History"
+                    {t(Msg::SnapshotHistory)}
                 }
                 button {
                     onclick: move |_| show_filters.set(!show_filters()),
@@ -1309,19 +3632,169 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                 }
             }
 
+            LastMetadataChangeCard { table: table.clone(), catalog_manager: catalog_manager }
+
+            DataFileBlameCard {
+                table: table.clone(),
+                catalog_manager: catalog_manager,
+                cache: data_file_origins_cache
+            }
+
+            // Appends Between Snapshots
+            if sorted_snapshots.len() > 1 {
+                div {
+                    class: "bg-white shadow rounded-lg",
+                    div {
+                        class: "px-4 py-5 sm:p-6",
+                        h3 {
+                            class: "text-lg leading-6 font-medium text-gray-900 mb-2",
+                            "Appends Between Snapshots"
+                        }
+                        p {
+                            class: "text-sm text-gray-500 mb-4",
+                            "Pick two snapshots to total the snapshots committed between them on the later one's lineage; the earlier one must be its ancestor. Totals come from snapshot summaries; no data is scanned."
+                        }
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-2 gap-4 mb-4",
+                            div {
+                                label { class: "block text-sm font-medium text-gray-700 mb-1", "From snapshot (exclusive)" }
+                                select {
+                                    class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                                    onchange: move |evt| append_from.set(evt.value().parse::<u64>().ok()),
+                                    option { value: "", "Select snapshot..." }
+                                    for snapshot in sorted_snapshots.iter() {
+                                        option {
+                                            value: "{snapshot.snapshot_id}",
+                                            selected: append_from() == Some(snapshot.snapshot_id),
+                                            {format!("{} ({}, {})", snapshot.snapshot_id, snapshot.operation(), snapshot.timestamp().format("%Y-%m-%d %H:%M"))}
+                                        }
+                                    }
+                                }
+                            }
+                            div {
+                                label { class: "block text-sm font-medium text-gray-700 mb-1", "To snapshot (inclusive)" }
+                                select {
+                                    class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                                    onchange: move |evt| append_to.set(evt.value().parse::<u64>().ok()),
+                                    option { value: "", "Select snapshot..." }
+                                    for snapshot in sorted_snapshots.iter() {
+                                        option {
+                                            value: "{snapshot.snapshot_id}",
+                                            selected: append_to() == Some(snapshot.snapshot_id),
+                                            {format!("{} ({}, {})", snapshot.snapshot_id, snapshot.operation(), snapshot.timestamp().format("%Y-%m-%d %H:%M"))}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        {match append_window {
+                            Some(Ok(range)) => rsx! {
+                                div {
+                                    class: "p-3 bg-green-50 border border-green-200 rounded-md text-sm text-green-800",
+                                    {
+                                        let records: u64 = range.iter()
+                                            .filter_map(|s| s.summary.as_ref()?.added_records.as_ref()?.parse::<u64>().ok())
+                                            .sum();
+                                        let files: u64 = range.iter()
+                                            .filter_map(|s| s.summary.as_ref()?.added_data_files.as_ref()?.parse::<u64>().ok())
+                                            .sum();
+                                        format!("{} append snapshot(s) added {} records in {} data files", range.len(), records, files)
+                                    }
+                                }
+                            },
+                            Some(Err(reason)) => rsx! {
+                                div {
+                                    class: "p-3 bg-yellow-50 border border-yellow-200 rounded-md text-sm text-yellow-800",
+                                    "Can't total this range: {reason}"
+                                }
+                            },
+                            None => rsx! {},
+                        }}
+                    }
+                }
+            }
+
             // Detailed Timeline
             div {
                 class: "bg-white shadow rounded-lg",
                 div {
                     class: "px-4 py-5 sm:p-6",
-                    h3 {
-                        class: "text-lg leading-6 font-medium text-gray-900 mb-2",
-                        "Snapshot Timeline"
+                    div {
+                        class: "flex items-center justify-between mb-2",
+                        h3 {
+                            class: "text-lg leading-6 font-medium text-gray-900",
+                            {t(Msg::SnapshotTimeline)}
+                        }
+                        div {
+                            class: "flex items-center space-x-3",
+                            button {
+                                onclick: export_timeline_image,
+                                class: "px-2 py-1 text-xs text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                title: "Save the snapshots shown below as an SVG timeline, one lane per operation",
+                                "🖼 Export image"
+                            }
+                            button {
+                                onclick: move |_| color_by_writer.toggle(),
+                                class: if color_by_writer() { "px-2 py-1 text-xs text-white border border-blue-600 rounded-md bg-blue-600" } else { "px-2 py-1 text-xs text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50" },
+                                title: "Color-code snapshots by the engine that wrote them (engine-name, spark.app.id, ...)",
+                                "By writer"
+                            }
+                            div {
+                                class: "inline-flex rounded-md border border-gray-300 overflow-hidden text-xs",
+                                title: "Cumulative totals are computed oldest first; overwrites and deletes reduce them",
+                                button {
+                                    onclick: move |_| show_cumulative.set(false),
+                                    class: if show_cumulative() { "px-3 py-1 bg-white text-gray-600 hover:bg-gray-50" } else { "px-3 py-1 bg-blue-600 text-white" },
+                                    "Per snapshot"
+                                }
+                                button {
+                                    onclick: move |_| show_cumulative.set(true),
+                                    class: if show_cumulative() { "px-3 py-1 bg-blue-600 text-white" } else { "px-3 py-1 bg-white text-gray-600 hover:bg-gray-50" },
+                                    "Cumulative"
+                                }
+                            }
+                        }
                     }
                     p {
                         class: "text-sm text-gray-500 mb-6",
                         "Detailed history showing all table snapshots from most recent to oldest"
                     }
+                    if color_by_writer() {
+                        div {
+                            class: "mb-6 flex flex-wrap gap-2",
+                            for group in writer_groups.iter() {
+                                span {
+                                    key: "{group.writer.clone().unwrap_or_default()}",
+                                    class: format!("inline-flex items-center px-2 py-1 text-xs rounded-full {}", writer_color(&group.writer)),
+                                    title: format!(
+                                        "{} records added; last write {}",
+                                        group.records_added,
+                                        chrono::DateTime::from_timestamp_millis(group.last_timestamp_ms)
+                                            .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+                                            .unwrap_or_else(|| "unknown".to_string())
+                                    ),
+                                    span { class: "font-medium", {group.writer.clone().unwrap_or_else(|| "unknown".to_string())} }
+                                    span { class: "ml-1", "· {group.snapshot_count} snapshots" }
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        class: "mb-6",
+                        input {
+                            r#type: "text",
+                            class: "block w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-blue-500 focus:border-blue-500",
+                            placeholder: "Snapshot id or date (2024-05-01 12:00)",
+                            value: "{filters().search}",
+                            oninput: move |evt| filters.with_mut(|f| f.search = evt.value()),
+                        }
+                        if !filters().search.trim().is_empty() && parse_snapshot_search(&filters().search).is_none() {
+                            p {
+                                class: "mt-1 text-xs text-red-600",
+                                "Enter a snapshot id or a date like 2024-05-01 12:00 (UTC)"
+                            }
+                        }
+                    }
                     if snapshots_loading() {
                         // Loading state
                         div {
@@ -1331,7 +3804,7 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                             }
                             span {
                                 class: "ml-3 text-sm text-gray-600",
-                                "Loading snapshots..."
+                                {t(Msg::LoadingSnapshots)}
                             }
                         }
                     } else if filtered_snapshots.is_empty() {
@@ -1352,14 +3825,16 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                             }
                             h3 {
                                 class: "text-lg font-medium text-gray-900 mb-2",
-                                "No snapshots found"
+                                {t(Msg::NoSnapshotsFound)}
                             }
                             p {
                                 class: "text-sm text-gray-500",
-                                if is_filtered(&filters()) {
+                                if !filters().search.trim().is_empty() {
+                                    {format!("No snapshot matches '{}'.", filters().search.trim())}
+                                } else if is_filtered(&filters()) {
                                     "No snapshots match your current filter criteria. Try adjusting your filters or use the \"Clear all\" button to see all snapshots."
                                 } else {
-                                    "This table has no snapshots to display."
+                                    {t(Msg::NoSnapshotsInTable)}
                                 }
                             }
                         }
@@ -1404,6 +3879,17 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                                                         ),
                                                         "{snapshot.operation()}"
                                                     }
+                                                    if color_by_writer() {
+                                                        {
+                                                            let writer = snapshot.writer();
+                                                            rsx! {
+                                                                span {
+                                                                    class: format!("inline-flex px-2 py-1 text-xs rounded-full {}", writer_color(&writer)),
+                                                                    {writer.unwrap_or_else(|| "unknown".to_string())}
+                                                                }
+                                                            }
+                                                        }
+                                                    }
                                                     if table.current_snapshot_id == Some(snapshot.snapshot_id) {
                                                         span {
                                                             class: "inline-flex px-2 py-1 text-xs font-semibold rounded-full bg-blue-100 text-blue-800",
@@ -1416,28 +3902,65 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                                                     {snapshot.timestamp().format("%Y-%m-%d %H:%M:%S UTC").to_string()}
                                                 }
                                             }
+                                            if let Some(discrepancy) = record_count_discrepancies.get(&snapshot.snapshot_id) {
+                                                div {
+                                                    class: "mt-2 rounded border border-yellow-200 bg-yellow-50 px-2 py-1 text-xs text-yellow-800",
+                                                    title: "total-records should equal the previous snapshot's total plus added-records minus deleted-records",
+                                                    "⚠️ Record count doesn't reconcile: summary reports {discrepancy.reported_records} rows, expected {discrepancy.expected_records} from snapshot {discrepancy.previous_snapshot_id} ({discrepancy.difference():+})"
+                                                }
+                                            }
                                             div {
                                                 class: "mt-2 grid grid-cols-1 gap-x-4 gap-y-2 sm:grid-cols-4",
-                                                div {
-                                                    class: "text-sm",
-                                                    span {
-                                                        class: "font-medium text-gray-500",
-                                                        "Records Added: "
-                                                    }
-                                                    span {
-                                                        class: "text-gray-900",
-                                                        "{snapshot.records_added()}"
+                                                if show_cumulative() {
+                                                    {
+                                                        let totals = cumulative_totals.get(&snapshot.snapshot_id).copied().unwrap_or_default();
+                                                        rsx! {
+                                                            div {
+                                                                class: "text-sm",
+                                                                span {
+                                                                    class: "font-medium text-gray-500",
+                                                                    "Records (cumulative): "
+                                                                }
+                                                                span {
+                                                                    class: "text-gray-900",
+                                                                    {totals.records.map_or("N/A".to_string(), |records| records.to_string())}
+                                                                }
+                                                            }
+                                                            div {
+                                                                class: "text-sm",
+                                                                span {
+                                                                    class: "font-medium text-gray-500",
+                                                                    "Size (cumulative): "
+                                                                }
+                                                                span {
+                                                                    class: "text-gray-900",
+                                                                    {totals.size_bytes.map_or("N/A".to_string(), format_bytes)}
+                                                                }
+                                                            }
+                                                        }
                                                     }
-                                                }
-                                                div {
-                                                    class: "text-sm",
-                                                    span {
-                                                        class: "font-medium text-gray-500",
-                                                        "Size Change: "
+                                                } else {
+                                                    div {
+                                                        class: "text-sm",
+                                                        span {
+                                                            class: "font-medium text-gray-500",
+                                                            "Records Added: "
+                                                        }
+                                                        span {
+                                                            class: "text-gray-900",
+                                                            "{snapshot.records_added()}"
+                                                        }
                                                     }
-                                                    span {
-                                                        class: "text-gray-900",
-                                                        "{snapshot.size_change()}"
+                                                    div {
+                                                        class: "text-sm",
+                                                        span {
+                                                            class: "font-medium text-gray-500",
+                                                            "Size Change: "
+                                                        }
+                                                        span {
+                                                            class: "text-gray-900",
+                                                            "{snapshot.size_change()}"
+                                                        }
                                                     }
                                                 }
                                                 if let Some(summary) = &snapshot.summary {
@@ -1468,10 +3991,74 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
                                             if let Some(summary) = &snapshot.summary {
                                                 if !snapshot.manifest_list.is_empty() {
                                                     div {
-                                                        class: "mt-2",
-                                                        p {
-                                                            class: "text-xs text-gray-400 font-mono break-all",
-                                                            "Manifest: {snapshot.manifest_list}"
+                                                        class: "mt-2 flex items-start text-xs text-gray-400 font-mono",
+                                                        // Clicks here select/copy the path rather than selecting the snapshot
+                                                        onclick: |e| e.stop_propagation(),
+                                                        span { class: "mr-1 flex-shrink-0", "Manifest:" }
+                                                        span {
+                                                            class: "break-all select-all cursor-text text-gray-500",
+                                                            "{snapshot.manifest_list}"
+                                                        }
+                                                        button {
+                                                            onclick: {
+                                                                let manifest_list = snapshot.manifest_list.clone();
+                                                                move |_| {
+                                                                    copy_to_clipboard(&manifest_list);
+                                                                    notify(ToastKind::Success, t(Msg::ManifestListPathCopied));
+                                                                }
+                                                            },
+                                                            class: "ml-2 text-gray-400 hover:text-gray-600 flex-shrink-0",
+                                                            title: "Copy manifest list path",
+                                                            "📋"
+                                                        }
+                                                    }
+                                                }
+                                                if !summary.properties.is_empty() {
+                                                    {
+                                                        let snapshot_id = snapshot.snapshot_id;
+                                                        let expanded = expanded_summaries.read().contains(&snapshot_id);
+                                                        let summary_json = serde_json::to_string_pretty(&summary.properties).unwrap_or_default();
+                                                        rsx! {
+                                                            div {
+                                                                class: "mt-2",
+                                                                onclick: |e| e.stop_propagation(),
+                                                                div {
+                                                                    class: "flex items-center space-x-3",
+                                                                    button {
+                                                                        onclick: move |_| {
+                                                                            let mut expanded_summaries = expanded_summaries.write();
+                                                                            if !expanded_summaries.remove(&snapshot_id) {
+                                                                                expanded_summaries.insert(snapshot_id);
+                                                                            }
+                                                                        },
+                                                                        class: "text-xs text-blue-600 hover:text-blue-800",
+                                                                        if expanded { "▾ Summary" } else { "▸ Summary" }
+                                                                        " ({summary.properties.len()} keys)"
+                                                                    }
+                                                                    button {
+                                                                        onclick: move |_| {
+                                                                            copy_to_clipboard(&summary_json);
+                                                                            notify(ToastKind::Success, t(Msg::SnapshotSummaryCopied));
+                                                                        },
+                                                                        class: "text-xs text-gray-500 hover:text-gray-700",
+                                                                        title: "Copy the full snapshot summary as JSON",
+                                                                        "📋 Copy as JSON"
+                                                                    }
+                                                                }
+                                                                if expanded {
+                                                                    table {
+                                                                        class: "mt-1 text-xs",
+                                                                        tbody {
+                                                                            for (key, value) in summary.properties.iter() {
+                                                                                tr {
+                                                                                    td { class: "pr-4 py-0.5 font-mono text-gray-500 align-top whitespace-nowrap", "{key}" }
+                                                                                    td { class: "py-0.5 font-mono text-gray-900 break-all", "{value}" }
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                }
+                                                            }
                                                         }
                                                     }
                                                 }
@@ -1490,7 +4077,15 @@ pub fn SnapshotTimelineTab(table: IcebergTable) -> Element {
 }
 
 #[component]
-pub fn TablePartitionsTab(table: IcebergTable) -> Element {
+pub fn TablePartitionsTab(
+    table: IcebergTable,
+    // Called with a source field id to show that field on the Schema tab
+    on_field_selected: EventHandler<i32>,
+) -> Element {
+    let mut spec_format = use_signal(|| PartitionSpecFormat::Readable);
+    let mut spec_copied = use_signal(|| false);
+    let spec_text = spec_format().render(&table).unwrap_or_default();
+
     rsx! {
         div {
             class: "space-y-6",
@@ -1501,9 +4096,46 @@ pub fn TablePartitionsTab(table: IcebergTable) -> Element {
                     class: "bg-white shadow rounded-lg",
                     div {
                         class: "px-4 py-5 sm:p-6",
-                        h3 {
-                            class: "text-lg leading-6 font-medium text-gray-900 mb-4",
-                            "Current Partition Specification (ID: {partition_spec.spec_id})"
+                        div {
+                            class: "flex items-center justify-between mb-4",
+                            h3 {
+                                class: "text-lg leading-6 font-medium text-gray-900",
+                                "Current Partition Specification (ID: {partition_spec.spec_id})"
+                            }
+                            div {
+                                class: "flex items-center space-x-2",
+                                select {
+                                    class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                                    onchange: move |evt| {
+                                        if let Some(selected) = PartitionSpecFormat::ALL
+                                            .into_iter()
+                                            .find(|f| f.label() == evt.value())
+                                        {
+                                            spec_format.set(selected);
+                                            spec_copied.set(false);
+                                        }
+                                    },
+                                    for option_format in PartitionSpecFormat::ALL {
+                                        option {
+                                            value: option_format.label(),
+                                            selected: spec_format() == option_format,
+                                            "{option_format.label()}"
+                                        }
+                                    }
+                                }
+                                button {
+                                    onclick: {
+                                        let spec_text = spec_text.clone();
+                                        move |_| {
+                                            copy_to_clipboard(&spec_text);
+                                            spec_copied.set(true);
+                                        }
+                                    },
+                                    class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                    title: "Copy the partition spec",
+                                    if spec_copied() { "✓ Copied" } else { "📋 Copy partition spec" }
+                                }
+                            }
                         }
                         div {
                             class: "mb-4",
@@ -1559,11 +4191,15 @@ pub fn TablePartitionsTab(table: IcebergTable) -> Element {
                                 tbody {
                                     class: "bg-white divide-y divide-gray-200",
                                     for field in &partition_spec.fields {
-                                        PartitionFieldRow { field: field.clone(), table: table.clone() }
+                                        PartitionFieldRow { field: field.clone(), table: table.clone(), on_field_selected }
                                     }
                                 }
                             }
                         }
+                        pre {
+                            class: "mt-4 text-xs font-mono bg-gray-50 border border-gray-200 rounded-md p-3 overflow-x-auto select-all",
+                            "{spec_text}"
+                        }
                     }
                 }
             } else {
@@ -1582,21 +4218,132 @@ pub fn TablePartitionsTab(table: IcebergTable) -> Element {
                     }
                 }
             }
+
+            // Default sort order
+            div {
+                class: "bg-white shadow rounded-lg",
+                div {
+                    class: "px-4 py-5 sm:p-6",
+                    match table.sort_order.as_ref().filter(|order| !order.fields.is_empty()) {
+                        Some(sort_order) => rsx! {
+                            h3 {
+                                class: "text-lg leading-6 font-medium text-gray-900 mb-4",
+                                "Sort Order (ID: {sort_order.order_id})"
+                            }
+                            div {
+                                class: "overflow-x-auto",
+                                table {
+                                    class: "min-w-full divide-y divide-gray-200",
+                                    thead {
+                                        class: "bg-gray-50",
+                                        tr {
+                                            for heading in ["Source Field", "Transform", "Direction", "Nulls"] {
+                                                th {
+                                                    class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                                    "{heading}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    tbody {
+                                        class: "bg-white divide-y divide-gray-200",
+                                        for field in &sort_order.fields {
+                                            tr {
+                                                td {
+                                                    class: "px-6 py-4 whitespace-nowrap text-sm",
+                                                    SchemaFieldLink { fields: table.schema.fields.clone(), field_id: field.source_id, on_field_selected }
+                                                }
+                                                td {
+                                                    class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
+                                                    span {
+                                                        class: "inline-flex px-2 py-1 text-xs font-semibold rounded-full bg-purple-100 text-purple-800",
+                                                        {field.transform.to_string()}
+                                                    }
+                                                }
+                                                td {
+                                                    class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900",
+                                                    {match field.direction {
+                                                        SortDirection::Ascending => "↑ Ascending",
+                                                        SortDirection::Descending => "↓ Descending",
+                                                    }}
+                                                }
+                                                td {
+                                                    class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900",
+                                                    {match field.null_order {
+                                                        NullOrder::First => "First",
+                                                        NullOrder::Last => "Last",
+                                                    }}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        None => rsx! {
+                            h3 { class: "text-lg font-medium text-gray-900 mb-2", "Sort Order" }
+                            p { class: "text-sm text-gray-500", "This table has no default sort order, so writers don't sort rows within data files." }
+                        },
+                    }
+                }
+            }
         }
     }
 }
 
+/// A schema field's dotted path, linking to the field on the Schema tab. Ids missing from the
+/// current schema (e.g. a dropped source column) are shown without a link.
 #[component]
-pub fn PartitionFieldRow(field: PartitionField, table: IcebergTable) -> Element {
-    // Find the source field name from the schema
-    let source_field_name = table
-        .schema
-        .fields
-        .iter()
-        .find(|f| f.id == field.source_id)
-        .map(|f| f.name.clone())
-        .unwrap_or_else(|| format!("Field {}", field.source_id));
+fn SchemaFieldLink(
+    fields: Vec<NestedField>,
+    field_id: i32,
+    on_field_selected: EventHandler<i32>,
+) -> Element {
+    match schema_field_lineage(&fields, field_id) {
+        Some(lineage) => {
+            let path = lineage
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>()
+                .join(".");
+            rsx! {
+                button {
+                    onclick: move |_| on_field_selected.call(field_id),
+                    class: "text-blue-600 hover:text-blue-800 hover:underline",
+                    title: "Show this field on the Schema tab",
+                    "{path}"
+                }
+            }
+        }
+        None => rsx! {
+            span { class: "text-gray-500", "Field {field_id}" }
+        },
+    }
+}
+
+/// The fields from the top of the schema down to the field with `field_id`, descending into
+/// structs
+fn schema_field_lineage(fields: &[NestedField], field_id: i32) -> Option<Vec<&NestedField>> {
+    for field in fields {
+        if field.id == field_id {
+            return Some(vec![field]);
+        }
+        if let DataType::Struct { fields: nested } = &field.field_type {
+            if let Some(mut lineage) = schema_field_lineage(nested, field_id) {
+                lineage.insert(0, field);
+                return Some(lineage);
+            }
+        }
+    }
+    None
+}
 
+#[component]
+pub fn PartitionFieldRow(
+    field: PartitionField,
+    table: IcebergTable,
+    on_field_selected: EventHandler<i32>,
+) -> Element {
     rsx! {
         tr {
             td {
@@ -1604,8 +4351,8 @@ pub fn PartitionFieldRow(field: PartitionField, table: IcebergTable) -> Element
                 "{field.field_id}"
             }
             td {
-                class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900",
-                "{source_field_name}"
+                class: "px-6 py-4 whitespace-nowrap text-sm",
+                SchemaFieldLink { fields: table.schema.fields.clone(), field_id: field.source_id, on_field_selected }
             }
             td {
                 class: "px-6 py-4 whitespace-nowrap text-sm text-gray-900",
@@ -1760,6 +4507,326 @@ pub fn HealthScoreBadge(score: f64) -> Element {
     }
 }
 
+/// Short age such as "45m", "5h" or "3.2d"
+pub fn format_age_hours(hours: f64) -> String {
+    if hours < 1.0 {
+        format!("{:.0}m", hours * 60.0)
+    } else if hours < 48.0 {
+        format!("{:.0}h", hours)
+    } else {
+        format!("{:.1}d", hours / 24.0)
+    }
+}
+
+/// Background colour class for a freshness dot or pill
+pub fn freshness_color_class(freshness: Freshness) -> &'static str {
+    match freshness {
+        Freshness::Fresh => "bg-green-500",
+        Freshness::Aging => "bg-yellow-500",
+        Freshness::Stale => "bg-red-500",
+        Freshness::Unknown => "bg-gray-400",
+    }
+}
+
+/// Warning for tables with pathologically large metadata: more snapshots than the display
+/// limit, or a metadata document over the size threshold. Offers loading every snapshot
+/// when some were left out, and the snapshot expiration that fixes the cause.
+#[component]
+pub fn LargeTableBanner(
+    table: IcebergTable,
+    snapshot_limit: usize,
+    metadata_warning_bytes: u64,
+    on_load_all: EventHandler<MouseEvent>,
+) -> Element {
+    let total = table.total_snapshot_count();
+    let large_metadata = table
+        .metadata_size_bytes
+        .filter(|size| *size > metadata_warning_bytes);
+    if total <= snapshot_limit && large_metadata.is_none() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "mx-6 mt-4 p-4 bg-yellow-50 border border-yellow-200 rounded-lg text-sm",
+            div {
+                class: "flex items-start justify-between",
+                div {
+                    p {
+                        class: "font-medium text-yellow-900",
+                        if table.omitted_snapshots > 0 {
+                            {format!(
+                                "⚠️ Showing the newest {} of {} snapshots to keep Hielo responsive",
+                                table.snapshots.len(),
+                                total
+                            )}
+                        } else if total > snapshot_limit {
+                            {format!("⚠️ This table keeps {} snapshots", total)}
+                        } else {
+                            "⚠️ This table's metadata is unusually large"
+                        }
+                    }
+                    p {
+                        class: "mt-1 text-yellow-800",
+                        if let Some(size) = large_metadata {
+                            {format!("Its metadata file is about {}, which slows every load. ", format_bytes(size))}
+                        }
+                        "Expiring old snapshots keeps the metadata small for every engine reading this table."
+                    }
+                    if table.omitted_snapshots > 0 {
+                        p {
+                            class: "mt-1 text-xs text-yellow-700",
+                            "Older snapshots are left out of the timeline and history until you load them all."
+                        }
+                    }
+                    MaintenanceCommand {
+                        action_type: MaintenanceActionType::RetentionPolicy,
+                        table: table.clone()
+                    }
+                }
+                if table.omitted_snapshots > 0 {
+                    button {
+                        onclick: move |e| on_load_all.call(e),
+                        class: "ml-4 flex-shrink-0 px-3 py-1 text-xs font-medium text-yellow-800 border border-yellow-300 rounded-md bg-white hover:bg-yellow-100",
+                        title: "Reload this table with every snapshot; may be slow",
+                        "Load all snapshots"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// New snapshots listed individually in the "since you last viewed" banner
+const NEW_SNAPSHOTS_SHOWN: usize = 5;
+
+/// Banner shown when a reopened table has new snapshots since the user last viewed it
+#[component]
+pub fn ChangesSinceLastViewBanner(
+    changes: ChangesSinceLastView,
+    on_show_snapshots: EventHandler<MouseEvent>,
+    on_dismiss: EventHandler<MouseEvent>,
+) -> Element {
+    let seen_at = chrono::DateTime::from_timestamp_millis(changes.last_seen.timestamp_ms).map_or(
+        "an unknown time".to_string(),
+        |at| {
+            at.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        },
+    );
+    let count = changes.new_snapshots.len();
+    let hidden = count.saturating_sub(NEW_SNAPSHOTS_SHOWN);
+
+    rsx! {
+        div {
+            class: "mx-6 mt-4 p-4 bg-blue-50 border border-blue-200 rounded-lg text-sm",
+            div {
+                class: "flex items-start justify-between",
+                div {
+                    p {
+                        class: "font-medium text-blue-900",
+                        {format!(
+                            "🆕 {} new snapshot{} since you last viewed this table ({})",
+                            count,
+                            if count == 1 { "" } else { "s" },
+                            seen_at
+                        )}
+                    }
+                    p {
+                        class: "mt-1 text-blue-800",
+                        {format!(
+                            "+{} records, −{} records · +{} files, −{} files",
+                            changes.records_added,
+                            changes.records_deleted,
+                            changes.data_files_added,
+                            changes.data_files_removed
+                        )}
+                    }
+                    if changes.last_seen_expired {
+                        p {
+                            class: "mt-1 text-xs text-yellow-700",
+                            "Snapshot {changes.last_seen.snapshot_id}, the one you last saw, has since been expired; showing the snapshots committed after it."
+                        }
+                    }
+                }
+                div {
+                    class: "flex items-center space-x-3 text-xs",
+                    button {
+                        onclick: move |e| on_show_snapshots.call(e),
+                        class: "text-blue-600 hover:text-blue-800",
+                        "View snapshots"
+                    }
+                    button {
+                        onclick: move |e| on_dismiss.call(e),
+                        class: "text-gray-400 hover:text-gray-600",
+                        title: "Dismiss",
+                        "×"
+                    }
+                }
+            }
+            ul {
+                class: "mt-2 space-y-1 text-xs text-gray-700",
+                for snapshot in changes.new_snapshots.iter().take(NEW_SNAPSHOTS_SHOWN) {
+                    li {
+                        key: "{snapshot.snapshot_id}",
+                        span { class: "font-mono", "{snapshot.snapshot_id}" }
+                        span { class: "ml-2 text-gray-500", {snapshot.timestamp().format("%Y-%m-%d %H:%M UTC").to_string()} }
+                        span { class: "ml-2", "{snapshot.operation()}" }
+                        span { class: "ml-2 text-gray-500", {format!("{} records added", snapshot.records_added())} }
+                    }
+                }
+                if hidden > 0 {
+                    li { class: "text-gray-500", "…and {hidden} more" }
+                }
+            }
+        }
+    }
+}
+
+/// Freshness pill for the table view, with an editor for this table's thresholds
+#[component]
+pub fn FreshnessBadge(table: IcebergTable, catalog_manager: Signal<CatalogManager>) -> Element {
+    let table_key = table.table_key();
+    let settings = catalog_manager.read().settings().clone();
+    let thresholds = settings.freshness_thresholds_for(&table_key);
+    let has_override = settings.table_freshness_thresholds.contains_key(&table_key);
+    let hours = TableAnalytics::hours_since_last_snapshot(&table.snapshots, chrono::Utc::now());
+    let freshness = TableAnalytics::classify_freshness(hours, &thresholds);
+
+    let mut editing = use_signal(|| false);
+    let mut aging_hours = use_signal(|| thresholds.aging_hours);
+    let mut stale_hours = use_signal(|| thresholds.stale_hours);
+    let mut save_error = use_signal(|| Option::<String>::None);
+
+    let (pill_class, text_class) = match freshness {
+        Freshness::Fresh => ("bg-green-100", "text-green-800"),
+        Freshness::Aging => ("bg-yellow-100", "text-yellow-800"),
+        Freshness::Stale => ("bg-red-100", "text-red-800"),
+        Freshness::Unknown => ("bg-gray-100", "text-gray-700"),
+    };
+    let age = hours.map(format_age_hours);
+    let title = match &age {
+        Some(age) => format!(
+            "Last snapshot {} ago. Aging after {}h, stale after {}h{}",
+            age,
+            thresholds.aging_hours,
+            thresholds.stale_hours,
+            if has_override {
+                " (table override)"
+            } else {
+                ""
+            }
+        ),
+        None => "This table has no snapshots yet".to_string(),
+    };
+
+    let save = {
+        let table_key = table_key.clone();
+        move |thresholds: Option<FreshnessThresholds>| match catalog_manager
+            .with_mut(|manager| manager.set_table_freshness_thresholds(&table_key, thresholds))
+        {
+            Ok(()) => {
+                save_error.set(None);
+                editing.set(false);
+            }
+            Err(e) => save_error.set(Some(e.to_string())),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "relative",
+            button {
+                onclick: move |_| {
+                    aging_hours.set(thresholds.aging_hours);
+                    stale_hours.set(thresholds.stale_hours);
+                    save_error.set(None);
+                    editing.set(!editing());
+                },
+                class: format!("inline-flex items-center px-2 py-1 rounded-full text-xs font-medium {pill_class} {text_class}"),
+                title: "{title}",
+                span { class: format!("w-2 h-2 rounded-full mr-1 {}", freshness_color_class(freshness)) }
+                "{freshness.label()}"
+                if let Some(age) = &age {
+                    span { class: "ml-1 font-normal", "· {age}" }
+                }
+            }
+            if editing() {
+                div {
+                    class: "absolute right-0 mt-1 w-64 p-3 bg-white border border-gray-200 rounded-md shadow-lg text-xs space-y-2 z-20",
+                    div { class: "font-medium text-gray-900", "Freshness thresholds for this table" }
+                    div {
+                        class: "flex items-center justify-between",
+                        label { class: "text-gray-600", "Aging after (hours)" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            step: "any",
+                            value: "{aging_hours}",
+                            oninput: move |evt| {
+                                if let Ok(value) = evt.value().parse::<f64>() {
+                                    aging_hours.set(value.max(0.0));
+                                }
+                            },
+                            class: "w-20 px-2 py-1 border border-gray-300 rounded text-xs"
+                        }
+                    }
+                    div {
+                        class: "flex items-center justify-between",
+                        label { class: "text-gray-600", "Stale after (hours)" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            step: "any",
+                            value: "{stale_hours}",
+                            oninput: move |evt| {
+                                if let Ok(value) = evt.value().parse::<f64>() {
+                                    stale_hours.set(value.max(0.0));
+                                }
+                            },
+                            class: "w-20 px-2 py-1 border border-gray-300 rounded text-xs"
+                        }
+                    }
+                    if stale_hours() < aging_hours() {
+                        div { class: "text-red-600", "Stale must be at least the aging threshold" }
+                    }
+                    if let Some(error) = save_error() {
+                        div { class: "text-red-600", "{error}" }
+                    }
+                    div {
+                        class: "flex justify-between",
+                        button {
+                            onclick: {
+                                let mut save = save.clone();
+                                move |_| save(None)
+                            },
+                            disabled: !has_override,
+                            class: "px-2 py-1 text-gray-600 hover:text-gray-800 disabled:opacity-50",
+                            title: "Remove this table's override and use the thresholds from Settings",
+                            "Use defaults"
+                        }
+                        button {
+                            onclick: {
+                                let mut save = save.clone();
+                                move |_| {
+                                    save(Some(FreshnessThresholds {
+                                        aging_hours: aging_hours(),
+                                        stale_hours: stale_hours(),
+                                    }))
+                                }
+                            },
+                            disabled: stale_hours() < aging_hours(),
+                            class: "px-2 py-1 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50",
+                            {t(Msg::Save)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct HealthCategoryCardProps {
     title: String,
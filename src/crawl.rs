@@ -0,0 +1,88 @@
+//! Crawls that load or inspect many tables at once, such as reports over a namespace or a
+//! whole catalog. The catalog manager's request limiter bounds the requests themselves; a
+//! crawl keeps no more items in flight than that limit and stops when its run goes stale.
+
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::rc::Rc;
+
+use crate::catalog::CatalogManager;
+
+/// One run of a crawl. Starting another run on the same generation, or cancelling it, makes
+/// this one stale: its workers stop picking up items and drop any result still in flight.
+#[derive(Clone, Copy)]
+pub struct CrawlRun {
+    /// `None` for runs that nothing cancels or supersedes
+    generation: Option<Signal<usize>>,
+    run: usize,
+}
+
+impl CrawlRun {
+    /// Begin a new run, superseding any earlier one on this generation
+    pub fn start(mut generation: Signal<usize>) -> Self {
+        *generation.write() += 1;
+        Self {
+            generation: Some(generation),
+            run: *generation.peek(),
+        }
+    }
+
+    /// A run that stays current until it finishes
+    pub fn detached() -> Self {
+        Self {
+            generation: None,
+            run: 0,
+        }
+    }
+
+    /// Stop whichever run is current on this generation
+    pub fn cancel(mut generation: Signal<usize>) {
+        *generation.write() += 1;
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.generation
+            .is_none_or(|generation| *generation.peek() == self.run)
+    }
+}
+
+/// Run `work` on every item and pass each result to `on_result`, keeping at most as many
+/// items in flight as the catalog manager allows concurrent requests. Results that arrive
+/// after the run went stale are dropped.
+pub fn crawl<T, R, Fut>(
+    catalog_manager: Signal<CatalogManager>,
+    run: CrawlRun,
+    items: Vec<T>,
+    work: impl Fn(T) -> Fut + Clone + 'static,
+    on_result: impl FnMut(R) + Clone + 'static,
+) where
+    T: 'static,
+    R: 'static,
+    Fut: Future<Output = R> + 'static,
+{
+    let workers = catalog_manager
+        .peek()
+        .settings()
+        .max_concurrent_requests
+        .clamp(1, items.len().max(1));
+    let queue = Rc::new(RefCell::new(VecDeque::from(items)));
+    for _ in 0..workers {
+        let queue = queue.clone();
+        let work = work.clone();
+        let mut on_result = on_result.clone();
+        spawn(async move {
+            while run.is_current() {
+                let Some(item) = queue.borrow_mut().pop_front() else {
+                    return;
+                };
+                let result = work(item).await;
+                if !run.is_current() {
+                    return;
+                }
+                on_result(result);
+            }
+        });
+    }
+}
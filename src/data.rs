@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IcebergTable {
@@ -15,6 +15,411 @@ pub struct IcebergTable {
     pub properties: HashMap<String, String>,
     pub partition_spec: Option<PartitionSpec>,
     pub partition_specs: Vec<PartitionSpec>, // Historical partition specs
+    #[serde(default)]
+    pub sort_order: Option<SortOrder>, // Default sort order, if the table is sorted
+    #[serde(default)]
+    pub refs: Vec<SnapshotRef>, // Named branches and tags
+    #[serde(default)]
+    pub statistics: Vec<StatisticsFile>, // Puffin column statistics, one file per snapshot
+    #[serde(default)]
+    pub partition_statistics: Vec<PartitionStatisticsFile>,
+    /// Iceberg table format version (1, 2 or 3); unknown in reports saved before it was kept
+    #[serde(default)]
+    pub format_version: Option<u8>,
+    /// Older snapshots left out of `snapshots` because the table has too many to show
+    #[serde(default)]
+    pub omitted_snapshots: usize,
+    /// Size of the table metadata document, as serialized JSON
+    #[serde(default)]
+    pub metadata_size_bytes: Option<u64>,
+    /// Entries in the metadata's snapshot log, which keeps growing until snapshots are expired
+    #[serde(default)]
+    pub snapshot_log_entries: usize,
+}
+
+/// A named branch or tag pointing at a snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SnapshotRef {
+    pub name: String,
+    pub snapshot_id: u64,
+    pub ref_type: SnapshotRefType,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SnapshotRefType {
+    Branch,
+    Tag,
+}
+
+/// A Puffin statistics file registered in the table metadata
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatisticsFile {
+    pub snapshot_id: u64,
+    pub statistics_path: String,
+    pub file_size_in_bytes: u64,
+    pub blobs: Vec<StatisticsBlob>,
+}
+
+/// Metadata for one blob in a statistics file, e.g. a Theta sketch for a column
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatisticsBlob {
+    pub blob_type: String,
+    pub field_ids: Vec<i32>,
+    pub properties: BTreeMap<String, String>,
+}
+
+impl StatisticsBlob {
+    /// Approximate distinct count, which writers record in the blob's `ndv` property
+    pub fn ndv(&self) -> Option<u64> {
+        self.properties.get("ndv")?.parse().ok()
+    }
+}
+
+/// A partition statistics file registered in the table metadata
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PartitionStatisticsFile {
+    pub snapshot_id: u64,
+    pub statistics_path: String,
+    pub file_size_in_bytes: u64,
+}
+
+/// A snapshot that no branch or tag can reach through its parent lineage
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OrphanedSnapshot {
+    pub snapshot_id: u64,
+    pub timestamp_ms: i64,
+    pub operation: String,
+    /// Bytes added by the snapshot, from its summary
+    pub added_size_bytes: Option<u64>,
+}
+
+impl IcebergTable {
+    /// Snapshots in the table metadata, including any left out of `snapshots`
+    pub fn total_snapshot_count(&self) -> usize {
+        self.snapshots.len() + self.omitted_snapshots
+    }
+
+    /// What the table's format version allows, in a sentence
+    pub fn format_version_note(&self) -> Option<&'static str> {
+        match self.format_version? {
+            1 => Some(
+                "Analytic tables: data files are added or replaced whole, with no row-level deletes",
+            ),
+            2 => Some(
+                "Supports row-level deletes (position and equality delete files) for merge-on-read updates",
+            ),
+            3 => Some(
+                "Adds deletion vectors, row lineage, column default values, nanosecond timestamps and variant types",
+            ),
+            _ => None,
+        }
+    }
+
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        let current = self.current_snapshot_id?;
+        self.snapshots.iter().find(|s| s.snapshot_id == current)
+    }
+
+    /// Statistics file for the current snapshot, if one was written
+    pub fn current_statistics(&self) -> Option<&StatisticsFile> {
+        let current = self.current_snapshot_id?;
+        self.statistics
+            .iter()
+            .find(|file| file.snapshot_id == current)
+    }
+
+    /// Approximate distinct counts per field id from the current snapshot's statistics.
+    /// Only single-column blobs count; multi-column sketches don't describe one field.
+    pub fn column_ndv(&self) -> HashMap<i32, u64> {
+        self.current_statistics()
+            .map(|file| {
+                file.blobs
+                    .iter()
+                    .filter_map(|blob| match blob.field_ids.as_slice() {
+                        [field_id] => Some((*field_id, blob.ndv()?)),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// `catalog.namespace.table`, as shown to the user
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}.{}", self.catalog_name, self.namespace, self.name)
+    }
+
+    /// Key of the table in per-table settings, see [`crate::config::table_key`]
+    pub fn table_key(&self) -> String {
+        crate::config::table_key(&self.catalog_name, &self.namespace, &self.name)
+    }
+}
+
+/// An Iceberg view: a versioned SQL definition with no data files of its own
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IcebergView {
+    pub name: String,
+    pub namespace: String,
+    pub catalog_name: String,
+    pub location: String,
+    pub current_version_id: i32,
+    pub versions: Vec<ViewVersion>, // Oldest first
+    pub schemas: Vec<TableSchema>,
+    pub properties: HashMap<String, String>,
+}
+
+impl IcebergView {
+    pub fn current_version(&self) -> Option<&ViewVersion> {
+        self.versions
+            .iter()
+            .find(|version| version.version_id == self.current_version_id)
+    }
+
+    pub fn schema(&self, schema_id: i32) -> Option<&TableSchema> {
+        self.schemas
+            .iter()
+            .find(|schema| schema.schema_id == schema_id)
+    }
+
+    /// `catalog.namespace.view`, in the same form as [`IcebergTable::qualified_name`]
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}.{}", self.catalog_name, self.namespace, self.name)
+    }
+}
+
+/// One version of a view's definition
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ViewVersion {
+    pub version_id: i32,
+    pub schema_id: i32,
+    pub timestamp_ms: i64,
+    pub summary: HashMap<String, String>,
+    /// The same query written for each engine that reads the view
+    pub representations: Vec<ViewRepresentation>,
+    /// Catalog and namespace that unqualified names in the SQL resolve against
+    pub default_catalog: Option<String>,
+    pub default_namespace: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ViewRepresentation {
+    pub dialect: String,
+    pub sql: String,
+}
+
+impl ViewVersion {
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.timestamp_ms).unwrap_or_else(Utc::now)
+    }
+}
+
+/// Headline numbers for a table, read from its current snapshot without converting it fully
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TableStats {
+    pub total_records: Option<u64>,
+    pub total_size_bytes: Option<u64>,
+    pub last_updated_ms: i64,
+    /// Commit time of the newest snapshot, for freshness checks
+    #[serde(default)]
+    pub latest_snapshot_ms: Option<i64>,
+}
+
+/// A table the user starred; favorites are watched by the freshness dashboard
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FavoriteTable {
+    pub catalog_name: String,
+    pub namespace: String,
+    pub table_name: String,
+}
+
+impl FavoriteTable {
+    pub fn qualified_name(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            self.catalog_name, self.namespace, self.table_name
+        )
+    }
+
+    /// Key of the table in per-table settings, see [`crate::config::table_key`]
+    pub fn table_key(&self) -> String {
+        crate::config::table_key(&self.catalog_name, &self.namespace, &self.table_name)
+    }
+}
+
+/// The current snapshot of a table when the user last viewed it. The timestamp outlives the
+/// snapshot, so changes can still be found after it was expired.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct LastSeenSnapshot {
+    pub snapshot_id: u64,
+    pub timestamp_ms: i64,
+}
+
+/// Snapshots a table gained since the user last viewed it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangesSinceLastView {
+    pub last_seen: LastSeenSnapshot,
+    /// Newest first
+    pub new_snapshots: Vec<Snapshot>,
+    pub records_added: u64,
+    pub records_deleted: u64,
+    pub data_files_added: u64,
+    pub data_files_removed: u64,
+    /// The last seen snapshot is no longer in the table's history, so the new snapshots are
+    /// those committed after it rather than its descendants
+    pub last_seen_expired: bool,
+}
+
+/// Result of checking one favorite against its freshness thresholds
+#[derive(Debug, Clone, PartialEq)]
+pub struct FreshnessCheck {
+    pub table: FavoriteTable,
+    pub hours_since_last_snapshot: Option<f64>,
+    pub freshness: Freshness,
+    /// Hours after which the table breaches its SLA (its "stale" threshold)
+    pub stale_hours: f64,
+    /// Why the table could not be checked, if loading it failed
+    pub error: Option<String>,
+    /// The table no longer exists in its catalog, so it was not loaded
+    pub missing: bool,
+}
+
+/// Catalog-wide counts shown on the welcome tab. Namespaces and tables are listed up front;
+/// telling Iceberg tables apart and summing their size loads every table, so it is optional.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatalogOverview {
+    pub namespace_count: usize,
+    /// Every listed table as (namespace, table name)
+    pub tables: Vec<(String, String)>,
+    /// Namespaces whose tables could not be listed
+    pub failed_namespaces: usize,
+    /// Set once every namespace has been listed
+    pub listed: bool,
+    pub error: Option<String>,
+    /// Tables loaded so far when classifying, `None` until classification is requested
+    pub classified: Option<usize>,
+    pub iceberg_count: usize,
+    pub total_size_bytes: u64,
+}
+
+impl CatalogOverview {
+    /// Whether every listed table has been loaded and classified
+    pub fn fully_classified(&self) -> bool {
+        self.classified == Some(self.tables.len())
+    }
+
+    /// Tables that could not be loaded as Iceberg tables (views, other formats, or failures)
+    pub fn other_count(&self) -> usize {
+        self.classified
+            .unwrap_or(0)
+            .saturating_sub(self.iceberg_count)
+    }
+}
+
+/// Live data files of the current snapshot aggregated by partition, read from its manifests
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PartitionStats {
+    /// Partition path, e.g. `event_date=2024-01-01/region=eu`
+    pub partition: String,
+    /// Partition field names and their display values in spec order; empty when unpartitioned
+    pub values: Vec<(String, String)>,
+    pub spec_id: i32,
+    pub file_count: u64,
+    pub record_count: u64,
+    pub total_size_bytes: u64,
+}
+
+/// Live data files of the current snapshot aggregated by the schema id they were written with
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SchemaFileStats {
+    pub schema_id: i32,
+    pub file_count: u64,
+    pub record_count: u64,
+    pub total_size_bytes: u64,
+}
+
+/// On-disk bytes of one column summed over the current snapshot's live data files, from the
+/// column sizes the manifests record for each file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ColumnSizeStats {
+    pub field_id: i32,
+    pub compressed_bytes: u64,
+    /// Values written, nulls included; `None` unless every file recorded a count
+    pub value_count: Option<u64>,
+    pub null_count: u64,
+    /// Smallest and largest value across the files' manifest bounds, rendered for display.
+    /// String and binary bounds may be truncated by the writer.
+    #[serde(default)]
+    pub lower_bound: Option<String>,
+    #[serde(default)]
+    pub upper_bound: Option<String>,
+}
+
+/// A schema field's share of the table's column data. Structs sum their nested fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSize {
+    pub compressed_bytes: u64,
+    pub share_percent: f64,
+    /// Estimated uncompressed over compressed size, only for fixed-width types
+    pub compression_ratio: Option<f64>,
+}
+
+/// A live data file of the current snapshot and the snapshot that first added it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DataFileOrigin {
+    pub file_path: String,
+    pub partition: String,
+    /// `None` when the manifest entry doesn't record it
+    pub added_snapshot_id: Option<u64>,
+    pub sequence_number: Option<i64>,
+    pub record_count: u64,
+    pub file_size_bytes: u64,
+}
+
+/// The live data one historical write still contributes to the current snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SnapshotContribution {
+    pub snapshot_id: Option<u64>,
+    /// Commit time and operation; `None` once the snapshot has been expired
+    pub timestamp_ms: Option<i64>,
+    pub operation: Option<String>,
+    pub file_count: u64,
+    pub record_count: u64,
+    pub total_size_bytes: u64,
+    /// Share of the current snapshot's live bytes
+    pub size_percent: f64,
+}
+
+/// Live data files still written with an older schema than the table's current one
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SchemaDriftSummary {
+    pub file_count: u64,
+    pub record_count: u64,
+    pub total_size_bytes: u64,
+    /// Share of the table's live bytes held by those files
+    pub size_percent: f64,
+}
+
+/// How unevenly data is spread across partitions
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PartitionSkewMetrics {
+    pub partition_count: usize,
+    pub median_size_bytes: u64,
+    pub max_size_bytes: u64,
+    /// Largest partition's bytes divided by the median partition's bytes
+    pub skew_ratio: f64,
+    /// 0 = perfectly even, approaching 1 = all data in one partition
+    pub gini_coefficient: f64,
+    /// Largest partitions first
+    pub heaviest_partitions: Vec<PartitionStats>,
+}
+
+/// A partition field with so many distinct values that each holds only a handful of records
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HighCardinalityPartitionField {
+    pub field: String,
+    pub distinct_values: usize,
+    /// Records across all partitions, divided over the field's distinct values
+    pub records_per_value: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -30,6 +435,12 @@ pub struct NestedField {
     pub required: bool,
     pub field_type: DataType,
     pub doc: Option<String>,
+    /// Value returned for rows written before the field existed, rendered for display
+    #[serde(default)]
+    pub initial_default: Option<String>,
+    /// Value used for the field when a writer does not supply one, rendered for display
+    #[serde(default)]
+    pub write_default: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -43,8 +454,15 @@ pub enum DataType {
     Time,
     Timestamp,
     TimestampTz,
+    /// Nanosecond precision timestamps, added in format version 3
+    TimestampNs,
+    TimestampTzNs,
     String,
     Uuid,
+    /// Binary of exactly `length` bytes
+    Fixed {
+        length: u64,
+    },
     Binary,
     Decimal {
         precision: u32,
@@ -74,13 +492,19 @@ impl DataType {
             DataType::Time => "time".to_string(),
             DataType::Timestamp => "timestamp".to_string(),
             DataType::TimestampTz => "timestamptz".to_string(),
+            DataType::TimestampNs => "timestamp_ns".to_string(),
+            DataType::TimestampTzNs => "timestamptz_ns".to_string(),
             DataType::String => "string".to_string(),
             DataType::Uuid => "uuid".to_string(),
+            DataType::Fixed { length } => format!("fixed[{}]", length),
             DataType::Binary => "binary".to_string(),
             DataType::Decimal { precision, scale } => format!("decimal({}, {})", precision, scale),
+            // Struct members are listed as nested fields, so only the element types are spelled out
             DataType::Struct { .. } => "struct".to_string(),
-            DataType::List { .. } => "list".to_string(),
-            DataType::Map { .. } => "map".to_string(),
+            DataType::List { element } => format!("list<{}>", element.to_string()),
+            DataType::Map { key, value } => {
+                format!("map<{}, {}>", key.to_string(), value.to_string())
+            }
         }
     }
 }
@@ -92,6 +516,8 @@ pub struct Snapshot {
     pub summary: Option<Summary>,
     pub manifest_list: String,
     pub schema_id: Option<i32>,
+    #[serde(default)]
+    pub parent_snapshot_id: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -105,6 +531,10 @@ pub struct Summary {
     pub added_files_size: Option<String>,
     pub removed_files_size: Option<String>,
     pub total_size: Option<String>,
+    /// Every key/value in the snapshot summary, including engine-specific ones
+    /// (e.g. `spark.app.id`) that have no typed field above
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -121,6 +551,32 @@ pub struct PartitionField {
     pub transform: PartitionTransform,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SortOrder {
+    pub order_id: i64,
+    pub fields: Vec<SortField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SortField {
+    pub source_id: i32,
+    pub transform: PartitionTransform,
+    pub direction: SortDirection,
+    pub null_order: NullOrder,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum NullOrder {
+    First,
+    Last,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PartitionTransform {
     Identity,
@@ -131,6 +587,7 @@ pub enum PartitionTransform {
     Day,
     Hour,
     Void,
+    Unknown,
 }
 
 impl PartitionTransform {
@@ -144,6 +601,7 @@ impl PartitionTransform {
             PartitionTransform::Day => "day".to_string(),
             PartitionTransform::Hour => "hour".to_string(),
             PartitionTransform::Void => "void".to_string(),
+            PartitionTransform::Unknown => "unknown".to_string(),
         }
     }
 }
@@ -160,6 +618,30 @@ impl Snapshot {
             .unwrap_or_else(|| "unknown".to_string())
     }
 
+    /// Engine or application that wrote this snapshot, read from the summary: the
+    /// `engine-name` (with `engine-version` when present) that recent Iceberg writers record,
+    /// otherwise inferred from engine-specific keys such as `spark.app.id`
+    pub fn writer(&self) -> Option<String> {
+        let properties = &self.summary.as_ref()?.properties;
+        if let Some(engine) = properties
+            .get("engine-name")
+            .filter(|e| !e.trim().is_empty())
+        {
+            return Some(match properties.get("engine-version") {
+                Some(version) if !version.trim().is_empty() => format!("{} {}", engine, version),
+                _ => engine.clone(),
+            });
+        }
+        [
+            ("spark.app.id", "spark"),
+            ("flink.job-id", "flink"),
+            ("trino_query_id", "trino"),
+        ]
+        .into_iter()
+        .find(|(key, _)| properties.contains_key(*key))
+        .map(|(_, engine)| engine.to_string())
+    }
+
     pub fn records_added(&self) -> String {
         self.summary
             .as_ref()
@@ -184,6 +666,131 @@ impl Snapshot {
     }
 }
 
+/// Snapshots attributed to one writing engine. `writer` is `None` for the bucket of
+/// snapshots whose summary doesn't say what wrote them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriterGroup {
+    pub writer: Option<String>,
+    pub snapshot_count: usize,
+    pub records_added: u64,
+    pub last_timestamp_ms: i64,
+}
+
+/// Outcome of one table validation check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationStatus {
+    Passed,
+    Failed,
+    /// Not enough information to run the check, e.g. an earlier read failed
+    Skipped,
+}
+
+/// One line of a table validation checklist
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationCheck {
+    pub name: String,
+    pub status: ValidationStatus,
+    pub detail: String,
+}
+
+impl ValidationCheck {
+    pub fn new(name: &str, status: ValidationStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Running table totals as of a snapshot, in commit order. `None` until a snapshot
+/// summary provides enough information to know the value.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CumulativeTotals {
+    pub records: Option<u64>,
+    pub size_bytes: Option<u64>,
+}
+
+/// A snapshot whose reported `total-records` does not equal the previous snapshot's total
+/// plus the records it added minus the records it deleted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordCountDiscrepancy {
+    pub snapshot_id: u64,
+    pub previous_snapshot_id: u64,
+    pub expected_records: i64,
+    pub reported_records: u64,
+}
+
+impl RecordCountDiscrepancy {
+    /// Reported minus expected; positive means more rows than the summaries account for
+    pub fn difference(&self) -> i64 {
+        self.reported_records as i64 - self.expected_records
+    }
+}
+
+/// Area of the table touched by a change between two metadata versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataChangeKind {
+    Schema,
+    Partitioning,
+    SortOrder,
+    Snapshots,
+    Refs,
+    Properties,
+    Location,
+}
+
+impl MetadataChangeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MetadataChangeKind::Schema => "Schema",
+            MetadataChangeKind::Partitioning => "Partitioning",
+            MetadataChangeKind::SortOrder => "Sort order",
+            MetadataChangeKind::Snapshots => "Snapshots",
+            MetadataChangeKind::Refs => "Branches & tags",
+            MetadataChangeKind::Properties => "Properties",
+            MetadataChangeKind::Location => "Location",
+        }
+    }
+}
+
+/// One difference between a metadata file and the one before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataChange {
+    pub kind: MetadataChangeKind,
+    pub description: String,
+}
+
+/// How a table compares between a source and a target catalog
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogDiffStatus {
+    OnlyInSource,
+    OnlyInTarget,
+    /// Present in both with the same schema and partitioning
+    Matches,
+    /// Present in both; schema or partitioning differences, described from source to target
+    Differs(Vec<MetadataChange>),
+    /// Present in both but one side could not be loaded
+    Failed(String),
+}
+
+/// One table in a catalog-to-catalog comparison
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogDiffEntry {
+    pub namespace: String,
+    pub table_name: String,
+    pub status: CatalogDiffStatus,
+}
+
+/// Changes made by a table's most recent metadata commit
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastMetadataChange {
+    /// The metadata file that was current before the latest commit
+    pub previous_location: String,
+    pub previous_timestamp_ms: i64,
+    pub changes: Vec<MetadataChange>,
+}
+
 // Health Analytics Data Structures
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -195,6 +802,54 @@ pub struct TableHealthMetrics {
     pub trends: TrendMetrics,
     pub alerts: Vec<HealthAlert>,
     pub recommendations: Vec<MaintenanceRecommendation>,
+    /// Snapshots with no summary; file and storage metrics only cover the rest
+    #[serde(default)]
+    pub snapshots_without_summary: usize,
+    /// Snapshots not reachable from any branch or tag
+    #[serde(default)]
+    pub orphaned_snapshots: Vec<OrphanedSnapshot>,
+    /// Categories that contributed to the score and alerts
+    #[serde(default = "HealthCategory::all")]
+    pub enabled_categories: Vec<HealthCategory>,
+    /// Spread of data across partitions; `None` until the manifests have been read, or when
+    /// fewer than two partitions hold data
+    #[serde(default)]
+    pub partition_skew: Option<PartitionSkewMetrics>,
+}
+
+/// Scored health categories; each can be switched off in settings
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum HealthCategory {
+    File,
+    Operational,
+    Storage,
+    Compaction,
+    /// Scored only once the manifests have been read for partition stats
+    Partition,
+}
+
+impl HealthCategory {
+    pub const ALL: [HealthCategory; 5] = [
+        HealthCategory::File,
+        HealthCategory::Operational,
+        HealthCategory::Storage,
+        HealthCategory::Compaction,
+        HealthCategory::Partition,
+    ];
+
+    pub fn all() -> Vec<HealthCategory> {
+        Self::ALL.to_vec()
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthCategory::File => "File Health",
+            HealthCategory::Operational => "Operational Health",
+            HealthCategory::Storage => "Storage Efficiency",
+            HealthCategory::Compaction => "Compaction Health",
+            HealthCategory::Partition => "Partition Health",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -292,6 +947,51 @@ pub enum AlertCategory {
     CompactionNeeded,
     PerformanceDegradation,
     DataFreshness,
+    PartitionSkew,
+    HighCardinalityPartition,
+    IncompleteMetadata,
+    OrphanedSnapshots,
+    MissingStatistics,
+    LargeMetadata,
+}
+
+/// How recently a table received a new snapshot, relative to its freshness thresholds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum Freshness {
+    Fresh,
+    Aging,
+    Stale,
+    /// The table has no snapshots yet
+    Unknown,
+}
+
+impl Freshness {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Freshness::Fresh => "Fresh",
+            Freshness::Aging => "Aging",
+            Freshness::Stale => "Stale",
+            Freshness::Unknown => "No data",
+        }
+    }
+}
+
+impl AlertCategory {
+    /// The scored category an alert belongs to, if any. Alerts outside the scored
+    /// categories (orphans, metadata gaps, missing statistics) are always shown.
+    pub fn health_category(&self) -> Option<HealthCategory> {
+        match self {
+            AlertCategory::SmallFiles => Some(HealthCategory::File),
+            AlertCategory::HighSnapshotFrequency => Some(HealthCategory::Operational),
+            AlertCategory::StorageGrowth => Some(HealthCategory::Storage),
+            AlertCategory::CompactionNeeded => Some(HealthCategory::Compaction),
+            AlertCategory::LargeMetadata => Some(HealthCategory::Operational),
+            AlertCategory::PartitionSkew | AlertCategory::HighCardinalityPartition => {
+                Some(HealthCategory::Partition)
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -318,6 +1018,9 @@ pub enum MaintenanceActionType {
     SchemaEvolution,
     RetentionPolicy,
     Optimization,
+    ComputeStatistics,
+    /// Cap retained metadata versions and expire snapshots to shrink metadata.json
+    MetadataCleanup,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
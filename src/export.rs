@@ -0,0 +1,1884 @@
+//! Exporters that turn Hielo's table model into shareable artifacts.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::analytics::TableAnalytics;
+use crate::catalog::{CatalogConfig, CatalogType, S3StorageOptions};
+use crate::config::is_secret_config_key;
+use crate::data::{
+    CatalogDiffEntry, CatalogDiffStatus, DataType, IcebergTable, MaintenanceActionType,
+    NestedField, PartitionSpec, PartitionTransform, Snapshot, SortOrder, TableHealthMetrics,
+    TableSchema,
+};
+
+/// Bumped whenever the report layout changes in a way consumers would notice
+pub const TABLE_REPORT_FORMAT_VERSION: u32 = 1;
+
+/// Everything Hielo knows about a table, as a single versioned document
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TableReport {
+    pub format_version: u32,
+    pub generated_at: DateTime<Utc>,
+    pub generated_by: String,
+    pub catalog: String,
+    pub namespace: String,
+    pub name: String,
+    pub location: String,
+    pub current_snapshot_id: Option<u64>,
+    pub current_schema: TableSchema,
+    pub schemas: Vec<TableSchema>,
+    pub partition_spec: Option<PartitionSpec>,
+    pub partition_specs: Vec<PartitionSpec>,
+    pub sort_order: Option<SortOrder>,
+    /// Sorted by key so reports diff cleanly
+    pub properties: BTreeMap<String, String>,
+    /// Oldest first
+    pub snapshots: Vec<Snapshot>,
+    pub health: TableHealthMetrics,
+}
+
+impl TableReport {
+    pub fn from_table(table: &IcebergTable, generated_at: DateTime<Utc>) -> Self {
+        let mut schemas = table.schemas.clone();
+        schemas.sort_by_key(|schema| schema.schema_id);
+        let mut partition_specs = table.partition_specs.clone();
+        partition_specs.sort_by_key(|spec| spec.spec_id);
+        let mut snapshots = table.snapshots.clone();
+        snapshots.sort_by_key(|snapshot| snapshot.timestamp_ms);
+
+        Self {
+            format_version: TABLE_REPORT_FORMAT_VERSION,
+            generated_at,
+            generated_by: format!("hielo {}", env!("CARGO_PKG_VERSION")),
+            catalog: table.catalog_name.clone(),
+            namespace: table.namespace.clone(),
+            name: table.name.clone(),
+            location: table.location.clone(),
+            current_snapshot_id: table.current_snapshot_id,
+            current_schema: table.schema.clone(),
+            schemas,
+            partition_spec: table.partition_spec.clone(),
+            partition_specs,
+            sort_order: table.sort_order.clone(),
+            properties: table.properties.clone().into_iter().collect(),
+            snapshots,
+            health: TableAnalytics::compute_health_metrics(table),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Suggested file name, e.g. `sales.orders-report.json`
+    pub fn file_name(&self) -> String {
+        format!("{}.{}-report.json", self.namespace, self.name)
+    }
+}
+
+/// Write an export into the user's downloads directory (home directory as a fallback)
+/// and return where it ended up
+pub fn save_to_downloads(file_name: &str, contents: &str) -> Result<PathBuf> {
+    let dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| anyhow::anyhow!("Could not determine a downloads directory"))?;
+    let path = dir.join(file_name);
+    std::fs::write(&path, contents)?;
+    log::info!("Exported {}", path.display());
+    Ok(path)
+}
+
+/// Local directory of a `file:` table location (including the single-slash `file:/path`
+/// form Hadoop writes). Object store locations give `None`.
+pub fn local_directory(location: &str) -> Option<PathBuf> {
+    let url = url::Url::parse(location.trim()).ok()?;
+    if url.scheme() != "file" {
+        return None;
+    }
+    url.to_file_path().ok()
+}
+
+/// Open a directory in Finder, Explorer or whatever `xdg-open` hands it to
+pub fn reveal_in_file_browser(path: &Path) -> Result<()> {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(target_os = "windows") {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+    std::process::Command::new(opener)
+        .arg(path)
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Could not run {}: {}", opener, e))?;
+    log::info!("Opened {} in the file browser", path.display());
+    Ok(())
+}
+
+/// SQL engine flavour for generated `CREATE TABLE` statements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    Spark,
+    /// Trino and Athena share the same Iceberg DDL
+    Trino,
+}
+
+impl SqlDialect {
+    pub const ALL: [SqlDialect; 2] = [SqlDialect::Spark, SqlDialect::Trino];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SqlDialect::Spark => "Spark SQL",
+            SqlDialect::Trino => "Trino / Athena",
+        }
+    }
+}
+
+/// Column type for `data_type` in the given dialect. Types the engine has no
+/// equivalent for fall back to the closest representation (e.g. Spark's `STRING` for `time`).
+pub fn sql_type(data_type: &DataType, dialect: SqlDialect) -> String {
+    let spark = dialect == SqlDialect::Spark;
+    let scalar = |spark_name: &str, trino_name: &str| {
+        if spark { spark_name } else { trino_name }.to_string()
+    };
+    match data_type {
+        DataType::Boolean => scalar("BOOLEAN", "boolean"),
+        DataType::Integer => scalar("INT", "integer"),
+        DataType::Long => scalar("BIGINT", "bigint"),
+        DataType::Float => scalar("FLOAT", "real"),
+        DataType::Double => scalar("DOUBLE", "double"),
+        DataType::Date => scalar("DATE", "date"),
+        DataType::Time => scalar("STRING", "time(6)"),
+        DataType::Timestamp => scalar("TIMESTAMP_NTZ", "timestamp(6)"),
+        DataType::TimestampTz => scalar("TIMESTAMP", "timestamp(6) with time zone"),
+        // Spark timestamps stop at microseconds
+        DataType::TimestampNs => scalar("TIMESTAMP_NTZ", "timestamp(9)"),
+        DataType::TimestampTzNs => scalar("TIMESTAMP", "timestamp(9) with time zone"),
+        DataType::String => scalar("STRING", "varchar"),
+        DataType::Uuid => scalar("STRING", "uuid"),
+        DataType::Fixed { .. } | DataType::Binary => scalar("BINARY", "varbinary"),
+        DataType::Decimal { precision, scale } => {
+            if spark {
+                format!("DECIMAL({}, {})", precision, scale)
+            } else {
+                format!("decimal({}, {})", precision, scale)
+            }
+        }
+        DataType::Struct { fields } => {
+            let members: Vec<String> = fields
+                .iter()
+                .map(|field| {
+                    let name = quote_identifier(&field.name, dialect);
+                    let field_type = sql_type(&field.field_type, dialect);
+                    if spark {
+                        format!("{}: {}", name, field_type)
+                    } else {
+                        format!("{} {}", name, field_type)
+                    }
+                })
+                .collect();
+            if spark {
+                format!("STRUCT<{}>", members.join(", "))
+            } else {
+                format!("row({})", members.join(", "))
+            }
+        }
+        DataType::List { element } => {
+            if spark {
+                format!("ARRAY<{}>", sql_type(element, dialect))
+            } else {
+                format!("array({})", sql_type(element, dialect))
+            }
+        }
+        DataType::Map { key, value } => {
+            if spark {
+                format!(
+                    "MAP<{}, {}>",
+                    sql_type(key, dialect),
+                    sql_type(value, dialect)
+                )
+            } else {
+                format!(
+                    "map({}, {})",
+                    sql_type(key, dialect),
+                    sql_type(value, dialect)
+                )
+            }
+        }
+    }
+}
+
+/// Leave plain identifiers bare; quote anything else with the dialect's quote character
+fn quote_identifier(name: &str, dialect: SqlDialect) -> String {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        return name.to_string();
+    }
+    match dialect {
+        SqlDialect::Spark => format!("`{}`", name.replace('`', "``")),
+        SqlDialect::Trino => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+fn quote_string(value: &str, dialect: SqlDialect) -> String {
+    match dialect {
+        SqlDialect::Spark => format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'")),
+        SqlDialect::Trino => format!("'{}'", value.replace('\'', "''")),
+    }
+}
+
+/// `catalog.namespace.table`, with each part quoted as needed
+fn qualified_table_name(table: &IcebergTable, dialect: SqlDialect) -> String {
+    std::iter::once(table.catalog_name.as_str())
+        .chain(table.namespace.split('.'))
+        .chain(std::iter::once(table.name.as_str()))
+        .map(|part| quote_identifier(part, dialect))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Partition transforms of the current spec, rendered for the dialect (void fields are dropped)
+fn partition_expressions(table: &IcebergTable, dialect: SqlDialect) -> Vec<String> {
+    let Some(spec) = &table.partition_spec else {
+        return Vec::new();
+    };
+    spec.fields
+        .iter()
+        .filter_map(|field| {
+            let source = table
+                .schema
+                .fields
+                .iter()
+                .find(|column| column.id == field.source_id)
+                .map(|column| column.name.as_str())
+                .unwrap_or(&field.name);
+            let column = quote_identifier(source, dialect);
+            let expression = match (dialect, &field.transform) {
+                (_, PartitionTransform::Void) => return None,
+                (_, PartitionTransform::Identity) => column,
+                (SqlDialect::Spark, PartitionTransform::Bucket { num_buckets }) => {
+                    format!("bucket({}, {})", num_buckets, column)
+                }
+                (SqlDialect::Spark, PartitionTransform::Truncate { width }) => {
+                    format!("truncate({}, {})", width, column)
+                }
+                (SqlDialect::Spark, PartitionTransform::Year) => format!("years({})", column),
+                (SqlDialect::Spark, PartitionTransform::Month) => format!("months({})", column),
+                (SqlDialect::Spark, PartitionTransform::Day) => format!("days({})", column),
+                (SqlDialect::Spark, PartitionTransform::Hour) => format!("hours({})", column),
+                (SqlDialect::Trino, PartitionTransform::Bucket { num_buckets }) => {
+                    format!("bucket({}, {})", column, num_buckets)
+                }
+                (SqlDialect::Trino, PartitionTransform::Truncate { width }) => {
+                    format!("truncate({}, {})", column, width)
+                }
+                (SqlDialect::Trino, PartitionTransform::Year) => format!("year({})", column),
+                (SqlDialect::Trino, PartitionTransform::Month) => format!("month({})", column),
+                (SqlDialect::Trino, PartitionTransform::Day) => format!("day({})", column),
+                (SqlDialect::Trino, PartitionTransform::Hour) => format!("hour({})", column),
+                // Kept visible so a copied spec can't silently lose a field
+                (_, PartitionTransform::Unknown) => format!("unknown({})", column),
+            };
+            Some(expression)
+        })
+        .collect()
+}
+
+/// Ways the current partition spec can be copied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionSpecFormat {
+    /// Transform expressions as written in Spark DDL, e.g. `days(ts), bucket(16, user_id)`
+    Readable,
+    /// The spec as Iceberg stores it in table metadata
+    IcebergJson,
+}
+
+impl PartitionSpecFormat {
+    pub const ALL: [PartitionSpecFormat; 2] = [
+        PartitionSpecFormat::Readable,
+        PartitionSpecFormat::IcebergJson,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PartitionSpecFormat::Readable => "Readable",
+            PartitionSpecFormat::IcebergJson => "Iceberg JSON",
+        }
+    }
+
+    /// The current spec in this format, `None` for unpartitioned tables
+    pub fn render(&self, table: &IcebergTable) -> Option<String> {
+        let spec = table.partition_spec.as_ref()?;
+        Some(match self {
+            PartitionSpecFormat::Readable => {
+                partition_expressions(table, SqlDialect::Spark).join(", ")
+            }
+            PartitionSpecFormat::IcebergJson => {
+                let fields: Vec<serde_json::Value> = spec
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        serde_json::json!({
+                            "name": field.name,
+                            "transform": field.transform.to_string(),
+                            "source-id": field.source_id,
+                            "field-id": field.field_id,
+                        })
+                    })
+                    .collect();
+                serde_json::to_string_pretty(
+                    &serde_json::json!({"spec-id": spec.spec_id, "fields": fields}),
+                )
+                .unwrap_or_default()
+            }
+        })
+    }
+}
+
+/// Column definitions of the current schema, one per line
+fn column_definitions(table: &IcebergTable, dialect: SqlDialect) -> String {
+    table
+        .schema
+        .fields
+        .iter()
+        .map(|field| {
+            let mut column = format!(
+                "  {} {}",
+                quote_identifier(&field.name, dialect),
+                sql_type(&field.field_type, dialect)
+            );
+            if field.required {
+                column.push_str(" NOT NULL");
+            }
+            if let Some(doc) = &field.doc {
+                column.push_str(&format!(" COMMENT {}", quote_string(doc, dialect)));
+            }
+            column
+        })
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
+/// Spark SQL `CREATE TABLE ... USING iceberg` for the table's current schema and spec
+pub fn generate_spark_ddl(table: &IcebergTable) -> String {
+    let dialect = SqlDialect::Spark;
+    let mut ddl = format!(
+        "CREATE TABLE {} (\n{}\n)\nUSING iceberg",
+        qualified_table_name(table, dialect),
+        column_definitions(table, dialect)
+    );
+
+    let partitions = partition_expressions(table, dialect);
+    if !partitions.is_empty() {
+        ddl.push_str(&format!("\nPARTITIONED BY ({})", partitions.join(", ")));
+    }
+    ddl.push_str(&format!(
+        "\nLOCATION {}",
+        quote_string(&table.location, dialect)
+    ));
+
+    let properties: BTreeMap<_, _> = table.properties.iter().collect();
+    if !properties.is_empty() {
+        let properties: Vec<String> = properties
+            .into_iter()
+            .map(|(key, value)| {
+                format!(
+                    "  {} = {}",
+                    quote_string(key, dialect),
+                    quote_string(value, dialect)
+                )
+            })
+            .collect();
+        ddl.push_str(&format!("\nTBLPROPERTIES (\n{}\n)", properties.join(",\n")));
+    }
+    ddl.push(';');
+    ddl
+}
+
+/// Trino / Athena `CREATE TABLE ... WITH (...)` for the table's current schema and spec
+pub fn generate_trino_ddl(table: &IcebergTable) -> String {
+    let dialect = SqlDialect::Trino;
+    let format = table
+        .properties
+        .get("write.format.default")
+        .map(|format| format.to_uppercase())
+        .unwrap_or_else(|| "PARQUET".to_string());
+
+    let mut options = vec![format!("  format = {}", quote_string(&format, dialect))];
+    let partitions = partition_expressions(table, dialect);
+    if !partitions.is_empty() {
+        let partitions: Vec<String> = partitions
+            .iter()
+            .map(|partition| quote_string(partition, dialect))
+            .collect();
+        options.push(format!("  partitioning = ARRAY[{}]", partitions.join(", ")));
+    }
+    options.push(format!(
+        "  location = {}",
+        quote_string(&table.location, dialect)
+    ));
+
+    format!(
+        "CREATE TABLE {} (\n{}\n)\nWITH (\n{}\n);",
+        qualified_table_name(table, dialect),
+        column_definitions(table, dialect),
+        options.join(",\n")
+    )
+}
+
+pub fn generate_ddl(table: &IcebergTable, dialect: SqlDialect) -> String {
+    match dialect {
+        SqlDialect::Spark => generate_spark_ddl(table),
+        SqlDialect::Trino => generate_trino_ddl(table),
+    }
+}
+
+/// Double-quote a YAML scalar unless it is a plain identifier
+fn yaml_scalar(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        && value
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    if plain {
+        value.to_string()
+    } else {
+        format!(
+            "\"{}\"",
+            value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+        )
+    }
+}
+
+/// dbt `sources:` block for the table. The source is named after the namespace, with the
+/// namespace as its schema; the table comment property, if any, becomes the description.
+pub fn dbt_source_yaml(table: &IcebergTable) -> String {
+    let mut yaml = format!(
+        "version: 2\n\nsources:\n  - name: {}\n    schema: {}\n    tables:\n      - name: {}\n",
+        yaml_scalar(&table.namespace.replace('.', "_")),
+        yaml_scalar(&table.namespace),
+        yaml_scalar(&table.name)
+    );
+    match table
+        .properties
+        .get("comment")
+        .filter(|comment| !comment.trim().is_empty())
+    {
+        Some(comment) => yaml.push_str(&format!(
+            "        description: {}\n",
+            yaml_scalar(comment.trim())
+        )),
+        None => yaml.push_str("        # description: \"\"\n"),
+    }
+    yaml
+}
+
+/// Make text safe inside a Markdown table cell
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn push_markdown_fields(
+    markdown: &mut String,
+    fields: &[NestedField],
+    parent: Option<&str>,
+    depth: usize,
+) {
+    for field in fields {
+        let path = match parent {
+            Some(parent) => format!("{}.{}", parent, field.name),
+            None => field.name.clone(),
+        };
+        let indent = "&nbsp;&nbsp;".repeat(depth);
+        markdown.push_str(&format!(
+            "| {} | {}`{}` | `{}` | {} | {} |\n",
+            field.id,
+            indent,
+            markdown_cell(&path),
+            field.field_type.to_string(),
+            if field.required { "yes" } else { "no" },
+            field.doc.as_deref().map(markdown_cell).unwrap_or_default()
+        ));
+        if let DataType::Struct { fields: nested } = &field.field_type {
+            push_markdown_fields(markdown, nested, Some(&path), depth + 1);
+        }
+    }
+}
+
+/// The current schema as a Markdown table for wikis and design docs. Struct children follow
+/// their parent as indented dotted paths (`address.city`).
+pub fn schema_markdown(table: &IcebergTable) -> String {
+    let mut markdown = format!(
+        "### {}.{} (schema {})\n\n| ID | Name | Type | Required | Description |\n| --- | --- | --- | --- | --- |\n",
+        table.namespace, table.name, table.schema.schema_id
+    );
+    push_markdown_fields(&mut markdown, &table.schema.fields, None, 0);
+    markdown
+}
+
+pub fn schema_markdown_file_name(table: &IcebergTable) -> String {
+    format!("{}.{}-schema.md", table.namespace, table.name)
+}
+
+/// A catalog-to-catalog comparison as a Markdown report: counts, then a section each for
+/// tables missing from either side, tables that differ and tables that could not be compared.
+/// `scope` is the compared namespace, or `None` for the whole catalog.
+pub fn catalog_diff_markdown(
+    source: &str,
+    target: &str,
+    scope: Option<&str>,
+    entries: &[CatalogDiffEntry],
+) -> String {
+    let count = |matches: fn(&CatalogDiffStatus) -> bool| {
+        entries
+            .iter()
+            .filter(|entry| matches(&entry.status))
+            .count()
+    };
+    let only_in_source = count(|status| matches!(status, CatalogDiffStatus::OnlyInSource));
+    let only_in_target = count(|status| matches!(status, CatalogDiffStatus::OnlyInTarget));
+    let differs = count(|status| matches!(status, CatalogDiffStatus::Differs(_)));
+    let matching = count(|status| matches!(status, CatalogDiffStatus::Matches));
+    let failed = count(|status| matches!(status, CatalogDiffStatus::Failed(_)));
+
+    let mut markdown = format!(
+        "# Catalog comparison: {} → {}\n\nScope: {}\n\n| Result | Tables |\n| --- | --- |\n| Matching | {} |\n| Different | {} |\n| Only in {} | {} |\n| Only in {} | {} |\n| Not compared | {} |\n",
+        source,
+        target,
+        scope.map_or_else(
+            || "all namespaces".to_string(),
+            |namespace| format!("namespace `{}`", namespace)
+        ),
+        matching,
+        differs,
+        source,
+        only_in_source,
+        target,
+        only_in_target,
+        failed
+    );
+
+    let mut sorted: Vec<&CatalogDiffEntry> = entries.iter().collect();
+    sorted.sort_by(|a, b| (&a.namespace, &a.table_name).cmp(&(&b.namespace, &b.table_name)));
+    let mut section = |title: String, lines: Vec<String>| {
+        if !lines.is_empty() {
+            markdown.push_str(&format!("\n## {}\n\n", title));
+            for line in lines {
+                markdown.push_str(&line);
+                markdown.push('\n');
+            }
+        }
+    };
+    let names = |matches: fn(&CatalogDiffStatus) -> bool| -> Vec<String> {
+        sorted
+            .iter()
+            .filter(|entry| matches(&entry.status))
+            .map(|entry| format!("- `{}.{}`", entry.namespace, entry.table_name))
+            .collect()
+    };
+    section(
+        format!("Missing from {}", target),
+        names(|status| matches!(status, CatalogDiffStatus::OnlyInSource)),
+    );
+    section(
+        format!("Missing from {}", source),
+        names(|status| matches!(status, CatalogDiffStatus::OnlyInTarget)),
+    );
+    section(
+        "Different".to_string(),
+        sorted
+            .iter()
+            .filter_map(|entry| match &entry.status {
+                CatalogDiffStatus::Differs(changes) => Some(format!(
+                    "- `{}.{}`\n{}",
+                    entry.namespace,
+                    entry.table_name,
+                    changes
+                        .iter()
+                        .map(|change| format!(
+                            "  - {}: {}",
+                            change.kind.label(),
+                            change.description
+                        ))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )),
+                _ => None,
+            })
+            .collect(),
+    );
+    section(
+        "Not compared".to_string(),
+        sorted
+            .iter()
+            .filter_map(|entry| match &entry.status {
+                CatalogDiffStatus::Failed(error) => Some(format!(
+                    "- `{}.{}`: {}",
+                    entry.namespace,
+                    entry.table_name,
+                    error.replace('\n', " ")
+                )),
+                _ => None,
+            })
+            .collect(),
+    );
+    markdown
+}
+
+pub fn catalog_diff_file_name(source: &str, target: &str, scope: Option<&str>) -> String {
+    match scope {
+        Some(namespace) => format!("{}-vs-{}.{}-diff.md", source, target, namespace),
+        None => format!("{}-vs-{}-diff.md", source, target),
+    }
+}
+
+/// Make a name valid in Avro (`[A-Za-z_][A-Za-z0-9_]*`) the way Iceberg does: other
+/// characters become `_x` plus their hex code, and a leading digit gets a `_` prefix
+fn avro_name(name: &str) -> String {
+    let mut sanitized = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            if index == 0 && c.is_ascii_digit() {
+                sanitized.push('_');
+            }
+            sanitized.push(c);
+        } else {
+            sanitized.push_str(&format!("_x{:X}", c as u32));
+        }
+    }
+    if sanitized.is_empty() {
+        sanitized.push('_');
+    }
+    sanitized
+}
+
+/// Smallest number of bytes whose two's complement range holds every decimal of `precision`
+fn decimal_fixed_size(precision: u32) -> u32 {
+    (1..=16)
+        .find(|bytes| (8 * bytes - 1) as f64 * 2f64.log10() >= precision as f64)
+        .unwrap_or(16)
+}
+
+/// `data_type` as Avro, following the Iceberg spec's Avro mapping. `name` is unique within
+/// the schema and names the record and fixed types this type defines.
+fn avro_type(data_type: &DataType, name: &str) -> serde_json::Value {
+    use serde_json::json;
+    match data_type {
+        DataType::Boolean => json!("boolean"),
+        DataType::Integer => json!("int"),
+        DataType::Long => json!("long"),
+        DataType::Float => json!("float"),
+        DataType::Double => json!("double"),
+        DataType::Date => json!({"type": "int", "logicalType": "date"}),
+        DataType::Time => json!({"type": "long", "logicalType": "time-micros"}),
+        DataType::Timestamp => {
+            json!({"type": "long", "logicalType": "timestamp-micros", "adjust-to-utc": false})
+        }
+        DataType::TimestampTz => {
+            json!({"type": "long", "logicalType": "timestamp-micros", "adjust-to-utc": true})
+        }
+        DataType::TimestampNs => {
+            json!({"type": "long", "logicalType": "timestamp-nanos", "adjust-to-utc": false})
+        }
+        DataType::TimestampTzNs => {
+            json!({"type": "long", "logicalType": "timestamp-nanos", "adjust-to-utc": true})
+        }
+        DataType::String => json!("string"),
+        DataType::Uuid => {
+            json!({"type": "fixed", "name": format!("{}_uuid", name), "size": 16, "logicalType": "uuid"})
+        }
+        DataType::Fixed { length } => {
+            json!({"type": "fixed", "name": format!("{}_fixed", name), "size": length})
+        }
+        DataType::Binary => json!("bytes"),
+        DataType::Decimal { precision, scale } => json!({
+            "type": "fixed",
+            "name": format!("{}_decimal", name),
+            "size": decimal_fixed_size(*precision),
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        DataType::Struct { fields } => avro_record(name, fields),
+        // Element and value nullability isn't kept in the table model, so they are optional
+        DataType::List { element } => json!({
+            "type": "array",
+            "items": ["null", avro_type(element, &format!("{}_element", name))],
+        }),
+        DataType::Map { key, value } if **key == DataType::String => json!({
+            "type": "map",
+            "values": ["null", avro_type(value, &format!("{}_value", name))],
+        }),
+        // Avro maps only have string keys; other keys become an array of key/value records
+        DataType::Map { key, value } => json!({
+            "type": "array",
+            "logicalType": "map",
+            "items": {
+                "type": "record",
+                "name": format!("{}_entry", name),
+                "fields": [
+                    {"name": "key", "type": avro_type(key, &format!("{}_key", name))},
+                    {"name": "value", "type": ["null", avro_type(value, &format!("{}_value", name))], "default": null},
+                ],
+            },
+        }),
+    }
+}
+
+fn avro_record(name: &str, fields: &[NestedField]) -> serde_json::Value {
+    let fields: Vec<serde_json::Value> = fields
+        .iter()
+        .map(|field| {
+            let field_type = avro_type(&field.field_type, &format!("r{}", field.id));
+            let mut avro_field = serde_json::Map::new();
+            avro_field.insert("name".to_string(), avro_name(&field.name).into());
+            if field.required {
+                avro_field.insert("type".to_string(), field_type);
+            } else {
+                avro_field.insert("type".to_string(), serde_json::json!(["null", field_type]));
+                avro_field.insert("default".to_string(), serde_json::Value::Null);
+            }
+            if let Some(doc) = &field.doc {
+                avro_field.insert("doc".to_string(), doc.clone().into());
+            }
+            avro_field.insert("field-id".to_string(), field.id.into());
+            serde_json::Value::Object(avro_field)
+        })
+        .collect();
+    serde_json::json!({"type": "record", "name": name, "fields": fields})
+}
+
+/// The current schema as an Avro schema (`.avsc`), with Iceberg field ids kept as
+/// `field-id` attributes. Optional fields are unions with `null`.
+pub fn avro_schema(table: &IcebergTable) -> String {
+    serde_json::to_string_pretty(&avro_schema_value(table)).unwrap_or_default()
+}
+
+fn avro_schema_value(table: &IcebergTable) -> serde_json::Value {
+    avro_record(&avro_name(&table.name), &table.schema.fields)
+}
+
+pub fn avro_schema_file_name(table: &IcebergTable) -> String {
+    format!("{}.{}.avsc", table.namespace, table.name)
+}
+
+/// `data_type` as an Arrow type in Arrow's JSON schema representation
+fn arrow_type(data_type: &DataType) -> serde_json::Value {
+    use serde_json::json;
+    match data_type {
+        DataType::Boolean => json!({"name": "bool"}),
+        DataType::Integer => json!({"name": "int", "bitWidth": 32, "isSigned": true}),
+        DataType::Long => json!({"name": "int", "bitWidth": 64, "isSigned": true}),
+        DataType::Float => json!({"name": "floatingpoint", "precision": "SINGLE"}),
+        DataType::Double => json!({"name": "floatingpoint", "precision": "DOUBLE"}),
+        DataType::Date => json!({"name": "date", "unit": "DAY"}),
+        DataType::Time => json!({"name": "time", "unit": "MICROSECOND", "bitWidth": 64}),
+        DataType::Timestamp => json!({"name": "timestamp", "unit": "MICROSECOND"}),
+        DataType::TimestampTz => {
+            json!({"name": "timestamp", "unit": "MICROSECOND", "timezone": "UTC"})
+        }
+        DataType::TimestampNs => json!({"name": "timestamp", "unit": "NANOSECOND"}),
+        DataType::TimestampTzNs => {
+            json!({"name": "timestamp", "unit": "NANOSECOND", "timezone": "UTC"})
+        }
+        DataType::String => json!({"name": "utf8"}),
+        DataType::Uuid => json!({"name": "fixedsizebinary", "byteWidth": 16}),
+        DataType::Fixed { length } => json!({"name": "fixedsizebinary", "byteWidth": length}),
+        DataType::Binary => json!({"name": "binary"}),
+        DataType::Decimal { precision, scale } => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 128})
+        }
+        DataType::Struct { .. } => json!({"name": "struct"}),
+        DataType::List { .. } => json!({"name": "list"}),
+        DataType::Map { .. } => json!({"name": "map", "keysSorted": false}),
+    }
+}
+
+/// An Arrow field. `field_id` is kept as `PARQUET:field_id` metadata, as PyIceberg does.
+fn arrow_field(
+    name: &str,
+    data_type: &DataType,
+    nullable: bool,
+    field_id: Option<i32>,
+) -> serde_json::Value {
+    let children: Vec<serde_json::Value> = match data_type {
+        DataType::Struct { fields } => fields.iter().map(arrow_schema_field).collect(),
+        DataType::List { element } => vec![arrow_field("element", element, true, None)],
+        DataType::Map { key, value } => vec![serde_json::json!({
+            "name": "entries",
+            "nullable": false,
+            "type": {"name": "struct"},
+            "children": [
+                arrow_field("key", key, false, None),
+                arrow_field("value", value, true, None),
+            ],
+        })],
+        _ => Vec::new(),
+    };
+    let mut field = serde_json::json!({
+        "name": name,
+        "nullable": nullable,
+        "type": arrow_type(data_type),
+        "children": children,
+    });
+    if let Some(id) = field_id {
+        field["metadata"] =
+            serde_json::json!([{"key": "PARQUET:field_id", "value": id.to_string()}]);
+    }
+    field
+}
+
+fn arrow_schema_field(field: &NestedField) -> serde_json::Value {
+    arrow_field(
+        &field.name,
+        &field.field_type,
+        !field.required,
+        Some(field.id),
+    )
+}
+
+/// The current schema as an Arrow schema in Arrow's JSON representation (the one used by
+/// the Arrow integration tests), with Iceberg field ids as field metadata
+pub fn arrow_schema_json(table: &IcebergTable) -> String {
+    serde_json::to_string_pretty(&arrow_schema_value(table)).unwrap_or_default()
+}
+
+fn arrow_schema_value(table: &IcebergTable) -> serde_json::Value {
+    let fields: Vec<serde_json::Value> =
+        table.schema.fields.iter().map(arrow_schema_field).collect();
+    serde_json::json!({ "fields": fields })
+}
+
+pub fn arrow_schema_file_name(table: &IcebergTable) -> String {
+    format!("{}.{}-arrow-schema.json", table.namespace, table.name)
+}
+
+/// Formats the current schema can be copied or saved in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaFormat {
+    Markdown,
+    Avro,
+    Arrow,
+}
+
+impl SchemaFormat {
+    pub const ALL: [SchemaFormat; 3] = [
+        SchemaFormat::Markdown,
+        SchemaFormat::Avro,
+        SchemaFormat::Arrow,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SchemaFormat::Markdown => "Markdown",
+            SchemaFormat::Avro => "Avro",
+            SchemaFormat::Arrow => "Arrow JSON",
+        }
+    }
+
+    pub fn render(&self, table: &IcebergTable) -> String {
+        match self {
+            SchemaFormat::Markdown => schema_markdown(table),
+            SchemaFormat::Avro => avro_schema(table),
+            SchemaFormat::Arrow => arrow_schema_json(table),
+        }
+    }
+
+    pub fn file_name(&self, table: &IcebergTable) -> String {
+        match self {
+            SchemaFormat::Markdown => schema_markdown_file_name(table),
+            SchemaFormat::Avro => avro_schema_file_name(table),
+            SchemaFormat::Arrow => arrow_schema_file_name(table),
+        }
+    }
+
+    /// Every table of a namespace in one document: Markdown gets a section per table, the
+    /// JSON formats an array of `{"table", "schema"}` objects. Tables that failed to load
+    /// are listed with their error instead of a schema.
+    pub fn render_namespace(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        tables: &[(String, Result<IcebergTable, String>)],
+    ) -> String {
+        let mut tables: Vec<&(String, Result<IcebergTable, String>)> = tables.iter().collect();
+        tables.sort_by(|a, b| a.0.cmp(&b.0));
+        match self {
+            SchemaFormat::Markdown => {
+                let failed = tables.iter().filter(|(_, table)| table.is_err()).count();
+                let mut markdown = format!(
+                    "# {}.{}\n\n{} table(s)",
+                    catalog_name,
+                    namespace,
+                    tables.len()
+                );
+                if failed > 0 {
+                    markdown.push_str(&format!(", {} could not be loaded", failed));
+                }
+                markdown.push('\n');
+                for (table_name, table) in tables {
+                    markdown.push('\n');
+                    match table {
+                        Ok(table) => markdown.push_str(&schema_markdown(table)),
+                        Err(error) => markdown.push_str(&format!(
+                            "### {}.{}\n\n> Could not load this table: {}\n",
+                            namespace,
+                            table_name,
+                            markdown_cell(error)
+                        )),
+                    }
+                }
+                markdown
+            }
+            SchemaFormat::Avro | SchemaFormat::Arrow => {
+                let entries: Vec<serde_json::Value> = tables
+                    .iter()
+                    .map(|(table_name, table)| {
+                        let name = format!("{}.{}", namespace, table_name);
+                        match table {
+                            Ok(table) => {
+                                let schema = match self {
+                                    SchemaFormat::Avro => avro_schema_value(table),
+                                    _ => arrow_schema_value(table),
+                                };
+                                serde_json::json!({"table": name, "schema": schema})
+                            }
+                            Err(error) => serde_json::json!({"table": name, "error": error}),
+                        }
+                    })
+                    .collect();
+                serde_json::to_string_pretty(&entries).unwrap_or_default()
+            }
+        }
+    }
+
+    pub fn namespace_file_name(&self, catalog_name: &str, namespace: &str) -> String {
+        match self {
+            SchemaFormat::Markdown => format!("{}.{}-schemas.md", catalog_name, namespace),
+            SchemaFormat::Avro => format!("{}.{}-avro-schemas.json", catalog_name, namespace),
+            SchemaFormat::Arrow => format!("{}.{}-arrow-schemas.json", catalog_name, namespace),
+        }
+    }
+}
+
+/// Quote a value for a POSIX shell
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// PyIceberg reads catalog properties from `PYICEBERG_CATALOG__<NAME>__<PROPERTY>`, turning
+/// `__` into `.` and `_` into `-`, lowercased. The name is reduced to what survives that
+/// round trip, so the CLI's `--catalog` finds it.
+fn pyiceberg_catalog_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn pyiceberg_env_var(catalog_name: &str, property: &str) -> String {
+    format!(
+        "PYICEBERG_CATALOG__{}__{}",
+        catalog_name.replace('-', "_").to_uppercase(),
+        property.replace('.', "__").replace('-', "_").to_uppercase()
+    )
+}
+
+/// Shell lines that point the PyIceberg CLI at a saved catalog through environment variables,
+/// then list its namespaces. Secrets aren't copied: they reference a variable the user sets.
+pub fn pyiceberg_cli_command(config: &CatalogConfig) -> String {
+    let name = pyiceberg_catalog_name(&config.name);
+    let value = |key: &str| {
+        config
+            .config
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+
+    let (catalog_type, property_keys): (&str, &[(&str, &str)]) = match config.catalog_type {
+        CatalogType::Rest => (
+            "rest",
+            &[
+                ("uri", "uri"),
+                ("warehouse", "warehouse"),
+                ("auth_token", "token"),
+            ],
+        ),
+        CatalogType::Glue => (
+            "glue",
+            &[
+                ("warehouse", "warehouse"),
+                ("region", "glue.region"),
+                ("profile", "glue.profile-name"),
+                ("endpoint_url", "glue.endpoint"),
+            ],
+        ),
+    };
+    let mut properties = vec![("type", Some(catalog_type.to_string()))];
+    for (key, property) in property_keys {
+        if let Some(value) = value(key) {
+            let value = (!is_secret_config_key(key)).then(|| value.to_string());
+            properties.push((property, value));
+        }
+    }
+    let s3 = S3StorageOptions::from_catalog_config(config);
+    for (property, value) in [("s3.endpoint", &s3.endpoint), ("s3.region", &s3.region)] {
+        if !value.trim().is_empty() {
+            properties.push((property, Some(value.trim().to_string())));
+        }
+    }
+
+    let mut command = format!(
+        "# PyIceberg CLI connection for catalog \"{}\"\n",
+        config.name
+    );
+    for (property, value) in properties {
+        let env_var = pyiceberg_env_var(&name, property);
+        match value {
+            Some(value) => {
+                command.push_str(&format!("export {}={}\n", env_var, shell_quote(&value)))
+            }
+            None => {
+                let secret_var = format!(
+                    "{}_{}",
+                    name.replace('-', "_").to_uppercase(),
+                    property.replace(['.', '-'], "_").to_uppercase()
+                );
+                command.push_str(&format!(
+                    "export {}=\"${}\"  # set {} first\n",
+                    env_var, secret_var, secret_var
+                ));
+            }
+        }
+    }
+    command.push_str(&format!("pyiceberg --catalog {} list\n", name));
+    command
+}
+
+/// Snapshots kept by the generated `expire_snapshots` call, on top of each engine's age cut-off
+const EXPIRE_RETAIN_LAST: u32 = 5;
+
+/// Metadata versions kept by the metadata cleanup command
+const METADATA_PREVIOUS_VERSIONS_MAX: u32 = 10;
+
+/// Copy-only procedure call that carries out a maintenance recommendation in the chosen engine.
+/// Actions that need a human decision (schema or partition changes, write batching) have none.
+pub fn maintenance_command(
+    action: &MaintenanceActionType,
+    table: &IcebergTable,
+    dialect: SqlDialect,
+) -> Option<String> {
+    let qualified_name = qualified_table_name(table, dialect);
+    // Spark procedures take the table as a string relative to the catalog
+    let spark_procedure = |procedure: &str, extra_args: &str| {
+        format!(
+            "CALL {}.system.{}(table => {}{});",
+            quote_identifier(&table.catalog_name, dialect),
+            procedure,
+            quote_string(&format!("{}.{}", table.namespace, table.name), dialect),
+            extra_args
+        )
+    };
+
+    match (action, dialect) {
+        (MaintenanceActionType::Compaction, SqlDialect::Spark) => {
+            Some(spark_procedure("rewrite_data_files", ""))
+        }
+        (MaintenanceActionType::Compaction, SqlDialect::Trino) => {
+            Some(format!("ALTER TABLE {} EXECUTE optimize;", qualified_name))
+        }
+        (MaintenanceActionType::RetentionPolicy, SqlDialect::Spark) => Some(spark_procedure(
+            "expire_snapshots",
+            &format!(", retain_last => {}", EXPIRE_RETAIN_LAST),
+        )),
+        (MaintenanceActionType::RetentionPolicy, SqlDialect::Trino) => Some(format!(
+            "ALTER TABLE {} EXECUTE expire_snapshots(retention_threshold => '7d');",
+            qualified_name
+        )),
+        (MaintenanceActionType::MetadataCleanup, SqlDialect::Spark) => Some(format!(
+            "ALTER TABLE {} SET TBLPROPERTIES (\n  'write.metadata.delete-after-commit.enabled' = 'true',\n  'write.metadata.previous-versions-max' = '{}'\n);\n{}",
+            qualified_name,
+            METADATA_PREVIOUS_VERSIONS_MAX,
+            spark_procedure(
+                "expire_snapshots",
+                &format!(", retain_last => {}", EXPIRE_RETAIN_LAST)
+            )
+        )),
+        // Trino can't set write.metadata.* properties, so only the expiration half applies
+        (MaintenanceActionType::MetadataCleanup, SqlDialect::Trino) => Some(format!(
+            "-- Set write.metadata.previous-versions-max from Spark; Trino can't change it\nALTER TABLE {} EXECUTE expire_snapshots(retention_threshold => '7d');",
+            qualified_name
+        )),
+        (MaintenanceActionType::ComputeStatistics, SqlDialect::Spark) => {
+            Some(spark_procedure("compute_table_stats", ""))
+        }
+        (MaintenanceActionType::ComputeStatistics, SqlDialect::Trino) => {
+            Some(format!("ANALYZE {};", qualified_name))
+        }
+        (
+            MaintenanceActionType::Optimization
+            | MaintenanceActionType::PartitionEvolution
+            | MaintenanceActionType::SchemaEvolution,
+            _,
+        ) => None,
+    }
+}
+
+/// Operations that always get their own lane in the timeline image, in display order
+const TIMELINE_OPERATIONS: [&str; 4] = ["append", "overwrite", "delete", "replace"];
+
+/// Marker color per operation, matching the badges in the snapshot timeline
+fn operation_color(operation: &str) -> &'static str {
+    match operation {
+        "append" => "#16a34a",
+        "overwrite" => "#ca8a04",
+        "delete" => "#dc2626",
+        _ => "#6b7280",
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Render snapshots as an SVG timeline: one lane per operation, a marker per snapshot and a
+/// UTC time axis. Operations beyond the standard four get lanes of their own after them.
+pub fn snapshot_timeline_svg(title: &str, snapshots: &[Snapshot]) -> String {
+    const WIDTH: f64 = 960.0;
+    const LEFT: f64 = 110.0;
+    const RIGHT: f64 = 30.0;
+    const TOP: f64 = 50.0;
+    const LANE_HEIGHT: f64 = 36.0;
+    const AXIS_HEIGHT: f64 = 40.0;
+    const TICKS: i64 = 5;
+
+    let mut lanes: Vec<String> = TIMELINE_OPERATIONS
+        .iter()
+        .map(|op| op.to_string())
+        .collect();
+    let mut extra_lanes: Vec<String> = snapshots
+        .iter()
+        .map(Snapshot::operation)
+        .filter(|operation| !lanes.contains(operation))
+        .collect();
+    extra_lanes.sort();
+    extra_lanes.dedup();
+    lanes.extend(extra_lanes);
+
+    let height = TOP + LANE_HEIGHT * lanes.len() as f64 + AXIS_HEIGHT;
+    let plot_width = WIDTH - LEFT - RIGHT;
+    let axis_y = TOP + LANE_HEIGHT * lanes.len() as f64;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"12\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n\
+         <text x=\"{LEFT}\" y=\"28\" font-size=\"16\" font-weight=\"bold\" fill=\"#111827\">{title}</text>\n",
+        w = WIDTH,
+        h = height,
+        title = escape_xml(title)
+    );
+
+    for (index, lane) in lanes.iter().enumerate() {
+        let y = TOP + LANE_HEIGHT * (index as f64 + 0.5);
+        svg.push_str(&format!(
+            "<line x1=\"{LEFT}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"#e5e7eb\"/>\n\
+             <text x=\"{label_x}\" y=\"{label_y}\" text-anchor=\"end\" fill=\"#374151\">{lane}</text>\n",
+            x2 = WIDTH - RIGHT,
+            label_x = LEFT - 10.0,
+            label_y = y + 4.0,
+            lane = escape_xml(lane)
+        ));
+    }
+
+    let Some(first) = snapshots.iter().map(|s| s.timestamp_ms).min() else {
+        svg.push_str(&format!(
+            "<text x=\"{x}\" y=\"{y}\" text-anchor=\"middle\" fill=\"#6b7280\">No snapshots</text>\n</svg>\n",
+            x = LEFT + plot_width / 2.0,
+            y = axis_y - LANE_HEIGHT / 2.0
+        ));
+        return svg;
+    };
+    let last = snapshots
+        .iter()
+        .map(|s| s.timestamp_ms)
+        .max()
+        .unwrap_or(first);
+    // A single instant still needs a span to place it in; pad by an hour either side
+    let (start, end) = if last > first {
+        (first, last)
+    } else {
+        (first - 3_600_000, last + 3_600_000)
+    };
+    let x_for = |timestamp_ms: i64| {
+        LEFT + (timestamp_ms - start) as f64 / (end - start) as f64 * plot_width
+    };
+
+    svg.push_str(&format!(
+        "<line x1=\"{LEFT}\" y1=\"{axis_y}\" x2=\"{x2}\" y2=\"{axis_y}\" stroke=\"#9ca3af\"/>\n",
+        x2 = WIDTH - RIGHT
+    ));
+    let tick_format = if end - start > 2 * 86_400_000 {
+        "%Y-%m-%d"
+    } else {
+        "%m-%d %H:%M"
+    };
+    for tick in 0..TICKS {
+        let timestamp_ms = start + (end - start) * tick / (TICKS - 1);
+        let x = x_for(timestamp_ms);
+        let label = DateTime::from_timestamp_millis(timestamp_ms)
+            .map(|time| time.format(tick_format).to_string())
+            .unwrap_or_default();
+        svg.push_str(&format!(
+            "<line x1=\"{x:.1}\" y1=\"{axis_y}\" x2=\"{x:.1}\" y2=\"{tick_end}\" stroke=\"#9ca3af\"/>\n\
+             <text x=\"{x:.1}\" y=\"{label_y}\" text-anchor=\"middle\" fill=\"#6b7280\">{label}</text>\n",
+            tick_end = axis_y + 5.0,
+            label_y = axis_y + 20.0
+        ));
+    }
+    svg.push_str(&format!(
+        "<text x=\"{x}\" y=\"{y}\" text-anchor=\"end\" fill=\"#9ca3af\" font-size=\"10\">UTC</text>\n",
+        x = WIDTH - RIGHT,
+        y = axis_y + 34.0
+    ));
+
+    for snapshot in snapshots {
+        let operation = snapshot.operation();
+        let lane = lanes.iter().position(|l| *l == operation).unwrap_or(0);
+        svg.push_str(&format!(
+            "<circle cx=\"{cx:.1}\" cy=\"{cy}\" r=\"5\" fill=\"{color}\" fill-opacity=\"0.8\"><title>{id} ({operation}) {time}</title></circle>\n",
+            cx = x_for(snapshot.timestamp_ms),
+            cy = TOP + LANE_HEIGHT * (lane as f64 + 0.5),
+            color = operation_color(&operation),
+            id = snapshot.snapshot_id,
+            operation = escape_xml(&operation),
+            time = snapshot.timestamp().format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{PartitionField, Summary};
+    use std::collections::HashMap;
+
+    fn snapshot(snapshot_id: u64, timestamp_ms: i64) -> Snapshot {
+        Snapshot {
+            snapshot_id,
+            timestamp_ms,
+            summary: Some(Summary {
+                operation: "append".to_string(),
+                added_data_files: Some("1".to_string()),
+                deleted_data_files: None,
+                added_records: Some("10".to_string()),
+                deleted_records: None,
+                total_records: None,
+                added_files_size: None,
+                removed_files_size: None,
+                total_size: None,
+                properties: BTreeMap::new(),
+            }),
+            manifest_list: format!("s3://bucket/metadata/snap-{}.avro", snapshot_id),
+            schema_id: Some(0),
+            parent_snapshot_id: None,
+        }
+    }
+
+    fn sample_table() -> IcebergTable {
+        let schema = TableSchema {
+            schema_id: 0,
+            fields: vec![NestedField {
+                id: 1,
+                name: "id".to_string(),
+                required: true,
+                field_type: DataType::Long,
+                doc: None,
+                initial_default: None,
+                write_default: None,
+            }],
+        };
+        let mut properties = HashMap::new();
+        properties.insert("write.format.default".to_string(), "parquet".to_string());
+        properties.insert("owner".to_string(), "data-team".to_string());
+
+        IcebergTable {
+            name: "orders".to_string(),
+            namespace: "sales".to_string(),
+            catalog_name: "prod".to_string(),
+            location: "s3://bucket/sales/orders".to_string(),
+            schema: schema.clone(),
+            schemas: vec![schema],
+            snapshots: vec![snapshot(2, 2_000), snapshot(1, 1_000)],
+            current_snapshot_id: Some(2),
+            properties,
+            partition_spec: None,
+            partition_specs: vec![],
+            sort_order: None,
+            refs: vec![],
+            statistics: vec![],
+            partition_statistics: vec![],
+            format_version: Some(2),
+            omitted_snapshots: 0,
+            metadata_size_bytes: None,
+            snapshot_log_entries: 0,
+        }
+    }
+
+    #[test]
+    fn test_table_report_contents() {
+        let table = sample_table();
+        let report = TableReport::from_table(&table, Utc::now());
+
+        assert_eq!(report.format_version, TABLE_REPORT_FORMAT_VERSION);
+        assert_eq!(report.catalog, "prod");
+        assert_eq!(report.file_name(), "sales.orders-report.json");
+        assert_eq!(
+            report
+                .snapshots
+                .iter()
+                .map(|s| s.snapshot_id)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            report.properties.keys().collect::<Vec<_>>(),
+            vec!["owner", "write.format.default"]
+        );
+    }
+
+    #[test]
+    fn test_table_report_json_layout() {
+        let report = TableReport::from_table(&sample_table(), Utc::now());
+        let json = report.to_json().unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        for key in [
+            "format_version",
+            "current_schema",
+            "schemas",
+            "partition_specs",
+            "sort_order",
+            "properties",
+            "snapshots",
+            "health",
+        ] {
+            assert!(value.get(key).is_some(), "missing key {}", key);
+        }
+
+        assert_eq!(value["format_version"], TABLE_REPORT_FORMAT_VERSION);
+        assert_eq!(value["snapshots"][0]["snapshot_id"], 1);
+        assert_eq!(value["current_schema"]["fields"][0]["name"], "id");
+    }
+
+    fn partitioned_table() -> IcebergTable {
+        let mut table = sample_table();
+        table.schema.fields.push(NestedField {
+            id: 2,
+            name: "created at".to_string(),
+            required: false,
+            field_type: DataType::TimestampTz,
+            doc: Some("When it's placed".to_string()),
+            initial_default: None,
+            write_default: None,
+        });
+        table.partition_spec = Some(PartitionSpec {
+            spec_id: 0,
+            fields: vec![
+                PartitionField {
+                    source_id: 2,
+                    field_id: 1000,
+                    name: "created_at_day".to_string(),
+                    transform: PartitionTransform::Day,
+                },
+                PartitionField {
+                    source_id: 1,
+                    field_id: 1001,
+                    name: "id_bucket".to_string(),
+                    transform: PartitionTransform::Bucket { num_buckets: 16 },
+                },
+            ],
+        });
+        table
+    }
+
+    #[test]
+    fn test_sql_type_mapping() {
+        let nested = DataType::Map {
+            key: Box::new(DataType::String),
+            value: Box::new(DataType::List {
+                element: Box::new(DataType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                }),
+            }),
+        };
+        assert_eq!(
+            sql_type(&nested, SqlDialect::Spark),
+            "MAP<STRING, ARRAY<DECIMAL(10, 2)>>"
+        );
+        assert_eq!(
+            sql_type(&nested, SqlDialect::Trino),
+            "map(varchar, array(decimal(10, 2)))"
+        );
+
+        let row = DataType::Struct {
+            fields: vec![NestedField {
+                id: 3,
+                name: "lat".to_string(),
+                required: false,
+                field_type: DataType::Double,
+                doc: None,
+                initial_default: None,
+                write_default: None,
+            }],
+        };
+        assert_eq!(sql_type(&row, SqlDialect::Spark), "STRUCT<lat: DOUBLE>");
+        assert_eq!(sql_type(&row, SqlDialect::Trino), "row(lat double)");
+        assert_eq!(sql_type(&DataType::Uuid, SqlDialect::Trino), "uuid");
+        assert_eq!(sql_type(&DataType::Time, SqlDialect::Spark), "STRING");
+    }
+
+    #[test]
+    fn test_spark_ddl() {
+        let ddl = generate_spark_ddl(&partitioned_table());
+        assert_eq!(
+            ddl,
+            "CREATE TABLE prod.sales.orders (\n  id BIGINT NOT NULL,\n  `created at` TIMESTAMP COMMENT 'When it\\'s placed'\n)\nUSING iceberg\nPARTITIONED BY (days(`created at`), bucket(16, id))\nLOCATION 's3://bucket/sales/orders'\nTBLPROPERTIES (\n  'owner' = 'data-team',\n  'write.format.default' = 'parquet'\n);"
+        );
+    }
+
+    #[test]
+    fn test_partition_spec_formats() {
+        let table = partitioned_table();
+        assert_eq!(
+            PartitionSpecFormat::Readable.render(&table).as_deref(),
+            Some("days(`created at`), bucket(16, id)")
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&PartitionSpecFormat::IcebergJson.render(&table).unwrap())
+                .unwrap();
+        assert_eq!(json["spec-id"], 0);
+        assert_eq!(json["fields"][0]["name"], "created_at_day");
+        assert_eq!(json["fields"][0]["transform"], "day");
+        assert_eq!(json["fields"][0]["source-id"], 2);
+        assert_eq!(json["fields"][1]["transform"], "bucket[16]");
+        assert_eq!(json["fields"][1]["field-id"], 1001);
+
+        assert_eq!(PartitionSpecFormat::Readable.render(&sample_table()), None);
+    }
+
+    #[test]
+    fn test_trino_ddl() {
+        let ddl = generate_trino_ddl(&partitioned_table());
+        assert_eq!(
+            ddl,
+            "CREATE TABLE prod.sales.orders (\n  id bigint NOT NULL,\n  \"created at\" timestamp(6) with time zone COMMENT 'When it''s placed'\n)\nWITH (\n  format = 'PARQUET',\n  partitioning = ARRAY['day(\"created at\")', 'bucket(id, 16)'],\n  location = 's3://bucket/sales/orders'\n);"
+        );
+
+        let unpartitioned = generate_trino_ddl(&sample_table());
+        assert!(!unpartitioned.contains("partitioning"));
+    }
+
+    #[test]
+    fn test_maintenance_command() {
+        let table = partitioned_table();
+        assert_eq!(
+            maintenance_command(
+                &MaintenanceActionType::Compaction,
+                &table,
+                SqlDialect::Spark
+            )
+            .unwrap(),
+            "CALL prod.system.rewrite_data_files(table => 'sales.orders');"
+        );
+        assert_eq!(
+            maintenance_command(
+                &MaintenanceActionType::Compaction,
+                &table,
+                SqlDialect::Trino
+            )
+            .unwrap(),
+            "ALTER TABLE prod.sales.orders EXECUTE optimize;"
+        );
+        assert_eq!(
+            maintenance_command(
+                &MaintenanceActionType::RetentionPolicy,
+                &table,
+                SqlDialect::Spark
+            )
+            .unwrap(),
+            "CALL prod.system.expire_snapshots(table => 'sales.orders', retain_last => 5);"
+        );
+        assert_eq!(
+            maintenance_command(
+                &MaintenanceActionType::ComputeStatistics,
+                &table,
+                SqlDialect::Trino
+            )
+            .unwrap(),
+            "ANALYZE prod.sales.orders;"
+        );
+        assert_eq!(
+            maintenance_command(
+                &MaintenanceActionType::MetadataCleanup,
+                &table,
+                SqlDialect::Spark
+            )
+            .unwrap(),
+            "ALTER TABLE prod.sales.orders SET TBLPROPERTIES (\n  'write.metadata.delete-after-commit.enabled' = 'true',\n  'write.metadata.previous-versions-max' = '10'\n);\nCALL prod.system.expire_snapshots(table => 'sales.orders', retain_last => 5);"
+        );
+        assert!(
+            maintenance_command(
+                &MaintenanceActionType::Optimization,
+                &table,
+                SqlDialect::Trino
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_snapshot_timeline_svg() {
+        let mut deleted = snapshot(3, 1_700_000_200_000);
+        deleted.summary.as_mut().unwrap().operation = "delete".to_string();
+        let mut unknown = snapshot(4, 1_700_000_300_000);
+        unknown.summary = None;
+        let snapshots = vec![
+            snapshot(1, 1_700_000_000_000),
+            snapshot(2, 1_700_000_100_000),
+            deleted,
+            unknown,
+        ];
+
+        let svg = snapshot_timeline_svg("sales.orders <prod>", &snapshots);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<circle").count(), 4);
+        assert!(svg.contains("sales.orders &lt;prod&gt;"));
+        // Standard lanes always appear, unusual operations get their own
+        assert!(svg.contains(">overwrite</text>"));
+        assert!(svg.contains(">unknown</text>"));
+        assert!(svg.contains("fill=\"#dc2626\""));
+
+        let empty = snapshot_timeline_svg("empty", &[]);
+        assert!(empty.contains("No snapshots"));
+        assert_eq!(empty.matches("<circle").count(), 0);
+    }
+
+    #[test]
+    fn test_dbt_source_yaml() {
+        let mut table = sample_table();
+        assert_eq!(
+            dbt_source_yaml(&table),
+            "version: 2\n\nsources:\n  - name: sales\n    schema: sales\n    tables:\n      - name: orders\n        # description: \"\"\n"
+        );
+
+        table.namespace = "analytics.core".to_string();
+        table.properties.insert(
+            "comment".to_string(),
+            "Orders with \"net\" totals".to_string(),
+        );
+        let yaml = dbt_source_yaml(&table);
+        assert!(yaml.contains("  - name: analytics_core\n    schema: \"analytics.core\"\n"));
+        assert!(yaml.contains("        description: \"Orders with \\\"net\\\" totals\"\n"));
+    }
+
+    #[test]
+    fn test_catalog_diff_markdown() {
+        let entry = |namespace: &str, table_name: &str, status| CatalogDiffEntry {
+            namespace: namespace.to_string(),
+            table_name: table_name.to_string(),
+            status,
+        };
+        let entries = vec![
+            entry("sales", "orders", CatalogDiffStatus::Matches),
+            entry("sales", "returns", CatalogDiffStatus::OnlyInTarget),
+            entry(
+                "sales",
+                "customers",
+                CatalogDiffStatus::Differs(vec![crate::data::MetadataChange {
+                    kind: crate::data::MetadataChangeKind::Schema,
+                    description: "Column `email` is missing from the target".to_string(),
+                }]),
+            ),
+            entry("audit", "events", CatalogDiffStatus::OnlyInSource),
+            entry(
+                "audit",
+                "log",
+                CatalogDiffStatus::Failed("timeout\nretrying".to_string()),
+            ),
+        ];
+
+        let markdown = catalog_diff_markdown("staging", "prod", None, &entries);
+        assert!(
+            markdown.starts_with("# Catalog comparison: staging → prod\n\nScope: all namespaces\n")
+        );
+        assert!(markdown.contains("| Matching | 1 |\n| Different | 1 |\n| Only in staging | 1 |\n| Only in prod | 1 |\n| Not compared | 1 |\n"));
+        assert!(markdown.contains("## Missing from prod\n\n- `audit.events`\n"));
+        assert!(markdown.contains("## Missing from staging\n\n- `sales.returns`\n"));
+        assert!(markdown.contains(
+            "## Different\n\n- `sales.customers`\n  - Schema: Column `email` is missing from the target\n"
+        ));
+        assert!(markdown.contains("## Not compared\n\n- `audit.log`: timeout retrying\n"));
+
+        // Sections with nothing in them are left out
+        let matching = catalog_diff_markdown("staging", "prod", Some("sales"), &entries[..1]);
+        assert!(matching.contains("Scope: namespace `sales`"));
+        assert!(!matching.contains("## "));
+        assert_eq!(
+            catalog_diff_file_name("staging", "prod", Some("sales")),
+            "staging-vs-prod.sales-diff.md"
+        );
+    }
+
+    #[test]
+    fn test_schema_markdown() {
+        let mut table = sample_table();
+        table.schema.fields.push(NestedField {
+            id: 2,
+            name: "address".to_string(),
+            required: false,
+            field_type: DataType::Struct {
+                fields: vec![NestedField {
+                    id: 3,
+                    name: "city".to_string(),
+                    required: false,
+                    field_type: DataType::String,
+                    doc: Some("City | town\nname".to_string()),
+                    initial_default: None,
+                    write_default: None,
+                }],
+            },
+            doc: None,
+            initial_default: None,
+            write_default: None,
+        });
+
+        let markdown = schema_markdown(&table);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines[0], "### sales.orders (schema 0)");
+        assert_eq!(lines[2], "| ID | Name | Type | Required | Description |");
+        assert_eq!(lines[4], "| 1 | `id` | `long` | yes |  |");
+        assert!(lines[5].starts_with("| 2 | `address` | `struct"));
+        assert_eq!(
+            lines[6],
+            "| 3 | &nbsp;&nbsp;`address.city` | `string` | no | City \\| town<br>name |"
+        );
+        assert_eq!(schema_markdown_file_name(&table), "sales.orders-schema.md");
+    }
+
+    fn nested_sample_table() -> IcebergTable {
+        let mut table = sample_table();
+        let field = |id: i32, name: &str, required: bool, field_type: DataType| NestedField {
+            id,
+            name: name.to_string(),
+            required,
+            field_type,
+            doc: None,
+            initial_default: None,
+            write_default: None,
+        };
+        table.schema.fields.extend([
+            field(
+                2,
+                "price",
+                false,
+                DataType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                },
+            ),
+            field(
+                3,
+                "tags",
+                false,
+                DataType::List {
+                    element: Box::new(DataType::String),
+                },
+            ),
+            field(
+                4,
+                "counts",
+                true,
+                DataType::Map {
+                    key: Box::new(DataType::Integer),
+                    value: Box::new(DataType::Long),
+                },
+            ),
+            field(
+                5,
+                "2nd address",
+                false,
+                DataType::Struct {
+                    fields: vec![field(6, "city", true, DataType::String)],
+                },
+            ),
+        ]);
+        table
+    }
+
+    #[test]
+    fn test_avro_schema() {
+        let table = nested_sample_table();
+        let avro: serde_json::Value = serde_json::from_str(&avro_schema(&table)).unwrap();
+        assert_eq!(avro["type"], "record");
+        assert_eq!(avro["name"], "orders");
+
+        let fields = avro["fields"].as_array().unwrap();
+        assert_eq!(fields[0]["name"], "id");
+        assert_eq!(fields[0]["type"], "long");
+        assert_eq!(fields[0]["field-id"], 1);
+
+        assert_eq!(fields[1]["type"][0], "null");
+        assert_eq!(fields[1]["type"][1]["logicalType"], "decimal");
+        assert_eq!(fields[1]["type"][1]["size"], 5);
+        assert_eq!(fields[1]["default"], serde_json::Value::Null);
+
+        assert_eq!(fields[2]["type"][1]["type"], "array");
+        assert_eq!(fields[2]["type"][1]["items"][1], "string");
+
+        // Non-string keys: an array of key/value records
+        assert_eq!(fields[3]["type"]["logicalType"], "map");
+        assert_eq!(fields[3]["type"]["items"]["fields"][0]["type"], "int");
+
+        assert_eq!(fields[4]["name"], "_2nd_x20address");
+        assert_eq!(fields[4]["type"][1]["name"], "r5");
+        assert_eq!(fields[4]["type"][1]["fields"][0]["type"], "string");
+
+        assert_eq!(avro_schema_file_name(&table), "sales.orders.avsc");
+    }
+
+    #[test]
+    fn test_arrow_schema_json() {
+        let table = nested_sample_table();
+        let arrow: serde_json::Value = serde_json::from_str(&arrow_schema_json(&table)).unwrap();
+        let fields = arrow["fields"].as_array().unwrap();
+
+        assert_eq!(fields[0]["name"], "id");
+        assert_eq!(fields[0]["nullable"], false);
+        assert_eq!(fields[0]["type"]["bitWidth"], 64);
+        assert_eq!(fields[0]["metadata"][0]["value"], "1");
+
+        assert_eq!(fields[1]["type"]["name"], "decimal");
+        assert_eq!(fields[1]["nullable"], true);
+
+        assert_eq!(fields[2]["type"]["name"], "list");
+        assert_eq!(fields[2]["children"][0]["type"]["name"], "utf8");
+
+        let entries = &fields[3]["children"][0];
+        assert_eq!(entries["name"], "entries");
+        assert_eq!(entries["children"][0]["nullable"], false);
+        assert_eq!(entries["children"][1]["type"]["bitWidth"], 64);
+
+        assert_eq!(fields[4]["name"], "2nd address");
+        assert_eq!(fields[4]["children"][0]["metadata"][0]["value"], "6");
+    }
+
+    #[test]
+    fn test_render_namespace() {
+        let tables = vec![
+            ("returns".to_string(), Err("forbidden".to_string())),
+            ("orders".to_string(), Ok(nested_sample_table())),
+        ];
+
+        let markdown = SchemaFormat::Markdown.render_namespace("prod", "sales", &tables);
+        assert!(markdown.starts_with("# prod.sales\n\n2 table(s), 1 could not be loaded\n"));
+        let orders = markdown.find("### sales.orders (schema").unwrap();
+        let returns = markdown
+            .find("### sales.returns\n\n> Could not load this table: forbidden\n")
+            .unwrap();
+        assert!(orders < returns);
+
+        let avro: serde_json::Value =
+            serde_json::from_str(&SchemaFormat::Avro.render_namespace("prod", "sales", &tables))
+                .unwrap();
+        assert_eq!(avro[0]["table"], "sales.orders");
+        assert_eq!(avro[0]["schema"]["type"], "record");
+        assert_eq!(avro[1]["table"], "sales.returns");
+        assert_eq!(avro[1]["error"], "forbidden");
+
+        assert_eq!(
+            SchemaFormat::Arrow.namespace_file_name("prod", "sales"),
+            "prod.sales-arrow-schemas.json"
+        );
+    }
+
+    #[test]
+    fn test_pyiceberg_cli_command() {
+        let mut config = CatalogConfig::new_rest(
+            "Prod EU".to_string(),
+            "https://catalog.example.com".to_string(),
+        );
+        config
+            .config
+            .insert("auth_token".to_string(), "secret-token".to_string());
+        config
+            .config
+            .insert("warehouse".to_string(), "it's-mine".to_string());
+
+        let command = pyiceberg_cli_command(&config);
+        assert!(!command.contains("secret-token"));
+        assert!(command.contains("export PYICEBERG_CATALOG__PROD_EU__TYPE='rest'\n"));
+        assert!(
+            command
+                .contains("export PYICEBERG_CATALOG__PROD_EU__URI='https://catalog.example.com'\n")
+        );
+        assert!(command.contains("export PYICEBERG_CATALOG__PROD_EU__WAREHOUSE='it'\\''s-mine'\n"));
+        assert!(command.contains(
+            "export PYICEBERG_CATALOG__PROD_EU__TOKEN=\"$PROD_EU_TOKEN\"  # set PROD_EU_TOKEN first\n"
+        ));
+        assert!(command.ends_with("pyiceberg --catalog prod-eu list\n"));
+
+        let mut glue = CatalogConfig::new_glue(
+            "lake".to_string(),
+            "s3://lake/warehouse".to_string(),
+            Some("eu-west-1".to_string()),
+        );
+        glue.config.insert(
+            crate::catalog::S3_ENDPOINT_KEY.to_string(),
+            "http://minio:9000".to_string(),
+        );
+        let command = pyiceberg_cli_command(&glue);
+        assert!(command.contains("export PYICEBERG_CATALOG__LAKE__GLUE__REGION='eu-west-1'\n"));
+        assert!(
+            command.contains("export PYICEBERG_CATALOG__LAKE__S3__ENDPOINT='http://minio:9000'\n")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_local_directory() {
+        assert_eq!(
+            local_directory("file:///tmp/warehouse/db/orders"),
+            Some(PathBuf::from("/tmp/warehouse/db/orders"))
+        );
+        assert_eq!(
+            local_directory("file:/tmp/warehouse/my%20table"),
+            Some(PathBuf::from("/tmp/warehouse/my table"))
+        );
+        assert_eq!(local_directory("s3://lake/warehouse/db/orders"), None);
+        assert_eq!(local_directory("/tmp/warehouse/db/orders"), None);
+    }
+}
@@ -0,0 +1,318 @@
+//! Read-only Glue catalog over an AWS SDK config Hielo builds itself.
+//!
+//! `iceberg_catalog_glue::GlueCatalog` creates its SDK client internally, leaving no way to
+//! hand it an HTTP client. Catalogs with a proxy set in Hielo use this instead, so the proxy
+//! lives on the client's own connector rather than in process-wide environment variables.
+//! Table files are still read through `FileIO`, which doesn't use that proxy.
+
+use async_trait::async_trait;
+use aws_sdk_glue::config::{Region, SharedHttpClient};
+use aws_smithy_http_client::proxy::ProxyConfig;
+use aws_smithy_http_client::tls::{self, rustls_provider::CryptoMode};
+use aws_smithy_http_client::{Builder as HttpClientBuilder, Connector};
+use iceberg::io::FileIO;
+use iceberg::spec::TableMetadata;
+use iceberg::table::Table;
+use iceberg::{
+    Catalog, Error, ErrorKind, Namespace, NamespaceIdent, Result, TableCommit, TableCreation,
+    TableIdent,
+};
+use std::collections::HashMap;
+
+use crate::config::ProxySettings;
+
+/// Glue table parameter pointing at the table's current metadata file
+const METADATA_LOCATION_PARAM: &str = "metadata_location";
+
+#[derive(Debug)]
+pub struct ReadOnlyGlueCatalog {
+    client: aws_sdk_glue::Client,
+    file_io: FileIO,
+}
+
+/// How to reach Glue and the warehouse behind it
+pub struct GlueConnectOptions<'a> {
+    pub warehouse: &'a str,
+    pub region: String,
+    pub profile: Option<&'a str>,
+    pub endpoint_url: Option<&'a str>,
+    pub file_io_props: HashMap<String, String>,
+}
+
+impl ReadOnlyGlueCatalog {
+    pub async fn new(options: GlueConnectOptions<'_>, proxy: &ProxySettings) -> Result<Self> {
+        let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(Region::new(options.region))
+            .http_client(proxied_http_client(proxy)?);
+        if let Some(profile) = options.profile {
+            loader = loader.profile_name(profile);
+        }
+        if let Some(endpoint) = options.endpoint_url {
+            loader = loader.endpoint_url(endpoint);
+        }
+        let sdk_config = loader.load().await;
+
+        let file_io = FileIO::from_path(options.warehouse)?
+            .with_props(options.file_io_props)
+            .build()?;
+
+        Ok(Self {
+            client: aws_sdk_glue::Client::new(&sdk_config),
+            file_io,
+        })
+    }
+
+    /// Glue database name for a namespace; Glue databases are flat
+    fn database_name(namespace: &NamespaceIdent) -> Result<String> {
+        match namespace.clone().inner().as_slice() {
+            [name] => Ok(name.clone()),
+            _ => Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Glue databases are not nested: {:?}", namespace),
+            )),
+        }
+    }
+}
+
+/// HTTP client for the AWS SDK that routes every request through the proxy
+fn proxied_http_client(proxy: &ProxySettings) -> Result<SharedHttpClient> {
+    let mut proxy_config = ProxyConfig::all(proxy.url.as_str()).map_err(|e| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Invalid proxy URL '{}'", proxy.url),
+        )
+        .with_source(e)
+    })?;
+    if !proxy.username.is_empty() {
+        proxy_config = proxy_config.with_basic_auth(&proxy.username, &proxy.password);
+    }
+    if !proxy.no_proxy.is_empty() {
+        proxy_config = proxy_config.no_proxy(&proxy.no_proxy);
+    }
+
+    Ok(
+        HttpClientBuilder::new().build_with_connector_fn(move |settings, components| {
+            let mut builder = Connector::builder().proxy_config(proxy_config.clone());
+            builder.set_connector_settings(settings.cloned());
+            if let Some(components) = components {
+                builder.set_sleep_impl(components.sleep_impl());
+            }
+            builder
+                .tls_provider(tls::Provider::Rustls(CryptoMode::AwsLc))
+                .build()
+        }),
+    )
+}
+
+fn glue_error(context: String, source: impl std::error::Error + Send + Sync + 'static) -> Error {
+    Error::new(ErrorKind::Unexpected, context).with_source(source)
+}
+
+fn read_only(operation: &str) -> Error {
+    Error::new(
+        ErrorKind::FeatureUnsupported,
+        format!(
+            "{} is not supported: Hielo only reads Glue catalogs",
+            operation
+        ),
+    )
+}
+
+#[async_trait]
+impl Catalog for ReadOnlyGlueCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        if parent.is_some() {
+            return Ok(Vec::new());
+        }
+
+        let mut namespaces = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = self
+                .client
+                .get_databases()
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| glue_error("Failed to list Glue databases".to_string(), e))?;
+            for database in response.database_list() {
+                namespaces.push(NamespaceIdent::new(database.name().to_string()));
+            }
+            next_token = response.next_token().map(str::to_string);
+            if next_token.is_none() {
+                return Ok(namespaces);
+            }
+        }
+    }
+
+    async fn create_namespace(
+        &self,
+        _namespace: &NamespaceIdent,
+        _properties: HashMap<String, String>,
+    ) -> Result<Namespace> {
+        Err(read_only("Creating a namespace"))
+    }
+
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
+        let database = Self::database_name(namespace)?;
+        let response = self
+            .client
+            .get_database()
+            .name(&database)
+            .send()
+            .await
+            .map_err(|e| glue_error(format!("Failed to get Glue database '{}'", database), e))?;
+        let properties = response
+            .database()
+            .and_then(|database| database.parameters())
+            .cloned()
+            .unwrap_or_default();
+        Ok(Namespace::with_properties(namespace.clone(), properties))
+    }
+
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
+        let database = Self::database_name(namespace)?;
+        match self.client.get_database().name(&database).send().await {
+            Ok(_) => Ok(true),
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|e| e.is_entity_not_found_exception()) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(glue_error(
+                format!("Failed to get Glue database '{}'", database),
+                e,
+            )),
+        }
+    }
+
+    async fn update_namespace(
+        &self,
+        _namespace: &NamespaceIdent,
+        _properties: HashMap<String, String>,
+    ) -> Result<()> {
+        Err(read_only("Updating a namespace"))
+    }
+
+    async fn drop_namespace(&self, _namespace: &NamespaceIdent) -> Result<()> {
+        Err(read_only("Dropping a namespace"))
+    }
+
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        let database = Self::database_name(namespace)?;
+        let mut tables = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = self
+                .client
+                .get_tables()
+                .database_name(&database)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| glue_error(format!("Failed to list tables in '{}'", database), e))?;
+            for table in response.table_list() {
+                tables.push(TableIdent::new(namespace.clone(), table.name().to_string()));
+            }
+            next_token = response.next_token().map(str::to_string);
+            if next_token.is_none() {
+                return Ok(tables);
+            }
+        }
+    }
+
+    async fn create_table(
+        &self,
+        _namespace: &NamespaceIdent,
+        _creation: TableCreation,
+    ) -> Result<Table> {
+        Err(read_only("Creating a table"))
+    }
+
+    async fn load_table(&self, table: &TableIdent) -> Result<Table> {
+        let database = Self::database_name(table.namespace())?;
+        let response = self
+            .client
+            .get_table()
+            .database_name(&database)
+            .name(table.name())
+            .send()
+            .await
+            .map_err(|e| glue_error(format!("Failed to get Glue table '{}'", table), e))?;
+        let metadata_location = response
+            .table()
+            .and_then(|glue_table| glue_table.parameters())
+            .and_then(|parameters| parameters.get(METADATA_LOCATION_PARAM))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Glue table '{}' is not an Iceberg table", table),
+                )
+            })?
+            .clone();
+
+        let content = self.file_io.new_input(&metadata_location)?.read().await?;
+        let metadata = serde_json::from_slice::<TableMetadata>(&content).map_err(|e| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid table metadata at '{}'", metadata_location),
+            )
+            .with_source(e)
+        })?;
+
+        Table::builder()
+            .file_io(self.file_io.clone())
+            .metadata_location(metadata_location)
+            .metadata(metadata)
+            .identifier(table.clone())
+            .build()
+    }
+
+    async fn drop_table(&self, _table: &TableIdent) -> Result<()> {
+        Err(read_only("Dropping a table"))
+    }
+
+    async fn table_exists(&self, table: &TableIdent) -> Result<bool> {
+        let database = Self::database_name(table.namespace())?;
+        match self
+            .client
+            .get_table()
+            .database_name(&database)
+            .name(table.name())
+            .send()
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|e| e.is_entity_not_found_exception()) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(glue_error(
+                format!("Failed to get Glue table '{}'", table),
+                e,
+            )),
+        }
+    }
+
+    async fn rename_table(&self, _src: &TableIdent, _dest: &TableIdent) -> Result<()> {
+        Err(read_only("Renaming a table"))
+    }
+
+    async fn register_table(
+        &self,
+        _table: &TableIdent,
+        _metadata_location: String,
+    ) -> Result<Table> {
+        Err(read_only("Registering a table"))
+    }
+
+    async fn update_table(&self, _commit: TableCommit) -> Result<Table> {
+        Err(read_only("Updating a table"))
+    }
+}
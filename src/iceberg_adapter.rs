@@ -1,20 +1,46 @@
 use crate::data::{
-    DataType, IcebergTable, NestedField, PartitionField, PartitionSpec, PartitionTransform,
-    Snapshot, Summary, TableSchema,
+    ColumnSizeStats, DataFileOrigin, DataType, IcebergTable, IcebergView, NestedField, NullOrder,
+    PartitionField, PartitionSpec, PartitionStatisticsFile, PartitionStats, PartitionTransform,
+    SchemaFileStats, Snapshot, SnapshotRef, SnapshotRefType, SortDirection, SortField, SortOrder,
+    StatisticsBlob, StatisticsFile, Summary, TableSchema, TableStats, ValidationCheck,
+    ValidationStatus, ViewRepresentation, ViewVersion,
 };
 use anyhow::Result;
-use iceberg::spec::{PartitionSpecRef, PrimitiveType, SchemaRef, Transform, Type};
+use iceberg::spec::{
+    Datum, Literal, ManifestContentType, Operation, PartitionSpecRef, PrimitiveLiteral,
+    PrimitiveType, SchemaRef, Struct, Transform, Type, ViewMetadata,
+};
 use iceberg::table::Table;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 
 /// Convert an iceberg-rust Table to our internal IcebergTable representation
 pub fn convert_iceberg_table(
     table: &Table,
     namespace: String,
     catalog_name: String,
+) -> Result<IcebergTable> {
+    convert_iceberg_table_windowed(table, namespace, catalog_name, None)
+}
+
+/// Like [`convert_iceberg_table`], but a table with more than `snapshot_limit` snapshots
+/// keeps only the newest ones, plus any the current state, a branch or a tag points at.
+/// The rest are counted in `omitted_snapshots`.
+pub fn convert_iceberg_table_windowed(
+    table: &Table,
+    namespace: String,
+    catalog_name: String,
+    snapshot_limit: Option<usize>,
 ) -> Result<IcebergTable> {
     let metadata = table.metadata();
 
+    // Serialized once: its size is reported, and refs are read from it since iceberg-rust
+    // doesn't expose them
+    let metadata_bytes = serde_json::to_vec(metadata).unwrap_or_default();
+    let metadata_json: serde_json::Value =
+        serde_json::from_slice(&metadata_bytes).unwrap_or_default();
+    let refs = convert_refs(&metadata_json);
+
     // Convert current schema
     let schema = convert_schema(metadata.current_schema())?;
 
@@ -24,9 +50,28 @@ pub fn convert_iceberg_table(
         .map(convert_schema)
         .collect::<Result<Vec<_>>>()?;
 
-    // Convert snapshots
-    let snapshots = metadata
-        .snapshots()
+    // Convert snapshots, windowed for tables with too many to show
+    let pinned_snapshots: std::collections::HashSet<u64> = refs
+        .iter()
+        .map(|reference| reference.snapshot_id)
+        .chain(metadata.current_snapshot().map(|s| s.snapshot_id() as u64))
+        .collect();
+    let (windowed, omitted_snapshots) = newest_window(
+        metadata.snapshots().collect(),
+        snapshot_limit,
+        |snapshot| snapshot.timestamp_ms(),
+        |snapshot| pinned_snapshots.contains(&(snapshot.snapshot_id() as u64)),
+    );
+    if omitted_snapshots > 0 {
+        log::info!(
+            "Showing {} of {} snapshots of {}",
+            windowed.len(),
+            windowed.len() + omitted_snapshots,
+            table.identifier()
+        );
+    }
+    let snapshots = windowed
+        .into_iter()
         .map(|snapshot| convert_snapshot(snapshot))
         .collect::<Result<Vec<_>>>()?;
 
@@ -49,6 +94,24 @@ pub fn convert_iceberg_table(
         .map(convert_partition_spec)
         .collect::<Result<Vec<_>>>()?;
 
+    // Convert the default sort order (order 0 means unsorted)
+    let sort_order = if metadata.default_sort_order().fields.is_empty() {
+        None
+    } else {
+        Some(convert_sort_order(metadata.default_sort_order())?)
+    };
+
+    let mut statistics: Vec<StatisticsFile> = metadata
+        .statistics_iter()
+        .map(convert_statistics_file)
+        .collect();
+    statistics.sort_by_key(|file| file.snapshot_id);
+    let mut partition_statistics: Vec<PartitionStatisticsFile> = metadata
+        .partition_statistics_iter()
+        .map(convert_partition_statistics_file)
+        .collect();
+    partition_statistics.sort_by_key(|file| file.snapshot_id);
+
     Ok(IcebergTable {
         name: table.identifier().name().to_string(),
         namespace,
@@ -61,6 +124,775 @@ pub fn convert_iceberg_table(
         properties,
         partition_spec,
         partition_specs,
+        sort_order,
+        refs,
+        statistics,
+        partition_statistics,
+        format_version: Some(metadata.format_version() as u8),
+        omitted_snapshots,
+        metadata_size_bytes: (!metadata_bytes.is_empty()).then_some(metadata_bytes.len() as u64),
+        snapshot_log_entries: metadata.history().len(),
+    })
+}
+
+/// Keep the newest `limit` items by timestamp, in their original order, along with any
+/// `always_keep` asks for. Returns the kept items and how many were left out.
+fn newest_window<T>(
+    items: Vec<T>,
+    limit: Option<usize>,
+    timestamp_ms: impl Fn(&T) -> i64,
+    always_keep: impl Fn(&T) -> bool,
+) -> (Vec<T>, usize) {
+    let Some(limit) = limit.filter(|limit| items.len() > *limit) else {
+        return (items, 0);
+    };
+    let mut newest_first: Vec<usize> = (0..items.len()).collect();
+    newest_first.sort_by_key(|&index| std::cmp::Reverse(timestamp_ms(&items[index])));
+    let mut keep = vec![false; items.len()];
+    for &index in newest_first.iter().take(limit) {
+        keep[index] = true;
+    }
+
+    let total = items.len();
+    let kept: Vec<T> = items
+        .into_iter()
+        .enumerate()
+        .filter(|(index, item)| keep[*index] || always_keep(item))
+        .map(|(_, item)| item)
+        .collect();
+    let omitted = total - kept.len();
+    (kept, omitted)
+}
+
+fn convert_statistics_file(file: &iceberg::spec::StatisticsFile) -> StatisticsFile {
+    StatisticsFile {
+        snapshot_id: file.snapshot_id as u64,
+        statistics_path: file.statistics_path.clone(),
+        file_size_in_bytes: file.file_size_in_bytes as u64,
+        blobs: file
+            .blob_metadata
+            .iter()
+            .map(|blob| StatisticsBlob {
+                blob_type: blob.r#type.clone(),
+                field_ids: blob.fields.clone(),
+                properties: blob
+                    .properties
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+fn convert_partition_statistics_file(
+    file: &iceberg::spec::PartitionStatisticsFile,
+) -> PartitionStatisticsFile {
+    PartitionStatisticsFile {
+        snapshot_id: file.snapshot_id as u64,
+        statistics_path: file.statistics_path.clone(),
+        file_size_in_bytes: file.file_size_in_bytes as u64,
+    }
+}
+
+/// Read branches and tags from the serialized metadata, which iceberg-rust doesn't expose as a list
+fn convert_refs(metadata: &serde_json::Value) -> Vec<SnapshotRef> {
+    let Some(refs) = metadata
+        .get("refs")
+        .and_then(|refs| refs.as_object())
+        .cloned()
+    else {
+        return Vec::new();
+    };
+
+    let mut refs: Vec<SnapshotRef> = refs
+        .into_iter()
+        .filter_map(|(name, reference)| {
+            let snapshot_id = reference.get("snapshot-id")?.as_i64()? as u64;
+            let ref_type = match reference.get("type")?.as_str()? {
+                "tag" => SnapshotRefType::Tag,
+                _ => SnapshotRefType::Branch,
+            };
+            Some(SnapshotRef {
+                name,
+                snapshot_id,
+                ref_type,
+            })
+        })
+        .collect();
+    refs.sort_by(|a, b| a.name.cmp(&b.name));
+    refs
+}
+
+/// Extract record count, data size and last-modified time from a table's current snapshot
+pub fn table_stats(table: &Table) -> TableStats {
+    let metadata = table.metadata();
+    let summary = metadata
+        .current_snapshot()
+        .map(|snapshot| &snapshot.summary().additional_properties);
+    let summary_value = |key: &str| {
+        summary
+            .and_then(|props| props.get(key))
+            .and_then(|value| value.parse::<u64>().ok())
+    };
+
+    TableStats {
+        total_records: summary_value("total-records"),
+        total_size_bytes: summary_value("total-files-size"),
+        last_updated_ms: metadata.last_updated_ms(),
+        latest_snapshot_ms: metadata
+            .snapshots()
+            .map(|snapshot| snapshot.timestamp_ms())
+            .max(),
+    }
+}
+
+/// Name the object that could not be read, since storage access can fail where the catalog
+/// itself succeeded. The iceberg error stays the source so callers can classify it; format
+/// with `{:#}` to include it in the message.
+fn manifest_read_error(kind: &str, path: &str, error: iceberg::Error) -> anyhow::Error {
+    anyhow::Error::new(error).context(format!("Could not read {} '{}'", kind, path))
+}
+
+/// Aggregate the current snapshot's live data files per partition by reading its manifests.
+/// Tables without a snapshot have no partitions to report.
+pub async fn partition_stats(table: &Table) -> Result<Vec<PartitionStats>> {
+    let metadata = table.metadata();
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(Vec::new());
+    };
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(|e| manifest_read_error("manifest list", snapshot.manifest_list(), e))?;
+
+    let mut by_partition: BTreeMap<(i32, String), PartitionStats> = BTreeMap::new();
+    for manifest_file in manifest_list.entries() {
+        if manifest_file.content != ManifestContentType::Data {
+            continue;
+        }
+        let spec = metadata.partition_spec_by_id(manifest_file.partition_spec_id);
+        let manifest = manifest_file
+            .load_manifest(table.file_io())
+            .await
+            .map_err(|e| manifest_read_error("manifest", &manifest_file.manifest_path, e))?;
+
+        for entry in manifest.entries() {
+            if !entry.is_alive() {
+                continue;
+            }
+            let data_file = entry.data_file();
+            let values = partition_field_values(spec, data_file.partition());
+            let partition = partition_path(&values);
+            let stats = by_partition
+                .entry((manifest_file.partition_spec_id, partition.clone()))
+                .or_insert_with(|| PartitionStats {
+                    partition,
+                    values,
+                    spec_id: manifest_file.partition_spec_id,
+                    file_count: 0,
+                    record_count: 0,
+                    total_size_bytes: 0,
+                });
+            stats.file_count += 1;
+            stats.record_count += data_file.record_count();
+            stats.total_size_bytes += data_file.file_size_in_bytes();
+        }
+    }
+
+    Ok(by_partition.into_values().collect())
+}
+
+/// Aggregate the current snapshot's live data files by the schema id recorded in the
+/// manifest that tracks them, so files written before a schema change can be spotted.
+pub async fn schema_file_stats(table: &Table) -> Result<Vec<SchemaFileStats>> {
+    let metadata = table.metadata();
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(Vec::new());
+    };
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(|e| manifest_read_error("manifest list", snapshot.manifest_list(), e))?;
+
+    let mut by_schema: BTreeMap<i32, SchemaFileStats> = BTreeMap::new();
+    for manifest_file in manifest_list.entries() {
+        if manifest_file.content != ManifestContentType::Data {
+            continue;
+        }
+        let manifest = manifest_file
+            .load_manifest(table.file_io())
+            .await
+            .map_err(|e| manifest_read_error("manifest", &manifest_file.manifest_path, e))?;
+        let schema_id = manifest.metadata().schema_id();
+
+        for entry in manifest.entries() {
+            if !entry.is_alive() {
+                continue;
+            }
+            let data_file = entry.data_file();
+            let stats = by_schema
+                .entry(schema_id)
+                .or_insert_with(|| SchemaFileStats {
+                    schema_id,
+                    file_count: 0,
+                    record_count: 0,
+                    total_size_bytes: 0,
+                });
+            stats.file_count += 1;
+            stats.record_count += data_file.record_count();
+            stats.total_size_bytes += data_file.file_size_in_bytes();
+        }
+    }
+
+    Ok(by_schema.into_values().collect())
+}
+
+/// Sum the column sizes, value counts and null counts the manifests record for each live
+/// data file of the current snapshot, by field id, and widen each column's bounds to cover
+/// every file. Files that don't record a column's size don't contribute to it. A bound is
+/// only given when every file holding non-null values of the column recorded one.
+pub async fn column_size_stats(table: &Table) -> Result<Vec<ColumnSizeStats>> {
+    let metadata = table.metadata();
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(Vec::new());
+    };
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(|e| manifest_read_error("manifest list", snapshot.manifest_list(), e))?;
+
+    let mut by_field: BTreeMap<i32, ColumnSizeStats> = BTreeMap::new();
+    let mut lower_bounds: HashMap<i32, Option<Datum>> = HashMap::new();
+    let mut upper_bounds: HashMap<i32, Option<Datum>> = HashMap::new();
+    for manifest_file in manifest_list.entries() {
+        if manifest_file.content != ManifestContentType::Data {
+            continue;
+        }
+        let manifest = manifest_file
+            .load_manifest(table.file_io())
+            .await
+            .map_err(|e| manifest_read_error("manifest", &manifest_file.manifest_path, e))?;
+
+        for entry in manifest.entries() {
+            if !entry.is_alive() {
+                continue;
+            }
+            let data_file = entry.data_file();
+            for (&field_id, &size) in data_file.column_sizes() {
+                let stats = by_field.entry(field_id).or_insert_with(|| ColumnSizeStats {
+                    field_id,
+                    compressed_bytes: 0,
+                    value_count: Some(0),
+                    null_count: 0,
+                    lower_bound: None,
+                    upper_bound: None,
+                });
+                stats.compressed_bytes += size;
+                stats.value_count = stats
+                    .value_count
+                    .zip(data_file.value_counts().get(&field_id))
+                    .map(|(total, count)| total + count);
+                stats.null_count += data_file
+                    .null_value_counts()
+                    .get(&field_id)
+                    .copied()
+                    .unwrap_or(0);
+
+                // A file with only nulls in the column has nothing to bound
+                let has_values = data_file
+                    .value_counts()
+                    .get(&field_id)
+                    .is_none_or(|&count| {
+                        count
+                            > data_file
+                                .null_value_counts()
+                                .get(&field_id)
+                                .copied()
+                                .unwrap_or(0)
+                    });
+                if has_values {
+                    widen_bound(
+                        &mut lower_bounds,
+                        field_id,
+                        data_file.lower_bounds().get(&field_id),
+                        Ordering::Less,
+                    );
+                    widen_bound(
+                        &mut upper_bounds,
+                        field_id,
+                        data_file.upper_bounds().get(&field_id),
+                        Ordering::Greater,
+                    );
+                }
+            }
+        }
+    }
+
+    for (field_id, stats) in by_field.iter_mut() {
+        stats.lower_bound = lower_bounds
+            .get(field_id)
+            .and_then(Option::as_ref)
+            .map(Datum::to_string);
+        stats.upper_bound = upper_bounds
+            .get(field_id)
+            .and_then(Option::as_ref)
+            .map(Datum::to_string);
+    }
+    Ok(by_field.into_values().collect())
+}
+
+/// Keep a file's bound for the field if it sorts `direction` of the one held so far. A
+/// missing bound, or one that can't be compared with the rest, leaves the field without a
+/// bound (`None`) for good, since the true extreme may be in that file.
+fn widen_bound(
+    bounds: &mut HashMap<i32, Option<Datum>>,
+    field_id: i32,
+    bound: Option<&Datum>,
+    direction: Ordering,
+) {
+    let widened = match (bounds.get(&field_id), bound) {
+        (Some(None), _) | (_, None) => None,
+        (None, Some(bound)) => Some(bound.clone()),
+        (Some(Some(current)), Some(bound)) => {
+            let (bound, current) = match bound.partial_cmp(current) {
+                Some(_) => (bound.clone(), current.clone()),
+                None => (promote_bound(bound), promote_bound(current)),
+            };
+            match bound.partial_cmp(&current) {
+                Some(ordering) if ordering == direction => Some(bound),
+                Some(_) => Some(current),
+                None => None,
+            }
+        }
+    };
+    bounds.insert(field_id, widened);
+}
+
+/// Bounds written before an `int` to `long` or `float` to `double` promotion keep their old
+/// type; widening them makes them comparable with bounds written since. Other bounds are
+/// returned as they are.
+fn promote_bound(bound: &Datum) -> Datum {
+    match bound.literal() {
+        PrimitiveLiteral::Int(value) if *bound.data_type() == PrimitiveType::Int => {
+            Datum::long(*value as i64)
+        }
+        PrimitiveLiteral::Float(value) if *bound.data_type() == PrimitiveType::Float => {
+            Datum::double(value.0 as f64)
+        }
+        _ => bound.clone(),
+    }
+}
+
+/// List the current snapshot's live data files with the snapshot that added each one, read
+/// from the manifest entries. Entries carried over from earlier manifests keep the id of the
+/// snapshot that originally added the file.
+pub async fn data_file_origins(table: &Table) -> Result<Vec<DataFileOrigin>> {
+    let metadata = table.metadata();
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(Vec::new());
+    };
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(|e| manifest_read_error("manifest list", snapshot.manifest_list(), e))?;
+
+    let mut origins = Vec::new();
+    for manifest_file in manifest_list.entries() {
+        if manifest_file.content != ManifestContentType::Data {
+            continue;
+        }
+        let spec = metadata.partition_spec_by_id(manifest_file.partition_spec_id);
+        let manifest = manifest_file
+            .load_manifest(table.file_io())
+            .await
+            .map_err(|e| manifest_read_error("manifest", &manifest_file.manifest_path, e))?;
+
+        for entry in manifest.entries() {
+            if !entry.is_alive() {
+                continue;
+            }
+            let data_file = entry.data_file();
+            origins.push(DataFileOrigin {
+                file_path: data_file.file_path().to_string(),
+                partition: format_partition_path(spec, data_file.partition()),
+                added_snapshot_id: entry.snapshot_id().map(|id| id as u64),
+                sequence_number: entry.sequence_number(),
+                record_count: data_file.record_count(),
+                file_size_bytes: data_file.file_size_in_bytes(),
+            });
+        }
+    }
+
+    Ok(origins)
+}
+
+/// Unreadable manifests named in a validation failure before the rest are counted
+const VALIDATION_PATHS_SHOWN: usize = 3;
+
+/// Check that the current snapshot's metadata and manifests are consistent: the schema and
+/// partition spec ids it refers to exist, the manifest list and every manifest can be read,
+/// and the summary's file and record totals match the manifests. Every check runs on its
+/// own, so a single failure is reported without abandoning the rest.
+pub async fn validate_table(table: &Table) -> Vec<ValidationCheck> {
+    let metadata = table.metadata();
+    let mut checks = Vec::new();
+
+    let schema_id = metadata.current_schema_id();
+    checks.push(match metadata.schema_by_id(schema_id) {
+        Some(_) => ValidationCheck::new(
+            "Current schema",
+            ValidationStatus::Passed,
+            format!("Schema {} is present", schema_id),
+        ),
+        None => ValidationCheck::new(
+            "Current schema",
+            ValidationStatus::Failed,
+            format!(
+                "current-schema-id {} is not in the table's schemas",
+                schema_id
+            ),
+        ),
+    });
+
+    let spec_id = metadata.default_partition_spec_id();
+    checks.push(match metadata.partition_spec_by_id(spec_id) {
+        Some(_) => ValidationCheck::new(
+            "Default partition spec",
+            ValidationStatus::Passed,
+            format!("Partition spec {} is present", spec_id),
+        ),
+        None => ValidationCheck::new(
+            "Default partition spec",
+            ValidationStatus::Failed,
+            format!(
+                "default-spec-id {} is not in the table's partition specs",
+                spec_id
+            ),
+        ),
+    });
+
+    let Some(snapshot) = metadata.current_snapshot() else {
+        checks.push(ValidationCheck::new(
+            "Current snapshot",
+            ValidationStatus::Skipped,
+            "The table has no current snapshot, so there are no manifests to check",
+        ));
+        return checks;
+    };
+
+    checks.push(match snapshot.schema_id() {
+        Some(id) if metadata.schema_by_id(id).is_some() => ValidationCheck::new(
+            "Snapshot schema",
+            ValidationStatus::Passed,
+            format!(
+                "Snapshot {} was written with schema {}",
+                snapshot.snapshot_id(),
+                id
+            ),
+        ),
+        Some(id) => ValidationCheck::new(
+            "Snapshot schema",
+            ValidationStatus::Failed,
+            format!(
+                "Snapshot {} refers to schema {}, which is not in the table's schemas",
+                snapshot.snapshot_id(),
+                id
+            ),
+        ),
+        None => ValidationCheck::new(
+            "Snapshot schema",
+            ValidationStatus::Skipped,
+            "The snapshot does not record a schema id",
+        ),
+    });
+
+    let manifest_list = match snapshot.load_manifest_list(table.file_io(), metadata).await {
+        Ok(manifest_list) => {
+            checks.push(ValidationCheck::new(
+                "Manifest list",
+                ValidationStatus::Passed,
+                format!("{} manifests listed", manifest_list.entries().len()),
+            ));
+            Some(manifest_list)
+        }
+        Err(e) => {
+            checks.push(ValidationCheck::new(
+                "Manifest list",
+                ValidationStatus::Failed,
+                format!(
+                    "{:#}",
+                    manifest_read_error("manifest list", snapshot.manifest_list(), e)
+                ),
+            ));
+            None
+        }
+    };
+
+    // Live data file and record counts, only known when every manifest could be read
+    let mut live_counts = None;
+    match &manifest_list {
+        Some(manifest_list) => {
+            let mut unreadable = Vec::new();
+            let mut missing_specs = std::collections::BTreeSet::new();
+            let (mut files, mut records) = (0u64, 0u64);
+            for manifest_file in manifest_list.entries() {
+                if metadata
+                    .partition_spec_by_id(manifest_file.partition_spec_id)
+                    .is_none()
+                {
+                    missing_specs.insert(manifest_file.partition_spec_id);
+                }
+                match manifest_file.load_manifest(table.file_io()).await {
+                    Ok(manifest) if manifest_file.content == ManifestContentType::Data => {
+                        for entry in manifest.entries().iter().filter(|e| e.is_alive()) {
+                            files += 1;
+                            records += entry.data_file().record_count();
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => unreadable.push(format!(
+                        "{:#}",
+                        manifest_read_error("manifest", &manifest_file.manifest_path, e)
+                    )),
+                }
+            }
+
+            let total = manifest_list.entries().len();
+            checks.push(if unreadable.is_empty() {
+                ValidationCheck::new(
+                    "Manifests",
+                    ValidationStatus::Passed,
+                    format!("All {} manifests are readable", total),
+                )
+            } else {
+                let mut detail = format!(
+                    "{} of {} manifests could not be read: {}",
+                    unreadable.len(),
+                    total,
+                    unreadable[..unreadable.len().min(VALIDATION_PATHS_SHOWN)].join("; ")
+                );
+                if unreadable.len() > VALIDATION_PATHS_SHOWN {
+                    detail.push_str(&format!(
+                        "; and {} more",
+                        unreadable.len() - VALIDATION_PATHS_SHOWN
+                    ));
+                }
+                ValidationCheck::new("Manifests", ValidationStatus::Failed, detail)
+            });
+
+            checks.push(if missing_specs.is_empty() {
+                ValidationCheck::new(
+                    "Manifest partition specs",
+                    ValidationStatus::Passed,
+                    "Every manifest uses a partition spec defined in the metadata",
+                )
+            } else {
+                ValidationCheck::new(
+                    "Manifest partition specs",
+                    ValidationStatus::Failed,
+                    format!(
+                        "Manifests refer to partition specs not in the metadata: {}",
+                        missing_specs
+                            .iter()
+                            .map(|id| id.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                )
+            });
+
+            if unreadable.is_empty() {
+                live_counts = Some((files, records));
+            }
+        }
+        None => checks.push(ValidationCheck::new(
+            "Manifests",
+            ValidationStatus::Skipped,
+            "The manifest list could not be read",
+        )),
+    }
+
+    let summary_value = |key: &str| {
+        snapshot
+            .summary()
+            .additional_properties
+            .get(key)
+            .and_then(|value| value.parse::<u64>().ok())
+    };
+    checks.push(summary_count_check(
+        "Data file count",
+        "total-data-files",
+        summary_value("total-data-files"),
+        live_counts.map(|(files, _)| files),
+    ));
+    checks.push(summary_count_check(
+        "Record count",
+        "total-records",
+        summary_value("total-records"),
+        live_counts.map(|(_, records)| records),
+    ));
+
+    checks
+}
+
+/// Compare a snapshot summary total with the same total counted from the manifests,
+/// skipping when either side is unknown
+fn summary_count_check(
+    name: &str,
+    summary_key: &str,
+    reported: Option<u64>,
+    counted: Option<u64>,
+) -> ValidationCheck {
+    match (reported, counted) {
+        (Some(reported), Some(counted)) if reported == counted => ValidationCheck::new(
+            name,
+            ValidationStatus::Passed,
+            format!("{} = {} matches the manifests", summary_key, reported),
+        ),
+        (Some(reported), Some(counted)) => ValidationCheck::new(
+            name,
+            ValidationStatus::Failed,
+            format!(
+                "Summary {} is {} but the manifests list {}",
+                summary_key, reported, counted
+            ),
+        ),
+        (None, _) => ValidationCheck::new(
+            name,
+            ValidationStatus::Skipped,
+            format!("The snapshot summary has no {}", summary_key),
+        ),
+        (Some(_), None) => ValidationCheck::new(
+            name,
+            ValidationStatus::Skipped,
+            "Not every manifest could be read",
+        ),
+    }
+}
+
+/// Render a partition tuple as a Hive-style path, e.g. `event_date=2024-01-01/region=eu`
+fn format_partition_path(spec: Option<&PartitionSpecRef>, partition: &Struct) -> String {
+    partition_path(&partition_field_values(spec, partition))
+}
+
+/// Partition field names with their display values, in spec order
+fn partition_field_values(
+    spec: Option<&PartitionSpecRef>,
+    partition: &Struct,
+) -> Vec<(String, String)> {
+    let Some(spec) = spec else {
+        return Vec::new();
+    };
+
+    spec.fields()
+        .iter()
+        .zip(partition.iter())
+        .map(|(field, value)| {
+            (
+                field.name.clone(),
+                format_partition_value(&field.transform, value),
+            )
+        })
+        .collect()
+}
+
+/// `field=value` segments joined into a path, e.g. `event_date=2024-01-01/region=eu`
+fn partition_path(values: &[(String, String)]) -> String {
+    if values.is_empty() {
+        return "(unpartitioned)".to_string();
+    }
+    values
+        .iter()
+        .map(|(field, value)| format!("{}={}", field, value))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Partition values of time transforms are ordinals since the epoch; show them as dates
+fn format_partition_value(transform: &Transform, value: Option<&Literal>) -> String {
+    let Some(Literal::Primitive(value)) = value else {
+        return value.map_or_else(|| "null".to_string(), |other| format!("{:?}", other));
+    };
+
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid epoch date");
+    match (transform, value) {
+        (Transform::Year, PrimitiveLiteral::Int(years)) => (1970 + years).to_string(),
+        (Transform::Month, PrimitiveLiteral::Int(months)) => {
+            format!(
+                "{}-{:02}",
+                1970 + months.div_euclid(12),
+                months.rem_euclid(12) + 1
+            )
+        }
+        (Transform::Day, PrimitiveLiteral::Int(days)) => epoch
+            .checked_add_signed(chrono::Duration::days(*days as i64))
+            .map(|date| date.to_string())
+            .unwrap_or_else(|| days.to_string()),
+        (Transform::Hour, PrimitiveLiteral::Int(hours)) => {
+            chrono::DateTime::from_timestamp(*hours as i64 * 3600, 0)
+                .map(|time| time.format("%Y-%m-%d-%H").to_string())
+                .unwrap_or_else(|| hours.to_string())
+        }
+        (_, PrimitiveLiteral::Boolean(v)) => v.to_string(),
+        (_, PrimitiveLiteral::Int(v)) => v.to_string(),
+        (_, PrimitiveLiteral::Long(v)) => v.to_string(),
+        (_, PrimitiveLiteral::Float(v)) => v.to_string(),
+        (_, PrimitiveLiteral::Double(v)) => v.to_string(),
+        (_, PrimitiveLiteral::String(v)) => v.clone(),
+        (_, other) => format!("{:?}", other),
+    }
+}
+
+/// Convert iceberg-rust view metadata to our internal IcebergView representation
+pub fn convert_view(
+    metadata: &ViewMetadata,
+    name: String,
+    namespace: String,
+    catalog_name: String,
+) -> Result<IcebergView> {
+    let mut versions: Vec<ViewVersion> = metadata
+        .versions()
+        .map(|version| ViewVersion {
+            version_id: version.version_id(),
+            schema_id: version.schema_id(),
+            timestamp_ms: version.timestamp_ms(),
+            summary: version.summary().clone(),
+            representations: version
+                .representations()
+                .iter()
+                .map(|representation| match representation {
+                    iceberg::spec::ViewRepresentation::Sql(sql) => ViewRepresentation {
+                        dialect: sql.dialect.clone(),
+                        sql: sql.sql.clone(),
+                    },
+                })
+                .collect(),
+            default_catalog: version.default_catalog().cloned(),
+            default_namespace: version.default_namespace().join("."),
+        })
+        .collect();
+    versions.sort_by_key(|version| (version.timestamp_ms, version.version_id));
+
+    let mut schemas = metadata
+        .schemas_iter()
+        .map(convert_schema)
+        .collect::<Result<Vec<_>>>()?;
+    schemas.sort_by_key(|schema| schema.schema_id);
+
+    Ok(IcebergView {
+        name,
+        namespace,
+        catalog_name,
+        location: metadata.location().to_string(),
+        current_version_id: metadata.current_version_id(),
+        versions,
+        schemas,
+        properties: metadata.properties().clone(),
     })
 }
 
@@ -85,9 +917,31 @@ fn convert_field(field: &iceberg::spec::NestedField) -> Result<NestedField> {
         required: field.required,
         field_type: convert_data_type(&field.field_type)?,
         doc: field.doc.clone(),
+        initial_default: field
+            .initial_default
+            .clone()
+            .map(|literal| format_default_value(literal, &field.field_type)),
+        write_default: field
+            .write_default
+            .clone()
+            .map(|literal| format_default_value(literal, &field.field_type)),
     })
 }
 
+/// Render a default literal using the field's type, so dates, timestamps and decimals
+/// read the way they were declared rather than as their physical representation
+fn format_default_value(literal: Literal, field_type: &Type) -> String {
+    let fallback = format!("{:?}", literal);
+    match literal.try_into_json(field_type) {
+        Ok(serde_json::Value::String(value)) => value,
+        Ok(value) => value.to_string(),
+        Err(e) => {
+            log::debug!("Could not render default value {}: {}", fallback, e);
+            fallback
+        }
+    }
+}
+
 fn convert_data_type(iceberg_type: &Type) -> Result<DataType> {
     match iceberg_type {
         Type::Primitive(primitive) => Ok(convert_primitive_type(primitive)),
@@ -116,6 +970,9 @@ fn convert_data_type(iceberg_type: &Type) -> Result<DataType> {
     }
 }
 
+/// `PrimitiveType` is a closed enum, so every type iceberg-rust can read maps to a
+/// Hielo type here; a primitive added upstream fails this match at compile time rather than
+/// being guessed at from its display string
 fn convert_primitive_type(primitive: &PrimitiveType) -> DataType {
     match primitive {
         PrimitiveType::Boolean => DataType::Boolean,
@@ -127,24 +984,30 @@ fn convert_primitive_type(primitive: &PrimitiveType) -> DataType {
         PrimitiveType::Time => DataType::Time,
         PrimitiveType::Timestamp => DataType::Timestamp,
         PrimitiveType::Timestamptz => DataType::TimestampTz,
+        PrimitiveType::TimestampNs => DataType::TimestampNs,
+        PrimitiveType::TimestamptzNs => DataType::TimestampTzNs,
         PrimitiveType::String => DataType::String,
         PrimitiveType::Uuid => DataType::Uuid,
-        PrimitiveType::Fixed(_) => DataType::Binary,
+        PrimitiveType::Fixed(length) => DataType::Fixed { length: *length },
         PrimitiveType::Binary => DataType::Binary,
         PrimitiveType::Decimal { precision, scale } => DataType::Decimal {
-            precision: { *precision },
-            scale: { *scale },
+            precision: *precision,
+            scale: *scale,
         },
-        PrimitiveType::TimestampNs => todo!(),
-        PrimitiveType::TimestamptzNs => todo!(),
     }
 }
 
 fn convert_snapshot(snapshot: &iceberg::spec::Snapshot) -> Result<Snapshot> {
-    let summary = Some(convert_summary(
-        snapshot,
-        &snapshot.summary().additional_properties,
-    ));
+    // `additional_properties` excludes the operation, which iceberg-rust keeps as a typed field
+    let mut properties = snapshot.summary().additional_properties.clone();
+    let operation = match snapshot.summary().operation {
+        Operation::Append => "append",
+        Operation::Replace => "replace",
+        Operation::Overwrite => "overwrite",
+        Operation::Delete => "delete",
+    };
+    properties.insert("operation".to_string(), operation.to_string());
+    let summary = Some(convert_summary(snapshot, &properties));
 
     Ok(Snapshot {
         snapshot_id: snapshot.snapshot_id() as u64,
@@ -152,6 +1015,7 @@ fn convert_snapshot(snapshot: &iceberg::spec::Snapshot) -> Result<Snapshot> {
         summary,
         manifest_list: snapshot.manifest_list().to_string(),
         schema_id: snapshot.schema_id(),
+        parent_snapshot_id: snapshot.parent_snapshot_id().map(|id| id as u64),
     })
 }
 
@@ -198,6 +1062,10 @@ fn convert_summary(
         added_files_size: summary.get("added-files-size").cloned(),
         removed_files_size: summary.get("removed-files-size").cloned(),
         total_size: summary.get("total-size").cloned(),
+        properties: summary
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
     }
 }
 
@@ -223,6 +1091,32 @@ fn convert_partition_field(field: &iceberg::spec::PartitionField) -> Result<Part
     })
 }
 
+fn convert_sort_order(sort_order: &iceberg::spec::SortOrder) -> Result<SortOrder> {
+    let fields = sort_order
+        .fields
+        .iter()
+        .map(|field| {
+            Ok(SortField {
+                source_id: field.source_id,
+                transform: convert_transform(&field.transform)?,
+                direction: match field.direction {
+                    iceberg::spec::SortDirection::Ascending => SortDirection::Ascending,
+                    iceberg::spec::SortDirection::Descending => SortDirection::Descending,
+                },
+                null_order: match field.null_order {
+                    iceberg::spec::NullOrder::First => NullOrder::First,
+                    iceberg::spec::NullOrder::Last => NullOrder::Last,
+                },
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(SortOrder {
+        order_id: sort_order.order_id,
+        fields,
+    })
+}
+
 fn convert_transform(transform: &Transform) -> Result<PartitionTransform> {
     match transform {
         Transform::Identity => Ok(PartitionTransform::Identity),
@@ -237,8 +1131,8 @@ fn convert_transform(transform: &Transform) -> Result<PartitionTransform> {
         Transform::Day => Ok(PartitionTransform::Day),
         Transform::Hour => Ok(PartitionTransform::Hour),
         Transform::Void => Ok(PartitionTransform::Void),
-        // Handle any other transform variants that might exist
-        _ => Ok(PartitionTransform::Identity), // Default fallback
+        // Kept as unknown rather than passed off as another transform
+        Transform::Unknown => Ok(PartitionTransform::Unknown),
     }
 }
 
@@ -246,6 +1140,48 @@ fn convert_transform(transform: &Transform) -> Result<PartitionTransform> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_newest_window() {
+        // (snapshot id, timestamp), not in timestamp order
+        let snapshots = vec![(1, 100), (2, 300), (3, 200), (4, 400), (5, 50)];
+        let timestamp = |s: &(u64, i64)| s.1;
+
+        let (kept, omitted) = newest_window(snapshots.clone(), Some(2), timestamp, |_| false);
+        assert_eq!(kept, vec![(2, 300), (4, 400)]);
+        assert_eq!(omitted, 3);
+
+        // Snapshots a ref points at are kept outside the window
+        let (kept, omitted) = newest_window(snapshots.clone(), Some(2), timestamp, |s| s.0 == 5);
+        assert_eq!(kept, vec![(2, 300), (4, 400), (5, 50)]);
+        assert_eq!(omitted, 2);
+
+        assert_eq!(
+            newest_window(snapshots.clone(), Some(5), timestamp, |_| false),
+            (snapshots.clone(), 0)
+        );
+        assert_eq!(
+            newest_window(snapshots.clone(), None, timestamp, |_| false),
+            (snapshots, 0)
+        );
+    }
+
+    #[test]
+    fn test_summary_count_check() {
+        let status = |reported, counted| {
+            summary_count_check("Record count", "total-records", reported, counted).status
+        };
+        assert_eq!(status(Some(10), Some(10)), ValidationStatus::Passed);
+        assert_eq!(status(Some(10), Some(12)), ValidationStatus::Failed);
+        assert_eq!(status(None, Some(12)), ValidationStatus::Skipped);
+        assert_eq!(status(Some(10), None), ValidationStatus::Skipped);
+
+        let failed = summary_count_check("Record count", "total-records", Some(10), Some(12));
+        assert_eq!(
+            failed.detail,
+            "Summary total-records is 10 but the manifests list 12"
+        );
+    }
+
     #[test]
     fn test_convert_primitive_types() {
         assert_eq!(
@@ -273,4 +1209,313 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_parameterized_type_display() {
+        let display = |primitive: PrimitiveType| convert_primitive_type(&primitive).to_string();
+        assert_eq!(
+            display(PrimitiveType::Decimal {
+                precision: 38,
+                scale: 9
+            }),
+            "decimal(38, 9)"
+        );
+        assert_eq!(display(PrimitiveType::Time), "time");
+        assert_eq!(display(PrimitiveType::Timestamp), "timestamp");
+        assert_eq!(display(PrimitiveType::Timestamptz), "timestamptz");
+        assert_eq!(display(PrimitiveType::TimestampNs), "timestamp_ns");
+        assert_eq!(display(PrimitiveType::TimestamptzNs), "timestamptz_ns");
+        assert_eq!(display(PrimitiveType::Fixed(16)), "fixed[16]");
+        assert_eq!(display(PrimitiveType::Uuid), "uuid");
+        assert_eq!(display(PrimitiveType::Binary), "binary");
+
+        let map = DataType::Map {
+            key: Box::new(DataType::String),
+            value: Box::new(DataType::List {
+                element: Box::new(DataType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                }),
+            }),
+        };
+        assert_eq!(map.to_string(), "map<string, list<decimal(10, 2)>>");
+    }
+
+    #[test]
+    fn test_convert_every_type() {
+        use iceberg::spec::{ListType, MapType, NestedField as IcebergField, StructType};
+
+        let primitives = vec![
+            (PrimitiveType::Boolean, DataType::Boolean),
+            (PrimitiveType::Int, DataType::Integer),
+            (PrimitiveType::Long, DataType::Long),
+            (PrimitiveType::Float, DataType::Float),
+            (PrimitiveType::Double, DataType::Double),
+            (PrimitiveType::Date, DataType::Date),
+            (PrimitiveType::Time, DataType::Time),
+            (PrimitiveType::Timestamp, DataType::Timestamp),
+            (PrimitiveType::Timestamptz, DataType::TimestampTz),
+            (PrimitiveType::TimestampNs, DataType::TimestampNs),
+            (PrimitiveType::TimestamptzNs, DataType::TimestampTzNs),
+            (PrimitiveType::String, DataType::String),
+            (PrimitiveType::Uuid, DataType::Uuid),
+            (PrimitiveType::Fixed(16), DataType::Fixed { length: 16 }),
+            (PrimitiveType::Binary, DataType::Binary),
+            (
+                PrimitiveType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                },
+                DataType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                },
+            ),
+        ];
+        for (id, (primitive, expected)) in primitives.into_iter().enumerate() {
+            assert_eq!(convert_primitive_type(&primitive), expected);
+
+            let field = IcebergField::optional(id as i32 + 1, "column", Type::Primitive(primitive));
+            let converted = convert_field(&field).unwrap();
+            assert_eq!(converted.field_type, expected);
+        }
+
+        let nested = IcebergField::required(
+            100,
+            "nested",
+            Type::Struct(StructType::new(vec![
+                IcebergField::required(
+                    101,
+                    "tags",
+                    Type::List(ListType::new(
+                        IcebergField::list_element(
+                            102,
+                            Type::Primitive(PrimitiveType::String),
+                            true,
+                        )
+                        .into(),
+                    )),
+                )
+                .into(),
+                IcebergField::optional(
+                    103,
+                    "scores",
+                    Type::Map(MapType::new(
+                        IcebergField::map_key_element(104, Type::Primitive(PrimitiveType::String))
+                            .into(),
+                        IcebergField::map_value_element(
+                            105,
+                            Type::Primitive(PrimitiveType::Double),
+                            false,
+                        )
+                        .into(),
+                    )),
+                )
+                .into(),
+            ])),
+        );
+        let converted = convert_field(&nested).unwrap();
+        let DataType::Struct { fields } = converted.field_type else {
+            panic!("expected a struct, got {:?}", converted.field_type);
+        };
+        let members: Vec<_> = fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.field_type.to_string()))
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("tags", "list<string>".to_string()),
+                ("scores", "map<string, double>".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_convert_transform() {
+        assert_eq!(
+            convert_transform(&Transform::Bucket(16)).unwrap(),
+            PartitionTransform::Bucket { num_buckets: 16 }
+        );
+        assert_eq!(
+            convert_transform(&Transform::Identity).unwrap(),
+            PartitionTransform::Identity
+        );
+        // A transform iceberg-rust can't name isn't mistaken for identity
+        let unknown = convert_transform(&Transform::Unknown).unwrap();
+        assert_eq!(unknown, PartitionTransform::Unknown);
+        assert_eq!(unknown.to_string(), "unknown");
+    }
+
+    #[test]
+    fn test_widen_bound() {
+        let mut lower = HashMap::new();
+        let mut upper = HashMap::new();
+        for bound in [Datum::int(5), Datum::long(-3), Datum::int(12)] {
+            widen_bound(&mut lower, 1, Some(&bound), Ordering::Less);
+            widen_bound(&mut upper, 1, Some(&bound), Ordering::Greater);
+        }
+        // Bounds from before an int to long promotion still count
+        assert_eq!(lower[&1], Some(Datum::long(-3)));
+        assert_eq!(upper[&1], Some(Datum::long(12)));
+
+        // A file with values but no bound may hold the true extreme
+        widen_bound(&mut lower, 2, Some(&Datum::int(1)), Ordering::Less);
+        widen_bound(&mut lower, 2, None, Ordering::Less);
+        widen_bound(&mut lower, 2, Some(&Datum::int(0)), Ordering::Less);
+        assert_eq!(lower[&2], None);
+
+        // Bounds that can't be compared leave the column without one
+        widen_bound(&mut upper, 3, Some(&Datum::int(1)), Ordering::Greater);
+        widen_bound(&mut upper, 3, Some(&Datum::string("a")), Ordering::Greater);
+        assert_eq!(upper[&3], None);
+    }
+
+    #[test]
+    fn test_format_default_value() {
+        assert_eq!(
+            format_default_value(Literal::int(42), &Type::Primitive(PrimitiveType::Int)),
+            "42"
+        );
+        assert_eq!(
+            format_default_value(
+                Literal::string("unknown"),
+                &Type::Primitive(PrimitiveType::String)
+            ),
+            "unknown"
+        );
+        // Dates are stored as days since epoch but should render as calendar dates
+        assert_eq!(
+            format_default_value(Literal::date(0), &Type::Primitive(PrimitiveType::Date)),
+            "1970-01-01"
+        );
+    }
+
+    #[test]
+    fn test_convert_statistics_files() {
+        let file: iceberg::spec::StatisticsFile = serde_json::from_value(serde_json::json!({
+            "snapshot-id": 42,
+            "statistics-path": "s3://bucket/metadata/42.stats",
+            "file-size-in-bytes": 1024,
+            "file-footer-size-in-bytes": 128,
+            "blob-metadata": [
+                {
+                    "type": "apache-datasketches-theta-v1",
+                    "snapshot-id": 42,
+                    "sequence-number": 3,
+                    "fields": [1],
+                    "properties": { "ndv": "1500" }
+                },
+                {
+                    "type": "apache-datasketches-theta-v1",
+                    "snapshot-id": 42,
+                    "sequence-number": 3,
+                    "fields": [2]
+                }
+            ]
+        }))
+        .unwrap();
+        let partition_file: iceberg::spec::PartitionStatisticsFile =
+            serde_json::from_value(serde_json::json!({
+                "snapshot-id": 42,
+                "statistics-path": "s3://bucket/metadata/42.parquet",
+                "file-size-in-bytes": 2048
+            }))
+            .unwrap();
+
+        let file = convert_statistics_file(&file);
+        assert_eq!(file.snapshot_id, 42);
+        assert_eq!(file.file_size_in_bytes, 1024);
+        assert_eq!(file.blobs.len(), 2);
+        assert_eq!(file.blobs[0].field_ids, vec![1]);
+        assert_eq!(file.blobs[0].ndv(), Some(1500));
+        assert_eq!(file.blobs[1].ndv(), None);
+
+        let partition_file = convert_partition_statistics_file(&partition_file);
+        assert_eq!(partition_file.snapshot_id, 42);
+        assert_eq!(partition_file.file_size_in_bytes, 2048);
+    }
+
+    #[test]
+    fn test_convert_view() {
+        let metadata: ViewMetadata = serde_json::from_value(serde_json::json!({
+            "view-uuid": "fa6506c3-7681-40c8-86dc-e36561f83385",
+            "format-version": 1,
+            "location": "s3://bucket/warehouse/default.db/event_agg",
+            "current-version-id": 2,
+            "properties": { "comment": "Daily event counts" },
+            "versions": [
+                {
+                    "version-id": 2,
+                    "timestamp-ms": 1573518981593,
+                    "schema-id": 1,
+                    "summary": { "engine-name": "trino" },
+                    "default-namespace": ["default"],
+                    "representations": [
+                        { "type": "sql", "sql": "SELECT day, count(*) AS n FROM events GROUP BY 1", "dialect": "trino" },
+                        { "type": "sql", "sql": "SELECT day, COUNT(1) AS n FROM events GROUP BY day", "dialect": "spark" }
+                    ]
+                },
+                {
+                    "version-id": 1,
+                    "timestamp-ms": 1573518431292,
+                    "schema-id": 1,
+                    "summary": { "engine-name": "spark" },
+                    "default-catalog": "prod",
+                    "default-namespace": ["default"],
+                    "representations": [
+                        { "type": "sql", "sql": "SELECT day, count(*) FROM events GROUP BY day", "dialect": "spark" }
+                    ]
+                }
+            ],
+            "schemas": [
+                {
+                    "schema-id": 1,
+                    "type": "struct",
+                    "fields": [
+                        { "id": 1, "name": "day", "required": false, "type": "date" },
+                        { "id": 2, "name": "n", "required": false, "type": "long" }
+                    ]
+                }
+            ],
+            "version-log": [
+                { "timestamp-ms": 1573518431292, "version-id": 1 },
+                { "timestamp-ms": 1573518981593, "version-id": 2 }
+            ]
+        }))
+        .unwrap();
+
+        let view = convert_view(
+            &metadata,
+            "event_agg".to_string(),
+            "default".to_string(),
+            "prod".to_string(),
+        )
+        .unwrap();
+        assert_eq!(view.qualified_name(), "prod.default.event_agg");
+        assert_eq!(view.location, "s3://bucket/warehouse/default.db/event_agg");
+        assert_eq!(
+            view.properties.get("comment").map(String::as_str),
+            Some("Daily event counts")
+        );
+
+        // Versions come oldest first, whatever order the metadata lists them in
+        let ids: Vec<i32> = view.versions.iter().map(|v| v.version_id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(view.versions[0].default_catalog.as_deref(), Some("prod"));
+
+        let current = view.current_version().unwrap();
+        assert_eq!(current.version_id, 2);
+        assert_eq!(current.default_namespace, "default");
+        let dialects: Vec<&str> = current
+            .representations
+            .iter()
+            .map(|r| r.dialect.as_str())
+            .collect();
+        assert_eq!(dialects, vec!["trino", "spark"]);
+
+        let schema = view.schema(current.schema_id).unwrap();
+        let names: Vec<&str> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["day", "n"]);
+    }
 }
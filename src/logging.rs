@@ -0,0 +1,170 @@
+//! Diagnostics logging. Everything goes to daily-rotated JSON files under `~/.hielo/logs`, so
+//! users can attach them to bug reports; debug builds also keep the usual console output.
+
+use anyhow::Result;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
+
+use crate::config::{AppConfig, LogLevel};
+
+const LOG_FILE_PREFIX: &str = "hielo";
+const LOG_FILE_SUFFIX: &str = "log";
+/// Number of daily files kept before the oldest is deleted
+const MAX_LOG_FILES: usize = 7;
+
+/// Directory holding the rotated log files
+pub fn log_dir() -> Result<PathBuf> {
+    let dir = AppConfig::config_dir()?.join("logs");
+    if !dir.exists() {
+        fs::create_dir_all(&dir)?;
+    }
+    Ok(dir)
+}
+
+/// Install the global subscriber. Must be called once, before the app is launched.
+pub fn init(level: LogLevel) {
+    let file_appender = log_dir().and_then(|dir| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .filename_suffix(LOG_FILE_SUFFIX)
+            .max_log_files(MAX_LOG_FILES)
+            .build(dir)
+            .map_err(anyhow::Error::from)
+    });
+    let file_layer = match file_appender {
+        Ok(appender) => Some(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_ansi(false)
+                .with_writer(appender),
+        ),
+        Err(e) => {
+            eprintln!(
+                "Could not open log file, logging to the console only: {}",
+                e
+            );
+            None
+        }
+    };
+    let console_layer =
+        (cfg!(debug_assertions) || file_layer.is_none()).then(tracing_subscriber::fmt::layer);
+
+    tracing_subscriber::registry()
+        .with(LevelFilter::from_level(level.as_tracing()))
+        .with(file_layer)
+        .with(console_layer)
+        .init();
+}
+
+/// A single line of the JSON log file
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+}
+
+/// Parse one JSON log line; lines that aren't log records are skipped
+pub fn parse_entry(line: &str) -> Option<LogEntry> {
+    let value: Value = serde_json::from_str(line).ok()?;
+    let fields = value.get("fields");
+    let field = |name: &str| {
+        fields
+            .and_then(|fields| fields.get(name))
+            .and_then(Value::as_str)
+    };
+    let text = |key: &str| value.get(key).and_then(Value::as_str);
+
+    Some(LogEntry {
+        timestamp: text("timestamp").unwrap_or_default().to_string(),
+        level: LogLevel::parse(text("level")?)?,
+        // Records bridged from the `log` crate carry their real target as a field
+        target: field("log.target")
+            .or_else(|| text("target"))
+            .unwrap_or_default()
+            .to_string(),
+        message: field("message").unwrap_or_default().to_string(),
+    })
+}
+
+/// Newest log file in `dir`. Rotated files are date-suffixed, so they sort by name.
+fn latest_log_file(dir: &Path) -> Option<PathBuf> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(LOG_FILE_PREFIX) && name.ends_with(LOG_FILE_SUFFIX)
+                })
+        })
+        .max()
+}
+
+/// Last `limit` entries of the newest log file in `dir`, oldest first
+pub fn read_recent_entries_in(dir: &Path, limit: usize) -> Result<Vec<LogEntry>> {
+    let Some(path) = latest_log_file(dir) else {
+        return Ok(Vec::new());
+    };
+    let contents = fs::read_to_string(&path)?;
+    let mut entries: Vec<LogEntry> = contents
+        .lines()
+        .rev()
+        .filter_map(parse_entry)
+        .take(limit)
+        .collect();
+    entries.reverse();
+    Ok(entries)
+}
+
+/// Last `limit` entries of the current log file, oldest first
+pub fn read_recent_entries(limit: usize) -> Result<Vec<LogEntry>> {
+    read_recent_entries_in(&log_dir()?, limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn line(level: &str, message: &str) -> String {
+        format!(
+            r#"{{"timestamp":"2025-01-01T00:00:00Z","level":"{}","fields":{{"message":"{}","log.target":"hielo::catalog"}},"target":"log"}}"#,
+            level, message
+        )
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let entry = parse_entry(&line("WARN", "slow catalog")).unwrap();
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.message, "slow catalog");
+        assert_eq!(entry.target, "hielo::catalog");
+
+        assert!(parse_entry("not json").is_none());
+        assert!(parse_entry(r#"{"fields":{"message":"no level"}}"#).is_none());
+    }
+
+    #[test]
+    fn test_read_recent_entries_uses_latest_file() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("hielo.2025-01-01.log"), line("INFO", "old")).unwrap();
+        let lines = ["one", "two", "three"]
+            .iter()
+            .map(|message| line("INFO", message))
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(dir.path().join("hielo.2025-01-02.log"), lines).unwrap();
+
+        let entries = read_recent_entries_in(dir.path(), 2).unwrap();
+        let messages: Vec<_> = entries.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["two", "three"]);
+    }
+}
@@ -8,12 +8,24 @@ mod catalog;
 mod catalog_ui;
 mod components;
 mod config;
+mod crawl;
 mod data;
+mod export;
+mod glue;
 mod iceberg_adapter;
+mod logging;
+mod settings_ui;
+mod strings;
+mod theme;
+mod toast;
+mod updates;
+mod views;
 
 use catalog::CatalogManager;
 use catalog_ui::CatalogConnectionScreen;
+use config::TableViewTab;
 use data::IcebergTable;
+use strings::{Msg, t, tf};
 
 #[derive(Debug, Clone, PartialEq)]
 enum AppState {
@@ -24,19 +36,638 @@ enum AppState {
 #[derive(Debug, Clone, PartialEq)]
 enum AppTab {
     Catalog,
-    Table { table: IcebergTable, tab_id: String },
+    Table {
+        table: IcebergTable,
+        tab_id: String,
+        refresh_interval_secs: Option<u64>,
+        last_refreshed: chrono::DateTime<chrono::Utc>,
+        /// Sub-tab shown for this table, restored when switching back to it
+        view_tab: TableViewTab,
+        /// Snapshots committed since the table was last viewed, until dismissed
+        changes_since_last_view: Option<data::ChangesSinceLastView>,
+        /// Label the user gave this tab, shown instead of the table name
+        custom_label: Option<String>,
+        /// Every snapshot is shown, overriding the large-table snapshot limit
+        all_snapshots: bool,
+    },
+    /// Placeholder shown while a newly opened table is still loading
+    LoadingTable {
+        tab_id: String,
+        catalog_name: String,
+        namespace: String,
+        table_name: String,
+        /// Sub-tab the table opens on once loaded
+        view_tab: TableViewTab,
+    },
+    /// A namespace's table list, kept open alongside its tables
+    Namespace {
+        catalog_name: String,
+        namespace: String,
+    },
+    /// An Iceberg view's definition, keyed like a table tab
+    View {
+        view: data::IcebergView,
+        tab_id: String,
+    },
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum TableViewTab {
-    Overview,
-    Schema,
-    Partitions,
-    SnapshotHistory,
+impl AppTab {
+    fn view_tab(&self) -> TableViewTab {
+        match self {
+            AppTab::Table { view_tab, .. } | AppTab::LoadingTable { view_tab, .. } => *view_tab,
+            AppTab::Catalog | AppTab::Namespace { .. } | AppTab::View { .. } => {
+                TableViewTab::Overview
+            }
+        }
+    }
+
+    fn set_view_tab(&mut self, selected: TableViewTab) {
+        if let AppTab::Table { view_tab, .. } | AppTab::LoadingTable { view_tab, .. } = self {
+            *view_tab = selected;
+        }
+    }
+}
+
+/// Second line of a table tab: the namespace, prefixed with the catalog when several are
+/// connected. Namespace tabs show their catalog.
+fn tab_subtitle(tab: &AppTab, show_catalog: bool) -> Option<String> {
+    let (catalog_name, namespace) = match tab {
+        AppTab::Catalog => return None,
+        AppTab::Namespace { catalog_name, .. } => return Some(catalog_name.clone()),
+        AppTab::Table { table, .. } => (&table.catalog_name, &table.namespace),
+        AppTab::View { view, .. } => (&view.catalog_name, &view.namespace),
+        AppTab::LoadingTable {
+            catalog_name,
+            namespace,
+            ..
+        } => (catalog_name, namespace),
+    };
+    Some(if show_catalog {
+        format!("{} · {}", catalog_name, namespace)
+    } else {
+        namespace.clone()
+    })
+}
+
+/// Icon shown beside a table in lists and the navigation tree
+fn table_type_icon(table_type: catalog::TableType) -> &'static str {
+    match table_type {
+        catalog::TableType::Iceberg => "🧊",
+        catalog::TableType::View => "👁️",
+        catalog::TableType::Unknown => "📄",
+    }
+}
+
+/// Full `catalog.namespace.table` path shown when hovering a tab
+fn tab_tooltip(tab: &AppTab) -> String {
+    match tab {
+        AppTab::Catalog => "Catalogs".to_string(),
+        AppTab::Table { table, .. } => format!(
+            "{}\nDouble-click to set a custom label",
+            table.qualified_name()
+        ),
+        AppTab::LoadingTable {
+            catalog_name,
+            namespace,
+            table_name,
+            ..
+        } => format!("{}.{}.{} (loading)", catalog_name, namespace, table_name),
+        AppTab::Namespace {
+            catalog_name,
+            namespace,
+        } => format!("{}.{} (namespace)", catalog_name, namespace),
+        AppTab::View { view, .. } => format!("{} (view)", view.qualified_name()),
+    }
+}
+
+/// How often the sleep/wake detector compares wall-clock time
+const WAKE_CHECK_SECS: u64 = 15;
+
+/// Auto-refresh intervals offered for table tabs, in seconds (`None` = off)
+const AUTO_REFRESH_INTERVALS: [(Option<u64>, &str); 4] = [
+    (None, "Off"),
+    (Some(30), "30s"),
+    (Some(60), "1m"),
+    (Some(300), "5m"),
+];
+
+/// Key of a table's tab, its [`config::table_key`]. Case is kept, as catalogs may hold
+/// tables whose names only differ by case.
+fn table_tab_id(catalog_name: &str, namespace: &str, table_name: &str) -> String {
+    config::table_key(catalog_name, namespace, table_name)
+}
+
+/// Resolve a typed `namespace.table` or `catalog.namespace.table` path. A leading catalog
+/// name is recognised by prefix, so catalog names may contain dots; without one the path
+/// is looked up in `default_catalog`. The last segment is the table, the rest the namespace.
+fn parse_table_path(
+    input: &str,
+    catalog_names: &[String],
+    default_catalog: Option<&str>,
+) -> Result<(String, String, String), String> {
+    let input = input.trim();
+    let explicit = catalog_names
+        .iter()
+        .filter_map(|name| {
+            input
+                .strip_prefix(name.as_str())
+                .and_then(|rest| rest.strip_prefix('.'))
+                .filter(|rest| rest.contains('.'))
+                .map(|rest| (name.clone(), rest))
+        })
+        // The longest matching name wins, e.g. `prod.eu` over `prod`
+        .max_by_key(|(name, _)| name.len());
+    let (catalog_name, path) = match explicit {
+        Some(found) => found,
+        None => {
+            let catalog_name = default_catalog.ok_or_else(|| {
+                "Several catalogs are connected; start the path with the catalog name".to_string()
+            })?;
+            (catalog_name.to_string(), input)
+        }
+    };
+    match path.rsplit_once('.') {
+        Some((namespace, table_name)) if !namespace.is_empty() && !table_name.is_empty() => {
+            Ok((catalog_name, namespace.to_string(), table_name.to_string()))
+        }
+        _ => Err(format!(
+            "'{}' is not a table path; use namespace.table or catalog.namespace.table",
+            input
+        )),
+    }
+}
+
+/// Parse a pasted list of table paths, one per line or comma separated. Entries are
+/// trimmed and may be quoted; blanks and repeats are skipped. Returns the tables in the
+/// order given, and each entry that isn't a table path with the reason.
+fn parse_table_list(
+    input: &str,
+    catalog_names: &[String],
+    default_catalog: Option<&str>,
+) -> (Vec<(String, String, String)>, Vec<(String, String)>) {
+    let mut tables = Vec::new();
+    let mut invalid = Vec::new();
+    for entry in input.split(['\n', ',']) {
+        let entry = entry
+            .trim()
+            .trim_matches(|c| c == '"' || c == '\'' || c == '`')
+            .trim();
+        if entry.is_empty() {
+            continue;
+        }
+        match parse_table_path(entry, catalog_names, default_catalog) {
+            Ok(table) if !tables.contains(&table) => tables.push(table),
+            Ok(_) => {}
+            Err(e) => invalid.push((entry.to_string(), e)),
+        }
+    }
+    (tables, invalid)
+}
+
+/// Names of every saved or connected catalog, for resolving typed table paths
+fn known_catalog_names(manager: &CatalogManager) -> Vec<String> {
+    let mut names: Vec<String> = manager
+        .get_saved_catalogs()
+        .iter()
+        .map(|config| config.name.clone())
+        .collect();
+    for connection in manager.get_connections() {
+        if !names.contains(&connection.config.name) {
+            names.push(connection.config.name.clone());
+        }
+    }
+    names
+}
+
+/// Index of the open tab, loaded or still loading, with the given key. Views share the
+/// key space, as a view and a table in one namespace can't have the same name.
+fn find_table_tab(tabs: &[AppTab], tab_id: &str) -> Option<usize> {
+    tabs.iter().position(|tab| match tab {
+        AppTab::Table {
+            tab_id: existing_id,
+            ..
+        }
+        | AppTab::LoadingTable {
+            tab_id: existing_id,
+            ..
+        }
+        | AppTab::View {
+            tab_id: existing_id,
+            ..
+        } => existing_id == tab_id,
+        AppTab::Catalog | AppTab::Namespace { .. } => false,
+    })
+}
+
+/// Another loaded tab showing the same table as `table` (same catalog and location),
+/// ignoring the tab at `own_index`
+fn find_same_table_tab(tabs: &[AppTab], table: &IcebergTable, own_index: usize) -> Option<usize> {
+    tabs.iter().enumerate().position(|(index, tab)| {
+        index != own_index
+            && matches!(tab, AppTab::Table { table: open, .. }
+                if open.catalog_name == table.catalog_name && open.location == table.location)
+    })
+}
+
+/// Connect a saved catalog that isn't connected, e.g. after an idle disconnect. The connect
+/// runs without borrowing the manager, as other tasks hold it across their requests, and one
+/// connect per catalog runs at a time.
+async fn ensure_catalog_connected(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: &str,
+) -> Result<(), catalog::CatalogError> {
+    // Connections whose exchanged token has expired, or that a request found dead, reconnect
+    if !catalog_manager.read().needs_connect(catalog_name) {
+        return Ok(());
+    }
+    let connect_lock = catalog_manager.read().connect_lock(catalog_name);
+    let _connecting = connect_lock.lock().await;
+    // Callers queued behind another connect find the catalog connected and skip this
+    let Some(connect) = catalog_manager.read().connector(catalog_name) else {
+        return Ok(());
+    };
+    let connected = connect.await;
+    write_when_free(catalog_manager, |manager| {
+        manager.finish_connect(catalog_name, connected)
+    })
+    .await
+}
+
+/// Run `update` on the manager once no task holds it. Catalog requests hold a read across
+/// their await, so a plain `write()` from a background task could hit one and panic.
+async fn write_when_free<R>(
+    mut catalog_manager: Signal<CatalogManager>,
+    update: impl FnOnce(&mut CatalogManager) -> R,
+) -> R {
+    loop {
+        if let Ok(mut manager) = catalog_manager.try_write() {
+            return update(&mut manager);
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    }
+}
+
+/// Load and convert a table. Unless `all_snapshots` is set, tables with more snapshots than
+/// the large-table limit keep only their newest ones.
+async fn fetch_table(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: &str,
+    namespace: &str,
+    table_name: &str,
+    all_snapshots: bool,
+) -> Result<IcebergTable, String> {
+    let iceberg_table =
+        load_catalog_table(catalog_manager, catalog_name, namespace, table_name).await?;
+    convert_table(
+        catalog_manager,
+        &iceberg_table,
+        catalog_name,
+        namespace,
+        all_snapshots,
+    )
+}
+
+/// Load a table, connecting its catalog first
+async fn load_catalog_table(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: &str,
+    namespace: &str,
+    table_name: &str,
+) -> Result<iceberg::table::Table, String> {
+    ensure_catalog_connected(catalog_manager, catalog_name)
+        .await
+        .map_err(|e| format!("Failed to connect catalog: {}", e))?;
+    let loaded = catalog_manager
+        .read()
+        .load_table(catalog_name, namespace, table_name)
+        .await;
+    // A connection that died (e.g. while the laptop slept) is re-established and the load
+    // retried once, instead of showing the socket error. It's only flagged here, as sibling
+    // loads may still be reading the manager.
+    let loaded = match loaded {
+        Err(e) if catalog::is_stale_connection_error(&e.to_string()) => {
+            log::warn!(
+                "Connection to catalog '{}' was dropped ({}); reconnecting",
+                catalog_name,
+                e
+            );
+            catalog_manager.read().mark_connection_stale(catalog_name);
+            ensure_catalog_connected(catalog_manager, catalog_name)
+                .await
+                .map_err(|e| format!("Failed to reconnect catalog: {}", e))?;
+            catalog_manager
+                .read()
+                .load_table(catalog_name, namespace, table_name)
+                .await
+        }
+        loaded => loaded,
+    };
+    loaded.map_err(|e| format!("Failed to load table: {}", e))
+}
+
+/// Convert a loaded table for display, windowing its snapshots as [`fetch_table`] does
+fn convert_table(
+    catalog_manager: Signal<CatalogManager>,
+    iceberg_table: &iceberg::table::Table,
+    catalog_name: &str,
+    namespace: &str,
+    all_snapshots: bool,
+) -> Result<IcebergTable, String> {
+    log::info!("Successfully loaded iceberg table, converting...");
+    let snapshot_limit =
+        (!all_snapshots).then(|| catalog_manager.peek().settings().large_table_snapshot_limit);
+    iceberg_adapter::convert_iceberg_table_windowed(
+        iceberg_table,
+        namespace.to_string(),
+        catalog_name.to_string(),
+        snapshot_limit,
+    )
+    .map_err(|e| format!("Failed to convert table: {}", e))
+}
+
+/// Load and convert a view for its tab
+async fn fetch_view(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: &str,
+    namespace: &str,
+    view_name: &str,
+) -> Result<data::IcebergView, String> {
+    ensure_catalog_connected(catalog_manager, catalog_name)
+        .await
+        .map_err(|e| format!("Failed to connect catalog: {}", e))?;
+    let metadata = catalog_manager
+        .read()
+        .load_view(catalog_name, namespace, view_name)
+        .await
+        .map_err(|e| format!("Failed to load view: {}", e))?;
+    iceberg_adapter::convert_view(
+        &metadata,
+        view_name.to_string(),
+        namespace.to_string(),
+        catalog_name.to_string(),
+    )
+    .map_err(|e| format!("Failed to convert view: {}", e))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ShortcutAction {
+    GlobalSearch,
+    ToggleNavigation,
+    CloseTab,
+    NextTab,
+    PreviousTab,
+    ShowShortcuts,
+    GoToTable,
+    FocusNavFilter,
+    CloseDialog,
+}
+
+/// A keyboard shortcut handled by the app's root key handler
+struct KeyboardShortcut {
+    action: ShortcutAction,
+    ctrl: bool,
+    shift: bool,
+    /// Lowercase character, or the `Key` variant name for named keys (e.g. "Tab")
+    key: &'static str,
+    label: &'static str,
+    description: &'static str,
+}
+
+/// Every keyboard shortcut. Both the key handler and the cheat sheet read from this list,
+/// so new shortcuts only need to be registered here.
+const KEYBOARD_SHORTCUTS: [KeyboardShortcut; 9] = [
+    KeyboardShortcut {
+        action: ShortcutAction::GlobalSearch,
+        ctrl: true,
+        shift: false,
+        key: "k",
+        label: "Ctrl+K",
+        description: "Open the command palette (tables and actions)",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::ToggleNavigation,
+        ctrl: true,
+        shift: false,
+        key: "b",
+        label: "Ctrl+B",
+        description: "Show or hide the navigation pane",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::CloseTab,
+        ctrl: true,
+        shift: false,
+        key: "w",
+        label: "Ctrl+W",
+        description: "Close the current table tab",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::NextTab,
+        ctrl: true,
+        shift: false,
+        key: "Tab",
+        label: "Ctrl+Tab",
+        description: "Next tab",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::PreviousTab,
+        ctrl: true,
+        shift: true,
+        key: "Tab",
+        label: "Ctrl+Shift+Tab",
+        description: "Previous tab",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::ShowShortcuts,
+        ctrl: true,
+        shift: false,
+        key: "/",
+        label: "Ctrl+/",
+        description: "Show this list of shortcuts",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::GoToTable,
+        ctrl: true,
+        shift: false,
+        key: "g",
+        label: "Ctrl+G",
+        description: "Go to a table by its namespace.table path",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::FocusNavFilter,
+        ctrl: true,
+        shift: true,
+        key: "f",
+        label: "Ctrl+Shift+F",
+        description: "Filter the catalog tree; Enter opens a single matching table",
+    },
+    KeyboardShortcut {
+        action: ShortcutAction::CloseDialog,
+        ctrl: false,
+        shift: false,
+        key: "Escape",
+        label: "Esc",
+        description: "Close the open dialog",
+    },
+];
+
+/// Find the registered shortcut matching a key press
+fn shortcut_for_key(key: &Key, ctrl: bool, shift: bool) -> Option<ShortcutAction> {
+    let key_name = match key {
+        Key::Character(c) => c.to_lowercase(),
+        named => format!("{:?}", named),
+    };
+    let is_character = matches!(key, Key::Character(_));
+    KEYBOARD_SHORTCUTS
+        .iter()
+        // Shift changes the character itself (e.g. "/" vs "?"), so only named keys compare it
+        .find(|s| s.key == key_name && s.ctrl == ctrl && (is_character || s.shift == shift))
+        .map(|s| s.action)
+}
+
+/// Actions offered by the command palette
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PaletteCommand {
+    AddCatalog,
+    OpenSettings,
+    ShowShortcuts,
+    ToggleNavigation,
+    RefreshTable,
+    CloseTab,
+    CopyTableReport,
+    SaveTableReport,
+    CopyDdl(export::SqlDialect),
+    CopyDbtSource,
+    CopySchemaMarkdown,
+    SaveSchemaMarkdown,
+    OpenMetadataFile,
+    GoToTable,
+    OpenTableList,
+}
+
+/// A command palette entry
+struct PaletteCommandEntry {
+    command: PaletteCommand,
+    name: &'static str,
+    /// Keyboard shortcut that triggers the same action, shown next to the name
+    shortcut: Option<ShortcutAction>,
+    /// Only offered while a table tab is active
+    needs_table: bool,
+}
+
+/// Every palette command. The palette lists and filters these; `App` runs them, so new
+/// commands only need an entry here and an arm in its handler.
+const PALETTE_COMMANDS: [PaletteCommandEntry; 15] = [
+    PaletteCommandEntry {
+        command: PaletteCommand::AddCatalog,
+        name: "Add catalog",
+        shortcut: None,
+        needs_table: false,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::OpenMetadataFile,
+        name: "Open metadata file",
+        shortcut: None,
+        needs_table: false,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::GoToTable,
+        name: "Go to table by path",
+        shortcut: Some(ShortcutAction::GoToTable),
+        needs_table: false,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::OpenTableList,
+        name: "Open tables from a list",
+        shortcut: None,
+        needs_table: false,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::OpenSettings,
+        name: "Open settings",
+        shortcut: None,
+        needs_table: false,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::ShowShortcuts,
+        name: "Show keyboard shortcuts",
+        shortcut: Some(ShortcutAction::ShowShortcuts),
+        needs_table: false,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::ToggleNavigation,
+        name: "Toggle navigation pane",
+        shortcut: Some(ShortcutAction::ToggleNavigation),
+        needs_table: false,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::RefreshTable,
+        name: "Refresh table",
+        shortcut: None,
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::CloseTab,
+        name: "Close tab",
+        shortcut: Some(ShortcutAction::CloseTab),
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::CopyTableReport,
+        name: "Copy table report (JSON)",
+        shortcut: None,
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::SaveTableReport,
+        name: "Export table report to downloads",
+        shortcut: None,
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::CopyDdl(export::SqlDialect::Spark),
+        name: "Copy Spark SQL DDL",
+        shortcut: None,
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::CopyDdl(export::SqlDialect::Trino),
+        name: "Copy Trino / Athena DDL",
+        shortcut: None,
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::CopyDbtSource,
+        name: "Copy dbt source YAML",
+        shortcut: None,
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::CopySchemaMarkdown,
+        name: "Copy schema as Markdown",
+        shortcut: None,
+        needs_table: true,
+    },
+    PaletteCommandEntry {
+        command: PaletteCommand::SaveSchemaMarkdown,
+        name: "Export schema as Markdown to downloads",
+        shortcut: None,
+        needs_table: true,
+    },
+];
+
+fn shortcut_label(action: ShortcutAction) -> Option<&'static str> {
+    KEYBOARD_SHORTCUTS
+        .iter()
+        .find(|s| s.action == action)
+        .map(|s| s.label)
 }
 
 fn main() {
-    dioxus_logger::init(tracing::Level::INFO).expect("failed to init logger");
+    let settings = config::AppConfig::load()
+        .map(|config| config.settings)
+        .unwrap_or_default();
+    logging::init(settings.log_level);
+    strings::init(settings.locale);
 
     LaunchBuilder::desktop()
         .with_cfg(dioxus::desktop::Config::new().with_window(
@@ -57,17 +688,79 @@ fn App() -> Element {
     });
     let mut open_tabs = use_signal(|| vec![AppTab::Catalog]);
     let mut active_tab_index = use_signal(|| 0usize);
-    let mut table_view_tab = use_signal(|| TableViewTab::Overview);
+    // Table tab whose custom label is being edited, by tab id, and the draft label
+    let mut editing_tab_label = use_signal(|| Option::<(String, String)>::None);
+    // An empty draft resets the tab to the table name
+    let mut save_tab_label = move || {
+        let Some((tab_id, draft)) = editing_tab_label() else {
+            return;
+        };
+        for tab in open_tabs.write().iter_mut() {
+            if let AppTab::Table {
+                tab_id: id,
+                custom_label,
+                ..
+            } = tab
+            {
+                if *id == tab_id {
+                    *custom_label = Some(draft.trim().to_string()).filter(|l| !l.is_empty());
+                }
+            }
+        }
+        editing_tab_label.set(None);
+    };
+    // Schema field the Schema sub-tab scrolls to and highlights next time it renders
+    let mut schema_focus_field = use_signal(|| Option::<i32>::None);
     let mut catalog_manager = use_signal(CatalogManager::new);
+    // Sub-tab selection is stored on the active table tab itself. Links into a tab the user
+    // hid, e.g. "show snapshots", leave the current sub-tab as it is.
+    let mut set_table_view_tab = move |view_tab: TableViewTab| {
+        if !catalog_manager
+            .peek()
+            .settings()
+            .visible_table_view_tabs()
+            .contains(&view_tab)
+        {
+            return;
+        }
+        if let Some(tab) = open_tabs.write().get_mut(active_tab_index()) {
+            tab.set_view_tab(view_tab);
+        }
+    };
     let mut loading_table = use_signal(|| false);
-    let mut error_message = use_signal(|| Option::<String>::None);
+    let toasts = toast::use_toasts_provider();
     let mut show_global_search = use_signal(|| false);
+    let mut show_settings = use_signal(|| false);
+    let mut show_shortcuts = use_signal(|| false);
+    let mut show_go_to_table = use_signal(|| false);
+    let mut show_open_table_list = use_signal(|| false);
+    let mut show_metadata_file_dialog = use_signal(|| false);
     let mut global_search_query = use_signal(String::new);
     let mut nav_pane_collapsed = use_signal(|| false);
+    // Set by the shortcut; the navigation pane focuses its filter and clears it
+    let mut focus_nav_filter = use_signal(|| false);
     let mut show_delete_confirmation = use_signal(|| false);
     let mut delete_catalog_name = use_signal(String::new);
     let expanded_catalogs = use_signal(std::collections::HashSet::<String>::new);
     let expanded_namespaces = use_signal(std::collections::HashSet::<String>::new);
+    let mut refreshing_tabs = use_signal(std::collections::HashSet::<String>::new);
+    let mut available_update = use_signal(|| Option::<String>::None);
+    let mut request_activity = use_signal(catalog::RequestActivity::default);
+    // Shown in the header for a tick after waking from sleep
+    let mut reconnect_notice = use_signal(|| Option::<String>::None);
+    let mut namespace_health_target = use_signal(|| Option::<(String, String)>::None);
+    let mut broken_tables_target = use_signal(|| Option::<String>::None);
+    // Catalog whose tables are being compared with another's
+    let mut catalog_diff_source = use_signal(|| Option::<String>::None);
+    let mut namespace_health_cache = use_signal(NamespaceHealthCache::new);
+    let schema_file_stats_cache =
+        use_signal(std::collections::HashMap::<String, Vec<data::SchemaFileStats>>::new);
+    let data_file_origins_cache =
+        use_signal(std::collections::HashMap::<String, Vec<data::DataFileOrigin>>::new);
+    let column_size_cache =
+        use_signal(std::collections::HashMap::<String, Vec<data::ColumnSizeStats>>::new);
+    let mut catalog_overview_cache =
+        use_signal(std::collections::HashMap::<String, data::CatalogOverview>::new);
 
     let mut close_tab = {
         let mut open_tabs = open_tabs.clone();
@@ -89,213 +782,834 @@ fn App() -> Element {
         }
     };
 
-    let load_table = move |(catalog_name, namespace, table_name): (String, String, String)| {
+    // Opens the table's tab straight away with a skeleton, then fills it in once loaded.
+    // Reloading an already open table keeps its current content until the new data arrives.
+    let mut load_table = move |(catalog_name, namespace, table_name): (String, String, String)| {
         log::info!(
             "Loading table: {} from namespace: {} in catalog: {}",
             table_name,
             namespace,
             catalog_name
         );
+        // Same-named tables of different catalogs get their own tabs
+        let tab_id = table_tab_id(&catalog_name, &namespace, &table_name);
+        let tab_index = find_table_tab;
+
+        let existing_index = tab_index(&open_tabs.read(), &tab_id);
+        match existing_index {
+            Some(index) => {
+                log::info!("Switching to existing tab at index: {}", index);
+                active_tab_index.set(index);
+                // Already loading; the pending load will fill it in
+                if matches!(open_tabs.read()[index], AppTab::LoadingTable { .. }) {
+                    return;
+                }
+            }
+            None => {
+                let new_index = {
+                    let mut tabs = open_tabs.write();
+                    tabs.push(AppTab::LoadingTable {
+                        tab_id: tab_id.clone(),
+                        catalog_name: catalog_name.clone(),
+                        namespace: namespace.clone(),
+                        table_name: table_name.clone(),
+                        view_tab: catalog_manager.read().settings().default_table_view_tab(),
+                    });
+                    tabs.len() - 1
+                };
+                log::info!("Adding new tab and switching to index: {}", new_index);
+                active_tab_index.set(new_index);
+            }
+        }
+
+        // Ensure we're in connected state
+        app_state.set(AppState::Connected);
+
+        // A reload keeps the tab's choice to show every snapshot
+        let all_snapshots = existing_index.is_some_and(|index| {
+            matches!(
+                open_tabs.read()[index],
+                AppTab::Table {
+                    all_snapshots: true,
+                    ..
+                }
+            )
+        });
+
         spawn(async move {
             loading_table.set(true);
-            error_message.set(None);
 
-            match catalog_manager
-                .read()
-                .load_table(&catalog_name, &namespace, &table_name)
-                .await
-            {
-                Ok(iceberg_table) => {
-                    log::info!("Successfully loaded iceberg table, converting...");
-                    match iceberg_adapter::convert_iceberg_table(
-                        &iceberg_table,
-                        namespace.clone(),
-                        catalog_name.clone(),
-                    ) {
-                        Ok(hielo_table) => {
-                            log::info!("Table converted successfully, creating tab...");
-                            // Create a unique tab ID
-                            let tab_id = format!("{}.{}", namespace, table_name);
-                            let new_tab = AppTab::Table {
+            let result = fetch_table(
+                catalog_manager,
+                &catalog_name,
+                &namespace,
+                &table_name,
+                all_snapshots,
+            )
+            .await;
+            // The tab may have moved or been closed while loading
+            let index = tab_index(&open_tabs.read(), &tab_id);
+            // Case-insensitive catalogs resolve e.g. `Orders` and `orders` to the same table;
+            // keep the tab already showing it rather than opening a second one
+            let same_table_tab = match (&result, index) {
+                (Ok(loaded), Some(index)) => find_same_table_tab(&open_tabs.read(), loaded, index),
+                _ => None,
+            };
+            if let (Some(existing), Some(index)) = (same_table_tab, index) {
+                log::warn!(
+                    "Tab {} resolves to the same table as an open tab, switching to it",
+                    tab_id
+                );
+                toasts.notify(
+                    toast::ToastKind::Info,
+                    tf(Msg::SameTableOpen, &[&table_name, &catalog_name]),
+                );
+                if matches!(open_tabs.read()[index], AppTab::LoadingTable { .. }) {
+                    close_tab(index);
+                    active_tab_index.set(if existing > index {
+                        existing - 1
+                    } else {
+                        existing
+                    });
+                }
+                loading_table.set(false);
+                return;
+            }
+            match (result, index) {
+                (Ok(hielo_table), Some(index)) => {
+                    log::info!("Table converted successfully, filling tab {}", tab_id);
+                    let changes = catalog_manager
+                        .peek()
+                        .last_seen_snapshot(&config::table_key(
+                            &hielo_table.catalog_name,
+                            &hielo_table.namespace,
+                            &hielo_table.name,
+                        ))
+                        .and_then(|seen| {
+                            analytics::TableAnalytics::changes_since(
+                                &hielo_table.snapshots,
+                                hielo_table.current_snapshot_id,
+                                seen,
+                            )
+                        });
+                    let seen = hielo_table.clone();
+                    match &mut open_tabs.write()[index] {
+                        AppTab::Table {
+                            table,
+                            last_refreshed,
+                            changes_since_last_view,
+                            ..
+                        } => {
+                            *table = hielo_table;
+                            *last_refreshed = chrono::Utc::now();
+                            // A reload with nothing new keeps the banner until it's dismissed
+                            if changes.is_some() {
+                                *changes_since_last_view = changes;
+                            }
+                        }
+                        placeholder => {
+                            *placeholder = AppTab::Table {
                                 table: hielo_table,
                                 tab_id: tab_id.clone(),
+                                refresh_interval_secs: None,
+                                last_refreshed: chrono::Utc::now(),
+                                view_tab: placeholder.view_tab(),
+                                changes_since_last_view: changes,
+                                custom_label: None,
+                                all_snapshots,
                             };
-
-                            // Check if tab already exists
-                            let existing_index = open_tabs.read().iter().position(|tab| {
-                                if let AppTab::Table {
-                                    tab_id: existing_id,
-                                    ..
-                                } = tab
-                                {
-                                    existing_id == &tab_id
-                                } else {
-                                    false
-                                }
-                            });
-
-                            if let Some(index) = existing_index {
-                                // Switch to existing tab
-                                log::info!("Switching to existing tab at index: {}", index);
-                                active_tab_index.set(index);
-                            } else {
-                                // Add new tab and switch to it
-                                let mut tabs = open_tabs.read().clone();
-                                tabs.push(new_tab);
-                                let new_index = tabs.len() - 1;
-                                log::info!("Adding new tab and switching to index: {}", new_index);
-                                open_tabs.set(tabs);
-                                active_tab_index.set(new_index);
-                            }
-
-                            // Ensure we're in connected state
-                            log::info!("Setting app state to Connected");
-                            app_state.set(AppState::Connected);
                         }
-                        Err(e) => {
-                            log::error!("Failed to convert table: {}", e);
-                            error_message.set(Some(format!("Failed to convert table: {}", e)));
+                    }
+                    // Only once the tab is filled, as waiting for the manager lets tabs move
+                    if let Err(e) =
+                        write_when_free(catalog_manager, |manager| manager.mark_table_seen(&seen))
+                            .await
+                    {
+                        log::error!("Failed to remember the last viewed snapshot: {}", e);
+                    }
+                }
+                (Ok(_), None) => {
+                    log::info!("Tab {} was closed before its table loaded", tab_id);
+                }
+                (Err(e), index) => {
+                    log::error!("{}", e);
+                    toasts.notify(toast::ToastKind::Error, e);
+                    // Drop the placeholder; an already loaded tab keeps its data
+                    if let Some(index) = index {
+                        if matches!(open_tabs.read()[index], AppTab::LoadingTable { .. }) {
+                            close_tab(index);
                         }
                     }
                 }
+            }
+            loading_table.set(false);
+        });
+    };
+
+    // A view loads in one request, so its tab opens once the definition has arrived
+    let mut open_view = move |(catalog_name, namespace, view_name): (String, String, String)| {
+        let tab_id = table_tab_id(&catalog_name, &namespace, &view_name);
+        app_state.set(AppState::Connected);
+        if let Some(index) = find_table_tab(&open_tabs.read(), &tab_id) {
+            active_tab_index.set(index);
+            return;
+        }
+        spawn(async move {
+            loading_table.set(true);
+            let result = fetch_view(catalog_manager, &catalog_name, &namespace, &view_name).await;
+            loading_table.set(false);
+            match result {
+                Ok(view) => {
+                    let existing = find_table_tab(&open_tabs.read(), &tab_id);
+                    let index = existing.unwrap_or_else(|| {
+                        let mut tabs = open_tabs.write();
+                        tabs.push(AppTab::View { view, tab_id });
+                        tabs.len() - 1
+                    });
+                    active_tab_index.set(index);
+                }
                 Err(e) => {
-                    log::error!("Failed to load table: {}", e);
-                    error_message.set(Some(format!("Failed to load table: {}", e)));
+                    log::error!("{}", e);
+                    toasts.notify(toast::ToastKind::Error, e);
                 }
             }
-            loading_table.set(false);
         });
     };
 
-    let on_catalog_connected = move |_| {
+    // Tables an action wants to open, held while the user confirms a bulk open
+    let mut pending_bulk_open = use_signal(|| Option::<Vec<(String, String, String)>>::None);
+    // Every bulk-open entry point goes through here, so large batches are confirmed first
+    let open_tables = move |tables: Vec<(String, String, String)>| {
+        let threshold = catalog_manager
+            .read()
+            .settings()
+            .bulk_open_confirm_threshold;
+        if tables.len() > threshold {
+            pending_bulk_open.set(Some(tables));
+        } else {
+            for table in tables {
+                load_table(table);
+            }
+        }
+    };
+
+    let open_namespace_tab = move |(catalog_name, namespace): (String, String)| {
+        let existing = open_tabs.read().iter().position(|tab| {
+            matches!(tab, AppTab::Namespace { catalog_name: c, namespace: n }
+                if *c == catalog_name && *n == namespace)
+        });
+        let index = existing.unwrap_or_else(|| {
+            let mut tabs = open_tabs.write();
+            tabs.push(AppTab::Namespace {
+                catalog_name,
+                namespace,
+            });
+            tabs.len() - 1
+        });
+        active_tab_index.set(index);
         app_state.set(AppState::Connected);
-        active_tab_index.set(0); // Switch to catalog tab
     };
 
-    rsx! {
-        div {
-            class: "min-h-screen bg-gray-100",
-            tabindex: "0", // Make div focusable for keyboard events
-            onkeydown: move |event| {
-                // Handle CTRL+K to open global search (only when connected)
-                let key_str = format!("{:?}", event.key());
-                if event.modifiers().ctrl() && key_str.contains("\"k\"") && matches!(app_state(), AppState::Connected) {
-                    show_global_search.set(true);
-                    global_search_query.set(String::new());
-                }
-            },
+    // Auto-refresh: only the active tab is polled, so background tabs pause until focused
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
 
-            // Loading overlay
-            if loading_table() {
-                div {
-                    class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-center justify-center",
-                    div {
-                        class: "bg-white p-8 rounded-lg shadow-lg",
-                        div {
-                            class: "flex items-center space-x-4",
-                            div {
-                                class: "animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600"
-                            }
-                            div {
-                                class: "text-lg font-medium text-gray-900",
-                                "Loading table..."
-                            }
-                        }
-                    }
+            let due = match open_tabs.read().get(active_tab_index()) {
+                Some(AppTab::Table {
+                    table,
+                    tab_id,
+                    refresh_interval_secs: Some(interval),
+                    last_refreshed,
+                    all_snapshots,
+                    ..
+                }) if (chrono::Utc::now() - *last_refreshed).num_seconds() >= *interval as i64 => {
+                    Some((
+                        tab_id.clone(),
+                        table.catalog_name.clone(),
+                        table.namespace.clone(),
+                        table.name.clone(),
+                        *all_snapshots,
+                    ))
                 }
+                _ => None,
+            };
+
+            let Some((tab_id, catalog_name, namespace, table_name, all_snapshots)) = due else {
+                continue;
+            };
+            // Skip if a refresh for this tab is still in flight
+            if !refreshing_tabs.write().insert(tab_id.clone()) {
+                continue;
             }
 
-            // Error message
-            if let Some(error) = error_message() {
-                div {
-                    class: "fixed top-4 right-4 z-40 bg-red-50 border border-red-200 rounded-md p-4 max-w-md",
-                    div {
-                        class: "flex",
-                        div {
-                            class: "flex-shrink-0",
-                            svg {
-                                class: "h-5 w-5 text-red-400",
-                                fill: "currentColor",
-                                view_box: "0 0 20 20",
-                                path {
-                                    fill_rule: "evenodd",
-                                    d: "M10 18a8 8 0 100-16 8 8 0 000 16zM8.707 7.293a1 1 0 00-1.414 1.414L8.586 10l-1.293 1.293a1 1 0 101.414 1.414L10 11.414l1.293 1.293a1 1 0 001.414-1.414L11.414 10l1.293-1.293a1 1 0 00-1.414-1.414L10 8.586 8.707 7.293z",
-                                    clip_rule: "evenodd"
-                                }
-                            }
-                        }
-                        div {
-                            class: "ml-3",
-                            p {
-                                class: "text-sm font-medium text-red-800",
-                                "Error"
-                            }
-                            p {
-                                class: "text-sm text-red-700",
-                                "{error}"
+            spawn(async move {
+                log::info!("Auto-refreshing table: {}", tab_id);
+                let result = fetch_table(
+                    catalog_manager,
+                    &catalog_name,
+                    &namespace,
+                    &table_name,
+                    all_snapshots,
+                )
+                .await;
+                for tab in open_tabs.write().iter_mut() {
+                    if let AppTab::Table {
+                        table,
+                        tab_id: id,
+                        last_refreshed,
+                        ..
+                    } = tab
+                    {
+                        if *id == tab_id {
+                            if let Ok(refreshed) = &result {
+                                *table = refreshed.clone();
                             }
+                            // Stamp failures too so a broken catalog isn't hammered every tick
+                            *last_refreshed = chrono::Utc::now();
                         }
-                        div {
-                            class: "ml-auto pl-3",
-                            button {
-                                onclick: move |_| error_message.set(None),
-                                class: "inline-flex text-red-400 hover:text-red-600",
-                                svg {
-                                    class: "h-5 w-5",
-                                    fill: "currentColor",
-                                    view_box: "0 0 20 20",
-                                    path {
-                                        fill_rule: "evenodd",
-                                        d: "M4.293 4.293a1 1 0 011.414 0L10 8.586l4.293-4.293a1 1 0 111.414 1.414L11.414 10l4.293 4.293a1 1 0 01-1.414 1.414L10 11.414l-4.293 4.293a1 1 0 01-1.414-1.414L8.586 10 4.293 5.707a1 1 0 010-1.414z",
-                                        clip_rule: "evenodd"
-                                    }
-                                }
-                            }
+                    }
+                }
+                match result {
+                    // The refreshed tab is the active one, so its new snapshots count as seen
+                    Ok(refreshed) => {
+                        if let Err(e) = write_when_free(catalog_manager, |manager| {
+                            manager.mark_table_seen(&refreshed)
+                        })
+                        .await
+                        {
+                            log::error!("Failed to remember the last viewed snapshot: {}", e);
                         }
                     }
+                    Err(e) => {
+                        log::error!("Auto-refresh of {} failed: {}", tab_id, e);
+                        toasts.notify(toast::ToastKind::Error, e);
+                    }
                 }
+                refreshing_tabs.write().remove(&tab_id);
+            });
+        }
+    });
+
+    // Poll the shared request limiter so queued catalog requests are visible in the header.
+    // `try_read` skips ticks where a connect holds the manager mutably.
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+            let activity = match catalog_manager.try_read() {
+                Ok(manager) => manager.request_activity(),
+                Err(_) => continue,
+            };
+            if activity != *request_activity.peek() {
+                request_activity.set(activity);
             }
+        }
+    });
 
-            // Global search modal (CTRL+F)
-            if show_global_search() {
-                GlobalSearchModal {
-                    catalog_manager: catalog_manager,
-                    search_query: global_search_query(),
-                    on_search_change: move |query: String| global_search_query.set(query),
-                    on_table_selected: load_table,
-                    on_close: move |_| {
-                        show_global_search.set(false);
-                        global_search_query.set(String::new());
-                    }
-                }
+    // Pinned catalogs connect eagerly; the rest connect on first use
+    use_future(move || async move {
+        let pinned = catalog_manager.peek().pinned_catalogs();
+        for catalog_name in pinned {
+            if let Err(e) = ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                log::error!("Failed to connect pinned catalog '{}': {}", catalog_name, e);
             }
+        }
+    });
 
-            // Main content based on app state
-            match app_state() {
-                AppState::CatalogConnection => rsx! {
-                    CatalogConnectionScreen {
-                        catalog_manager: catalog_manager,
-                        on_catalog_connected: on_catalog_connected,
-                        on_table_selected: load_table
-                    }
-                },
-                AppState::Connected => rsx! {
-                    // Layout with sidebar and main content
-                    div {
-                        class: "flex h-screen bg-gray-100",
+    // Idle auto-disconnect; the next list or load reconnects through `ensure_catalog_connected`
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+            let has_idle = match catalog_manager.try_read() {
+                Ok(manager) => !manager.idle_catalogs().is_empty(),
+                Err(_) => continue,
+            };
+            if !has_idle {
+                continue;
+            }
+            // A task that took the manager since the check is left alone until the next tick
+            let disconnected = match catalog_manager.try_write() {
+                Ok(mut manager) => manager.disconnect_idle(),
+                Err(_) => continue,
+            };
+            catalog_overview_cache.with_mut(|cache| {
+                for catalog_name in &disconnected {
+                    cache.remove(catalog_name);
+                }
+            });
+        }
+    });
 
-                        // Left Navigation Pane
-                        LeftNavigationPane {
-                            collapsed: nav_pane_collapsed(),
-                            catalog_manager: catalog_manager,
-                            expanded_catalogs: expanded_catalogs,
-                            expanded_namespaces: expanded_namespaces,
-                            on_toggle_collapse: move |_| nav_pane_collapsed.set(!nav_pane_collapsed()),
-                            on_catalog_delete_requested: move |catalog_name: String| {
-                                delete_catalog_name.set(catalog_name);
-                                show_delete_confirmation.set(true);
-                            },
+    // Sleep/wake detection: a wall-clock jump between ticks means the machine was suspended,
+    // so connections are dropped up front and re-established on next use
+    use_future(move || async move {
+        let tick = std::time::Duration::from_secs(WAKE_CHECK_SECS);
+        let mut last_tick = chrono::Utc::now();
+        // Set on wake until the connections could be dropped, which waits for running
+        // requests to finish
+        let mut wake_pending = false;
+        loop {
+            tokio::time::sleep(tick).await;
+            let now = chrono::Utc::now();
+            let slept = catalog::slept_between(last_tick, now, tick);
+            last_tick = now;
+            if slept {
+                log::info!("Woke from sleep; dropping catalog connections");
+                wake_pending = true;
+            }
+            if !wake_pending {
+                if reconnect_notice.peek().is_some() {
+                    reconnect_notice.set(None);
+                }
+                continue;
+            }
+            let disconnected = {
+                let Ok(mut manager) = catalog_manager.try_write() else {
+                    continue;
+                };
+                if manager.request_activity().in_flight > 0 {
+                    continue;
+                }
+                manager.disconnect_after_sleep()
+            };
+            wake_pending = false;
+            if disconnected.is_empty() {
+                continue;
+            }
+            catalog_overview_cache.with_mut(|cache| {
+                for catalog_name in &disconnected {
+                    cache.remove(catalog_name);
+                }
+            });
+            reconnect_notice.set(Some(format!(
+                "Woke from sleep; reconnecting {} on next use",
+                if disconnected.len() == 1 {
+                    disconnected[0].clone()
+                } else {
+                    format!("{} catalogs", disconnected.len())
+                }
+            )));
+        }
+    });
+
+    // Startup update check, rate-limited to once a day; the cached result is reused in between
+    use_future(move || async move {
+        let settings = catalog_manager.read().settings().clone();
+        if !settings.check_for_updates {
+            return;
+        }
+
+        let latest = if updates::is_check_due(settings.last_update_check, chrono::Utc::now()) {
+            let proxy = catalog_manager.read().global_proxy();
+            let latest = updates::fetch_latest_version(proxy).await;
+            // Failures are logged by the manager and only cost an extra check next launch
+            let _ = write_when_free(catalog_manager, |manager| {
+                let mut updated = manager.settings().clone();
+                updated.last_update_check = Some(chrono::Utc::now());
+                if latest.is_some() {
+                    updated.latest_release_version = latest.clone();
+                }
+                manager.update_settings(updated)
+            })
+            .await;
+            latest.or(settings.latest_release_version)
+        } else {
+            settings.latest_release_version
+        };
+
+        if let Some(latest) = latest {
+            if updates::is_newer_version(&latest, updates::CURRENT_VERSION)
+                && settings.dismissed_update_version.as_deref() != Some(latest.as_str())
+            {
+                available_update.set(Some(latest));
+            }
+        }
+    });
+
+    let on_catalog_connected = move |_| {
+        app_state.set(AppState::Connected);
+        active_tab_index.set(0); // Switch to catalog tab
+    };
+
+    // Open a metadata.json as a read-only table in the pseudo metadata-file catalog
+    let mut open_metadata_file =
+        move |(location, props): (String, std::collections::HashMap<String, String>)| {
+            let registered = catalog_manager
+                .with_mut(|manager| manager.register_metadata_file(&location, props));
+            match registered {
+                Ok((directory, file_name)) => {
+                    show_metadata_file_dialog.set(false);
+                    load_table((
+                        catalog::METADATA_FILE_CATALOG.to_string(),
+                        directory,
+                        file_name,
+                    ));
+                }
+                Err(e) => toasts.notify(toast::ToastKind::Error, e.to_string()),
+            }
+        };
+
+    let run_command = move |command: PaletteCommand| {
+        let active_table = match open_tabs.read().get(active_tab_index()) {
+            Some(AppTab::Table { table, .. }) => Some(table.clone()),
+            _ => None,
+        };
+        match (command, active_table) {
+            (PaletteCommand::AddCatalog, _) => app_state.set(AppState::CatalogConnection),
+            (PaletteCommand::OpenSettings, _) => show_settings.set(true),
+            (PaletteCommand::ShowShortcuts, _) => show_shortcuts.set(true),
+            (PaletteCommand::OpenMetadataFile, _) => show_metadata_file_dialog.set(true),
+            (PaletteCommand::GoToTable, _) => show_go_to_table.set(true),
+            (PaletteCommand::OpenTableList, _) => show_open_table_list.set(true),
+            (PaletteCommand::ToggleNavigation, _) => nav_pane_collapsed.set(!nav_pane_collapsed()),
+            (PaletteCommand::CloseTab, Some(_)) => close_tab(active_tab_index()),
+            (PaletteCommand::RefreshTable, Some(table)) => {
+                load_table((table.catalog_name, table.namespace, table.name));
+            }
+            (PaletteCommand::CopyTableReport, Some(table)) => {
+                match export::TableReport::from_table(&table, chrono::Utc::now()).to_json() {
+                    Ok(json) => {
+                        components::copy_to_clipboard(&json);
+                        toasts.notify(toast::ToastKind::Success, t(Msg::ReportCopied));
+                    }
+                    Err(e) => toasts.notify(
+                        toast::ToastKind::Error,
+                        format!("{}: {}", t(Msg::FailedToBuildReport), e),
+                    ),
+                }
+            }
+            (PaletteCommand::SaveTableReport, Some(table)) => {
+                let report = export::TableReport::from_table(&table, chrono::Utc::now());
+                let saved = report
+                    .to_json()
+                    .and_then(|json| export::save_to_downloads(&report.file_name(), &json));
+                match saved {
+                    Ok(path) => toasts.notify(
+                        toast::ToastKind::Success,
+                        tf(Msg::SavedFile, &[&path.display()]),
+                    ),
+                    Err(e) => toasts.notify(
+                        toast::ToastKind::Error,
+                        format!("{}: {}", t(Msg::FailedToExportReport), e),
+                    ),
+                }
+            }
+            (PaletteCommand::CopyDdl(dialect), Some(table)) => {
+                components::copy_to_clipboard(&export::generate_ddl(&table, dialect));
+                toasts.notify(
+                    toast::ToastKind::Success,
+                    tf(Msg::DdlCopied, &[&dialect.label()]),
+                );
+            }
+            (PaletteCommand::CopyDbtSource, Some(table)) => {
+                components::copy_to_clipboard(&export::dbt_source_yaml(&table));
+                toasts.notify(toast::ToastKind::Success, t(Msg::DbtSourceCopied));
+            }
+            (PaletteCommand::CopySchemaMarkdown, Some(table)) => {
+                components::copy_to_clipboard(&export::schema_markdown(&table));
+                toasts.notify(toast::ToastKind::Success, t(Msg::SchemaCopiedAsMarkdown));
+            }
+            (PaletteCommand::SaveSchemaMarkdown, Some(table)) => {
+                let saved = export::save_to_downloads(
+                    &export::schema_markdown_file_name(&table),
+                    &export::schema_markdown(&table),
+                );
+                match saved {
+                    Ok(path) => toasts.notify(
+                        toast::ToastKind::Success,
+                        tf(Msg::SavedFile, &[&path.display()]),
+                    ),
+                    Err(e) => toasts.notify(
+                        toast::ToastKind::Error,
+                        format!("{}: {}", t(Msg::FailedToExportSchema), e),
+                    ),
+                }
+            }
+            (command, None) => log::info!("Command {:?} needs an open table tab", command),
+        }
+    };
+
+    rsx! {
+        div {
+            id: "app-root",
+            class: "min-h-screen bg-gray-100",
+            tabindex: "0", // Make div focusable for keyboard events
+            onkeydown: move |event| {
+                let Some(action) = shortcut_for_key(&event.key(), event.modifiers().ctrl(), event.modifiers().shift()) else {
+                    return;
+                };
+                let connected = matches!(app_state(), AppState::Connected);
+                match action {
+                    ShortcutAction::GlobalSearch if connected => {
+                        show_global_search.set(true);
+                        global_search_query.set(String::new());
+                    }
+                    ShortcutAction::ToggleNavigation if connected => {
+                        nav_pane_collapsed.set(!nav_pane_collapsed());
+                    }
+                    ShortcutAction::CloseTab if connected => {
+                        event.prevent_default();
+                        close_tab(active_tab_index());
+                    }
+                    ShortcutAction::NextTab | ShortcutAction::PreviousTab if connected => {
+                        event.prevent_default();
+                        let tab_count = open_tabs.read().len();
+                        let offset = if action == ShortcutAction::NextTab { 1 } else { tab_count - 1 };
+                        active_tab_index.set((active_tab_index() + offset) % tab_count);
+                    }
+                    ShortcutAction::ShowShortcuts => show_shortcuts.set(!show_shortcuts()),
+                    ShortcutAction::GoToTable if connected => {
+                        event.prevent_default();
+                        show_go_to_table.set(true);
+                    }
+                    ShortcutAction::FocusNavFilter if connected => {
+                        event.prevent_default();
+                        nav_pane_collapsed.set(false);
+                        focus_nav_filter.set(true);
+                    }
+                    ShortcutAction::CloseDialog => show_shortcuts.set(false),
+                    _ => {}
+                }
+            },
+
+            toast::ToastStack {}
+
+            // Command palette (Ctrl+K)
+            if show_global_search() {
+                CommandPalette {
+                    catalog_manager: catalog_manager,
+                    search_query: global_search_query(),
+                    has_active_table: matches!(open_tabs.read().get(active_tab_index()), Some(AppTab::Table { .. })),
+                    on_search_change: move |query: String| global_search_query.set(query),
+                    on_table_selected: load_table,
+                    on_view_selected: open_view,
+                    on_command: run_command,
+                    on_close: move |_| {
+                        show_global_search.set(false);
+                        global_search_query.set(String::new());
+                    }
+                }
+            }
+
+            // Namespace health report
+            if let Some((health_catalog, health_namespace)) = namespace_health_target() {
+                NamespaceHealthReport {
+                    catalog_manager: catalog_manager,
+                    catalog_name: health_catalog,
+                    namespace: health_namespace,
+                    health_cache: namespace_health_cache,
+                    on_table_selected: move |target| {
+                        namespace_health_target.set(None);
+                        load_table(target);
+                        // The report is about health, so land on the snapshot timeline
+                        set_table_view_tab(TableViewTab::SnapshotHistory);
+                    },
+                    on_close: move |_| namespace_health_target.set(None)
+                }
+            }
+
+            // Broken tables scan
+            if let Some(scan_catalog) = broken_tables_target() {
+                BrokenTablesReport {
+                    catalog_manager: catalog_manager,
+                    catalog_name: scan_catalog,
+                    on_close: move |_| broken_tables_target.set(None)
+                }
+            }
+
+            // Catalog comparison
+            if let Some(source_catalog) = catalog_diff_source() {
+                CatalogDiffReport {
+                    catalog_manager: catalog_manager,
+                    source_catalog: source_catalog,
+                    on_close: move |_| catalog_diff_source.set(None)
+                }
+            }
+
+            // Open a table from a metadata file
+            if show_metadata_file_dialog() {
+                catalog_ui::OpenMetadataFileDialog {
+                    on_open: open_metadata_file,
+                    on_close: move |_| show_metadata_file_dialog.set(false)
+                }
+            }
+
+            // Confirm opening many tables at once
+            if let Some(tables) = pending_bulk_open() {
+                BulkOpenConfirmationDialog {
+                    count: tables.len(),
+                    on_confirm: move |_| {
+                        let tables = pending_bulk_open.write().take();
+                        if let Some(tables) = tables {
+                            for table in tables {
+                                load_table(table);
+                            }
+                        }
+                    },
+                    on_cancel: move |_| pending_bulk_open.set(None)
+                }
+            }
+
+            // Open a table by its typed path
+            if show_go_to_table() {
+                GoToTableDialog {
+                    catalog_manager: catalog_manager,
+                    default_catalog: match open_tabs.read().get(active_tab_index()) {
+                        Some(AppTab::Table { table, .. }) => Some(table.catalog_name.clone()),
+                        Some(AppTab::View { view, .. }) => Some(view.catalog_name.clone()),
+                        _ => None,
+                    },
+                    on_open: move |path| {
+                        show_go_to_table.set(false);
+                        load_table(path);
+                    },
+                    on_open_view: move |path| {
+                        show_go_to_table.set(false);
+                        open_view(path);
+                    },
+                    on_close: move |_| show_go_to_table.set(false)
+                }
+            }
+
+            // Open a pasted list of tables
+            if show_open_table_list() {
+                OpenTableListDialog {
+                    catalog_manager: catalog_manager,
+                    default_catalog: match open_tabs.read().get(active_tab_index()) {
+                        Some(AppTab::Table { table, .. }) => Some(table.catalog_name.clone()),
+                        _ => None,
+                    },
+                    on_open: open_tables,
+                    on_close: move |_| show_open_table_list.set(false)
+                }
+            }
+
+            // Keyboard shortcuts cheat sheet
+            if show_shortcuts() {
+                ShortcutsModal {
+                    on_close: move |_| show_shortcuts.set(false)
+                }
+            }
+
+            // Settings panel
+            if show_settings() {
+                settings_ui::SettingsModal {
+                    catalog_manager: catalog_manager,
+                    on_close: move |_| show_settings.set(false)
+                }
+            }
+
+            // Main content based on app state
+            match app_state() {
+                AppState::CatalogConnection => rsx! {
+                    CatalogConnectionScreen {
+                        catalog_manager: catalog_manager,
+                        on_catalog_connected: on_catalog_connected,
+                        on_table_selected: load_table,
+                        on_view_selected: open_view
+                    }
+                },
+                AppState::Connected => rsx! {
+                    // Layout with sidebar and main content
+                    div {
+                        class: "flex h-screen bg-gray-100",
+
+                        // Left Navigation Pane
+                        LeftNavigationPane {
+                            collapsed: nav_pane_collapsed(),
+                            focus_filter: focus_nav_filter,
+                            catalog_manager: catalog_manager,
+                            expanded_catalogs: expanded_catalogs,
+                            expanded_namespaces: expanded_namespaces,
+                            on_toggle_collapse: move |_| nav_pane_collapsed.set(!nav_pane_collapsed()),
+                            on_catalog_delete_requested: move |catalog_name: String| {
+                                delete_catalog_name.set(catalog_name);
+                                show_delete_confirmation.set(true);
+                            },
+                            on_catalog_disconnected: move |catalog_name: String| {
+                                catalog_overview_cache.with_mut(|cache| {
+                                    cache.remove(&catalog_name);
+                                });
+                                namespace_health_cache.with_mut(|cache| {
+                                    cache.retain(|(catalog, _, _), _| *catalog != catalog_name);
+                                });
+                            },
+                            on_warehouse_switched: move |catalog_name: String| {
+                                // Open tables and health results came from the previous warehouse
+                                let belongs = |tab: &AppTab| match tab {
+                                    AppTab::Table { table, .. } => table.catalog_name == catalog_name,
+                                    AppTab::View { view, .. } => view.catalog_name == catalog_name,
+                                    AppTab::LoadingTable { catalog_name: name, .. }
+                                    | AppTab::Namespace { catalog_name: name, .. } => *name == catalog_name,
+                                    AppTab::Catalog => false,
+                                };
+                                let active = active_tab_index();
+                                let active_closed = open_tabs.read().get(active).is_some_and(belongs);
+                                let closed_before_active = open_tabs.read().iter().take(active).filter(|tab| belongs(*tab)).count();
+                                open_tabs.write().retain(|tab| !belongs(tab));
+                                active_tab_index.set(if active_closed { 0 } else { active - closed_before_active });
+                                catalog_overview_cache.with_mut(|cache| {
+                                    cache.remove(&catalog_name);
+                                });
+                                namespace_health_cache.with_mut(|cache| {
+                                    cache.retain(|(catalog, _, _), _| *catalog != catalog_name);
+                                });
+                                if namespace_health_target.read().as_ref().is_some_and(|(name, _)| *name == catalog_name) {
+                                    namespace_health_target.set(None);
+                                }
+                                if broken_tables_target.read().as_deref() == Some(catalog_name.as_str()) {
+                                    broken_tables_target.set(None);
+                                }
+                            },
+                            on_catalog_renamed: move |(old_name, new_name): (String, String)| {
+                                for tab in open_tabs.write().iter_mut() {
+                                    let (tab_id, catalog_name) = match tab {
+                                        AppTab::Table { table, tab_id, .. } => (tab_id, &mut table.catalog_name),
+                                        AppTab::View { view, tab_id } => (tab_id, &mut view.catalog_name),
+                                        AppTab::LoadingTable { tab_id, catalog_name, .. } => (tab_id, catalog_name),
+                                        AppTab::Namespace { catalog_name, .. } => {
+                                            if *catalog_name == old_name {
+                                                *catalog_name = new_name.clone();
+                                            }
+                                            continue;
+                                        }
+                                        AppTab::Catalog => continue,
+                                    };
+                                    if *catalog_name == old_name {
+                                        *catalog_name = new_name.clone();
+                                        if let Some(renamed) = config::rename_catalog_in_table_key(tab_id, &old_name, &new_name) {
+                                            *tab_id = renamed;
+                                        }
+                                    }
+                                }
+                                namespace_health_cache.with_mut(|cache| {
+                                    *cache = cache
+                                        .drain()
+                                        .map(|((catalog, namespace, table), value)| {
+                                            let catalog = if catalog == old_name { new_name.clone() } else { catalog };
+                                            ((catalog, namespace, table), value)
+                                        })
+                                        .collect();
+                                });
+                                catalog_overview_cache.with_mut(|cache| {
+                                    if let Some(overview) = cache.remove(&old_name) {
+                                        cache.insert(new_name.clone(), overview);
+                                    }
+                                });
+                                namespace_health_target.with_mut(|target| {
+                                    if let Some((catalog_name, _)) = target {
+                                        if *catalog_name == old_name {
+                                            *catalog_name = new_name.clone();
+                                        }
+                                    }
+                                });
+                            },
                             on_table_selected: load_table,
+                            on_view_selected: open_view,
+                            on_namespace_health_requested: move |target| namespace_health_target.set(Some(target)),
+                            on_namespace_tab_requested: open_namespace_tab,
+                            on_broken_tables_scan_requested: move |catalog_name| broken_tables_target.set(Some(catalog_name)),
+                            on_catalog_compare_requested: move |catalog_name| catalog_diff_source.set(Some(catalog_name)),
                             on_add_catalog: move |_| app_state.set(AppState::CatalogConnection)
                         }
 
@@ -317,6 +1631,74 @@ fn App() -> Element {
                                                 "🧊 Hielo"
                                             }
                                         }
+                                        div {
+                                            class: "flex items-center space-x-3",
+                                            if let Some(notice) = reconnect_notice() {
+                                                span {
+                                                    class: "text-xs text-yellow-700",
+                                                    title: "Catalog connections are dropped after the computer sleeps and re-established by the next list or load",
+                                                    "🔄 {notice}"
+                                                }
+                                            }
+                                            if request_activity().in_flight > 0 || request_activity().queued > 0 {
+                                                span {
+                                                    class: "text-xs text-gray-500",
+                                                    title: "Catalog requests in flight / waiting for a free slot (see Settings → Catalog Requests)",
+                                                    {
+                                                        let activity = request_activity();
+                                                        if activity.queued > 0 {
+                                                            format!("⏳ {} running · {} queued", activity.in_flight, activity.queued)
+                                                        } else {
+                                                            format!("⏳ {} running", activity.in_flight)
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            button {
+                                                onclick: move |_| show_settings.set(true),
+                                                class: "p-2 text-gray-500 hover:text-gray-700 hover:bg-gray-100 rounded-md",
+                                                title: t(Msg::Settings),
+                                                "⚙️"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // New release banner
+                            if let Some(version) = available_update() {
+                                {
+                                    let current_version = updates::CURRENT_VERSION;
+                                    rsx! {
+                                        div {
+                                            class: "flex items-center justify-between px-4 sm:px-6 lg:px-8 py-2 bg-blue-50 border-b border-blue-200 text-sm text-blue-800 flex-shrink-0",
+                                            span {
+                                                "🎉 Hielo {version} is available (you have {current_version}). "
+                                                a {
+                                                    href: updates::RELEASES_PAGE,
+                                                    target: "_blank",
+                                                    class: "font-medium underline hover:text-blue-900",
+                                                    "View release"
+                                                }
+                                            }
+                                            button {
+                                                onclick: move |_| {
+                                                    let dismissed = available_update();
+                                                    available_update.set(None);
+                                                    spawn(async move {
+                                                        let _ = write_when_free(catalog_manager, |manager| {
+                                                            let mut updated = manager.settings().clone();
+                                                            updated.dismissed_update_version = dismissed;
+                                                            manager.update_settings(updated)
+                                                        })
+                                                        .await;
+                                                    });
+                                                },
+                                                class: "text-blue-400 hover:text-blue-600",
+                                                title: "Dismiss until the next release",
+                                                "✕"
+                                            }
+                                        }
                                     }
                                 }
                             }
@@ -338,17 +1720,115 @@ fn App() -> Element {
                                                         "text-gray-500 hover:text-gray-700 hover:bg-gray-100"
                                                     }
                                                 ),
+                                                if let Some(draft) = match tab {
+                                                    AppTab::Table { tab_id, .. } => editing_tab_label()
+                                                        .filter(|(editing_id, _)| editing_id == tab_id)
+                                                        .map(|(_, draft)| draft),
+                                                    _ => None,
+                                                } {
+                                                    div {
+                                                        class: "flex items-center px-2 py-1 space-x-1",
+                                                        input {
+                                                            r#type: "text",
+                                                            value: "{draft}",
+                                                            placeholder: "Custom label",
+                                                            oninput: move |evt| {
+                                                                editing_tab_label.with_mut(|editing| {
+                                                                    if let Some((_, draft)) = editing {
+                                                                        *draft = evt.value();
+                                                                    }
+                                                                });
+                                                            },
+                                                            onkeydown: move |event: dioxus::prelude::Event<dioxus::html::KeyboardData>| {
+                                                                if event.key() == Key::Enter {
+                                                                    save_tab_label();
+                                                                } else if event.key() == Key::Escape {
+                                                                    editing_tab_label.set(None);
+                                                                }
+                                                            },
+                                                            onblur: move |_| save_tab_label(),
+                                                            class: "w-40 px-2 py-0.5 border border-gray-300 rounded text-sm text-gray-900 focus:outline-none focus:ring-1 focus:ring-blue-500",
+                                                            autofocus: true
+                                                        }
+                                                        button {
+                                                            // Runs before the input's blur would save the draft
+                                                            onmousedown: move |evt| {
+                                                                evt.prevent_default();
+                                                                editing_tab_label.with_mut(|editing| {
+                                                                    if let Some((_, draft)) = editing {
+                                                                        draft.clear();
+                                                                    }
+                                                                });
+                                                                save_tab_label();
+                                                            },
+                                                            class: "text-xs text-gray-400 hover:text-gray-700",
+                                                            title: "Reset to the table name",
+                                                            "↺"
+                                                        }
+                                                    }
+                                                } else {
                                                 button {
                                                     onclick: move |_| active_tab_index.set(index),
-                                                    class: "px-4 py-2 text-sm font-medium flex-1 text-left",
+                                                    ondoubleclick: {
+                                                        let editing = match tab {
+                                                            AppTab::Table { tab_id, table, custom_label, .. } => Some((
+                                                                tab_id.clone(),
+                                                                custom_label.clone().unwrap_or_else(|| table.name.clone()),
+                                                            )),
+                                                            _ => None,
+                                                        };
+                                                        move |_| {
+                                                            if editing.is_some() {
+                                                                editing_tab_label.set(editing.clone());
+                                                            }
+                                                        }
+                                                    },
+                                                    class: "px-4 py-1 text-sm font-medium flex-1 text-left min-w-0",
+                                                    title: tab_tooltip(tab),
+                                                    div {
+                                                        class: "truncate",
+                                                        style: "max-width: 14rem;",
+                                                        {
+                                                            match tab {
+                                                                AppTab::Catalog => "📁 Catalogs".to_string(),
+                                                                AppTab::Table { custom_label: Some(label), .. } => format!("📊 {}", label),
+                                                                AppTab::Table { table, .. } => format!("📊 {}", table.name),
+                                                                AppTab::LoadingTable { table_name, .. } => format!("⏳ {}", table_name),
+                                                                AppTab::Namespace { namespace, .. } => format!("🗂 {}", namespace),
+                                                                AppTab::View { view, .. } => format!("👁️ {}", view.name),
+                                                            }
+                                                        }
+                                                    }
+                                                    if let Some(subtitle) = tab_subtitle(tab, catalog_manager.read().get_connections().len() > 1) {
+                                                        div {
+                                                            class: "truncate text-xs font-normal text-gray-400",
+                                                            style: "max-width: 14rem;",
+                                                            "{subtitle}"
+                                                        }
+                                                    }
+                                                }
+                                                }
+                                                if let AppTab::Table { table, .. } = tab {
                                                     {
-                                                        match tab {
-                                                            AppTab::Catalog => "📁 Catalogs".to_string(),
-                                                            AppTab::Table { table, .. } => format!("📊 {}", table.name),
+                                                        let thresholds = catalog_manager.read().settings().freshness_thresholds_for(&table.table_key());
+                                                        let hours = analytics::TableAnalytics::hours_since_last_snapshot(&table.snapshots, chrono::Utc::now());
+                                                        let freshness = analytics::TableAnalytics::classify_freshness(hours, &thresholds);
+                                                        rsx! {
+                                                            span {
+                                                                class: format!("w-2 h-2 rounded-full mr-2 {}", components::freshness_color_class(freshness)),
+                                                                title: "Data freshness: {freshness.label()}"
+                                                            }
                                                         }
                                                     }
                                                 }
-                                                if matches!(tab, AppTab::Table { .. }) {
+                                                if let AppTab::Table { changes_since_last_view: Some(changes), .. } = tab {
+                                                    span {
+                                                        class: "px-1.5 mr-2 text-xs font-semibold rounded-full bg-blue-100 text-blue-800",
+                                                        title: "New snapshots since you last viewed this table",
+                                                        {format!("+{}", changes.new_snapshots.len())}
+                                                    }
+                                                }
+                                                if !matches!(tab, AppTab::Catalog) {
                                                     button {
                                                         onclick: move |e| {
                                                             e.stop_propagation();
@@ -371,12 +1851,27 @@ fn App() -> Element {
                                         match current_tab {
                                             AppTab::Catalog => rsx! {
                                                 div {
-                                                    class: "p-6",
+                                                    class: "p-6 h-full",
+                                                    ondragover: move |evt| evt.prevent_default(),
+                                                    ondrop: move |evt| {
+                                                        evt.prevent_default();
+                                                        if let Some(path) = catalog_ui::dropped_file_path(&evt) {
+                                                            open_metadata_file((path, std::collections::HashMap::new()));
+                                                        }
+                                                    },
+                                                    CatalogOverviewCard {
+                                                        catalog_manager: catalog_manager,
+                                                        overview_cache: catalog_overview_cache
+                                                    }
+                                                    FreshnessDashboardCard {
+                                                        catalog_manager: catalog_manager,
+                                                        on_table_selected: load_table
+                                                    }
                                                     div {
                                                         class: "text-center py-12",
                                                         h2 {
                                                             class: "text-2xl font-semibold text-gray-900 mb-4",
-                                                            "Welcome to Hielo! 🧊"
+                                                            {format!("{} 🧊", t(Msg::WelcomeTitle))}
                                                         }
                                                         p {
                                                             class: "text-gray-600 mb-6",
@@ -386,13 +1881,20 @@ fn App() -> Element {
                                                             class: "text-sm text-gray-500 space-y-2",
                                                             p { "➕ Click 'Add' in the left panel to connect to a catalog" }
                                                             p { "💡 Press Ctrl+K to search for tables globally" }
+                                                            p { "⌨️ Press Ctrl+/ to see all keyboard shortcuts" }
                                                             p { "🌳 Click catalog names to expand namespaces" }
                                                             p { "🧊 Click Iceberg tables to open them" }
+                                                            p { "📄 Drop a metadata.json file here to open it without a catalog" }
+                                                        }
+                                                        button {
+                                                            onclick: move |_| show_metadata_file_dialog.set(true),
+                                                            class: "mt-6 px-4 py-2 text-sm font-medium text-blue-600 border border-blue-300 rounded-md hover:bg-blue-50",
+                                                            "📄 Open metadata file"
                                                         }
                                                     }
                                                 }
                                             },
-                                            AppTab::Table { table, .. } => rsx! {
+                                            AppTab::Table { table, refresh_interval_secs, last_refreshed, view_tab, changes_since_last_view, .. } => rsx! {
                                                 div {
                                                     class: "h-full flex flex-col",
 
@@ -401,55 +1903,123 @@ fn App() -> Element {
                                                         class: "flex justify-between border-b border-gray-200 bg-gray-50 px-6",
                                                         div {
                                                             class: "flex",
-                                                            button {
-                                                                onclick: move |_| table_view_tab.set(TableViewTab::Overview),
-                                                                class: format!("px-4 py-2 text-sm font-medium {}",
-                                                                    if matches!(table_view_tab(), TableViewTab::Overview) {
-                                                                        "text-blue-600 border-b-2 border-blue-600 bg-white"
-                                                                    } else {
-                                                                        "text-gray-500 hover:text-gray-700"
+                                                            for tab_option in catalog_manager.read().settings().visible_table_view_tabs() {
+                                                                button {
+                                                                    onclick: move |_| set_table_view_tab(tab_option),
+                                                                    class: format!("px-4 py-2 text-sm font-medium {}",
+                                                                        if *view_tab == tab_option {
+                                                                            "text-blue-600 border-b-2 border-blue-600 bg-white"
+                                                                        } else {
+                                                                            "text-gray-500 hover:text-gray-700"
+                                                                        }
+                                                                    ),
+                                                                    {t(tab_option.label())}
+                                                                }
+                                                            }
+                                                        }
+
+                                                        // Refresh button
+                                                        div {
+                                                            class: "flex items-center space-x-3",
+                                                            {
+                                                                let favorite = data::FavoriteTable {
+                                                                    catalog_name: table.catalog_name.clone(),
+                                                                    namespace: table.namespace.clone(),
+                                                                    table_name: table.name.clone(),
+                                                                };
+                                                                let is_favorite = catalog_manager.read().is_favorite_table(&favorite);
+                                                                rsx! {
+                                                                    button {
+                                                                        onclick: move |_| {
+                                                                            let favorite = favorite.clone();
+                                                                            spawn(async move {
+                                                                                if let Err(e) = write_when_free(catalog_manager, |manager| manager.toggle_favorite_table(favorite)).await {
+                                                                                    log::error!("Failed to save favorite tables: {}", e);
+                                                                                }
+                                                                            });
+                                                                        },
+                                                                        class: if is_favorite { "text-lg text-yellow-500 hover:text-yellow-600" } else { "text-lg text-gray-400 hover:text-yellow-500" },
+                                                                        title: if is_favorite { "Remove from favorites" } else { "Add to favorites; the welcome tab tracks their freshness" },
+                                                                        if is_favorite { "★" } else { "☆" }
                                                                     }
-                                                                ),
-                                                                "Overview"
+                                                                }
+                                                            }
+                                                            components::FreshnessBadge {
+                                                                table: table.clone(),
+                                                                catalog_manager: catalog_manager
                                                             }
                                                             button {
-                                                                onclick: move |_| table_view_tab.set(TableViewTab::Schema),
-                                                                class: format!("px-4 py-2 text-sm font-medium {}",
-                                                                    if matches!(table_view_tab(), TableViewTab::Schema) {
-                                                                        "text-blue-600 border-b-2 border-blue-600 bg-white"
-                                                                    } else {
-                                                                        "text-gray-500 hover:text-gray-700"
+                                                                onclick: {
+                                                                    let table_clone = table.clone();
+                                                                    move |_| {
+                                                                        let report = export::TableReport::from_table(&table_clone, chrono::Utc::now());
+                                                                        match report.to_json() {
+                                                                            Ok(json) => {
+                                                                                components::copy_to_clipboard(&json);
+                                                                                toasts.notify(toast::ToastKind::Success, t(Msg::ReportCopied));
+                                                                            }
+                                                                            Err(e) => toasts.notify(toast::ToastKind::Error, format!("{}: {}", t(Msg::FailedToBuildReport), e)),
+                                                                        }
                                                                     }
-                                                                ),
-                                                                "Schema"
+                                                                },
+                                                                class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                                                title: "Copy the full table report (schemas, partitioning, snapshots, health) as JSON",
+                                                                "📋 Report"
                                                             }
                                                             button {
-                                                                onclick: move |_| table_view_tab.set(TableViewTab::Partitions),
-                                                                class: format!("px-4 py-2 text-sm font-medium {}",
-                                                                    if matches!(table_view_tab(), TableViewTab::Partitions) {
-                                                                        "text-blue-600 border-b-2 border-blue-600 bg-white"
-                                                                    } else {
-                                                                        "text-gray-500 hover:text-gray-700"
+                                                                onclick: {
+                                                                    let table_clone = table.clone();
+                                                                    move |_| {
+                                                                        let report = export::TableReport::from_table(&table_clone, chrono::Utc::now());
+                                                                        let saved = report
+                                                                            .to_json()
+                                                                            .and_then(|json| export::save_to_downloads(&report.file_name(), &json));
+                                                                        match saved {
+                                                                            Ok(path) => toasts.notify(toast::ToastKind::Success, tf(Msg::SavedFile, &[&path.display()])),
+                                                                            Err(e) => toasts.notify(toast::ToastKind::Error, format!("{}: {}", t(Msg::FailedToExportReport), e)),
+                                                                        }
                                                                     }
-                                                                ),
-                                                                "Partitions"
+                                                                },
+                                                                class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                                                title: "Save the full table report as JSON to your downloads folder",
+                                                                "💾 Report"
                                                             }
                                                             button {
-                                                                onclick: move |_| table_view_tab.set(TableViewTab::SnapshotHistory),
-                                                                class: format!("px-4 py-2 text-sm font-medium {}",
-                                                                    if matches!(table_view_tab(), TableViewTab::SnapshotHistory) {
-                                                                        "text-blue-600 border-b-2 border-blue-600 bg-white"
-                                                                    } else {
-                                                                        "text-gray-500 hover:text-gray-700"
+                                                                onclick: {
+                                                                    let table_clone = table.clone();
+                                                                    move |_| {
+                                                                        components::copy_to_clipboard(&export::dbt_source_yaml(&table_clone));
+                                                                        toasts.notify(toast::ToastKind::Success, t(Msg::DbtSourceCopied));
                                                                     }
-                                                                ),
-                                                                "Snapshots"
+                                                                },
+                                                                class: "px-2 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                                                                title: "Copy a dbt sources YAML block for this table",
+                                                                "📋 dbt"
+                                                            }
+                                                            span {
+                                                                class: "text-xs text-gray-500",
+                                                                title: "Time the table metadata was last loaded",
+                                                                {format!("Refreshed {}", last_refreshed.with_timezone(&chrono::Local).format("%H:%M:%S"))}
+                                                            }
+                                                            select {
+                                                                class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                                                                title: "Auto-refresh interval (only runs while this tab is active)",
+                                                                onchange: move |evt| {
+                                                                    let interval = evt.value().parse::<u64>().ok();
+                                                                    if let Some(AppTab::Table { refresh_interval_secs, .. }) =
+                                                                        open_tabs.write().get_mut(active_tab_index())
+                                                                    {
+                                                                        *refresh_interval_secs = interval;
+                                                                    }
+                                                                },
+                                                                for (interval, label) in AUTO_REFRESH_INTERVALS {
+                                                                    option {
+                                                                        value: interval.map(|i| i.to_string()).unwrap_or_default(),
+                                                                        selected: *refresh_interval_secs == interval,
+                                                                        {format!("Auto: {}", label)}
+                                                                    }
+                                                                }
                                                             }
-                                                        }
-
-                                                        // Refresh button
-                                                        div {
-                                                            class: "flex items-center",
                                                             button {
                                                                 onclick: {
                                                                     let table_clone = table.clone();
@@ -486,7 +2056,37 @@ fn App() -> Element {
                                                                         }
                                                                     }
                                                                 }
-                                                                "Refresh"
+                                                                {t(Msg::Refresh)}
+                                                            }
+                                                        }
+                                                    }
+
+                                                    components::LargeTableBanner {
+                                                        table: table.clone(),
+                                                        snapshot_limit: catalog_manager.read().settings().large_table_snapshot_limit,
+                                                        metadata_warning_bytes: catalog_manager.read().settings().large_metadata_warning_mb * 1024 * 1024,
+                                                        on_load_all: {
+                                                            let table = table.clone();
+                                                            move |_| {
+                                                                if let Some(AppTab::Table { all_snapshots, .. }) =
+                                                                    open_tabs.write().get_mut(active_tab_index())
+                                                                {
+                                                                    *all_snapshots = true;
+                                                                }
+                                                                load_table((table.catalog_name.clone(), table.namespace.clone(), table.name.clone()));
+                                                            }
+                                                        }
+                                                    }
+                                                    if let Some(changes) = changes_since_last_view {
+                                                        components::ChangesSinceLastViewBanner {
+                                                            changes: changes.clone(),
+                                                            on_show_snapshots: move |_| set_table_view_tab(TableViewTab::SnapshotHistory),
+                                                            on_dismiss: move |_| {
+                                                                if let Some(AppTab::Table { changes_since_last_view, .. }) =
+                                                                    open_tabs.write().get_mut(active_tab_index())
+                                                                {
+                                                                    *changes_since_last_view = None;
+                                                                }
                                                             }
                                                         }
                                                     }
@@ -494,35 +2094,85 @@ fn App() -> Element {
                                                     // Table sub-tab content
                                                     div {
                                                         class: "flex-1 overflow-y-auto p-6",
-                                                        match table_view_tab() {
+                                                        match view_tab {
                                                             TableViewTab::Overview => rsx! {
                                                                 components::TableOverviewTab {
-                                                                    table: table.clone()
+                                                                    table: table.clone(),
+                                                                    warehouse: catalog_manager.read().catalog_warehouse(&table.catalog_name),
+                                                                    catalog_manager: catalog_manager
                                                                 }
                                                             },
                                                             TableViewTab::Schema => rsx! {
                                                                 components::TableSchemaTab {
-                                                                    table: table.clone()
+                                                                    table: table.clone(),
+                                                                    catalog_manager: catalog_manager,
+                                                                    schema_file_stats_cache: schema_file_stats_cache,
+                                                                    column_size_cache: column_size_cache,
+                                                                    focus_field: schema_focus_field
                                                                 }
                                                             },
                                                             TableViewTab::Partitions => rsx! {
                                                                 components::TablePartitionsTab {
-                                                                    table: table.clone()
+                                                                    table: table.clone(),
+                                                                    on_field_selected: move |field_id: i32| {
+                                                                        schema_focus_field.set(Some(field_id));
+                                                                        set_table_view_tab(TableViewTab::Schema);
+                                                                    }
                                                                 }
                                                             },
                                                             TableViewTab::SnapshotHistory => rsx! {
                                                                 components::SnapshotTimelineTab {
-                                                                    table: table.clone()
+                                                                    table: table.clone(),
+                                                                    catalog_manager: catalog_manager,
+                                                                    data_file_origins_cache: data_file_origins_cache
                                                                 }
                                                             },
                                                         }
                                                     }
                                                 }
                                             },
-                                        }
-                                    }
-                                }
-                            }
+                                            AppTab::LoadingTable { namespace, table_name, .. } => rsx! {
+                                                components::TableLoadingSkeleton {
+                                                    namespace: namespace.clone(),
+                                                    table_name: table_name.clone()
+                                                }
+                                            },
+                                            AppTab::Namespace { catalog_name, namespace } => rsx! {
+                                                NamespaceTab {
+                                                    // A fresh instance per namespace, so switching tabs reloads
+                                                    key: "{catalog_name}::{namespace}",
+                                                    catalog_manager: catalog_manager,
+                                                    catalog_name: catalog_name.clone(),
+                                                    namespace: namespace.clone(),
+                                                    on_table_selected: load_table,
+                                                    on_view_selected: open_view,
+                                                    on_open_all: open_tables
+                                                }
+                                            },
+                                            AppTab::View { view, tab_id } => rsx! {
+                                                div {
+                                                    class: "h-full flex flex-col",
+                                                    // Views have a single, read-only sub-tab
+                                                    div {
+                                                        class: "flex border-b border-gray-200 bg-gray-50 px-6",
+                                                        span {
+                                                            class: "px-4 py-2 text-sm font-medium text-blue-600 border-b-2 border-blue-600 bg-white",
+                                                            {t(Msg::TabViewDefinition)}
+                                                        }
+                                                    }
+                                                    div {
+                                                        class: "flex-1 overflow-y-auto p-6",
+                                                        components::ViewDefinitionTab {
+                                                            key: "{tab_id}",
+                                                            view: view.clone()
+                                                        }
+                                                    }
+                                                }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
                         }
 
                         // Delete confirmation dialog
@@ -534,8 +2184,9 @@ fn App() -> Element {
                                     if let Err(e) = catalog_manager.with_mut(|manager| {
                                         manager.delete_catalog(&catalog_name_to_delete)
                                     }) {
-                                        error_message.set(Some(format!("Failed to delete catalog: {}", e)));
+                                        toasts.notify(toast::ToastKind::Error, format!("{}: {}", t(Msg::FailedToDeleteCatalog), e));
                                     }
+                                    catalog_overview_cache.write().remove(&catalog_name_to_delete);
                                     show_delete_confirmation.set(false);
                                     delete_catalog_name.set(String::new());
                                 },
@@ -568,10 +2219,10 @@ fn App() -> Element {
                 top: 0.5rem;
                 width: 0.75rem;
                 height: 0.75rem;
-                background-color: #3b82f6;
+                background-color: rgb(var(--accent-500));
                 border-radius: 50%;
                 border: 2px solid white;
-                box-shadow: 0 0 0 2px #3b82f6;
+                box-shadow: 0 0 0 2px rgb(var(--accent-500));
             }}
             
             .timeline-item::after {{
@@ -589,100 +2240,2893 @@ fn App() -> Element {
             }}
             "
         }
+
+        // Accent color, routed through the blue utilities above
+        style { {theme::accent_stylesheet(&catalog_manager.read().settings().accent_color)} }
+    }
+}
+
+/// Walk every namespace of every connected catalog and collect its tables, tagged with the
+/// catalog they came from. Per-namespace failures are collected rather than aborting the crawl.
+async fn crawl_all_tables(
+    catalog_manager: Signal<CatalogManager>,
+) -> (Vec<(String, catalog::TableReference)>, Vec<String>) {
+    let catalog_names: Vec<String> = catalog_manager
+        .read()
+        .get_connections()
+        .iter()
+        .map(|conn| conn.config.name.clone())
+        .collect();
+
+    let mut tables = Vec::new();
+    let mut errors = Vec::new();
+    for catalog_name in catalog_names {
+        // The crawl can outlast an exchanged token, so it's renewed before each catalog
+        if let Err(e) = ensure_catalog_connected(catalog_manager, &catalog_name).await {
+            errors.push(format!("{}: {}", catalog_name, e));
+            continue;
+        }
+        let namespaces = match catalog_manager.read().list_namespaces(&catalog_name).await {
+            Ok(namespaces) => namespaces,
+            Err(e) => {
+                errors.push(format!("{}: {}", catalog_name, e));
+                continue;
+            }
+        };
+
+        for namespace in namespaces {
+            if let Err(e) = ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                errors.push(format!("{}.{}: {}", catalog_name, namespace, e));
+                continue;
+            }
+            match catalog_manager
+                .read()
+                .list_tables(&catalog_name, &namespace)
+                .await
+            {
+                Ok(namespace_tables) => {
+                    tables.extend(
+                        namespace_tables
+                            .into_iter()
+                            .map(|table| (catalog_name.clone(), table)),
+                    );
+                }
+                Err(e) => errors.push(format!("{}.{}: {}", catalog_name, namespace, e)),
+            }
+        }
+    }
+
+    (tables, errors)
+}
+
+/// A command palette row: an action or a table to open
+#[derive(Debug, Clone, PartialEq)]
+enum PaletteItem {
+    Command(PaletteCommand),
+    Table(catalog::TableReference),
+}
+
+/// Upper bound on tables rendered in the palette, however often "show more" is used
+const PALETTE_TABLE_MAX_SHOWN: usize = 1000;
+
+#[component]
+fn CommandPalette(
+    catalog_manager: Signal<CatalogManager>,
+    search_query: String,
+    has_active_table: bool,
+    on_search_change: EventHandler<String>,
+    on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
+    on_command: EventHandler<PaletteCommand>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut all_tables = use_signal(Vec::<catalog::TableReference>::new);
+    let mut loading = use_signal(|| false);
+    let mut error_message = use_signal(|| Option::<String>::None);
+    let mut selected_index = use_signal(|| 0usize);
+    let page_size = catalog_manager.read().settings().search_result_limit.max(1);
+    // Tables revealed so far; grows by a page with "show more" and resets when the query changes
+    let mut shown_limit = use_signal(|| page_size);
+
+    // Load all tables from all namespaces when modal opens
+    use_effect(move || {
+        spawn(async move {
+            loading.set(true);
+            error_message.set(None);
+
+            let connections = catalog_manager.read().get_connections().to_vec();
+            if let Some(connection) = connections.first() {
+                let catalog_name = connection.config.name.clone();
+
+                let namespaces =
+                    match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                        Ok(()) => catalog_manager.read().list_namespaces(&catalog_name).await,
+                        Err(e) => Err(e),
+                    };
+                match namespaces {
+                    Ok(namespaces) => {
+                        let mut tables = Vec::new();
+
+                        for namespace in namespaces {
+                            let listed = match ensure_catalog_connected(
+                                catalog_manager,
+                                &catalog_name,
+                            )
+                            .await
+                            {
+                                Ok(()) => {
+                                    catalog_manager
+                                        .read()
+                                        .list_tables(&catalog_name, &namespace)
+                                        .await
+                                }
+                                Err(e) => Err(e),
+                            };
+                            match listed {
+                                Ok(namespace_tables) => {
+                                    tables.extend(namespace_tables);
+                                }
+                                Err(e) => {
+                                    error_message.set(Some(format!(
+                                        "Failed to load tables from namespace '{}': {}",
+                                        namespace, e
+                                    )));
+                                }
+                            }
+                        }
+
+                        all_tables.set(tables);
+                    }
+                    Err(e) => {
+                        error_message.set(Some(format!("Failed to load namespaces: {}", e)));
+                    }
+                }
+            } else {
+                error_message.set(Some("No catalog connection found".to_string()));
+            }
+
+            loading.set(false);
+        });
+    });
+
+    let catalog_name = catalog_manager
+        .read()
+        .get_connections()
+        .first()
+        .map_or_else(
+            || "unknown".to_string(),
+            |connection| connection.config.name.clone(),
+        );
+
+    // Filter commands and tables based on search query
+    let query_clone = search_query.clone();
+    let query_lower = query_clone.to_lowercase();
+    let commands: Vec<&PaletteCommandEntry> = PALETTE_COMMANDS
+        .iter()
+        .filter(|entry| has_active_table || !entry.needs_table)
+        .filter(|entry| entry.name.to_lowercase().contains(&query_lower))
+        .collect();
+    let filtered_tables: Vec<catalog::TableReference> = if query_clone.is_empty() {
+        all_tables()
+    } else {
+        all_tables()
+            .into_iter()
+            .filter(|table| {
+                table.full_name.to_lowercase().contains(&query_lower)
+                    || table.name.to_lowercase().contains(&query_lower)
+                    || table.namespace.to_lowercase().contains(&query_lower)
+            })
+            .collect()
+    };
+
+    let shown_tables = shown_limit().min(PALETTE_TABLE_MAX_SHOWN);
+    let hidden_tables = filtered_tables.len().saturating_sub(shown_tables);
+    let can_show_more = hidden_tables > 0 && shown_tables < PALETTE_TABLE_MAX_SHOWN;
+
+    // Keyboard navigation runs over commands first, then tables
+    let items: Vec<PaletteItem> = commands
+        .iter()
+        .map(|entry| PaletteItem::Command(entry.command))
+        .chain(
+            filtered_tables
+                .iter()
+                .take(shown_tables)
+                .cloned()
+                .map(PaletteItem::Table),
+        )
+        .collect();
+    let selected = selected_index().min(items.len().saturating_sub(1));
+
+    let activate = {
+        let catalog_name = catalog_name.clone();
+        move |item: PaletteItem| match item {
+            PaletteItem::Command(command) => {
+                on_close.call(());
+                on_command.call(command);
+            }
+            // Only allow selection of Iceberg tables and views
+            PaletteItem::Table(table) => {
+                let selected = (catalog_name.clone(), table.namespace, table.name);
+                match table.table_type {
+                    catalog::TableType::Iceberg => on_table_selected.call(selected),
+                    catalog::TableType::View => on_view_selected.call(selected),
+                    catalog::TableType::Unknown => return,
+                }
+                on_close.call(());
+            }
+        }
+    };
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            // Modal content
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-2xl w-full mx-4 flex flex-col",
+                style: "max-height: 32rem;",
+                onclick: |e| e.stop_propagation(), // Prevent closing when clicking inside modal
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    h3 {
+                        class: "text-lg font-medium text-gray-900",
+                        "🔍 Command Palette (Ctrl+K)"
+                    }
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "text-gray-400 hover:text-gray-600",
+                        "✕"
+                    }
+                }
+
+                // Search input
+                div {
+                    class: "p-4 border-b border-gray-200",
+                    input {
+                        r#type: "text",
+                        placeholder: "Type a command or a table name...",
+                        value: search_query,
+                        oninput: move |evt| {
+                            selected_index.set(0);
+                            shown_limit.set(page_size);
+                            on_search_change.call(evt.value());
+                        },
+                        onkeydown: {
+                            let items = items.clone();
+                            let activate = activate.clone();
+                            move |event: dioxus::prelude::Event<dioxus::html::KeyboardData>| {
+                                match event.key() {
+                                    Key::Escape => on_close.call(()),
+                                    Key::ArrowDown if !items.is_empty() => {
+                                        event.prevent_default();
+                                        // Moving past the last shown table reveals the next batch
+                                        if selected + 1 == items.len() && can_show_more {
+                                            shown_limit.set(shown_tables + page_size);
+                                            selected_index.set(selected + 1);
+                                        } else {
+                                            selected_index.set((selected + 1) % items.len());
+                                        }
+                                    }
+                                    Key::ArrowUp if !items.is_empty() => {
+                                        event.prevent_default();
+                                        selected_index.set((selected + items.len() - 1) % items.len());
+                                    }
+                                    Key::Enter => {
+                                        if let Some(item) = items.get(selected) {
+                                            activate(item.clone());
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        },
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent",
+                        autofocus: true
+                    }
+                }
+
+                // Results
+                div {
+                    class: "flex-1 overflow-y-auto",
+                    if !commands.is_empty() {
+                        div {
+                            class: "px-4 pt-3 pb-1 text-xs font-semibold text-gray-500 uppercase tracking-wider",
+                            "Commands"
+                        }
+                        div {
+                            class: "divide-y divide-gray-100",
+                            for (index, entry) in commands.iter().enumerate() {
+                                button {
+                                    onclick: {
+                                        let activate = activate.clone();
+                                        let command = entry.command;
+                                        move |_| activate(PaletteItem::Command(command))
+                                    },
+                                    onmouseenter: move |_| selected_index.set(index),
+                                    class: format!(
+                                        "w-full px-4 py-2 text-left flex items-center justify-between text-sm {}",
+                                        if index == selected { "bg-blue-50" } else { "hover:bg-gray-50" }
+                                    ),
+                                    span { class: "text-gray-900", "⚡ {entry.name}" }
+                                    if let Some(label) = entry.shortcut.and_then(shortcut_label) {
+                                        kbd {
+                                            class: "px-2 py-0.5 text-xs font-mono text-gray-600 bg-gray-100 border border-gray-300 rounded",
+                                            "{label}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        class: "px-4 pt-3 pb-1 text-xs font-semibold text-gray-500 uppercase tracking-wider",
+                        "Tables"
+                    }
+                    if loading() {
+                        div {
+                            class: "flex items-center justify-center py-8",
+                            div {
+                                class: "animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600"
+                            }
+                        }
+                    } else if let Some(error) = error_message() {
+                        div {
+                            class: "p-4 text-red-600 text-sm",
+                            {format!("{}: {}", t(Msg::Error), error)}
+                        }
+                    } else if filtered_tables.is_empty() {
+                        div {
+                            class: "p-4 text-gray-500 text-sm text-center",
+                            if query_clone.is_empty() {
+                                {t(Msg::NoTablesFound)}
+                            } else {
+                                "No tables match your search"
+                            }
+                        }
+                    } else {
+                        div {
+                            class: "divide-y divide-gray-200",
+                            for (offset, table) in filtered_tables.iter().take(shown_tables).enumerate() {
+                                {
+                                    let index = commands.len() + offset;
+                                    let selectable = table.table_type != catalog::TableType::Unknown;
+                                    rsx! {
+                                        button {
+                                            onclick: {
+                                                let activate = activate.clone();
+                                                let table_clone = table.clone();
+                                                move |_| activate(PaletteItem::Table(table_clone.clone()))
+                                            },
+                                            onmouseenter: move |_| selected_index.set(index),
+                                            class: format!(
+                                                "w-full px-4 py-3 text-left flex items-center justify-between {} {}",
+                                                if index == selected { "bg-blue-50" } else { "hover:bg-gray-50" },
+                                                if selectable { "cursor-pointer" } else { "cursor-not-allowed opacity-50" }
+                                            ),
+                                            disabled: !selectable,
+
+                                            div {
+                                                class: "flex items-center",
+                                                span {
+                                                    class: "mr-3 text-lg",
+                                                    {table_type_icon(table.table_type)}
+                                                }
+                                                div {
+                                                    div {
+                                                        class: "font-medium text-gray-900 text-sm",
+                                                        "{table.full_name}"
+                                                    }
+                                                    div {
+                                                        class: "text-gray-500 text-xs",
+                                                        "{table.namespace} • {table.name}"
+                                                    }
+                                                }
+                                            }
+
+                                            span {
+                                                class: "text-gray-400 text-xs",
+                                                if !selectable {
+                                                    "Not Iceberg"
+                                                } else if index == selected {
+                                                    "Press Enter"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Footer
+                div {
+                    class: "p-3 bg-gray-50 border-t border-gray-200 text-xs text-gray-500",
+                    if !filtered_tables.is_empty() {
+                        div {
+                            class: "flex items-center justify-between",
+                            span { "↑↓ to select, Enter to run · Showing {shown_tables.min(filtered_tables.len())} of {filtered_tables.len()} tables" }
+                            if can_show_more {
+                                button {
+                                    onclick: move |_| shown_limit.set(shown_tables + page_size),
+                                    class: "font-medium text-blue-600 hover:text-blue-800",
+                                    {format!("Show {} more", hidden_tables.min(page_size))}
+                                }
+                            } else if hidden_tables > 0 {
+                                span { "Refine the search to see the rest" }
+                            }
+                        }
+                    } else {
+                        "↑↓ to select, Enter to run · Use Ctrl+K to open this palette anytime"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Condensed health metrics for one row of the namespace health report
+#[derive(Debug, Clone, PartialEq)]
+struct TableHealthSummary {
+    health_score: f64,
+    top_alert: Option<data::HealthAlert>,
+    recommended_action: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HealthReportSort {
+    Table,
+    Score,
+    Alert,
+}
+
+fn alert_severity_rank(severity: &data::AlertSeverity) -> u8 {
+    match severity {
+        data::AlertSeverity::Info => 1,
+        data::AlertSeverity::Warning => 2,
+        data::AlertSeverity::Critical => 3,
+        data::AlertSeverity::Emergency => 4,
+    }
+}
+
+fn maintenance_priority_rank(priority: &data::MaintenancePriority) -> u8 {
+    match priority {
+        data::MaintenancePriority::Low => 1,
+        data::MaintenancePriority::Medium => 2,
+        data::MaintenancePriority::High => 3,
+        data::MaintenancePriority::Urgent => 4,
+    }
+}
+
+/// Keep only the most severe alert and the highest-priority recommendation
+fn summarize_table_health(metrics: data::TableHealthMetrics) -> TableHealthSummary {
+    let top_alert = metrics
+        .alerts
+        .into_iter()
+        .max_by_key(|alert| alert_severity_rank(&alert.severity));
+    let recommended_action = metrics
+        .recommendations
+        .into_iter()
+        .max_by_key(|rec| maintenance_priority_rank(&rec.priority))
+        .map(|rec| rec.description);
+
+    TableHealthSummary {
+        health_score: metrics.health_score,
+        top_alert,
+        recommended_action,
+    }
+}
+
+/// Namespace health results by (catalog, namespace, table)
+type NamespaceHealthCache =
+    std::collections::HashMap<(String, String, String), Result<TableHealthSummary, String>>;
+
+#[component]
+fn NamespaceHealthReport(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: String,
+    namespace: String,
+    // Kept by the caller so reopening the report is instant
+    health_cache: Signal<NamespaceHealthCache>,
+    on_table_selected: EventHandler<(String, String, String)>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut table_names = use_signal(|| Option::<Vec<String>>::None);
+    let mut list_error = use_signal(|| Option::<String>::None);
+    let mut sort_column = use_signal(|| HealthReportSort::Score);
+    let mut sort_descending = use_signal(|| false);
+    let scan_generation = use_signal(|| 0usize);
+    let cache_key = {
+        let catalog_name = catalog_name.clone();
+        let namespace = namespace.clone();
+        move |table_name: &str| {
+            (
+                catalog_name.clone(),
+                namespace.clone(),
+                table_name.to_string(),
+            )
+        }
+    };
+
+    let scan = {
+        let catalog_name = catalog_name.clone();
+        let namespace = namespace.clone();
+        let cache_key = cache_key.clone();
+        move || {
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            let cache_key = cache_key.clone();
+            spawn(async move {
+                list_error.set(None);
+                let listed = match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                    Ok(()) => {
+                        catalog_manager
+                            .read()
+                            .list_tables(&catalog_name, &namespace)
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
+                let names: Vec<String> = match listed {
+                    Ok(tables) => tables
+                        .into_iter()
+                        .filter(|t| t.table_type == catalog::TableType::Iceberg)
+                        .map(|t| t.name)
+                        .collect(),
+                    Err(e) => {
+                        list_error.set(Some(format!("Failed to list tables: {}", e)));
+                        return;
+                    }
+                };
+                table_names.set(Some(names.clone()));
+
+                let uncached: Vec<String> = names
+                    .into_iter()
+                    .filter(|table_name| !health_cache.peek().contains_key(&cache_key(table_name)))
+                    .collect();
+                crawl::crawl(
+                    catalog_manager,
+                    crawl::CrawlRun::start(scan_generation),
+                    uncached,
+                    move |table_name: String| {
+                        let catalog_name = catalog_name.clone();
+                        let namespace = namespace.clone();
+                        async move {
+                            let (enabled_categories, metadata_warning_mb) = {
+                                let manager = catalog_manager.read();
+                                let settings = manager.settings();
+                                (
+                                    settings.enabled_health_categories.clone(),
+                                    settings.large_metadata_warning_mb,
+                                )
+                            };
+                            // Loaded once for both the metadata and the partition scan
+                            let loaded = match load_catalog_table(
+                                catalog_manager,
+                                &catalog_name,
+                                &namespace,
+                                &table_name,
+                            )
+                            .await
+                            {
+                                Ok(loaded) => loaded,
+                                Err(e) => return (table_name, Err(e)),
+                            };
+                            let table = match convert_table(
+                                catalog_manager,
+                                &loaded,
+                                &catalog_name,
+                                &namespace,
+                                false,
+                            ) {
+                                Ok(table) => table,
+                                Err(e) => return (table_name, Err(e)),
+                            };
+                            // Unreadable manifests only leave partition health unscored
+                            let partition_stats =
+                                if enabled_categories.contains(&data::HealthCategory::Partition) {
+                                    let stats = catalog_manager
+                                        .read()
+                                        .loaded_partition_stats(&catalog_name, &loaded)
+                                        .await;
+                                    stats.ok()
+                                } else {
+                                    None
+                                };
+                            let metrics = analytics::TableAnalytics::compute_health_metrics_for(
+                                &table,
+                                &enabled_categories,
+                                metadata_warning_mb,
+                                partition_stats.as_deref(),
+                            );
+                            (table_name, Ok(summarize_table_health(metrics)))
+                        }
+                    },
+                    move |(table_name, result): (String, Result<TableHealthSummary, String>)| {
+                        health_cache.write().insert(cache_key(&table_name), result);
+                    },
+                );
+            });
+        }
+    };
+
+    use_effect({
+        let scan = scan.clone();
+        move || {
+            scan();
+        }
+    });
+
+    let names = table_names().unwrap_or_default();
+    let cache = health_cache.read();
+    let mut rows: Vec<(String, Option<Result<TableHealthSummary, String>>)> = names
+        .iter()
+        .map(|name| (name.clone(), cache.get(&cache_key(name)).cloned()))
+        .collect();
+    drop(cache);
+    let completed = rows.iter().filter(|(_, result)| result.is_some()).count();
+    let total = rows.len();
+
+    // Failed and still-loading rows always sink to the bottom
+    rows.sort_by(|(a_name, a), (b_name, b)| {
+        let a_ok = a.as_ref().and_then(|r| r.as_ref().ok());
+        let b_ok = b.as_ref().and_then(|r| r.as_ref().ok());
+        let ordering = match (a_ok, b_ok) {
+            (Some(a), Some(b)) => match sort_column() {
+                HealthReportSort::Table => a_name.cmp(b_name),
+                HealthReportSort::Score => a.health_score.total_cmp(&b.health_score),
+                HealthReportSort::Alert => a
+                    .top_alert
+                    .as_ref()
+                    .map(|alert| alert_severity_rank(&alert.severity))
+                    .cmp(
+                        &b.top_alert
+                            .as_ref()
+                            .map(|alert| alert_severity_rank(&alert.severity)),
+                    ),
+            },
+            (Some(_), None) => return std::cmp::Ordering::Less,
+            (None, Some(_)) => return std::cmp::Ordering::Greater,
+            (None, None) => return a_name.cmp(b_name),
+        };
+        if sort_descending() {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    let mut sort_by = move |column: HealthReportSort| {
+        if sort_column() == column {
+            sort_descending.set(!sort_descending());
+        } else {
+            sort_column.set(column);
+            // Most alarming first for alerts; worst score first for scores
+            sort_descending.set(column == HealthReportSort::Alert);
+        }
+    };
+    let sort_indicator = move |column: HealthReportSort| {
+        if sort_column() == column {
+            if sort_descending() { " ▼" } else { " ▲" }
+        } else {
+            ""
+        }
+    };
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-4xl w-full mx-4 flex flex-col",
+                style: "max-height: 80vh;",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    div {
+                        h3 {
+                            class: "text-lg font-medium text-gray-900",
+                            "🩺 Namespace Health: {namespace}"
+                        }
+                        p {
+                            class: "text-xs text-gray-500",
+                            if total > 0 && completed < total {
+                                "Scanning {completed} of {total} tables in {catalog_name}..."
+                            } else {
+                                "{total} Iceberg tables in {catalog_name}"
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex items-center space-x-3",
+                        button {
+                            onclick: {
+                                let catalog_name = catalog_name.clone();
+                                let namespace = namespace.clone();
+                                let scan = scan.clone();
+                                move |_| {
+                                    health_cache.write().retain(|(catalog, listed_namespace, _), _| {
+                                        *catalog != catalog_name || *listed_namespace != namespace
+                                    });
+                                    scan();
+                                }
+                            },
+                            disabled: total > 0 && completed < total,
+                            class: "px-3 py-1 text-xs font-medium text-blue-600 hover:text-blue-800 disabled:text-gray-400",
+                            title: "Discard cached results and reload every table",
+                            "↻ Rescan"
+                        }
+                        button {
+                            onclick: move |_| on_close.call(()),
+                            class: "text-gray-400 hover:text-gray-600",
+                            "✕"
+                        }
+                    }
+                }
+
+                // Progress
+                if total > 0 && completed < total {
+                    div {
+                        class: "h-1 bg-gray-100",
+                        div {
+                            class: "h-1 bg-blue-600 transition-all",
+                            style: format!("width: {}%", completed * 100 / total),
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto",
+                    if let Some(error) = list_error() {
+                        div {
+                            class: "p-4 text-red-600 text-sm",
+                            "{error}"
+                        }
+                    } else if table_names().is_none() {
+                        div {
+                            class: "flex items-center justify-center py-8",
+                            div {
+                                class: "animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600"
+                            }
+                        }
+                    } else if rows.is_empty() {
+                        div {
+                            class: "p-4 text-gray-500 text-sm text-center",
+                            "No Iceberg tables in this namespace"
+                        }
+                    } else {
+                        table {
+                            class: "min-w-full divide-y divide-gray-200",
+                            thead {
+                                class: "bg-gray-50 sticky top-0",
+                                tr {
+                                    th {
+                                        class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:text-gray-700",
+                                        onclick: move |_| sort_by(HealthReportSort::Table),
+                                        "Table{sort_indicator(HealthReportSort::Table)}"
+                                    }
+                                    th {
+                                        class: "px-4 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:text-gray-700",
+                                        onclick: move |_| sort_by(HealthReportSort::Score),
+                                        "Score{sort_indicator(HealthReportSort::Score)}"
+                                    }
+                                    th {
+                                        class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:text-gray-700",
+                                        onclick: move |_| sort_by(HealthReportSort::Alert),
+                                        "Top Alert{sort_indicator(HealthReportSort::Alert)}"
+                                    }
+                                    th {
+                                        class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                        "Recommended Action"
+                                    }
+                                }
+                            }
+                            tbody {
+                                class: "bg-white divide-y divide-gray-200",
+                                for (table_name, result) in rows {
+                                    tr {
+                                        class: "hover:bg-blue-50 cursor-pointer",
+                                        title: "Open this table's health panel",
+                                        onclick: {
+                                            let catalog_name = catalog_name.clone();
+                                            let namespace = namespace.clone();
+                                            let table_name = table_name.clone();
+                                            move |_| {
+                                                on_table_selected.call((catalog_name.clone(), namespace.clone(), table_name.clone()));
+                                            }
+                                        },
+                                        td { class: "px-4 py-2 text-sm font-medium text-gray-900", "🧊 {table_name}" }
+                                        {match result {
+                                            Some(Ok(summary)) => rsx! {
+                                                td {
+                                                    class: format!("px-4 py-2 text-sm font-bold text-right {}",
+                                                        if summary.health_score >= 75.0 {
+                                                            "text-green-700"
+                                                        } else if summary.health_score >= 60.0 {
+                                                            "text-yellow-700"
+                                                        } else {
+                                                            "text-red-700"
+                                                        }
+                                                    ),
+                                                    {format!("{:.0}", summary.health_score)}
+                                                }
+                                                td {
+                                                    class: "px-4 py-2 text-xs text-gray-700",
+                                                    if let Some(alert) = summary.top_alert {
+                                                        span {
+                                                            class: match alert.severity {
+                                                                data::AlertSeverity::Critical | data::AlertSeverity::Emergency => "text-red-700",
+                                                                data::AlertSeverity::Warning => "text-yellow-700",
+                                                                data::AlertSeverity::Info => "text-blue-700",
+                                                            },
+                                                            "{alert.message}"
+                                                        }
+                                                    } else {
+                                                        span { class: "text-gray-400", "None" }
+                                                    }
+                                                }
+                                                td {
+                                                    class: "px-4 py-2 text-xs text-gray-700",
+                                                    {summary.recommended_action.unwrap_or_else(|| "—".to_string())}
+                                                }
+                                            },
+                                            Some(Err(e)) => rsx! {
+                                                td {
+                                                    class: "px-4 py-2 text-xs text-red-600",
+                                                    colspan: "3",
+                                                    title: "{e}",
+                                                    {t(Msg::FailedToLoad)}
+                                                }
+                                            },
+                                            None => rsx! {
+                                                td {
+                                                    class: "px-4 py-2 text-xs text-gray-400",
+                                                    colspan: "3",
+                                                    {t(Msg::Loading)}
+                                                }
+                                            },
+                                        }}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A listed table whose metadata could not be loaded
+#[derive(Debug, Clone, PartialEq)]
+struct BrokenTable {
+    namespace: String,
+    table_name: String,
+    kind: catalog::LoadFailureKind,
+    error: String,
+}
+
+/// Try to load every table of a catalog and list the ones that fail, grouped by cause
+#[component]
+fn BrokenTablesReport(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: String,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut total = use_signal(|| 0usize);
+    let mut checked = use_signal(|| 0usize);
+    let mut failures = use_signal(Vec::<BrokenTable>::new);
+    let mut list_errors = use_signal(Vec::<String>::new);
+    let mut listing = use_signal(|| false);
+    let mut cancelled = use_signal(|| false);
+    let scan_generation = use_signal(|| 0usize);
+
+    let scan = {
+        let catalog_name = catalog_name.clone();
+        move || {
+            let catalog_name = catalog_name.clone();
+            total.set(0);
+            checked.set(0);
+            failures.set(Vec::new());
+            list_errors.set(Vec::new());
+            cancelled.set(false);
+            listing.set(true);
+            let run = crawl::CrawlRun::start(scan_generation);
+            spawn(async move {
+                let namespaces =
+                    match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                        Ok(()) => catalog_manager.read().list_namespaces(&catalog_name).await,
+                        Err(e) => Err(e),
+                    };
+                if !run.is_current() {
+                    return;
+                }
+                let namespaces = match namespaces {
+                    Ok(namespaces) => namespaces,
+                    Err(e) => {
+                        list_errors
+                            .write()
+                            .push(format!("Failed to list namespaces: {}", e));
+                        listing.set(false);
+                        return;
+                    }
+                };
+
+                let mut tables = Vec::new();
+                for namespace in namespaces {
+                    let names = match ensure_catalog_connected(catalog_manager, &catalog_name).await
+                    {
+                        Ok(()) => {
+                            catalog_manager
+                                .read()
+                                .list_table_names(&catalog_name, &namespace)
+                                .await
+                        }
+                        Err(e) => Err(e),
+                    };
+                    if !run.is_current() {
+                        return;
+                    }
+                    match names {
+                        Ok(names) => {
+                            *total.write() += names.len();
+                            tables.extend(names.into_iter().map(|name| (namespace.clone(), name)));
+                        }
+                        Err(e) => list_errors.write().push(format!("{}: {}", namespace, e)),
+                    }
+                }
+                listing.set(false);
+
+                crawl::crawl(
+                    catalog_manager,
+                    run,
+                    tables,
+                    move |(namespace, table_name): (String, String)| {
+                        let catalog_name = catalog_name.clone();
+                        async move {
+                            let result = match ensure_catalog_connected(
+                                catalog_manager,
+                                &catalog_name,
+                            )
+                            .await
+                            {
+                                Ok(()) => catalog_manager
+                                    .read()
+                                    .load_table(&catalog_name, &namespace, &table_name)
+                                    .await
+                                    .map(|_| ()),
+                                Err(e) => Err(e),
+                            };
+                            (namespace, table_name, result.err())
+                        }
+                    },
+                    move |(namespace, table_name, error): (
+                        String,
+                        String,
+                        Option<catalog::CatalogError>,
+                    )| {
+                        if let Some(e) = error {
+                            failures.write().push(BrokenTable {
+                                namespace,
+                                table_name,
+                                kind: e.load_failure_kind(),
+                                error: e.message().to_string(),
+                            });
+                        }
+                        *checked.write() += 1;
+                    },
+                );
+            });
+        }
+    };
+
+    use_effect({
+        let mut scan = scan.clone();
+        move || {
+            scan();
+        }
+    });
+
+    let running = listing() || (checked() < total() && !cancelled());
+    let mut sorted_failures = failures();
+    sorted_failures.sort_by(|a, b| {
+        (a.kind, &a.namespace, &a.table_name).cmp(&(b.kind, &b.namespace, &b.table_name))
+    });
+    let mut kind_counts: Vec<(catalog::LoadFailureKind, usize)> = Vec::new();
+    for failure in &sorted_failures {
+        match kind_counts.last_mut() {
+            Some((kind, count)) if *kind == failure.kind => *count += 1,
+            _ => kind_counts.push((failure.kind, 1)),
+        }
+    }
+    let status = if running {
+        format!(
+            "Checked {} of {} tables in {}...",
+            checked(),
+            total(),
+            catalog_name
+        )
+    } else if cancelled() {
+        format!(
+            "Cancelled after checking {} of {} tables",
+            checked(),
+            total()
+        )
+    } else {
+        format!("Checked {} tables in {}", checked(), catalog_name)
+    };
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-4xl w-full mx-4 flex flex-col",
+                style: "max-height: 80vh;",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    div {
+                        h3 {
+                            class: "text-lg font-medium text-gray-900",
+                            "🔎 Broken Tables: {catalog_name}"
+                        }
+                        p { class: "text-xs text-gray-500", "{status}" }
+                    }
+                    div {
+                        class: "flex items-center space-x-3",
+                        if running {
+                            button {
+                                onclick: move |_| {
+                                    cancelled.set(true);
+                                    listing.set(false);
+                                    crawl::CrawlRun::cancel(scan_generation);
+                                },
+                                class: "px-3 py-1 text-xs font-medium text-red-600 hover:text-red-800",
+                                "■ Cancel"
+                            }
+                        } else {
+                            button {
+                                onclick: {
+                                    let mut scan = scan.clone();
+                                    move |_| scan()
+                                },
+                                class: "px-3 py-1 text-xs font-medium text-blue-600 hover:text-blue-800",
+                                "↻ Rescan"
+                            }
+                        }
+                        button {
+                            onclick: move |_| on_close.call(()),
+                            class: "text-gray-400 hover:text-gray-600",
+                            "✕"
+                        }
+                    }
+                }
+
+                // Progress
+                if running && total() > 0 {
+                    div {
+                        class: "h-1 bg-gray-100",
+                        div {
+                            class: "h-1 bg-blue-600 transition-all",
+                            style: format!("width: {}%", checked() * 100 / total()),
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto",
+                    for error in list_errors() {
+                        div { class: "px-4 pt-3 text-red-600 text-sm", "{error}" }
+                    }
+                    if !kind_counts.is_empty() {
+                        div {
+                            class: "flex flex-wrap gap-2 px-4 pt-3",
+                            for (kind, count) in kind_counts {
+                                span {
+                                    class: "inline-flex px-2 py-1 text-xs font-medium rounded bg-red-100 text-red-800",
+                                    "{kind.label()}: {count}"
+                                }
+                            }
+                        }
+                    }
+                    if sorted_failures.is_empty() {
+                        if running {
+                            div {
+                                class: "flex items-center justify-center py-8",
+                                div {
+                                    class: "animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600"
+                                }
+                            }
+                        } else if list_errors().is_empty() {
+                            div {
+                                class: "p-4 text-green-700 text-sm text-center",
+                                "✅ Every checked table loaded successfully"
+                            }
+                        }
+                    } else {
+                        table {
+                            class: "min-w-full divide-y divide-gray-200 mt-3",
+                            thead {
+                                class: "bg-gray-50 sticky top-0",
+                                tr {
+                                    th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Table" }
+                                    th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Cause" }
+                                    th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Error" }
+                                }
+                            }
+                            tbody {
+                                class: "bg-white divide-y divide-gray-200",
+                                for failure in sorted_failures {
+                                    tr {
+                                        td { class: "px-4 py-2 text-sm font-medium text-gray-900 whitespace-nowrap", "{failure.namespace}.{failure.table_name}" }
+                                        td { class: "px-4 py-2 text-xs text-red-700 whitespace-nowrap", "{failure.kind.label()}" }
+                                        td { class: "px-4 py-2 text-xs text-gray-600 font-mono break-all", "{failure.error}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compare the tables of a namespace, or of every namespace, between two connected catalogs:
+/// which tables exist on only one side, and which differ in schema or partitioning. Meant
+/// for checking an environment before promoting to the next one, e.g. staging against prod.
+#[component]
+fn CatalogDiffReport(
+    catalog_manager: Signal<CatalogManager>,
+    source_catalog: String,
+    on_close: EventHandler<()>,
+) -> Element {
+    let target_options: Vec<String> = catalog_manager
+        .read()
+        .get_connections()
+        .iter()
+        .map(|connection| connection.config.name.clone())
+        .filter(|name| *name != source_catalog)
+        .collect();
+    let mut target_catalog = use_signal(|| target_options.first().cloned().unwrap_or_default());
+    // Namespace to compare; `None` compares the whole catalog
+    let mut scope = use_signal(|| Option::<String>::None);
+    let mut source_namespaces = use_signal(Vec::<String>::new);
+    let mut entries = use_signal(Vec::<data::CatalogDiffEntry>::new);
+    let mut list_errors = use_signal(Vec::<String>::new);
+    let mut total = use_signal(|| 0usize);
+    let mut compared = use_signal(|| 0usize);
+    let mut listing = use_signal(|| false);
+    let mut cancelled = use_signal(|| false);
+    let compare_generation = use_signal(|| 0usize);
+    // The catalogs and scope of the results shown, fixed when a comparison starts
+    let mut compared_scope = use_signal(|| Option::<(String, Option<String>)>::None);
+
+    // Offer the source's namespaces as scopes
+    use_effect({
+        let source_catalog = source_catalog.clone();
+        move || {
+            let source_catalog = source_catalog.clone();
+            spawn(async move {
+                if ensure_catalog_connected(catalog_manager, &source_catalog)
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+                let namespaces = catalog_manager
+                    .read()
+                    .list_namespaces(&source_catalog)
+                    .await;
+                if let Ok(namespaces) = namespaces {
+                    source_namespaces.set(namespaces);
+                }
+            });
+        }
+    });
+
+    let compare = {
+        let source_catalog = source_catalog.clone();
+        move |_: MouseEvent| {
+            let source_catalog = source_catalog.clone();
+            let target_catalog = target_catalog();
+            if target_catalog.is_empty() {
+                return;
+            }
+            let scope = scope();
+            compared_scope.set(Some((target_catalog.clone(), scope.clone())));
+            entries.set(Vec::new());
+            list_errors.set(Vec::new());
+            total.set(0);
+            compared.set(0);
+            cancelled.set(false);
+            listing.set(true);
+            let run = crawl::CrawlRun::start(compare_generation);
+            spawn(async move {
+                // Namespaces of each side; a namespace one side lacks has no tables there
+                let mut catalog_namespaces = Vec::new();
+                for catalog_name in [&source_catalog, &target_catalog] {
+                    let listed = match ensure_catalog_connected(catalog_manager, catalog_name).await
+                    {
+                        Ok(()) => catalog_manager.read().list_namespaces(catalog_name).await,
+                        Err(e) => Err(e),
+                    };
+                    if !run.is_current() {
+                        return;
+                    }
+                    match listed {
+                        Ok(namespaces) => catalog_namespaces.push(namespaces),
+                        Err(e) => {
+                            list_errors.write().push(format!(
+                                "{}: {}",
+                                tf(Msg::FailedToListNamespacesOf, &[catalog_name]),
+                                e
+                            ));
+                            listing.set(false);
+                            return;
+                        }
+                    }
+                }
+                let mut namespaces: Vec<String> = match &scope {
+                    Some(namespace) => vec![namespace.clone()],
+                    None => catalog_namespaces.concat(),
+                };
+                namespaces.sort();
+                namespaces.dedup();
+
+                let mut shared_tables = Vec::new();
+                for namespace in namespaces {
+                    let mut sides: Vec<std::collections::BTreeSet<String>> = Vec::new();
+                    for (catalog_name, listed) in [&source_catalog, &target_catalog]
+                        .into_iter()
+                        .zip(&catalog_namespaces)
+                    {
+                        if !listed.contains(&namespace) {
+                            sides.push(std::collections::BTreeSet::new());
+                            continue;
+                        }
+                        let names =
+                            match ensure_catalog_connected(catalog_manager, catalog_name).await {
+                                Ok(()) => {
+                                    catalog_manager
+                                        .read()
+                                        .list_table_names(catalog_name, &namespace)
+                                        .await
+                                }
+                                Err(e) => Err(e),
+                            };
+                        if !run.is_current() {
+                            return;
+                        }
+                        match names {
+                            Ok(names) => sides.push(names.into_iter().collect()),
+                            Err(e) => {
+                                list_errors
+                                    .write()
+                                    .push(format!("{}.{}: {}", catalog_name, namespace, e));
+                                break;
+                            }
+                        }
+                    }
+                    let [source_tables, target_tables] = sides.as_slice() else {
+                        continue;
+                    };
+
+                    let entry = |table_name: &String, status| data::CatalogDiffEntry {
+                        namespace: namespace.clone(),
+                        table_name: table_name.clone(),
+                        status,
+                    };
+                    entries.write().extend(
+                        source_tables
+                            .difference(target_tables)
+                            .map(|name| entry(name, data::CatalogDiffStatus::OnlyInSource))
+                            .chain(
+                                target_tables
+                                    .difference(source_tables)
+                                    .map(|name| entry(name, data::CatalogDiffStatus::OnlyInTarget)),
+                            ),
+                    );
+
+                    let shared: Vec<String> =
+                        source_tables.intersection(target_tables).cloned().collect();
+                    *total.write() += shared.len();
+                    shared_tables.extend(shared.into_iter().map(|name| (namespace.clone(), name)));
+                }
+                listing.set(false);
+
+                crawl::crawl(
+                    catalog_manager,
+                    run,
+                    shared_tables,
+                    move |(namespace, table_name): (String, String)| {
+                        let source_catalog = source_catalog.clone();
+                        let target_catalog = target_catalog.clone();
+                        async move {
+                            let source = fetch_table(
+                                catalog_manager,
+                                &source_catalog,
+                                &namespace,
+                                &table_name,
+                                false,
+                            )
+                            .await;
+                            let target = fetch_table(
+                                catalog_manager,
+                                &target_catalog,
+                                &namespace,
+                                &table_name,
+                                false,
+                            )
+                            .await;
+                            let status = match (source, target) {
+                                (Ok(source), Ok(target)) => {
+                                    let changes =
+                                        analytics::TableAnalytics::environment_differences(
+                                            &source, &target,
+                                        );
+                                    if changes.is_empty() {
+                                        data::CatalogDiffStatus::Matches
+                                    } else {
+                                        data::CatalogDiffStatus::Differs(changes)
+                                    }
+                                }
+                                (Err(e), _) => data::CatalogDiffStatus::Failed(format!(
+                                    "{}: {}",
+                                    source_catalog, e
+                                )),
+                                (_, Err(e)) => data::CatalogDiffStatus::Failed(format!(
+                                    "{}: {}",
+                                    target_catalog, e
+                                )),
+                            };
+                            data::CatalogDiffEntry {
+                                namespace,
+                                table_name,
+                                status,
+                            }
+                        }
+                    },
+                    move |entry: data::CatalogDiffEntry| {
+                        entries.write().push(entry);
+                        *compared.write() += 1;
+                    },
+                );
+            });
+        }
+    };
+
+    let running = listing() || (compared() < total() && !cancelled());
+    let mut sorted_entries = entries();
+    sorted_entries
+        .sort_by(|a, b| (&a.namespace, &a.table_name).cmp(&(&b.namespace, &b.table_name)));
+    let (mut matching, mut mismatched) = (0usize, Vec::new());
+    for entry in sorted_entries {
+        if entry.status == data::CatalogDiffStatus::Matches {
+            matching += 1;
+        } else {
+            mismatched.push(entry);
+        }
+    }
+    let status = match compared_scope() {
+        None => t(Msg::PickCatalogToCompare).to_string(),
+        Some((target, _)) if running => {
+            tf(Msg::ComparisonProgress, &[&compared(), &total(), &target])
+        }
+        Some((target, _)) if cancelled() => {
+            tf(Msg::ComparisonCancelled, &[&compared(), &total(), &target])
+        }
+        Some((target, _)) => tf(
+            Msg::ComparisonSummary,
+            &[&matching, &target, &mismatched.len()],
+        ),
+    };
+    let save_report = {
+        let source_catalog = source_catalog.clone();
+        move |_| {
+            let Some((target, scope)) = compared_scope() else {
+                return;
+            };
+            let saved = export::save_to_downloads(
+                &export::catalog_diff_file_name(&source_catalog, &target, scope.as_deref()),
+                &export::catalog_diff_markdown(
+                    &source_catalog,
+                    &target,
+                    scope.as_deref(),
+                    &entries(),
+                ),
+            );
+            match saved {
+                Ok(path) => toast::notify(
+                    toast::ToastKind::Success,
+                    tf(Msg::SavedFile, &[&path.display()]),
+                ),
+                Err(e) => toast::notify(
+                    toast::ToastKind::Error,
+                    format!("{}: {}", t(Msg::FailedToSaveComparison), e),
+                ),
+            }
+        }
+    };
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-4xl w-full mx-4 flex flex-col",
+                style: "max-height: 80vh;",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    div {
+                        h3 {
+                            class: "text-lg font-medium text-gray-900",
+                            {format!("⇄ {}: {}", t(Msg::CompareCatalogs), source_catalog)}
+                        }
+                        p { class: "text-xs text-gray-500", "{status}" }
+                    }
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "text-gray-400 hover:text-gray-600",
+                        "✕"
+                    }
+                }
+
+                // Catalog and scope
+                div {
+                    class: "flex items-center space-x-3 px-4 py-3 border-b border-gray-200 text-sm",
+                    if target_options.is_empty() {
+                        span { class: "text-gray-500", {t(Msg::ConnectAnotherCatalog)} }
+                    } else {
+                        span { class: "text-gray-600", {t(Msg::CompareAgainst)} }
+                        select {
+                            class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                            disabled: running,
+                            onchange: move |evt| target_catalog.set(evt.value()),
+                            for option_catalog in target_options.iter() {
+                                option {
+                                    value: "{option_catalog}",
+                                    selected: *option_catalog == target_catalog(),
+                                    "{option_catalog}"
+                                }
+                            }
+                        }
+                        span { class: "text-gray-600", {t(Msg::CompareIn)} }
+                        select {
+                            class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                            disabled: running,
+                            onchange: move |evt| {
+                                let value = evt.value();
+                                scope.set((!value.is_empty()).then_some(value));
+                            },
+                            option { value: "", selected: scope().is_none(), {t(Msg::AllNamespaces)} }
+                            for namespace in source_namespaces() {
+                                option {
+                                    value: "{namespace}",
+                                    selected: scope().as_ref() == Some(&namespace),
+                                    "{namespace}"
+                                }
+                            }
+                        }
+                        if running {
+                            button {
+                                onclick: move |_| {
+                                    cancelled.set(true);
+                                    listing.set(false);
+                                    crawl::CrawlRun::cancel(compare_generation);
+                                },
+                                class: "px-3 py-1 text-xs font-medium text-red-600 hover:text-red-800",
+                                {format!("■ {}", t(Msg::Cancel))}
+                            }
+                        } else {
+                            button {
+                                onclick: compare,
+                                class: "px-3 py-1 text-xs font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700",
+                                {t(Msg::Compare)}
+                            }
+                            if compared_scope().is_some() {
+                                button {
+                                    onclick: save_report,
+                                    class: "px-3 py-1 text-xs font-medium text-blue-600 hover:text-blue-800",
+                                    title: t(Msg::DownloadComparisonHelp),
+                                    {format!("⬇ {}", t(Msg::DownloadReport))}
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Progress
+                if running && total() > 0 {
+                    div {
+                        class: "h-1 bg-gray-100",
+                        div {
+                            class: "h-1 bg-blue-600 transition-all",
+                            style: format!("width: {}%", compared() * 100 / total()),
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto",
+                    for error in list_errors() {
+                        div { class: "px-4 pt-3 text-red-600 text-sm", "{error}" }
+                    }
+                    if mismatched.is_empty() {
+                        if running {
+                            div {
+                                class: "flex items-center justify-center py-8",
+                                div {
+                                    class: "animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600"
+                                }
+                            }
+                        } else if compared_scope().is_some() && list_errors().is_empty() {
+                            div {
+                                class: "p-4 text-green-700 text-sm text-center",
+                                {format!("✅ {}", t(Msg::CatalogsMatch))}
+                            }
+                        }
+                    } else {
+                        table {
+                            class: "min-w-full divide-y divide-gray-200",
+                            thead {
+                                class: "bg-gray-50 sticky top-0",
+                                tr {
+                                    th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", {t(Msg::ColumnTable)} }
+                                    th { class: "px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", {t(Msg::ColumnDifference)} }
+                                }
+                            }
+                            tbody {
+                                class: "bg-white divide-y divide-gray-200",
+                                for entry in mismatched {
+                                    tr {
+                                        td { class: "px-4 py-2 text-sm font-medium text-gray-900 whitespace-nowrap align-top", "{entry.namespace}.{entry.table_name}" }
+                                        td {
+                                            class: "px-4 py-2 text-xs",
+                                            {match entry.status {
+                                                data::CatalogDiffStatus::OnlyInSource => rsx! {
+                                                    span { class: "text-red-700", {tf(Msg::MissingFrom, &[&compared_scope().map(|(target, _)| target).unwrap_or_default()])} }
+                                                },
+                                                data::CatalogDiffStatus::OnlyInTarget => rsx! {
+                                                    span { class: "text-yellow-700", {tf(Msg::MissingFrom, &[&source_catalog])} }
+                                                },
+                                                data::CatalogDiffStatus::Differs(changes) => rsx! {
+                                                    ul {
+                                                        class: "space-y-0.5 text-gray-700",
+                                                        for change in changes {
+                                                            li { "{change.kind.label()}: {change.description}" }
+                                                        }
+                                                    }
+                                                },
+                                                data::CatalogDiffStatus::Failed(error) => rsx! {
+                                                    span { class: "text-gray-500 font-mono break-all", title: "{error}", {format!("{}: {}", t(Msg::NotCompared), error)} }
+                                                },
+                                                data::CatalogDiffStatus::Matches => rsx! {},
+                                            }}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Live summary of a connected catalog for the welcome tab. Namespaces and tables are listed
+/// the first time a catalog is shown and cached; classifying tables and summing their size
+/// loads every table, so it only runs on request.
+#[component]
+fn CatalogOverviewCard(
+    catalog_manager: Signal<CatalogManager>,
+    overview_cache: Signal<std::collections::HashMap<String, data::CatalogOverview>>,
+) -> Element {
+    let catalog_names: Vec<String> = catalog_manager
+        .read()
+        .get_connections()
+        .iter()
+        .map(|connection| connection.config.name.clone())
+        .collect();
+    let mut selected = use_signal(String::new);
+    // Catalogs being listed or classified by this card
+    let mut listing = use_signal(std::collections::HashSet::<String>::new);
+    let mut classifying = use_signal(std::collections::HashSet::<String>::new);
+
+    // Fall back to the first catalog until one is picked, or if the picked one was removed
+    let catalog_name = if catalog_names.contains(&selected()) {
+        selected()
+    } else {
+        catalog_names.first().cloned().unwrap_or_default()
+    };
+
+    let mut list_catalog = move |catalog_name: String| {
+        listing.write().insert(catalog_name.clone());
+        spawn(async move {
+            let mut overview = data::CatalogOverview::default();
+            let namespaces = match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                Ok(()) => catalog_manager.read().list_namespaces(&catalog_name).await,
+                Err(e) => Err(e),
+            };
+            match namespaces {
+                Ok(namespaces) => {
+                    overview.namespace_count = namespaces.len();
+                    for namespace in namespaces {
+                        let names =
+                            match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                                Ok(()) => {
+                                    catalog_manager
+                                        .read()
+                                        .list_table_names(&catalog_name, &namespace)
+                                        .await
+                                }
+                                Err(e) => Err(e),
+                            };
+                        match names {
+                            Ok(names) => overview
+                                .tables
+                                .extend(names.into_iter().map(|name| (namespace.clone(), name))),
+                            Err(_) => overview.failed_namespaces += 1,
+                        }
+                        // Publish partial counts so large catalogs show progress
+                        overview_cache
+                            .write()
+                            .insert(catalog_name.clone(), overview.clone());
+                    }
+                    overview.listed = true;
+                }
+                Err(e) => overview.error = Some(e.message().to_string()),
+            }
+            overview_cache
+                .write()
+                .insert(catalog_name.clone(), overview);
+            listing.write().remove(&catalog_name);
+        });
+    };
+
+    let mut classify_catalog = move |catalog_name: String| {
+        let Some(tables) = overview_cache
+            .peek()
+            .get(&catalog_name)
+            .map(|overview| overview.tables.clone())
+        else {
+            return;
+        };
+        overview_cache.with_mut(|cache| {
+            if let Some(overview) = cache.get_mut(&catalog_name) {
+                overview.classified = Some(0);
+                overview.iceberg_count = 0;
+                overview.total_size_bytes = 0;
+            }
+        });
+        classifying.write().insert(catalog_name.clone());
+
+        crawl::crawl(
+            catalog_manager,
+            crawl::CrawlRun::detached(),
+            tables,
+            {
+                let catalog_name = catalog_name.clone();
+                move |(namespace, table_name): (String, String)| {
+                    let catalog_name = catalog_name.clone();
+                    async move {
+                        ensure_catalog_connected(catalog_manager, &catalog_name).await?;
+                        catalog_manager
+                            .read()
+                            .table_stats(&catalog_name, &namespace, &table_name)
+                            .await
+                    }
+                }
+            },
+            move |result: Result<data::TableStats, catalog::CatalogError>| {
+                overview_cache.with_mut(|cache| {
+                    let Some(overview) = cache.get_mut(&catalog_name) else {
+                        return;
+                    };
+                    if let Ok(stats) = result {
+                        overview.iceberg_count += 1;
+                        overview.total_size_bytes += stats.total_size_bytes.unwrap_or(0);
+                    }
+                    overview.classified = Some(overview.classified.unwrap_or(0) + 1);
+                    if overview.fully_classified() {
+                        classifying.write().remove(&catalog_name);
+                    }
+                });
+            },
+        );
+    };
+
+    // List the shown catalog the first time it appears, or again if an earlier listing was cut short
+    use_effect(move || {
+        let selected = selected();
+        let catalog_name = {
+            let manager = catalog_manager.read();
+            let connections = manager.get_connections();
+            connections
+                .iter()
+                .find(|connection| connection.config.name == selected)
+                .or_else(|| connections.first())
+                .map(|connection| connection.config.name.clone())
+        };
+        let Some(catalog_name) = catalog_name else {
+            return;
+        };
+        if listing.peek().contains(&catalog_name) {
+            return;
+        }
+        let needs_listing = !overview_cache
+            .peek()
+            .get(&catalog_name)
+            .is_some_and(|overview| overview.listed || overview.error.is_some());
+        if needs_listing {
+            list_catalog(catalog_name);
+        }
+    });
+
+    if catalog_names.is_empty() {
+        return rsx! {};
+    }
+
+    let overview = overview_cache.read().get(&catalog_name).cloned();
+    let is_listing = listing.read().contains(&catalog_name);
+    let is_classifying = classifying.read().contains(&catalog_name)
+        && !overview
+            .as_ref()
+            .is_some_and(data::CatalogOverview::fully_classified);
+
+    rsx! {
+        div {
+            class: "max-w-2xl mx-auto mb-8 bg-white border border-gray-200 rounded-lg shadow-sm text-left",
+            div {
+                class: "flex items-center justify-between px-4 py-3 border-b border-gray-200",
+                h3 {
+                    class: "text-sm font-medium text-gray-900",
+                    "📊 Catalog Overview"
+                }
+                div {
+                    class: "flex items-center space-x-2",
+                    if catalog_names.len() > 1 {
+                        select {
+                            class: "text-sm border border-gray-300 rounded-md px-2 py-1",
+                            value: "{catalog_name}",
+                            onchange: move |evt| selected.set(evt.value()),
+                            for name in catalog_names.iter() {
+                                option { value: "{name}", "{name}" }
+                            }
+                        }
+                    } else {
+                        span { class: "text-sm text-gray-600", "{catalog_name}" }
+                    }
+                    button {
+                        disabled: is_listing || is_classifying,
+                        onclick: {
+                            let catalog_name = catalog_name.clone();
+                            move |_| {
+                                overview_cache.write().remove(&catalog_name);
+                                list_catalog(catalog_name.clone());
+                            }
+                        },
+                        class: "px-2 py-1 text-xs text-gray-600 hover:text-gray-900 disabled:opacity-50",
+                        title: "List namespaces and tables again",
+                        "↻"
+                    }
+                }
+            }
+            match overview {
+                None => rsx! {
+                    div {
+                        class: "flex items-center px-4 py-6 text-sm text-gray-500",
+                        div { class: "animate-spin rounded-full h-4 w-4 border-b-2 border-blue-600 mr-2" }
+                        "Listing namespaces..."
+                    }
+                },
+                Some(overview) => rsx! {
+                    if let Some(error) = overview.error.clone() {
+                        div { class: "px-4 py-3 text-sm text-red-600", {format!("{}: {}", t(Msg::FailedToListNamespaces), error)} }
+                    } else {
+                        div {
+                            class: "grid grid-cols-2 sm:grid-cols-4 gap-4 px-4 py-4",
+                            div {
+                                p { class: "text-xs text-gray-500", "Namespaces" }
+                                p { class: "text-xl font-semibold text-gray-900", "{overview.namespace_count}" }
+                            }
+                            div {
+                                p { class: "text-xs text-gray-500", "Tables" }
+                                p {
+                                    class: "text-xl font-semibold text-gray-900",
+                                    if is_listing { "{overview.tables.len()}…" } else { "{overview.tables.len()}" }
+                                }
+                            }
+                            div {
+                                p { class: "text-xs text-gray-500", "Iceberg / other" }
+                                p {
+                                    class: "text-xl font-semibold text-gray-900",
+                                    if overview.classified.is_some() {
+                                        {format!("{} / {}", overview.iceberg_count, overview.other_count())}
+                                    } else {
+                                        "—"
+                                    }
+                                }
+                            }
+                            div {
+                                p { class: "text-xs text-gray-500", "Data size" }
+                                p {
+                                    class: "text-xl font-semibold text-gray-900",
+                                    if overview.classified.is_some() {
+                                        {components::format_bytes(overview.total_size_bytes)}
+                                    } else {
+                                        "—"
+                                    }
+                                }
+                            }
+                        }
+                        if overview.failed_namespaces > 0 {
+                            p {
+                                class: "px-4 pb-2 text-xs text-yellow-700",
+                                "⚠️ Tables could not be listed in {overview.failed_namespaces} namespace(s)"
+                            }
+                        }
+                        div {
+                            class: "flex items-center justify-between px-4 py-3 border-t border-gray-100 text-xs text-gray-500",
+                            if is_listing {
+                                span { "Listing tables..." }
+                            } else if is_classifying {
+                                span {
+                                    {format!("Loading tables: {} of {}", overview.classified.unwrap_or(0), overview.tables.len())}
+                                }
+                            } else if overview.fully_classified() {
+                                span { "Size is the sum of each Iceberg table's current snapshot" }
+                            } else {
+                                span { "Telling Iceberg tables apart and summing their size loads every table" }
+                            }
+                            if !is_listing && !is_classifying && !overview.tables.is_empty() {
+                                button {
+                                    onclick: {
+                                        let catalog_name = catalog_name.clone();
+                                        move |_| classify_catalog(catalog_name.clone())
+                                    },
+                                    class: "px-3 py-1 font-medium text-blue-600 border border-blue-300 rounded-md hover:bg-blue-50",
+                                    if overview.classified.is_some() { "Recompute" } else { "Classify tables & compute size" }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Choices for re-checking favorites automatically, in minutes
+const FRESHNESS_CHECK_INTERVALS: [u64; 3] = [5, 15, 60];
+
+/// Favorite tables that have gone longer without a snapshot than their freshness thresholds
+/// allow, stalest first. Checks load each favorite, so they run on request or on the interval
+/// chosen here.
+#[component]
+fn FreshnessDashboardCard(
+    catalog_manager: Signal<CatalogManager>,
+    on_table_selected: EventHandler<(String, String, String)>,
+) -> Element {
+    let favorites = catalog_manager.read().settings().favorite_tables.clone();
+    let check_minutes = catalog_manager.read().settings().freshness_check_minutes;
+    let mut checks = use_signal(Vec::<data::FreshnessCheck>::new);
+    let mut pending = use_signal(|| 0usize);
+    let mut last_checked = use_signal(|| Option::<chrono::DateTime<chrono::Utc>>::None);
+    let check_generation = use_signal(|| 0usize);
+
+    // A periodic re-check leaves catalogs that aren't connected alone, e.g. ones disconnected
+    // for being idle, instead of reconnecting them; their last results stay on the card
+    let mut check_all = move |periodic: bool| {
+        let mut favorites = catalog_manager.peek().settings().favorite_tables.clone();
+        if periodic {
+            favorites.retain(|favorite| {
+                catalog_manager
+                    .peek()
+                    .get_connection(&favorite.catalog_name)
+                    .is_some()
+            });
+            checks.with_mut(|checks| checks.retain(|check| !favorites.contains(&check.table)));
+        } else {
+            checks.set(Vec::new());
+        }
+        pending.set(favorites.len());
+        last_checked.set(Some(chrono::Utc::now()));
+        let run = crawl::CrawlRun::start(check_generation);
+
+        spawn(async move {
+            // One batch of cheap existence checks per catalog before anything is loaded, so
+            // dropped tables are flagged instead of failing to load
+            let mut by_catalog: std::collections::BTreeMap<String, Vec<(String, String)>> =
+                std::collections::BTreeMap::new();
+            for favorite in &favorites {
+                by_catalog
+                    .entry(favorite.catalog_name.clone())
+                    .or_default()
+                    .push((favorite.namespace.clone(), favorite.table_name.clone()));
+            }
+            let mut missing = std::collections::HashSet::new();
+            for (catalog_name, tables) in by_catalog {
+                if ensure_catalog_connected(catalog_manager, &catalog_name)
+                    .await
+                    .is_err()
+                {
+                    continue;
+                }
+                let gone = catalog_manager
+                    .read()
+                    .missing_tables(&catalog_name, &tables)
+                    .await;
+                for (namespace, table_name) in gone {
+                    missing.insert((catalog_name.clone(), namespace, table_name));
+                }
+            }
+
+            if !run.is_current() {
+                return;
+            }
+
+            let mut to_load = Vec::new();
+            for favorite in favorites {
+                if !missing.contains(&(
+                    favorite.catalog_name.clone(),
+                    favorite.namespace.clone(),
+                    favorite.table_name.clone(),
+                )) {
+                    to_load.push(favorite);
+                    continue;
+                }
+                let stale_hours = catalog_manager
+                    .peek()
+                    .settings()
+                    .freshness_thresholds_for(&favorite.table_key())
+                    .stale_hours;
+                checks.write().push(data::FreshnessCheck {
+                    table: favorite,
+                    hours_since_last_snapshot: None,
+                    freshness: data::Freshness::Unknown,
+                    stale_hours,
+                    error: None,
+                    missing: true,
+                });
+                pending.with_mut(|pending| *pending = pending.saturating_sub(1));
+            }
+
+            crawl::crawl(
+                catalog_manager,
+                run,
+                to_load,
+                move |favorite: data::FavoriteTable| async move {
+                    let result =
+                        match ensure_catalog_connected(catalog_manager, &favorite.catalog_name)
+                            .await
+                        {
+                            Ok(()) => {
+                                catalog_manager
+                                    .read()
+                                    .table_stats(
+                                        &favorite.catalog_name,
+                                        &favorite.namespace,
+                                        &favorite.table_name,
+                                    )
+                                    .await
+                            }
+                            Err(e) => Err(e),
+                        };
+                    let thresholds = catalog_manager
+                        .peek()
+                        .settings()
+                        .freshness_thresholds_for(&favorite.table_key());
+                    match result {
+                        Ok(stats) => {
+                            let hours = stats.latest_snapshot_ms.map(|ms| {
+                                analytics::TableAnalytics::hours_since(ms, chrono::Utc::now())
+                            });
+                            data::FreshnessCheck {
+                                table: favorite,
+                                hours_since_last_snapshot: hours,
+                                freshness: analytics::TableAnalytics::classify_freshness(
+                                    hours,
+                                    &thresholds,
+                                ),
+                                stale_hours: thresholds.stale_hours,
+                                error: None,
+                                missing: false,
+                            }
+                        }
+                        Err(e) => {
+                            log::error!(
+                                "Freshness check failed for {}: {}",
+                                favorite.qualified_name(),
+                                e
+                            );
+                            data::FreshnessCheck {
+                                table: favorite,
+                                hours_since_last_snapshot: None,
+                                freshness: data::Freshness::Unknown,
+                                stale_hours: thresholds.stale_hours,
+                                error: Some(e.message().to_string()),
+                                missing: false,
+                            }
+                        }
+                    }
+                },
+                move |check: data::FreshnessCheck| {
+                    checks.write().push(check);
+                    pending.with_mut(|pending| *pending = pending.saturating_sub(1));
+                },
+            );
+        });
+    };
+
+    // Re-check on the chosen interval; a minute tick keeps a changed interval taking effect
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+            let Some(minutes) = catalog_manager
+                .try_read()
+                .ok()
+                .and_then(|manager| manager.settings().freshness_check_minutes)
+            else {
+                continue;
+            };
+            let due = last_checked.peek().is_none_or(|checked| {
+                chrono::Utc::now()
+                    .signed_duration_since(checked)
+                    .num_minutes()
+                    >= minutes as i64
+            });
+            if due && *pending.peek() == 0 {
+                check_all(true);
+            }
+        }
+    });
+
+    if favorites.is_empty() {
+        return rsx! {};
+    }
+
+    let checks_read = checks.read();
+    let breaches = analytics::TableAnalytics::freshness_breaches(&checks_read);
+    let failed: Vec<&data::FreshnessCheck> = checks_read
+        .iter()
+        .filter(|check| check.error.is_some())
+        .collect();
+    let missing: Vec<&data::FreshnessCheck> =
+        checks_read.iter().filter(|check| check.missing).collect();
+    let within_sla = checks_read.len() - breaches.len() - failed.len() - missing.len();
+    let is_checking = pending() > 0;
+
+    rsx! {
+        div {
+            class: "max-w-2xl mx-auto mb-8 bg-white border border-gray-200 rounded-lg shadow-sm text-left",
+            div {
+                class: "flex items-center justify-between px-4 py-3 border-b border-gray-200",
+                h3 {
+                    class: "text-sm font-medium text-gray-900",
+                    "⏱️ Favorites Freshness"
+                }
+                div {
+                    class: "flex items-center space-x-2",
+                    select {
+                        class: "text-xs border border-gray-300 rounded-md px-2 py-1",
+                        title: "Re-check favorites automatically while Hielo is open",
+                        value: check_minutes.map(|minutes| minutes.to_string()).unwrap_or_default(),
+                        onchange: move |evt| {
+                            let minutes = evt.value().parse().ok();
+                            spawn(async move {
+                                let saved = write_when_free(catalog_manager, |manager| {
+                                    let mut updated = manager.settings().clone();
+                                    updated.freshness_check_minutes = minutes;
+                                    manager.update_settings(updated)
+                                })
+                                .await;
+                                if let Err(e) = saved {
+                                    log::error!("Failed to save freshness check interval: {}", e);
+                                }
+                            });
+                        },
+                        option { value: "", "Check on demand" }
+                        for minutes in FRESHNESS_CHECK_INTERVALS {
+                            option { value: "{minutes}", "Every {minutes} min" }
+                        }
+                    }
+                    button {
+                        disabled: is_checking,
+                        onclick: move |_| check_all(false),
+                        class: "px-3 py-1 text-xs font-medium text-blue-600 border border-blue-300 rounded-md hover:bg-blue-50 disabled:opacity-50",
+                        if is_checking { "Checking..." } else { "Check now" }
+                    }
+                }
+            }
+            if last_checked().is_none() {
+                p {
+                    class: "px-4 py-4 text-sm text-gray-500",
+                    {format!("{} favorite table(s). Checking loads each one to find its newest snapshot.", favorites.len())}
+                }
+            } else {
+                if breaches.is_empty() && !is_checking {
+                    p { class: "px-4 py-4 text-sm text-green-700", "✅ Every favorite is within its freshness SLA" }
+                }
+                for check in breaches.iter() {
+                    {
+                        let check = (*check).clone();
+                        let hours = check.hours_since_last_snapshot.unwrap_or_default();
+                        rsx! {
+                            button {
+                                key: "{check.table.qualified_name()}",
+                                onclick: {
+                                    let table = check.table.clone();
+                                    move |_| on_table_selected.call((
+                                        table.catalog_name.clone(),
+                                        table.namespace.clone(),
+                                        table.table_name.clone(),
+                                    ))
+                                },
+                                class: "w-full flex items-center justify-between px-4 py-2 text-sm hover:bg-gray-50 border-b border-gray-100",
+                                title: "Open table",
+                                span { class: "font-mono text-gray-900 truncate", "{check.table.qualified_name()}" }
+                                span {
+                                    class: "flex items-center space-x-2 flex-shrink-0 ml-4",
+                                    span {
+                                        class: "text-xs text-gray-500",
+                                        {format!("{} since last snapshot · SLA {}", components::format_age_hours(hours), components::format_age_hours(check.stale_hours))}
+                                    }
+                                    span { class: "inline-block w-2 h-2 rounded-full {components::freshness_color_class(check.freshness)}" }
+                                    span { class: "text-xs font-medium text-gray-700", "{check.freshness.label()}" }
+                                }
+                            }
+                        }
+                    }
+                }
+                if !missing.is_empty() {
+                    div {
+                        class: "px-4 py-2 text-xs text-gray-600 border-b border-gray-100",
+                        for check in missing.iter() {
+                            div {
+                                key: "{check.table.qualified_name()}",
+                                class: "flex items-center justify-between py-1",
+                                span {
+                                    span { class: "font-mono text-gray-900", "{check.table.qualified_name()}" }
+                                    " no longer exists"
+                                }
+                                button {
+                                    onclick: {
+                                        let favorite = check.table.clone();
+                                        move |_| {
+                                            let favorite = favorite.clone();
+                                            spawn(async move {
+                                                if let Err(e) = write_when_free(catalog_manager, |manager| manager.toggle_favorite_table(favorite.clone())).await {
+                                                    log::error!("Failed to remove favorite: {}", e);
+                                                    return;
+                                                }
+                                                checks.write().retain(|check| check.table != favorite);
+                                            });
+                                        }
+                                    },
+                                    class: "px-2 py-0.5 text-xs text-red-600 border border-red-300 rounded hover:bg-red-50",
+                                    title: "Remove this table from favorites",
+                                    "Remove"
+                                }
+                            }
+                        }
+                    }
+                }
+                if !failed.is_empty() {
+                    div {
+                        class: "px-4 py-2 text-xs text-red-600",
+                        for check in failed.iter() {
+                            p {
+                                key: "{check.table.qualified_name()}",
+                                {format!("Could not check {}: {}", check.table.qualified_name(), check.error.clone().unwrap_or_default())}
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "px-4 py-3 border-t border-gray-100 text-xs text-gray-500",
+                    if is_checking {
+                        {format!("Checking {} of {} favorites...", checks_read.len(), favorites.len())}
+                    } else {
+                        {format!("{} of {} favorites within SLA", within_sla, favorites.len())}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A namespace's tables in a tab of their own, shown with the table explorer
+#[component]
+fn NamespaceTab(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: String,
+    namespace: String,
+    on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
+    on_open_all: EventHandler<Vec<(String, String, String)>>,
+) -> Element {
+    let mut tables = use_signal(|| Option::<Result<Vec<catalog::TableReference>, String>>::None);
+    let mut search_query = use_signal(String::new);
+    let mut show_schema_export = use_signal(|| false);
+
+    let mut load_tables = {
+        let catalog_name = catalog_name.clone();
+        let namespace = namespace.clone();
+        move || {
+            let catalog_name = catalog_name.clone();
+            let namespace = namespace.clone();
+            tables.set(None);
+            spawn(async move {
+                let result = match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                    Ok(()) => {
+                        catalog_manager
+                            .read()
+                            .list_tables(&catalog_name, &namespace)
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
+                tables.set(Some(result.map_err(|e| e.to_string())));
+            });
+        }
+    };
+
+    use_effect({
+        let mut load_tables = load_tables.clone();
+        move || {
+            load_tables();
+        }
+    });
+
+    let iceberg_tables: Vec<(String, String, String)> = match &*tables.read() {
+        Some(Ok(table_list)) => table_list
+            .iter()
+            .filter(|table| table.table_type == catalog::TableType::Iceberg)
+            .map(|table| {
+                (
+                    catalog_name.clone(),
+                    table.namespace.clone(),
+                    table.name.clone(),
+                )
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div {
+            class: "p-6",
+            div {
+                class: "flex items-center justify-between mb-4",
+                p {
+                    class: "text-sm text-gray-500",
+                    "🗂 {catalog_name} / {namespace}"
+                }
+                div {
+                    class: "flex items-center space-x-2",
+                    if !iceberg_tables.is_empty() {
+                        button {
+                            onclick: {
+                                let iceberg_tables = iceberg_tables.clone();
+                                move |_| on_open_all.call(iceberg_tables.clone())
+                            },
+                            class: "px-3 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                            title: "Open every Iceberg table in this namespace in its own tab",
+                            "Open all ({iceberg_tables.len()})"
+                        }
+                        button {
+                            onclick: move |_| show_schema_export.set(true),
+                            class: "px-3 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50",
+                            title: "Export the schema of every Iceberg table in this namespace to one file",
+                            "📄 Export schemas"
+                        }
+                    }
+                    button {
+                        onclick: move |_| load_tables(),
+                        disabled: tables().is_none(),
+                        class: "px-3 py-1 text-sm text-gray-600 border border-gray-300 rounded-md bg-white hover:bg-gray-50 disabled:opacity-50",
+                        "🔄 Refresh"
+                    }
+                }
+            }
+            match tables() {
+                None => rsx! {
+                    div {
+                        class: "flex items-center justify-center py-12",
+                        div { class: "animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600" }
+                        span { class: "ml-3 text-sm text-gray-600", {t(Msg::LoadingTables)} }
+                    }
+                },
+                Some(Err(error)) => rsx! {
+                    div {
+                        class: "p-4 bg-red-50 border border-red-200 rounded-md text-sm text-red-700",
+                        {format!("{}: {}", t(Msg::FailedToListTables), error)}
+                    }
+                },
+                Some(Ok(table_list)) => rsx! {
+                    catalog_ui::TableExplorerView {
+                        catalog_name: catalog_name.clone(),
+                        namespace: namespace.clone(),
+                        tables: table_list,
+                        search_query: search_query(),
+                        on_search_change: move |query: String| search_query.set(query),
+                        catalog_manager: catalog_manager,
+                        on_table_selected: on_table_selected,
+                        on_view_selected: on_view_selected
+                    }
+                },
+            }
+            if show_schema_export() {
+                NamespaceSchemaExportDialog {
+                    catalog_manager: catalog_manager,
+                    catalog_name: catalog_name.clone(),
+                    namespace: namespace.clone(),
+                    table_names: iceberg_tables.iter().map(|(_, _, table_name)| table_name.clone()).collect::<Vec<_>>(),
+                    on_close: move |_| show_schema_export.set(false),
+                }
+            }
+        }
+    }
+}
+
+/// Load every table of a namespace and save their schemas into one file. Tables that fail
+/// to load are noted in the file rather than stopping the export.
+#[component]
+fn NamespaceSchemaExportDialog(
+    catalog_manager: Signal<CatalogManager>,
+    catalog_name: String,
+    namespace: String,
+    table_names: Vec<String>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut format = use_signal(|| export::SchemaFormat::Markdown);
+    let mut loaded = use_signal(Vec::<(String, Result<IcebergTable, String>)>::new);
+    let mut running = use_signal(|| false);
+    let mut cancelled = use_signal(|| false);
+    let mut outcome = use_signal(|| Option::<Result<String, String>>::None);
+    let export_generation = use_signal(|| 0usize);
+    let total = table_names.len();
+
+    let start = {
+        let catalog_name = catalog_name.clone();
+        let namespace = namespace.clone();
+        let table_names = table_names.clone();
+        move |_| {
+            loaded.set(Vec::new());
+            outcome.set(None);
+            cancelled.set(false);
+            running.set(true);
+            crawl::crawl(
+                catalog_manager,
+                crawl::CrawlRun::start(export_generation),
+                table_names.clone(),
+                {
+                    let catalog_name = catalog_name.clone();
+                    let namespace = namespace.clone();
+                    move |table_name: String| {
+                        let catalog_name = catalog_name.clone();
+                        let namespace = namespace.clone();
+                        async move {
+                            let table = fetch_table(
+                                catalog_manager,
+                                &catalog_name,
+                                &namespace,
+                                &table_name,
+                                false,
+                            )
+                            .await;
+                            (table_name, table)
+                        }
+                    }
+                },
+                {
+                    let catalog_name = catalog_name.clone();
+                    let namespace = namespace.clone();
+                    move |loaded_table: (String, Result<IcebergTable, String>)| {
+                        loaded.write().push(loaded_table);
+                        if loaded.peek().len() < total {
+                            return;
+                        }
+                        let format = *format.peek();
+                        let contents =
+                            format.render_namespace(&catalog_name, &namespace, &loaded.peek());
+                        let saved = export::save_to_downloads(
+                            &format.namespace_file_name(&catalog_name, &namespace),
+                            &contents,
+                        );
+                        outcome.set(Some(
+                            saved
+                                .map(|path| path.display().to_string())
+                                .map_err(|e| e.to_string()),
+                        ));
+                        running.set(false);
+                    }
+                },
+            );
+        }
+    };
+
+    let done = loaded.read().len();
+    let failed = loaded
+        .read()
+        .iter()
+        .filter(|(_, table)| table.is_err())
+        .count();
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| {
+                cancelled.set(true);
+                crawl::CrawlRun::cancel(export_generation);
+                on_close.call(());
+            },
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-lg w-full mx-4",
+                onclick: |e| e.stop_propagation(),
+
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    h3 {
+                        class: "text-lg font-medium text-gray-900",
+                        "📄 Export schemas: {namespace}"
+                    }
+                    button {
+                        onclick: move |_| {
+                            cancelled.set(true);
+                            crawl::CrawlRun::cancel(export_generation);
+                            on_close.call(());
+                        },
+                        class: "text-gray-400 hover:text-gray-600",
+                        "✕"
+                    }
+                }
+
+                div {
+                    class: "p-4 space-y-4",
+                    p {
+                        class: "text-sm text-gray-600",
+                        {format!("Loads each of the {} Iceberg table(s) in {}.{} and saves their current schemas into one file in your downloads folder.", total, catalog_name, namespace)}
+                    }
+                    div {
+                        class: "flex items-center space-x-2",
+                        label { class: "text-sm text-gray-700", "Format" }
+                        select {
+                            class: "text-sm border border-gray-300 rounded-md px-2 py-1",
+                            disabled: running(),
+                            onchange: move |evt| {
+                                if let Some(selected) = export::SchemaFormat::ALL
+                                    .into_iter()
+                                    .find(|format| format.label() == evt.value())
+                                {
+                                    format.set(selected);
+                                }
+                            },
+                            for option_format in export::SchemaFormat::ALL {
+                                option {
+                                    value: "{option_format.label()}",
+                                    selected: format() == option_format,
+                                    {match option_format {
+                                        export::SchemaFormat::Markdown => "Markdown (one section per table)",
+                                        export::SchemaFormat::Avro => "Avro (JSON array)",
+                                        export::SchemaFormat::Arrow => "Arrow JSON (JSON array)",
+                                    }}
+                                }
+                            }
+                        }
+                    }
+
+                    if running() || cancelled() {
+                        div {
+                            div {
+                                class: "h-1 bg-gray-100 rounded",
+                                div {
+                                    class: "h-1 bg-blue-600 rounded transition-all",
+                                    style: format!("width: {}%", if total == 0 { 100 } else { done * 100 / total }),
+                                }
+                            }
+                            p {
+                                class: "mt-1 text-xs text-gray-500",
+                                if cancelled() {
+                                    {format!("Cancelled after loading {} of {} tables", done, total)}
+                                } else {
+                                    {format!("Loaded {} of {} tables...", done, total)}
+                                }
+                            }
+                        }
+                    }
+                    match outcome() {
+                        Some(Ok(path)) => rsx! {
+                            p {
+                                class: "text-sm text-green-700 break-all",
+                                if failed > 0 {
+                                    {format!("Saved to {} ({} table(s) could not be loaded and are noted in the file)", path, failed)}
+                                } else {
+                                    {format!("Saved to {}", path)}
+                                }
+                            }
+                        },
+                        Some(Err(error)) => rsx! {
+                            p { class: "text-sm text-red-600", "Failed to save export: {error}" }
+                        },
+                        None => rsx! {},
+                    }
+                }
+
+                div {
+                    class: "flex justify-end space-x-2 px-4 py-3 border-t border-gray-200",
+                    if running() {
+                        button {
+                            onclick: move |_| {
+                                cancelled.set(true);
+                                crawl::CrawlRun::cancel(export_generation);
+                                running.set(false);
+                            },
+                            class: "px-3 py-1 text-sm font-medium text-red-600 hover:text-red-800",
+                            "■ Cancel"
+                        }
+                    } else {
+                        button {
+                            disabled: total == 0,
+                            onclick: start,
+                            class: "px-3 py-1 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:opacity-50",
+                            if outcome().is_some() || cancelled() { "Export again" } else { "Export" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Minimal input for opening a table whose path is already known. The table is looked up
+/// in its namespace first, so a typo or a non-Iceberg table is reported here rather than
+/// as a failed load.
+#[component]
+fn GoToTableDialog(
+    catalog_manager: Signal<CatalogManager>,
+    // Catalog of the active table tab, used when the path doesn't name one
+    default_catalog: Option<String>,
+    on_open: EventHandler<(String, String, String)>,
+    on_open_view: EventHandler<(String, String, String)>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut path = use_signal(String::new);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut checking = use_signal(|| false);
+
+    let submit = move |_| {
+        let catalog_names = known_catalog_names(&catalog_manager.read());
+        let default_catalog = default_catalog
+            .clone()
+            .or_else(|| match catalog_names.as_slice() {
+                [only] => Some(only.clone()),
+                _ => None,
+            });
+        let (catalog_name, namespace, table_name) =
+            match parse_table_path(&path(), &catalog_names, default_catalog.as_deref()) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    error.set(Some(e));
+                    return;
+                }
+            };
+
+        checking.set(true);
+        error.set(None);
+        spawn(async move {
+            let listed = match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                Ok(()) => {
+                    catalog_manager
+                        .read()
+                        .list_tables(&catalog_name, &namespace)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            checking.set(false);
+            let tables = match listed {
+                Ok(tables) => tables,
+                Err(e) => {
+                    error.set(Some(format!(
+                        "Could not list {}.{}: {}",
+                        catalog_name,
+                        namespace,
+                        e.message()
+                    )));
+                    return;
+                }
+            };
+            match tables.iter().find(|table| table.name == table_name) {
+                None => error.set(Some(format!(
+                    "No table '{}' in namespace '{}' of {}",
+                    table_name, namespace, catalog_name
+                ))),
+                Some(table) => match table.table_type {
+                    catalog::TableType::Iceberg => {
+                        on_open.call((catalog_name, namespace, table_name))
+                    }
+                    catalog::TableType::View => {
+                        on_open_view.call((catalog_name, namespace, table_name))
+                    }
+                    catalog::TableType::Unknown => error.set(Some(format!(
+                        "'{}.{}' is not an Iceberg table",
+                        namespace, table_name
+                    ))),
+                },
+            }
+        });
+    };
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-lg w-full mx-4 p-4",
+                onclick: |e| e.stop_propagation(),
+                input {
+                    r#type: "text",
+                    placeholder: "namespace.table or catalog.namespace.table",
+                    value: "{path}",
+                    disabled: checking(),
+                    oninput: move |evt| {
+                        path.set(evt.value());
+                        error.set(None);
+                    },
+                    onkeydown: {
+                        let mut submit = submit.clone();
+                        move |event: dioxus::prelude::Event<dioxus::html::KeyboardData>| {
+                            if event.key() == Key::Enter {
+                                submit(());
+                            } else if event.key() == Key::Escape {
+                                on_close.call(());
+                            }
+                        }
+                    },
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm font-mono focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent",
+                    autofocus: true
+                }
+                if checking() {
+                    p { class: "mt-2 text-xs text-gray-500", "Looking up table..." }
+                } else if let Some(error) = error() {
+                    p { class: "mt-2 text-xs text-red-600", "{error}" }
+                } else {
+                    p { class: "mt-2 text-xs text-gray-500", "Enter opens the table; Esc cancels" }
+                }
+            }
+        }
     }
 }
 
+/// Open every table in a pasted list, e.g. one shared by a teammate. Each table is looked
+/// up first so the ones that can't be opened are listed rather than failing one by one;
+/// the rest go through the bulk-open confirmation, which closes this dialog.
 #[component]
-fn GlobalSearchModal(
+fn OpenTableListDialog(
     catalog_manager: Signal<CatalogManager>,
-    search_query: String,
-    on_search_change: EventHandler<String>,
-    on_table_selected: EventHandler<(String, String, String)>,
+    // Catalog of the active table tab, used for entries that don't name one
+    default_catalog: Option<String>,
+    on_open: EventHandler<Vec<(String, String, String)>>,
     on_close: EventHandler<()>,
 ) -> Element {
-    let mut all_tables = use_signal(Vec::<catalog::TableReference>::new);
-    let mut loading = use_signal(|| false);
-    let mut error_message = use_signal(|| Option::<String>::None);
+    let mut list = use_signal(String::new);
+    let mut checking = use_signal(|| false);
+    // Outcome of the last attempt: how many tables were opened, and each entry that wasn't
+    let mut outcome = use_signal(|| Option::<(usize, Vec<(String, String)>)>::None);
 
-    // Load all tables from all namespaces when modal opens
-    use_effect(move || {
+    // Start from whatever is on the clipboard; the list can still be edited or pasted
+    use_future(move || async move {
+        if let Some(text) = components::read_clipboard().await {
+            if list.peek().is_empty() {
+                list.set(text);
+            }
+        }
+    });
+
+    let submit = move |_| {
+        let catalog_names = known_catalog_names(&catalog_manager.read());
+        let default_catalog = default_catalog
+            .clone()
+            .or_else(|| match catalog_names.as_slice() {
+                [only] => Some(only.clone()),
+                _ => None,
+            });
+        let (tables, mut failed) =
+            parse_table_list(&list(), &catalog_names, default_catalog.as_deref());
+
+        checking.set(true);
+        outcome.set(None);
         spawn(async move {
-            loading.set(true);
-            error_message.set(None);
+            // One listing per namespace, however many of its tables are in the list
+            let mut listings: std::collections::HashMap<
+                (String, String),
+                Result<Vec<catalog::TableReference>, String>,
+            > = std::collections::HashMap::new();
+            let mut found = Vec::new();
+            for (catalog_name, namespace, table_name) in tables {
+                let key = (catalog_name.clone(), namespace.clone());
+                if !listings.contains_key(&key) {
+                    let listed =
+                        match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                            Ok(()) => {
+                                catalog_manager
+                                    .read()
+                                    .list_tables(&catalog_name, &namespace)
+                                    .await
+                            }
+                            Err(e) => Err(e),
+                        };
+                    listings.insert(key.clone(), listed.map_err(|e| e.message().to_string()));
+                }
+                let entry = format!("{}.{}.{}", catalog_name, namespace, table_name);
+                match &listings[&key] {
+                    Err(e) => failed.push((
+                        entry,
+                        format!("Could not list {}.{}: {}", catalog_name, namespace, e),
+                    )),
+                    Ok(listed) => match listed.iter().find(|table| table.name == table_name) {
+                        None => failed.push((entry, "No such table".to_string())),
+                        Some(table) if table.table_type == catalog::TableType::View => {
+                            failed.push((entry, "A view, not a table".to_string()))
+                        }
+                        Some(table) if table.table_type != catalog::TableType::Iceberg => {
+                            failed.push((entry, "Not an Iceberg table".to_string()))
+                        }
+                        Some(_) => found.push((catalog_name, namespace, table_name)),
+                    },
+                }
+            }
+            checking.set(false);
 
-            let connections = catalog_manager.read().get_connections().to_vec();
-            if let Some(connection) = connections.first() {
-                let catalog_name = connection.config.name.clone();
+            // Tables over the threshold wait on the bulk-open confirmation, so nothing is
+            // opened yet: hand them over and close rather than stack the two dialogs
+            let threshold = catalog_manager
+                .read()
+                .settings()
+                .bulk_open_confirm_threshold;
+            if found.len() > threshold {
+                if !failed.is_empty() {
+                    toast::notify(
+                        toast::ToastKind::Warning,
+                        tf(
+                            Msg::TablesNotOpened,
+                            &[
+                                &failed.len(),
+                                &failed
+                                    .iter()
+                                    .map(|(entry, reason)| format!("{} ({})", entry, reason))
+                                    .collect::<Vec<_>>()
+                                    .join(", "),
+                            ],
+                        ),
+                    );
+                }
+                on_open.call(found);
+                on_close.call(());
+                return;
+            }
 
-                match catalog_manager.read().list_namespaces(&catalog_name).await {
-                    Ok(namespaces) => {
-                        let mut tables = Vec::new();
+            let opened = found.len();
+            if !found.is_empty() {
+                on_open.call(found);
+            }
+            if failed.is_empty() && opened > 0 {
+                on_close.call(());
+            } else {
+                outcome.set(Some((opened, failed)));
+            }
+        });
+    };
 
-                        for namespace in namespaces {
-                            match catalog_manager
-                                .read()
-                                .list_tables(&catalog_name, &namespace)
-                                .await
-                            {
-                                Ok(namespace_tables) => {
-                                    tables.extend(namespace_tables);
-                                }
-                                Err(e) => {
-                                    error_message.set(Some(format!(
-                                        "Failed to load tables from namespace '{}': {}",
-                                        namespace, e
-                                    )));
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-lg w-full mx-4 p-4",
+                onclick: |e| e.stop_propagation(),
+                h3 {
+                    class: "text-lg font-medium text-gray-900 mb-2",
+                    "Open tables from a list"
+                }
+                textarea {
+                    rows: "8",
+                    placeholder: "namespace.table or catalog.namespace.table, one per line or comma separated",
+                    value: "{list}",
+                    disabled: checking(),
+                    oninput: move |evt| {
+                        list.set(evt.value());
+                        outcome.set(None);
+                    },
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm font-mono focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent",
+                    autofocus: true
+                }
+                if checking() {
+                    p { class: "mt-2 text-xs text-gray-500", "Looking up tables..." }
+                } else if let Some((opened, failed)) = outcome() {
+                    div {
+                        class: "mt-2 text-xs",
+                        p {
+                            class: if failed.is_empty() { "text-gray-700" } else { "text-red-700" },
+                            {format!("Opened {} {}; {} could not be opened", opened, if opened == 1 { "table" } else { "tables" }, failed.len())}
+                        }
+                        ul {
+                            class: "mt-1 max-h-40 overflow-y-auto",
+                            for (entry, reason) in failed {
+                                li {
+                                    key: "{entry}",
+                                    class: "text-red-600",
+                                    span { class: "font-mono", "{entry}" }
+                                    ": {reason}"
                                 }
                             }
                         }
-
-                        all_tables.set(tables);
                     }
-                    Err(e) => {
-                        error_message.set(Some(format!("Failed to load namespaces: {}", e)));
+                }
+                div {
+                    class: "mt-3 flex justify-end space-x-3",
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "px-4 py-2 text-sm font-medium text-gray-700 bg-white border border-gray-300 rounded-md hover:bg-gray-50",
+                        {t(Msg::Cancel)}
+                    }
+                    button {
+                        onclick: submit,
+                        disabled: checking() || list().trim().is_empty(),
+                        class: "px-4 py-2 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700 disabled:bg-gray-300",
+                        "Open tables"
                     }
                 }
-            } else {
-                error_message.set(Some("No catalog connection found".to_string()));
             }
+        }
+    }
+}
 
-            loading.set(false);
-        });
-    });
-
-    // Filter tables based on search query
-    let query_clone = search_query.clone();
-    let filtered_tables: Vec<catalog::TableReference> = if query_clone.is_empty() {
-        all_tables()
-    } else {
-        let query_lower = query_clone.to_lowercase();
-        all_tables()
-            .into_iter()
-            .filter(|table| {
-                table.full_name.to_lowercase().contains(&query_lower)
-                    || table.name.to_lowercase().contains(&query_lower)
-                    || table.namespace.to_lowercase().contains(&query_lower)
-            })
-            .collect()
-    };
-
+#[component]
+fn ShortcutsModal(on_close: EventHandler<()>) -> Element {
     rsx! {
         // Modal overlay
         div {
             class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
             onclick: move |_| on_close.call(()),
 
-            // Modal content
             div {
-                class: "bg-white rounded-lg shadow-xl max-w-2xl w-full mx-4 max-h-96 flex flex-col",
-                onclick: |e| e.stop_propagation(), // Prevent closing when clicking inside modal
+                class: "bg-white rounded-lg shadow-xl max-w-md w-full mx-4 flex flex-col",
+                onclick: |e| e.stop_propagation(),
 
                 // Header
                 div {
                     class: "flex items-center justify-between p-4 border-b border-gray-200",
                     h3 {
                         class: "text-lg font-medium text-gray-900",
-                        "🔍 Find Table (Ctrl+K)"
+                        "⌨️ Keyboard Shortcuts"
                     }
                     button {
                         onclick: move |_| on_close.call(()),
@@ -691,128 +5135,19 @@ fn GlobalSearchModal(
                     }
                 }
 
-                // Search input
-                div {
-                    class: "p-4 border-b border-gray-200",
-                    input {
-                        r#type: "text",
-                        placeholder: "Search by table name or namespace.table_name...",
-                        value: search_query,
-                        oninput: move |evt| on_search_change.call(evt.value()),
-                        onkeydown: move |event| {
-                            let key_str = format!("{:?}", event.key());
-                            if key_str.contains("Escape") {
-                                on_close.call(());
-                            }
-                        },
-                        class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent",
-                        autofocus: true
-                    }
-                }
-
-                // Results
                 div {
-                    class: "flex-1 overflow-y-auto",
-                    if loading() {
-                        div {
-                            class: "flex items-center justify-center py-8",
-                            div {
-                                class: "animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600"
-                            }
-                        }
-                    } else if let Some(error) = error_message() {
-                        div {
-                            class: "p-4 text-red-600 text-sm",
-                            "Error: {error}"
-                        }
-                    } else if filtered_tables.is_empty() {
-                        div {
-                            class: "p-4 text-gray-500 text-sm text-center",
-                            if query_clone.is_empty() {
-                                "No tables found"
-                            } else {
-                                "No tables match your search"
-                            }
-                        }
-                    } else {
+                    class: "p-4 divide-y divide-gray-100",
+                    for shortcut in KEYBOARD_SHORTCUTS.iter() {
                         div {
-                            class: "divide-y divide-gray-200",
-                            for table in filtered_tables.iter().take(10) { // Limit to first 10 results
-                                button {
-                                    onclick: {
-                                        let table_clone = table.clone();
-                                        let connections = catalog_manager.read().get_connections().to_vec();
-                                        let catalog_name = if let Some(connection) = connections.first() {
-                                            connection.config.name.clone()
-                                        } else {
-                                            "unknown".to_string()
-                                        };
-                                        move |_| {
-                                            // Only allow selection of Iceberg tables
-                                            if table_clone.table_type == catalog::TableType::Iceberg {
-                                                on_table_selected.call((catalog_name.clone(), table_clone.namespace.clone(), table_clone.name.clone()));
-                                                on_close.call(());
-                                            }
-                                        }
-                                    },
-                                    class: format!(
-                                        "w-full px-4 py-3 text-left hover:bg-gray-50 flex items-center justify-between {}",
-                                        if table.table_type == catalog::TableType::Iceberg {
-                                            "cursor-pointer"
-                                        } else {
-                                            "cursor-not-allowed opacity-50"
-                                        }
-                                    ),
-                                    disabled: table.table_type != catalog::TableType::Iceberg,
-
-                                    div {
-                                        class: "flex items-center",
-                                        span {
-                                            class: "mr-3 text-lg",
-                                            if table.table_type == catalog::TableType::Iceberg {
-                                                "🧊"
-                                            } else {
-                                                "📄"
-                                            }
-                                        }
-                                        div {
-                                            div {
-                                                class: "font-medium text-gray-900 text-sm",
-                                                "{table.full_name}"
-                                            }
-                                            div {
-                                                class: "text-gray-500 text-xs",
-                                                "{table.namespace} • {table.name}"
-                                            }
-                                        }
-                                    }
-
-                                    if table.table_type == catalog::TableType::Iceberg {
-                                        span {
-                                            class: "text-gray-400 text-xs",
-                                            "Press Enter"
-                                        }
-                                    } else {
-                                        span {
-                                            class: "text-gray-400 text-xs",
-                                            "Not Iceberg"
-                                        }
-                                    }
-                                }
+                            class: "flex items-center justify-between py-2 text-sm",
+                            span { class: "text-gray-700", "{shortcut.description}" }
+                            kbd {
+                                class: "px-2 py-1 text-xs font-mono text-gray-700 bg-gray-100 border border-gray-300 rounded",
+                                "{shortcut.label}"
                             }
                         }
                     }
                 }
-
-                // Footer
-                div {
-                    class: "p-3 bg-gray-50 border-t border-gray-200 text-xs text-gray-500",
-                    if !filtered_tables.is_empty() {
-                        "Showing {filtered_tables.len().min(10)} of {filtered_tables.len()} tables"
-                    } else {
-                        "Use Ctrl+K to open this search anytime"
-                    }
-                }
             }
         }
     }
@@ -918,6 +5253,63 @@ fn compute_filtered_tree(
     result
 }
 
+/// Collapse-state key of the ungrouped catalogs. Group names are trimmed and never empty, so
+/// it can't clash with a group, not even one named "Ungrouped".
+const UNGROUPED_GROUP_KEY: &str = "";
+
+/// Split the (filtered) catalog tree into navigation groups, sorted by name, with the
+/// ungrouped catalogs (`None`) at the end
+fn group_catalog_nodes(
+    saved_catalogs: &[catalog::CatalogConfig],
+    filtered_tree: &[FilteredTreeNode],
+) -> Vec<(Option<String>, Vec<FilteredTreeNode>)> {
+    let mut grouped: std::collections::BTreeMap<String, Vec<FilteredTreeNode>> =
+        std::collections::BTreeMap::new();
+    let mut ungrouped = Vec::new();
+
+    for node in filtered_tree {
+        let group = saved_catalogs
+            .iter()
+            .find(|c| c.name == node.catalog_name)
+            .and_then(|c| c.group.clone());
+        match group {
+            Some(group) => grouped.entry(group).or_default().push(node.clone()),
+            None => ungrouped.push(node.clone()),
+        }
+    }
+
+    let mut groups: Vec<_> = grouped
+        .into_iter()
+        .map(|(group, nodes)| (Some(group), nodes))
+        .collect();
+    if !ungrouped.is_empty() {
+        groups.push((None, ungrouped));
+    }
+    groups
+}
+
+/// The only Iceberg table or view left in the filtered tree, with its catalog
+fn single_table_match(
+    filtered_tree: &[FilteredTreeNode],
+) -> Option<(String, catalog::TableReference)> {
+    let mut matches = filtered_tree.iter().flat_map(|catalog| {
+        catalog
+            .filtered_namespaces
+            .iter()
+            .flat_map(move |namespace| {
+                namespace
+                    .filtered_tables
+                    .iter()
+                    .map(move |table| (catalog, table))
+            })
+    });
+    let (catalog, table) = matches.next()?;
+    if matches.next().is_some() || table.table_type == catalog::TableType::Unknown {
+        return None;
+    }
+    Some((catalog.catalog_name.clone(), table.clone()))
+}
+
 fn compute_filter_counts(filtered_tree: &[FilteredTreeNode]) -> (usize, usize, usize) {
     let mut catalog_count = 0;
     let mut namespace_count = 0;
@@ -942,12 +5334,24 @@ fn compute_filter_counts(filtered_tree: &[FilteredTreeNode]) -> (usize, usize, u
 #[component]
 fn LeftNavigationPane(
     collapsed: bool,
+    // Set to move focus into the filter input, e.g. from the keyboard shortcut
+    focus_filter: Signal<bool>,
     catalog_manager: Signal<CatalogManager>,
     expanded_catalogs: Signal<std::collections::HashSet<String>>,
     expanded_namespaces: Signal<std::collections::HashSet<String>>,
     on_toggle_collapse: EventHandler<()>,
     on_catalog_delete_requested: EventHandler<String>,
+    // Called after a catalog is disconnected, to drop caches held outside the pane
+    on_catalog_disconnected: EventHandler<String>,
+    on_catalog_renamed: EventHandler<(String, String)>,
+    // Called after a catalog switched warehouse, to drop what was loaded from the previous one
+    on_warehouse_switched: EventHandler<String>,
     on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
+    on_namespace_health_requested: EventHandler<(String, String)>,
+    on_namespace_tab_requested: EventHandler<(String, String)>,
+    on_broken_tables_scan_requested: EventHandler<String>,
+    on_catalog_compare_requested: EventHandler<String>,
     on_add_catalog: EventHandler<()>,
 ) -> Element {
     let mut namespace_tables =
@@ -956,6 +5360,40 @@ fn LeftNavigationPane(
     let mut catalog_namespaces = use_signal(std::collections::HashMap::<String, Vec<String>>::new);
     let mut nav_filter_query = use_signal(String::new);
     let mut debounced_filter_query = use_signal(String::new);
+    let mut filter_input = use_signal(|| Option::<std::rc::Rc<MountedData>>::None);
+    let mut search_all_mode = use_signal(|| false);
+    let mut crawled_tables = use_signal(|| Option::<Vec<(String, catalog::TableReference)>>::None);
+    let mut crawl_errors = use_signal(Vec::<String>::new);
+    let mut crawling = use_signal(|| false);
+
+    let crawl_tables = move || {
+        if crawling() {
+            return;
+        }
+        spawn(async move {
+            crawling.set(true);
+            let (tables, errors) = crawl_all_tables(catalog_manager).await;
+            crawled_tables.set(Some(tables));
+            crawl_errors.set(errors);
+            crawling.set(false);
+        });
+    };
+
+    // Focus the filter once requested. If the pane was collapsed the stored input is stale and
+    // focusing fails; the request stays pending until the expanded pane mounts a new one.
+    use_effect(move || {
+        if !focus_filter() {
+            return;
+        }
+        if let Some(input) = filter_input() {
+            spawn(async move {
+                match input.set_focus(true).await {
+                    Ok(()) => focus_filter.set(false),
+                    Err(e) => log::debug!("Catalog filter not focusable yet: {}", e),
+                }
+            });
+        }
+    });
 
     // Debounce filter input
     use_effect(move || {
@@ -972,39 +5410,17 @@ fn LeftNavigationPane(
         log::info!("Loading namespaces for catalog: {}", catalog_name);
         spawn(async move {
             // First, ensure the catalog is connected
-            let catalog_config = {
-                let manager = catalog_manager.read();
-                manager
-                    .get_saved_catalogs()
-                    .iter()
-                    .find(|c| c.name == catalog_name)
-                    .cloned()
-            };
-
-            if let Some(config) = catalog_config {
-                // Try to connect the catalog if not already connected
-                let is_connected = catalog_manager
-                    .read()
-                    .get_connections()
-                    .iter()
-                    .any(|conn| conn.config.name == catalog_name);
-
-                if !is_connected {
-                    log::info!("Catalog not connected, connecting: {}", catalog_name);
-                    let connect_result = {
-                        let mut manager_guard = catalog_manager.write();
-                        manager_guard.connect_catalog(config).await
-                    };
+            let is_saved = catalog_manager
+                .read()
+                .get_saved_catalogs()
+                .iter()
+                .any(|c| c.name == catalog_name);
 
-                    match connect_result {
-                        Ok(_) => {
-                            log::info!("Successfully connected to catalog: {}", catalog_name);
-                        }
-                        Err(e) => {
-                            log::error!("Failed to connect catalog {}: {}", catalog_name, e);
-                            return;
-                        }
-                    }
+            if is_saved {
+                // Connects catalogs not connected yet or disconnected while idle
+                if let Err(e) = ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                    log::error!("Failed to connect catalog {}: {}", catalog_name, e);
+                    return;
                 }
 
                 // Now try to list namespaces
@@ -1054,12 +5470,40 @@ fn LeftNavigationPane(
         }
     };
 
-    let mut toggle_namespace_expansion = move |namespace_key: String| {
-        let namespace_parts: Vec<&str> = namespace_key.split("::").collect();
-        if namespace_parts.len() == 2 {
-            let catalog_name = namespace_parts[0];
-            let namespace_name = namespace_parts[1];
+    // Close the connection but keep the saved catalog; its node collapses and the next expand
+    // reconnects and lists everything afresh
+    let mut disconnect_catalog = move |catalog_name: String| {
+        // A running request holds the manager; dropping the connection under it would panic
+        match catalog_manager.try_write() {
+            Ok(mut manager) => {
+                manager.disconnect_catalog(&catalog_name);
+            }
+            Err(_) => {
+                toast::notify(
+                    toast::ToastKind::Warning,
+                    tf(Msg::CatalogBusy, &[&catalog_name]),
+                );
+                return;
+            }
+        }
+        expanded_catalogs.with_mut(|expanded| {
+            expanded.remove(&catalog_name);
+        });
+        catalog_namespaces.with_mut(|namespaces| {
+            namespaces.remove(&catalog_name);
+        });
+        let key_prefix = format!("{}::", catalog_name);
+        namespace_tables.with_mut(|tables| tables.retain(|key, _| !key.starts_with(&key_prefix)));
+        expanded_namespaces
+            .with_mut(|expanded| expanded.retain(|key| !key.starts_with(&key_prefix)));
+        loading_namespaces.with_mut(|loading| loading.retain(|key| !key.starts_with(&key_prefix)));
+        // Crawl results include the catalog's tables; the next search re-crawls
+        crawled_tables.set(None);
+        on_catalog_disconnected.call(catalog_name);
+    };
 
+    let mut toggle_namespace_expansion = move |namespace_key: String| {
+        if let Some((catalog_name, namespace_name)) = namespace_key.split_once("::") {
             let should_expand = !expanded_namespaces.read().contains(&namespace_key);
 
             expanded_namespaces.with_mut(|expanded| {
@@ -1081,11 +5525,17 @@ fn LeftNavigationPane(
                         loading.insert(namespace_key_clone.clone());
                     });
 
-                    match catalog_manager
-                        .read()
-                        .list_tables(&catalog_name, &namespace_name)
-                        .await
-                    {
+                    let listed =
+                        match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                            Ok(()) => {
+                                catalog_manager
+                                    .read()
+                                    .list_tables(&catalog_name, &namespace_name)
+                                    .await
+                            }
+                            Err(e) => Err(e),
+                        };
+                    match listed {
                         Ok(tables) => {
                             namespace_tables.with_mut(|map| {
                                 map.insert(namespace_key_clone.clone(), tables);
@@ -1118,6 +5568,54 @@ fn LeftNavigationPane(
         compute_filtered_tree(&saved_catalogs, &namespaces, &tables, &query)
     };
 
+    // Group catalogs by their label; ungrouped catalogs are listed last
+    let catalog_groups = group_catalog_nodes(&saved_catalogs, &filtered_tree);
+    let has_groups = catalog_groups.iter().any(|(group, _)| group.is_some());
+    let collapsed_groups = catalog_manager
+        .read()
+        .settings()
+        .collapsed_catalog_groups
+        .clone();
+
+    // Flat catalog-wide search results, only used in "search all tables" mode
+    let search_results: Vec<(String, catalog::TableReference)> = {
+        let query = debounced_filter_query().to_lowercase();
+        crawled_tables()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(catalog_name, table)| {
+                query.is_empty()
+                    || table.full_name.to_lowercase().contains(&query)
+                    || catalog_name.to_lowercase().contains(&query)
+            })
+            .collect()
+    };
+
+    // Iceberg tables and views open in tabs; other tables can't be opened
+    let open_reference = move |(catalog_name, table): (String, catalog::TableReference)| {
+        let selected = (catalog_name, table.namespace, table.name);
+        match table.table_type {
+            catalog::TableType::Iceberg => on_table_selected.call(selected),
+            catalog::TableType::View => on_view_selected.call(selected),
+            catalog::TableType::Unknown => {}
+        }
+    };
+
+    // The table Enter opens from the filter, once the debounced results have caught up
+    let single_match =
+        if nav_filter_query().is_empty() || nav_filter_query() != debounced_filter_query() {
+            None
+        } else if search_all_mode() {
+            match search_results.as_slice() {
+                [(catalog_name, table)] if table.table_type != catalog::TableType::Unknown => {
+                    Some((catalog_name.clone(), table.clone()))
+                }
+                _ => None,
+            }
+        } else {
+            single_table_match(&filtered_tree)
+        };
+
     // Compute filter result counts
     let (catalog_count, namespace_count, table_count) = if !debounced_filter_query().is_empty() {
         compute_filter_counts(&filtered_tree)
@@ -1159,13 +5657,28 @@ fn LeftNavigationPane(
                                 r#type: "text",
                                 value: "{nav_filter_query()}",
                                 oninput: move |evt| nav_filter_query.set(evt.value()),
-                                onkeydown: move |evt| {
-                                    if evt.key() == Key::Escape {
-                                        nav_filter_query.set(String::new());
+                                onmounted: move |evt| filter_input.set(Some(evt.data())),
+                                onkeydown: {
+                                    let single_match = single_match.clone();
+                                    move |evt: KeyboardEvent| match evt.key() {
+                                        // The first Escape clears the filter; the next hands focus back to the app
+                                        // so its shortcuts work again
+                                        Key::Escape if !nav_filter_query().is_empty() => {
+                                            nav_filter_query.set(String::new());
+                                        }
+                                        Key::Escape => {
+                                            document::eval("document.getElementById('app-root')?.focus();");
+                                        }
+                                        Key::Enter => {
+                                            if let Some(table) = single_match.clone() {
+                                                open_reference(table);
+                                            }
+                                        }
+                                        _ => {}
                                     }
                                 },
                                 class: "block w-full pl-9 pr-8 py-1.5 text-sm border border-gray-300 rounded-md bg-white placeholder-gray-500 focus:outline-none focus:ring-1 focus:ring-blue-500 focus:border-blue-500",
-                                placeholder: "Filter catalogs, namespaces, tables..."
+                                placeholder: if search_all_mode() { "Search all tables in connected catalogs..." } else { "Filter catalogs, namespaces, tables..." }
                             }
                             // Clear button
                             if !nav_filter_query().is_empty() {
@@ -1191,7 +5704,7 @@ fn LeftNavigationPane(
                 }
 
                 // Filter result counts
-                if !nav_filter_query().is_empty() {
+                if !nav_filter_query().is_empty() && !search_all_mode() {
                     div {
                         class: "px-3 py-1 text-xs text-gray-600 bg-gray-50 border-b border-gray-200",
                         if catalog_count > 0 || namespace_count > 0 || table_count > 0 {
@@ -1204,6 +5717,9 @@ fn LeftNavigationPane(
                                 span { class: "font-medium", "{table_count}" }
                                 " tables"
                             }
+                            if single_match.is_some() {
+                                span { class: "ml-1 text-gray-400", "· ↵ to open" }
+                            }
                         } else if !debounced_filter_query().is_empty() {
                             span {
                                 class: "text-gray-500 italic",
@@ -1221,12 +5737,27 @@ fn LeftNavigationPane(
                             class: "flex items-center gap-2 flex-1",
                             h2 {
                                 class: "text-lg font-semibold text-gray-900",
-                                "📚 Catalogs"
+                                {format!("📚 {}", t(Msg::Catalogs))}
+                            }
+                            // Search-all toggle
+                            button {
+                                onclick: move |_| {
+                                    let enabled = !search_all_mode();
+                                    search_all_mode.set(enabled);
+                                    if enabled && crawled_tables.read().is_none() {
+                                        crawl_tables();
+                                    }
+                                },
+                                class: format!("ml-auto px-2 py-1 text-sm rounded-md transition-colors {}",
+                                    if search_all_mode() { "bg-blue-100 text-blue-700" } else { "text-gray-500 hover:bg-gray-100" }
+                                ),
+                                title: "Search all tables across connected catalogs",
+                                "🔎"
                             }
                             // Add Catalog Button
                             button {
                                 onclick: move |_| on_add_catalog.call(()),
-                                class: "ml-auto px-3 py-1 bg-blue-600 text-white text-sm rounded-md hover:bg-blue-700 transition-colors flex items-center gap-1",
+                                class: "px-3 py-1 bg-blue-600 text-white text-sm rounded-md hover:bg-blue-700 transition-colors flex items-center gap-1",
                                 title: "Add New Catalog",
                                 span { "+" }
                                 span { "Add" }
@@ -1252,7 +5783,66 @@ fn LeftNavigationPane(
                 }
             }
 
-            if !collapsed {
+            if !collapsed && search_all_mode() {
+                // Flat catalog-wide search results
+                div {
+                    class: "flex-1 overflow-y-auto p-2",
+                    div {
+                        class: "flex items-center justify-between px-2 pb-2 text-xs text-gray-500",
+                        if crawling() {
+                            span { "Crawling connected catalogs..." }
+                        } else if let Some(tables) = crawled_tables() {
+                            span { "{search_results.len()} of {tables.len()} tables" }
+                        }
+                        button {
+                            onclick: move |_| crawl_tables(),
+                            disabled: crawling(),
+                            class: "text-blue-600 hover:text-blue-800 disabled:text-gray-400",
+                            title: "Re-crawl all connected catalogs",
+                            "↻ Refresh"
+                        }
+                    }
+                    for error in crawl_errors() {
+                        div {
+                            class: "px-2 py-1 text-xs text-red-600 truncate",
+                            title: "{error}",
+                            "⚠ {error}"
+                        }
+                    }
+                    if !crawling() && crawled_tables().is_some_and(|tables| tables.is_empty()) {
+                        div {
+                            class: "text-center py-8 text-xs text-gray-500",
+                            "No tables found. Only connected catalogs are searched; expand a catalog to connect it."
+                        }
+                    }
+                    div {
+                        class: "space-y-1",
+                        for (catalog_name, table) in search_results.into_iter().take(200) {
+                            {
+                                let selectable = table.table_type != catalog::TableType::Unknown;
+                                let icon = table_type_icon(table.table_type);
+                                let open_args = (catalog_name.clone(), table.clone());
+                                rsx! {
+                                    div {
+                                        class: format!("px-2 py-1 rounded {}",
+                                            if selectable { "cursor-pointer hover:bg-blue-50" } else { "opacity-50 cursor-not-allowed" }
+                                        ),
+                                        onclick: move |_| open_reference(open_args.clone()),
+                                        div {
+                                            class: "text-sm text-gray-900 truncate",
+                                            "{icon} {table.name}"
+                                        }
+                                        div {
+                                            class: "text-xs text-gray-500 truncate",
+                                            "{catalog_name} › {table.namespace}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            } else if !collapsed {
                 // Catalog list
                 div {
                     class: "flex-1 overflow-y-auto p-2",
@@ -1266,7 +5856,34 @@ fn LeftNavigationPane(
                     } else {
                         div {
                             class: "space-y-1",
-                            for filtered_catalog in filtered_tree.iter() {
+                            for (group, nodes) in catalog_groups {
+                                {
+                                    let group_key = group.clone().unwrap_or_else(|| UNGROUPED_GROUP_KEY.to_string());
+                                    let label = group.unwrap_or_else(|| "Ungrouped".to_string());
+                                    let group_collapsed = has_groups
+                                        && nav_filter_query().is_empty()
+                                        && collapsed_groups.contains(&group_key);
+                                    rsx! {
+                                        if has_groups {
+                                            button {
+                                                onclick: move |_| {
+                                                    let group_key = group_key.clone();
+                                                    spawn(async move {
+                                                        // Failures are logged by the manager; the toggle still applies for this session
+                                                        let _ = write_when_free(catalog_manager, |manager| {
+                                                            manager.set_group_collapsed(&group_key, !group_collapsed)
+                                                        })
+                                                        .await;
+                                                    });
+                                                },
+                                                class: "w-full flex items-center px-2 pt-2 pb-1 text-xs font-semibold text-gray-500 uppercase tracking-wider hover:text-gray-700",
+                                                span { class: "mr-1", if group_collapsed { "▸" } else { "▾" } }
+                                                span { class: "truncate", "{label}" }
+                                                span { class: "ml-auto font-normal normal-case", "{nodes.len()}" }
+                                            }
+                                        }
+                                        if !group_collapsed {
+                                            for filtered_catalog in nodes.iter() {
                                 CatalogTreeNode {
                                     catalog_name: filtered_catalog.catalog_name.clone(),
                                     catalog_type: filtered_catalog.catalog_type.clone(),
@@ -1284,7 +5901,32 @@ fn LeftNavigationPane(
                                     on_toggle_catalog: move |name: String| toggle_catalog_expansion(name),
                                     on_toggle_namespace: move |key: String| toggle_namespace_expansion(key),
                                     on_delete_catalog: on_catalog_delete_requested,
-                                    on_table_selected: on_table_selected
+                                    on_disconnect_catalog: move |name: String| disconnect_catalog(name),
+                                    on_catalog_renamed: move |(old_name, new_name): (String, String)| {
+                                        expanded_catalogs.with_mut(|expanded| {
+                                            if expanded.remove(&old_name) {
+                                                expanded.insert(new_name.clone());
+                                            }
+                                        });
+                                        // Crawl results carry catalog names; the next search re-crawls
+                                        crawled_tables.set(None);
+                                        on_catalog_renamed.call((old_name, new_name));
+                                    },
+                                    on_warehouse_switched: move |name: String| {
+                                        // Crawl results list the previous warehouse's tables
+                                        crawled_tables.set(None);
+                                        on_warehouse_switched.call(name);
+                                    },
+                                    on_table_selected: on_table_selected,
+                                    on_view_selected: on_view_selected,
+                                    on_namespace_health_requested: on_namespace_health_requested,
+                                    on_namespace_tab_requested: on_namespace_tab_requested,
+                                    on_broken_tables_scan_requested: on_broken_tables_scan_requested,
+                                    on_catalog_compare_requested: on_catalog_compare_requested
+                                }
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -1309,7 +5951,15 @@ fn CatalogTreeNode(
     on_toggle_catalog: EventHandler<String>,
     on_toggle_namespace: EventHandler<String>,
     on_delete_catalog: EventHandler<String>,
+    on_disconnect_catalog: EventHandler<String>,
+    on_catalog_renamed: EventHandler<(String, String)>,
+    on_warehouse_switched: EventHandler<String>,
     on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
+    on_namespace_health_requested: EventHandler<(String, String)>,
+    on_namespace_tab_requested: EventHandler<(String, String)>,
+    on_broken_tables_scan_requested: EventHandler<String>,
+    on_catalog_compare_requested: EventHandler<String>,
 ) -> Element {
     // Use filtered namespaces if provided, otherwise use normal logic
     let namespaces_to_render = if let Some(filtered) = &filtered_namespaces {
@@ -1325,14 +5975,273 @@ fn CatalogTreeNode(
             .unwrap_or_default()
     };
 
-    // Simple loading check: if expanded but no namespaces loaded yet
-    let loading_catalog = expanded && namespaces_to_render.is_empty();
+    // Loading while expanded and nothing has been listed yet (a namespace filter may
+    // legitimately leave the list empty)
+    let namespaces_loaded = catalog_namespaces.read().contains_key(&catalog_name);
+    let loading_catalog = expanded && namespaces_to_render.is_empty() && !namespaces_loaded;
 
     let catalog_icon = match catalog_type {
         catalog::CatalogType::Rest => "🌐",
         catalog::CatalogType::Glue => "🔗",
     };
 
+    let mut show_details = use_signal(|| false);
+    let tls_verification_disabled = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .any(|c| c.name == catalog_name && c.tls_verification_disabled());
+    let connected_at = catalog_manager
+        .read()
+        .get_connection(&catalog_name)
+        .map(|conn| conn.connected_at);
+    let auto_disconnect = catalog_manager.read().auto_disconnect_reason(&catalog_name);
+    let (status_dot_class, status_label) = if connected_at.is_some() {
+        ("bg-green-500", "Connected")
+    } else if let Some(reason) = auto_disconnect {
+        ("bg-yellow-400", reason.label())
+    } else {
+        ("bg-gray-300", "Not connected")
+    };
+    let display_config = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .find(|c| c.name == catalog_name)
+        .map(config::sanitize_config_for_display);
+    // Built from the unsanitized config; the generator itself leaves secrets out
+    let cli_command = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .find(|c| c.name == catalog_name)
+        .map(export::pyiceberg_cli_command);
+    let mut cli_command_copied = use_signal(|| false);
+    // Glue catalogs are region-scoped; the region is shown when a connect finds nothing and
+    // when the warehouse bucket lives elsewhere
+    let (glue_region, cross_region_bucket) = catalog_manager
+        .read()
+        .get_connection(&catalog_name)
+        .filter(|conn| conn.config.catalog_type == catalog::CatalogType::Glue)
+        .map(|conn| {
+            (
+                Some(conn.config.glue_region()),
+                conn.cross_region_bucket().map(str::to_string),
+            )
+        })
+        .unwrap_or_default();
+    let namespace_count = catalog_namespaces
+        .read()
+        .get(&catalog_name)
+        .map(|namespaces| namespaces.len());
+
+    // Namespace filter, editable from the details popover
+    let current_namespace_filter = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .find(|c| c.name == catalog_name)
+        .and_then(|c| c.namespace_filter.clone());
+    let showing_all_namespaces = catalog_manager
+        .read()
+        .is_showing_all_namespaces(&catalog_name);
+    let namespace_filter_active = current_namespace_filter.is_some() && !showing_all_namespaces;
+    let mut namespace_filter_draft = use_signal(String::new);
+    let mut namespace_filter_error = use_signal(|| Option::<String>::None);
+
+    // Re-list namespaces after the filter changes; unconnected catalogs pick it up on expand
+    let reload_namespaces = {
+        let catalog_name = catalog_name.clone();
+        move || {
+            let catalog_name = catalog_name.clone();
+            if catalog_manager
+                .read()
+                .get_connection(&catalog_name)
+                .is_none()
+            {
+                catalog_namespaces.with_mut(|namespaces| {
+                    namespaces.remove(&catalog_name);
+                });
+                return;
+            }
+            spawn(async move {
+                let listed = match ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                    Ok(()) => catalog_manager.read().list_namespaces(&catalog_name).await,
+                    Err(e) => Err(e),
+                };
+                match listed {
+                    Ok(ns_list) => {
+                        catalog_namespaces.with_mut(|namespaces| {
+                            namespaces.insert(catalog_name.clone(), ns_list);
+                        });
+                    }
+                    Err(e) => {
+                        log::error!(
+                            "Failed to reload namespaces for catalog {}: {}",
+                            catalog_name,
+                            e
+                        );
+                    }
+                }
+            });
+        }
+    };
+    let save_namespace_filter = {
+        let catalog_name = catalog_name.clone();
+        let mut reload_namespaces = reload_namespaces.clone();
+        move || {
+            let filter = Some(namespace_filter_draft()).filter(|f| !f.trim().is_empty());
+            match catalog_manager
+                .with_mut(|manager| manager.set_namespace_filter(&catalog_name, filter))
+            {
+                Ok(()) => {
+                    namespace_filter_error.set(None);
+                    reload_namespaces();
+                }
+                Err(e) => namespace_filter_error.set(Some(e.to_string())),
+            }
+        }
+    };
+
+    // Warehouse switcher for REST catalogs serving several warehouses from one endpoint
+    let (current_warehouse, known_warehouses) = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .find(|c| c.name == catalog_name && c.catalog_type == catalog::CatalogType::Rest)
+        .map(|c| {
+            (
+                c.config.get("warehouse").cloned(),
+                Some(c.known_warehouses()),
+            )
+        })
+        .unwrap_or_default();
+    let mut warehouse_draft = use_signal(String::new);
+    let mut switching_warehouse = use_signal(|| false);
+    let mut warehouse_error = use_signal(|| Option::<String>::None);
+    let switch_warehouse = {
+        let catalog_name = catalog_name.clone();
+        let reload_namespaces = reload_namespaces.clone();
+        move |warehouse: String| {
+            let catalog_name = catalog_name.clone();
+            let mut reload_namespaces = reload_namespaces.clone();
+            spawn(async move {
+                switching_warehouse.set(true);
+                warehouse_error.set(None);
+                let result = catalog_manager
+                    .write()
+                    .switch_warehouse(&catalog_name, &warehouse)
+                    .await;
+                switching_warehouse.set(false);
+                match result {
+                    Ok(()) => {
+                        warehouse_draft.set(String::new());
+                        // Namespaces and tables listed so far belong to the previous warehouse
+                        let prefix = format!("{}::", catalog_name);
+                        namespace_tables.with_mut(|tables| {
+                            tables.retain(|key, _| !key.starts_with(&prefix));
+                        });
+                        reload_namespaces();
+                        on_warehouse_switched.call(catalog_name.clone());
+                        toast::notify(
+                            toast::ToastKind::Success,
+                            tf(Msg::WarehouseSwitched, &[&catalog_name, &warehouse]),
+                        );
+                    }
+                    Err(e) => warehouse_error.set(Some(e.to_string())),
+                }
+            });
+        }
+    };
+
+    // Inline group editor: `Some(draft)` while open
+    let mut editing_group = use_signal(|| Option::<String>::None);
+    let mut group_error = use_signal(|| Option::<String>::None);
+    let current_group = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .find(|c| c.name == catalog_name)
+        .and_then(|c| c.group.clone());
+    let pinned = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .any(|c| c.name == catalog_name && c.pinned);
+    let known_groups: std::collections::BTreeSet<String> = catalog_manager
+        .read()
+        .get_saved_catalogs()
+        .iter()
+        .filter_map(|c| c.group.clone())
+        .collect();
+    let save_group = {
+        let catalog_name = catalog_name.clone();
+        move || {
+            let group = editing_group().filter(|g| !g.trim().is_empty());
+            let catalog_name = catalog_name.clone();
+            spawn(async move {
+                let saved = write_when_free(catalog_manager, |manager| {
+                    manager.set_catalog_group(&catalog_name, group)
+                })
+                .await;
+                match saved {
+                    Ok(()) => {
+                        editing_group.set(None);
+                        group_error.set(None);
+                    }
+                    Err(e) => group_error.set(Some(e.to_string())),
+                }
+            });
+        }
+    };
+
+    // Inline rename editor: `Some(draft)` while open
+    let mut editing_name = use_signal(|| Option::<String>::None);
+    let mut rename_error = use_signal(|| Option::<String>::None);
+    let save_rename = {
+        let catalog_name = catalog_name.clone();
+        move || {
+            let Some(new_name) = editing_name().map(|name| name.trim().to_string()) else {
+                return;
+            };
+            if new_name == catalog_name {
+                editing_name.set(None);
+                return;
+            }
+            match catalog_manager
+                .with_mut(|manager| manager.rename_catalog(&catalog_name, &new_name))
+            {
+                Ok(()) => {
+                    let rekey = |key: String, separator: &str| {
+                        config::rename_catalog_in_key(&key, &catalog_name, &new_name, separator)
+                            .unwrap_or(key)
+                    };
+                    catalog_namespaces.with_mut(|namespaces| {
+                        if let Some(list) = namespaces.remove(&catalog_name) {
+                            namespaces.insert(new_name.clone(), list);
+                        }
+                    });
+                    namespace_tables.with_mut(|tables| {
+                        *tables = tables
+                            .drain()
+                            .map(|(key, value)| (rekey(key, "::"), value))
+                            .collect();
+                    });
+                    loading_namespaces.with_mut(|loading| {
+                        *loading = loading.drain().map(|key| rekey(key, "::")).collect();
+                    });
+                    expanded_namespaces.with_mut(|expanded| {
+                        *expanded = expanded.drain().map(|key| rekey(key, "::")).collect();
+                    });
+                    editing_name.set(None);
+                    rename_error.set(None);
+                    on_catalog_renamed.call((catalog_name.clone(), new_name));
+                }
+                Err(e) => rename_error.set(Some(e.to_string())),
+            }
+        }
+    };
+
     rsx! {
         div {
             class: "select-none",
@@ -1350,59 +6259,514 @@ fn CatalogTreeNode(
                             log::info!("Catalog clicked: {}", catalog_name_toggle);
                             on_toggle_catalog.call(catalog_name_toggle.clone())
                         }
-                    },
+                    },
+
+                    // Expand/collapse icon
+                    div {
+                        class: "w-4 h-4 mr-1 flex items-center justify-center",
+                        if loading_catalog {
+                            div {
+                                class: "animate-spin rounded-full h-3 w-3 border border-gray-300 border-t-blue-600"
+                            }
+                        } else {
+                            svg {
+                                class: format!("h-3 w-3 text-gray-500 transition-transform {}",
+                                    if expanded { "rotate-90" } else { "" }
+                                ),
+                                fill: "none",
+                                stroke: "currentColor",
+                                view_box: "0 0 24 24",
+                                path {
+                                    stroke_linecap: "round",
+                                    stroke_linejoin: "round",
+                                    stroke_width: "2",
+                                    d: "M9 5l7 7-7 7"
+                                }
+                            }
+                        }
+                    }
+
+                    // Catalog icon and name
+                    span { class: "text-sm mr-2", "{catalog_icon}" }
+                    span { class: "text-sm font-medium text-gray-900 truncate", "{catalog_name}" }
+                    span {
+                        class: "ml-2 w-2 h-2 rounded-full flex-shrink-0 {status_dot_class}",
+                        title: "{status_label}"
+                    }
+                    if tls_verification_disabled {
+                        span {
+                            class: "ml-1 text-xs text-red-600",
+                            title: "TLS certificate verification is disabled for this catalog",
+                            "⚠️"
+                        }
+                    }
+                    if let Some(filter) = current_namespace_filter.clone().filter(|_| namespace_filter_active) {
+                        span {
+                            class: "ml-1 px-1 text-xs text-blue-700 bg-blue-50 rounded",
+                            title: "Namespace filter: {filter}",
+                            "filtered"
+                        }
+                    }
+                }
+
+                // Pin button, always shown on pinned catalogs as their indicator
+                button {
+                    onclick: {
+                        let catalog_name = catalog_name.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            let catalog_name = catalog_name.clone();
+                            spawn(async move {
+                                if let Err(e) = write_when_free(catalog_manager, |manager| manager.set_catalog_pinned(&catalog_name, !pinned)).await {
+                                    log::error!("Failed to pin catalog: {}", e);
+                                    return;
+                                }
+                                if pinned {
+                                    return;
+                                }
+                                if let Err(e) = ensure_catalog_connected(catalog_manager, &catalog_name).await {
+                                    log::error!("Failed to connect pinned catalog '{}': {}", catalog_name, e);
+                                }
+                            });
+                        }
+                    },
+                    class: format!("p-1 hover:bg-blue-100 rounded transition-all text-xs {}",
+                        if pinned { "opacity-100" } else { "opacity-0 group-hover:opacity-100" }
+                    ),
+                    title: if pinned {
+                        "Pinned: connects at startup and stays connected. Click to unpin."
+                    } else {
+                        "Pin: connect at startup and never disconnect when idle"
+                    },
+                    "📌"
+                }
+
+                // Rename button
+                button {
+                    onclick: {
+                        let catalog_name = catalog_name.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            if editing_name().is_some() {
+                                editing_name.set(None);
+                            } else {
+                                editing_name.set(Some(catalog_name.clone()));
+                            }
+                            rename_error.set(None);
+                        }
+                    },
+                    class: format!("p-1 hover:bg-blue-100 rounded transition-all text-xs {}",
+                        if editing_name().is_some() { "opacity-100" } else { "opacity-0 group-hover:opacity-100" }
+                    ),
+                    title: "Rename catalog",
+                    "✏️"
+                }
+
+                // Broken tables scan button
+                button {
+                    onclick: {
+                        let catalog_name = catalog_name.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            on_broken_tables_scan_requested.call(catalog_name.clone());
+                        }
+                    },
+                    class: "p-1 hover:bg-blue-100 rounded transition-all text-xs opacity-0 group-hover:opacity-100",
+                    title: "Scan for broken tables",
+                    "🔎"
+                }
+
+                // Compare with another catalog
+                button {
+                    onclick: {
+                        let catalog_name = catalog_name.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            on_catalog_compare_requested.call(catalog_name.clone());
+                        }
+                    },
+                    class: "p-1 hover:bg-blue-100 rounded transition-all text-xs opacity-0 group-hover:opacity-100",
+                    title: "Compare tables with another catalog",
+                    "⇄"
+                }
+
+                // Group button
+                button {
+                    onclick: {
+                        let current_group = current_group.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            if editing_group().is_some() {
+                                editing_group.set(None);
+                            } else {
+                                editing_group.set(Some(current_group.clone().unwrap_or_default()));
+                            }
+                            group_error.set(None);
+                        }
+                    },
+                    class: format!("p-1 hover:bg-blue-100 rounded transition-all text-xs {}",
+                        if editing_group().is_some() { "opacity-100" } else { "opacity-0 group-hover:opacity-100" }
+                    ),
+                    title: "Set group",
+                    "🏷️"
+                }
+
+                // Connection details button
+                button {
+                    onclick: {
+                        let current_namespace_filter = current_namespace_filter.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            namespace_filter_draft.set(current_namespace_filter.clone().unwrap_or_default());
+                            namespace_filter_error.set(None);
+                            show_details.set(!show_details());
+                        }
+                    },
+                    class: format!("p-1 hover:bg-blue-100 rounded transition-all text-xs text-blue-500 {}",
+                        if show_details() { "opacity-100" } else { "opacity-0 group-hover:opacity-100" }
+                    ),
+                    title: "Connection details",
+                    "ⓘ"
+                }
+
+                // Disconnect button, keeping the saved config
+                if connected_at.is_some() {
+                    button {
+                        onclick: {
+                            let catalog_name = catalog_name.clone();
+                            move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                                e.stop_propagation();
+                                on_disconnect_catalog.call(catalog_name.clone());
+                            }
+                        },
+                        class: "p-1 hover:bg-blue-100 rounded transition-all text-xs opacity-0 group-hover:opacity-100",
+                        title: "Disconnect (keeps the saved catalog; expanding it reconnects)",
+                        "⏏"
+                    }
+                }
+
+                // Delete button
+                button {
+                    onclick: {
+                        let catalog_name_delete = catalog_name.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            on_delete_catalog.call(catalog_name_delete.clone());
+                        }
+                    },
+                    class: "opacity-0 group-hover:opacity-100 p-1 hover:bg-red-100 rounded transition-all",
+                    title: "Delete catalog",
+                    svg {
+                        class: "h-3 w-3 text-red-500",
+                        fill: "none",
+                        stroke: "currentColor",
+                        view_box: "0 0 24 24",
+                        path {
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                            stroke_width: "2",
+                            d: "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16"
+                        }
+                    }
+                }
+            }
+
+            // Rename editor
+            if let Some(draft) = editing_name() {
+                div {
+                    class: "mx-2 my-1 p-2 bg-white border border-gray-200 rounded-md shadow-lg text-xs space-y-2",
+                    div { class: "font-medium text-gray-900", "Rename catalog" }
+                    input {
+                        r#type: "text",
+                        value: "{draft}",
+                        oninput: move |evt| editing_name.set(Some(evt.value())),
+                        onkeydown: {
+                            let mut save_rename = save_rename.clone();
+                            move |event: dioxus::prelude::Event<dioxus::html::KeyboardData>| {
+                                if event.key() == Key::Enter {
+                                    save_rename();
+                                } else if event.key() == Key::Escape {
+                                    editing_name.set(None);
+                                }
+                            }
+                        },
+                        class: "w-full px-2 py-1 border border-gray-300 rounded text-xs focus:outline-none focus:ring-1 focus:ring-blue-500",
+                        autofocus: true
+                    }
+                    if let Some(error) = rename_error() {
+                        div { class: "text-red-600", "{error}" }
+                    }
+                    div {
+                        class: "flex justify-end space-x-2",
+                        button {
+                            onclick: move |_| editing_name.set(None),
+                            class: "px-2 py-1 text-gray-600 hover:text-gray-800",
+                            {t(Msg::Cancel)}
+                        }
+                        button {
+                            onclick: {
+                                let mut save_rename = save_rename.clone();
+                                move |_| save_rename()
+                            },
+                            class: "px-2 py-1 bg-blue-600 text-white rounded hover:bg-blue-700",
+                            "Rename"
+                        }
+                    }
+                }
+            }
+
+            // Group editor
+            if let Some(draft) = editing_group() {
+                div {
+                    class: "mx-2 my-1 p-2 bg-white border border-gray-200 rounded-md shadow-lg text-xs space-y-2",
+                    div { class: "font-medium text-gray-900", "Group" }
+                    input {
+                        r#type: "text",
+                        value: "{draft}",
+                        list: format!("catalog-groups-{}", catalog_name),
+                        placeholder: "e.g. prod (empty for ungrouped)",
+                        oninput: move |evt| editing_group.set(Some(evt.value())),
+                        onkeydown: {
+                            let mut save_group = save_group.clone();
+                            move |event: dioxus::prelude::Event<dioxus::html::KeyboardData>| {
+                                if event.key() == Key::Enter {
+                                    save_group();
+                                } else if event.key() == Key::Escape {
+                                    editing_group.set(None);
+                                }
+                            }
+                        },
+                        class: "w-full px-2 py-1 border border-gray-300 rounded text-xs focus:outline-none focus:ring-1 focus:ring-blue-500",
+                        autofocus: true
+                    }
+                    datalist {
+                        id: format!("catalog-groups-{}", catalog_name),
+                        for group in known_groups.iter() {
+                            option { value: "{group}" }
+                        }
+                    }
+                    if let Some(error) = group_error() {
+                        div { class: "text-red-600", "{error}" }
+                    }
+                    div {
+                        class: "flex justify-end space-x-2",
+                        button {
+                            onclick: move |_| editing_group.set(None),
+                            class: "px-2 py-1 text-gray-600 hover:text-gray-800",
+                            {t(Msg::Cancel)}
+                        }
+                        button {
+                            onclick: {
+                                let mut save_group = save_group.clone();
+                                move |_| save_group()
+                            },
+                            class: "px-2 py-1 bg-blue-600 text-white rounded hover:bg-blue-700",
+                            {t(Msg::Save)}
+                        }
+                    }
+                }
+            }
 
-                    // Expand/collapse icon
+            // Connection details popover
+            if show_details() {
+                div {
+                    class: "mx-2 my-1 p-3 bg-white border border-gray-200 rounded-md shadow-lg text-xs space-y-1",
                     div {
-                        class: "w-4 h-4 mr-1 flex items-center justify-center",
-                        if loading_catalog {
-                            div {
-                                class: "animate-spin rounded-full h-3 w-3 border border-gray-300 border-t-blue-600"
+                        class: "flex items-center justify-between mb-1",
+                        span { class: "font-medium text-gray-900", "Connection details" }
+                        button {
+                            onclick: move |_| show_details.set(false),
+                            class: "text-gray-400 hover:text-gray-600",
+                            "✕"
+                        }
+                    }
+                    if tls_verification_disabled {
+                        div {
+                            class: "p-2 bg-red-50 border border-red-200 rounded text-red-700 font-medium",
+                            "⚠️ TLS certificate verification is disabled for this catalog"
+                        }
+                    }
+                    div {
+                        span { class: "text-gray-500", "Type: " }
+                        span { class: "text-gray-900", "{catalog_type:?}" }
+                    }
+                    div {
+                        span { class: "text-gray-500", "Status: " }
+                        if let Some(since) = connected_at {
+                            span {
+                                class: "text-green-700",
+                                {format!("Connected since {}", since.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S"))}
                             }
                         } else {
-                            svg {
-                                class: format!("h-3 w-3 text-gray-500 transition-transform {}",
-                                    if expanded { "rotate-90" } else { "" }
-                                ),
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M9 5l7 7-7 7"
+                            span { class: "text-gray-500", "{status_label}" }
+                        }
+                    }
+                    div {
+                        span { class: "text-gray-500", "Namespaces: " }
+                        span {
+                            class: "text-gray-900",
+                            {namespace_count.map(|count| count.to_string()).unwrap_or_else(|| "not loaded".to_string())}
+                        }
+                    }
+                    if let Some(known_warehouses) = known_warehouses {
+                        div {
+                            class: "pt-1",
+                            span { class: "text-gray-500", "Warehouse: " }
+                            div {
+                                class: "flex items-center space-x-1 mt-1",
+                                if !known_warehouses.is_empty() {
+                                    select {
+                                        disabled: switching_warehouse(),
+                                        onchange: {
+                                            let switch_warehouse = switch_warehouse.clone();
+                                            move |evt: dioxus::prelude::Event<dioxus::html::FormData>| {
+                                                if !evt.value().is_empty() {
+                                                    switch_warehouse(evt.value());
+                                                }
+                                            }
+                                        },
+                                        class: "flex-1 min-w-0 px-1 py-1 border border-gray-300 rounded text-xs font-mono bg-white",
+                                        if current_warehouse.is_none() {
+                                            option { value: "", selected: true, "(server default)" }
+                                        }
+                                        for warehouse in known_warehouses {
+                                            option {
+                                                value: "{warehouse}",
+                                                selected: current_warehouse.as_deref() == Some(warehouse.as_str()),
+                                                "{warehouse}"
+                                            }
+                                        }
+                                    }
+                                }
+                                input {
+                                    r#type: "text",
+                                    value: "{warehouse_draft}",
+                                    placeholder: "Another warehouse",
+                                    disabled: switching_warehouse(),
+                                    oninput: move |evt| warehouse_draft.set(evt.value()),
+                                    onkeydown: {
+                                        let switch_warehouse = switch_warehouse.clone();
+                                        move |event: dioxus::prelude::Event<dioxus::html::KeyboardData>| {
+                                            if event.key() == Key::Enter {
+                                                switch_warehouse(warehouse_draft());
+                                            }
+                                        }
+                                    },
+                                    class: "flex-1 min-w-0 px-2 py-1 border border-gray-300 rounded text-xs font-mono focus:outline-none focus:ring-1 focus:ring-blue-500"
+                                }
+                                button {
+                                    onclick: {
+                                        let switch_warehouse = switch_warehouse.clone();
+                                        move |_| switch_warehouse(warehouse_draft())
+                                    },
+                                    disabled: switching_warehouse() || warehouse_draft().trim().is_empty(),
+                                    class: "px-2 py-1 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:bg-gray-300",
+                                    "Switch"
                                 }
                             }
+                            if switching_warehouse() {
+                                div { class: "mt-1 text-gray-500", "Reconnecting..." }
+                            }
+                            if let Some(error) = warehouse_error() {
+                                div { class: "mt-1 text-red-600", "{error}" }
+                            }
                         }
                     }
-
-                    // Catalog icon and name
-                    span { class: "text-sm mr-2", "{catalog_icon}" }
-                    span { class: "text-sm font-medium text-gray-900 truncate", "{catalog_name}" }
-                }
-
-                // Delete button
-                button {
-                    onclick: {
-                        let catalog_name_delete = catalog_name.clone();
-                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
-                            e.stop_propagation();
-                            on_delete_catalog.call(catalog_name_delete.clone());
+                    div {
+                        class: "pt-1",
+                        div {
+                            class: "flex items-center justify-between",
+                            span { class: "text-gray-500", "Namespace filter: " }
+                            if current_namespace_filter.is_some() {
+                                label {
+                                    class: "flex items-center text-gray-600",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: showing_all_namespaces,
+                                        onchange: {
+                                            let catalog_name = catalog_name.clone();
+                                            let mut reload_namespaces = reload_namespaces.clone();
+                                            move |evt: dioxus::prelude::Event<dioxus::html::FormData>| {
+                                                catalog_manager.with_mut(|manager| {
+                                                    manager.set_show_all_namespaces(&catalog_name, evt.checked())
+                                                });
+                                                reload_namespaces();
+                                            }
+                                        },
+                                        class: "mr-1"
+                                    }
+                                    "Show all"
+                                }
+                            }
                         }
-                    },
-                    class: "opacity-0 group-hover:opacity-100 p-1 hover:bg-red-100 rounded transition-all",
-                    title: "Delete catalog",
-                    svg {
-                        class: "h-3 w-3 text-red-500",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16"
+                        div {
+                            class: "flex items-center space-x-1 mt-1",
+                            input {
+                                r#type: "text",
+                                value: "{namespace_filter_draft}",
+                                placeholder: "e.g. team_a or *_shared (comma-separated)",
+                                oninput: move |evt| namespace_filter_draft.set(evt.value()),
+                                onkeydown: {
+                                    let mut save_namespace_filter = save_namespace_filter.clone();
+                                    move |event: dioxus::prelude::Event<dioxus::html::KeyboardData>| {
+                                        if event.key() == Key::Enter {
+                                            save_namespace_filter();
+                                        }
+                                    }
+                                },
+                                class: "flex-1 px-2 py-1 border border-gray-300 rounded text-xs font-mono focus:outline-none focus:ring-1 focus:ring-blue-500"
+                            }
+                            button {
+                                onclick: {
+                                    let mut save_namespace_filter = save_namespace_filter.clone();
+                                    move |_| save_namespace_filter()
+                                },
+                                class: "px-2 py-1 bg-blue-600 text-white rounded hover:bg-blue-700",
+                                "Apply"
+                            }
+                        }
+                        if namespace_filter_active {
+                            div {
+                                class: "mt-1 text-blue-700",
+                                "Only matching namespaces are listed. Prefixes match the start of the name; * and ? are wildcards."
+                            }
+                        } else if showing_all_namespaces {
+                            div {
+                                class: "mt-1 text-gray-500",
+                                "Filter bypassed: showing all namespaces."
+                            }
+                        }
+                        if let Some(error) = namespace_filter_error() {
+                            div { class: "mt-1 text-red-600", "{error}" }
+                        }
+                    }
+                    if let Some(command) = cli_command {
+                        div {
+                            class: "pt-1",
+                            button {
+                                onclick: move |_| {
+                                    components::copy_to_clipboard(&command);
+                                    cli_command_copied.set(true);
+                                },
+                                class: "px-2 py-1 border border-gray-300 rounded text-gray-700 hover:bg-gray-50",
+                                title: "Environment variables and a pyiceberg command using this connection; secrets are left as variables to fill in",
+                                if cli_command_copied() { "✓ Copied" } else { "📋 Copy as PyIceberg CLI command" }
+                            }
+                        }
+                    }
+                    if let Some(display_config) = display_config {
+                        {
+                            let mut entries: Vec<_> = display_config.config.into_iter().collect();
+                            entries.sort();
+                            rsx! {
+                                for (key, value) in entries {
+                                    div {
+                                        class: "break-all",
+                                        span { class: "text-gray-500", "{key}: " }
+                                        span { class: "font-mono text-gray-900", "{value}" }
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -1412,6 +6776,49 @@ fn CatalogTreeNode(
             if expanded {
                 div {
                     class: "ml-4 mt-1 space-y-1",
+                    if namespaces_loaded && namespaces_to_render.is_empty() && namespace_filter_active {
+                        div {
+                            class: "px-2 py-1 text-xs text-gray-500 italic",
+                            "No namespaces match the filter"
+                        }
+                    }
+                    if let Some(region) = glue_region.clone().filter(|_| {
+                        namespaces_loaded
+                            && namespaces_to_render.is_empty()
+                            && !namespace_filter_active
+                            && filtered_namespaces.is_none()
+                    }) {
+                        div {
+                            class: "px-2 py-2 text-xs text-yellow-800 bg-yellow-50 border border-yellow-200 rounded",
+                            p {
+                                "No databases found in "
+                                span { class: "font-mono font-semibold", "{region}" }
+                                button {
+                                    onclick: {
+                                        let region = region.clone();
+                                        move |_| components::copy_to_clipboard(&region)
+                                    },
+                                    class: "ml-1 text-yellow-700 hover:text-yellow-900",
+                                    title: "Copy region",
+                                    "📋"
+                                }
+                            }
+                            p {
+                                class: "mt-1",
+                                "Glue catalogs only list databases in their own region. If you expected some, check the region this catalog was saved with."
+                            }
+                        }
+                    }
+                    if let (Some(region), Some(bucket_region)) = (glue_region.clone(), cross_region_bucket.clone()) {
+                        div {
+                            class: "px-2 py-2 text-xs text-yellow-800 bg-yellow-50 border border-yellow-200 rounded",
+                            "⚠️ The warehouse bucket is in "
+                            span { class: "font-mono font-semibold", "{bucket_region}" }
+                            " but Glue is set to "
+                            span { class: "font-mono font-semibold", "{region}" }
+                            ". Reading table files will cross regions, which is slower and may incur transfer costs."
+                        }
+                    }
                     for namespace_name in namespaces_to_render.iter() {
                         {
                             let filtered_ns_data = filtered_namespaces
@@ -1432,7 +6839,10 @@ fn CatalogTreeNode(
                                     namespace_tables: namespace_tables,
                                     loading_namespaces: loading_namespaces,
                                     on_toggle_namespace: on_toggle_namespace,
-                                    on_table_selected: on_table_selected
+                                    on_table_selected: on_table_selected,
+                                    on_view_selected: on_view_selected,
+                                    on_health_report: on_namespace_health_requested,
+                                    on_open_tab: on_namespace_tab_requested
                                 }
                             }
                         }
@@ -1454,6 +6864,9 @@ fn NamespaceTreeNode(
     loading_namespaces: Signal<std::collections::HashSet<String>>,
     on_toggle_namespace: EventHandler<String>,
     on_table_selected: EventHandler<(String, String, String)>,
+    on_view_selected: EventHandler<(String, String, String)>,
+    on_health_report: EventHandler<(String, String)>,
+    on_open_tab: EventHandler<(String, String)>,
 ) -> Element {
     let is_loading = loading_namespaces.read().contains(&namespace_key);
 
@@ -1467,6 +6880,12 @@ fn NamespaceTreeNode(
             .cloned()
             .unwrap_or_default()
     };
+    // Names that only differ by case may be one table in a case-insensitive catalog
+    let case_variants: std::collections::HashSet<String> =
+        catalog::case_variant_groups(tables_to_render.iter().map(|table| table.name.as_str()))
+            .into_iter()
+            .flatten()
+            .collect();
 
     rsx! {
         div {
@@ -1474,7 +6893,7 @@ fn NamespaceTreeNode(
 
             // Namespace header
             div {
-                class: "flex items-center hover:bg-gray-50 rounded px-2 py-1 cursor-pointer",
+                class: "group flex items-center hover:bg-gray-50 rounded px-2 py-1 cursor-pointer",
                 onclick: move |_| on_toggle_namespace.call(namespace_key.clone()),
 
                 // Expand/collapse icon
@@ -1505,9 +6924,37 @@ fn NamespaceTreeNode(
                 // Namespace icon and name
                 span { class: "text-sm mr-2", "📁" }
                 span {
-                    class: "text-sm text-gray-700 truncate",
+                    class: "text-sm text-gray-700 truncate flex-1",
                     "{namespace_name}"
                 }
+
+                // Health report button
+                button {
+                    onclick: {
+                        let catalog_name = catalog_name.clone();
+                        let namespace_name = namespace_name.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            on_health_report.call((catalog_name.clone(), namespace_name.clone()));
+                        }
+                    },
+                    class: "opacity-0 group-hover:opacity-100 p-1 hover:bg-blue-100 rounded transition-all text-xs",
+                    title: "Scan all tables' health",
+                    "🩺"
+                }
+                button {
+                    onclick: {
+                        let catalog_name = catalog_name.clone();
+                        let namespace_name = namespace_name.clone();
+                        move |e: dioxus::prelude::Event<dioxus::html::MouseData>| {
+                            e.stop_propagation();
+                            on_open_tab.call((catalog_name.clone(), namespace_name.clone()));
+                        }
+                    },
+                    class: "opacity-0 group-hover:opacity-100 p-1 hover:bg-blue-100 rounded transition-all text-xs",
+                    title: "Open namespace in a tab",
+                    "🗂"
+                }
             }
 
             // Tables (when expanded)
@@ -1517,44 +6964,49 @@ fn NamespaceTreeNode(
                     if tables_to_render.is_empty() {
                         div {
                             class: "px-2 py-1 text-xs text-gray-500 italic",
-                            "No tables found"
+                            {t(Msg::NoTablesFound)}
                         }
                     } else {
                         for table in tables_to_render.iter() {
                             div {
                                 class: format!("flex items-center px-2 py-1 rounded transition-colors {}",
-                                    if table.table_type == catalog::TableType::Iceberg {
-                                        "hover:bg-blue-50 cursor-pointer"
-                                    } else {
-                                        "cursor-not-allowed opacity-50"
+                                    match table.table_type {
+                                        catalog::TableType::Iceberg => "hover:bg-blue-50 cursor-pointer",
+                                        catalog::TableType::View => "hover:bg-purple-50 cursor-pointer",
+                                        catalog::TableType::Unknown => "cursor-not-allowed opacity-50",
                                     }
                                 ),
                                 onclick: {
-                                    let catalog_name = catalog_name.clone();
-                                    let namespace_name = namespace_name.clone();
-                                    let table_name = table.name.clone();
+                                    let selected = (catalog_name.clone(), namespace_name.clone(), table.name.clone());
                                     let table_type = table.table_type;
-                                    move |_| {
-                                        if table_type == catalog::TableType::Iceberg {
-                                            on_table_selected.call((catalog_name.clone(), namespace_name.clone(), table_name.clone()));
-                                        }
+                                    move |_| match table_type {
+                                        catalog::TableType::Iceberg => on_table_selected.call(selected.clone()),
+                                        catalog::TableType::View => on_view_selected.call(selected.clone()),
+                                        catalog::TableType::Unknown => {}
                                     }
                                 },
 
                                 span {
                                     class: "text-sm mr-2",
-                                    if table.table_type == catalog::TableType::Iceberg { "🧊" } else { "📄" }
+                                    {table_type_icon(table.table_type)}
                                 }
                                 span {
                                     class: format!("text-xs truncate {}",
-                                        if table.table_type == catalog::TableType::Iceberg {
-                                            "text-gray-800"
-                                        } else {
+                                        if table.table_type == catalog::TableType::Unknown {
                                             "text-gray-500"
+                                        } else {
+                                            "text-gray-800"
                                         }
                                     ),
                                     "{table.name}"
                                 }
+                                if case_variants.contains(&table.name) {
+                                    span {
+                                        class: "ml-1 text-xs text-yellow-600",
+                                        title: "Another table here differs only by case; the catalog may treat them as the same table",
+                                        "⚠️"
+                                    }
+                                }
                             }
                         }
                     }
@@ -1564,6 +7016,70 @@ fn NamespaceTreeNode(
     }
 }
 
+/// Asks before an action opens more tables than the configured threshold
+#[component]
+fn BulkOpenConfirmationDialog(
+    count: usize,
+    on_confirm: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-center justify-center",
+            onclick: move |_| on_cancel.call(()),
+
+            // Modal content
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-md w-full mx-4",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    h3 {
+                        class: "text-lg font-medium text-gray-900",
+                        "Open {count} tables?"
+                    }
+                    button {
+                        onclick: move |_| on_cancel.call(()),
+                        class: "text-gray-400 hover:text-gray-600",
+                        "✕"
+                    }
+                }
+
+                // Content
+                div {
+                    class: "p-4",
+                    p {
+                        class: "text-sm text-gray-600 mb-4",
+                        "Each table opens in its own tab and is loaded from its catalog, which sends {count} load requests."
+                    }
+                    p {
+                        class: "text-xs text-gray-500",
+                        "The number of tables that can open without this prompt is set in Settings."
+                    }
+                }
+
+                // Actions
+                div {
+                    class: "flex justify-end space-x-3 p-4 border-t border-gray-200",
+                    button {
+                        onclick: move |_| on_cancel.call(()),
+                        class: "px-4 py-2 text-sm font-medium text-gray-700 bg-gray-100 hover:bg-gray-200 rounded-md transition-colors",
+                        {t(Msg::Cancel)}
+                    }
+                    button {
+                        onclick: move |_| on_confirm.call(()),
+                        class: "px-4 py-2 text-sm font-medium text-white bg-blue-600 hover:bg-blue-700 rounded-md transition-colors",
+                        "Open {count} tables"
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn DeleteConfirmationDialog(
     catalog_name: String,
@@ -1616,15 +7132,205 @@ fn DeleteConfirmationDialog(
                     button {
                         onclick: move |_| on_cancel.call(()),
                         class: "px-4 py-2 text-sm font-medium text-gray-700 bg-gray-100 hover:bg-gray-200 rounded-md transition-colors",
-                        "Cancel"
+                        {t(Msg::Cancel)}
                     }
                     button {
                         onclick: move |_| on_confirm.call(()),
                         class: "px-4 py-2 text-sm font-medium text-white bg-red-600 hover:bg-red-700 rounded-md transition-colors",
-                        "Delete"
+                        {t(Msg::Delete)}
                     }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn table_reference(namespace: &str, name: &str) -> catalog::TableReference {
+        catalog::TableReference {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            full_name: format!("{}.{}", namespace, name),
+            table_type: catalog::TableType::Iceberg,
+        }
+    }
+
+    fn loading_tab(catalog_name: &str, namespace: &str, table_name: &str) -> AppTab {
+        AppTab::LoadingTable {
+            tab_id: table_tab_id(catalog_name, namespace, table_name),
+            catalog_name: catalog_name.to_string(),
+            namespace: namespace.to_string(),
+            table_name: table_name.to_string(),
+            view_tab: TableViewTab::Overview,
+        }
+    }
+
+    #[test]
+    fn test_parse_table_path() {
+        let catalogs = vec!["prod".to_string(), "prod.eu".to_string()];
+        assert_eq!(
+            parse_table_path(" sales.orders ", &catalogs, Some("prod")),
+            Ok((
+                "prod".to_string(),
+                "sales".to_string(),
+                "orders".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_table_path("prod.eu.sales.orders", &catalogs, None),
+            Ok((
+                "prod.eu".to_string(),
+                "sales".to_string(),
+                "orders".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_table_path("prod.analytics.core.events", &catalogs, None),
+            Ok((
+                "prod".to_string(),
+                "analytics.core".to_string(),
+                "events".to_string()
+            ))
+        );
+        assert!(parse_table_path("sales.orders", &catalogs, None).is_err());
+        assert!(parse_table_path("orders", &catalogs, Some("prod")).is_err());
+    }
+
+    #[test]
+    fn test_parse_table_list() {
+        let catalogs = vec!["prod".to_string(), "dev".to_string()];
+        let (tables, invalid) = parse_table_list(
+            "sales.orders, `dev.sales.orders`\n\n  \"sales.customers\"\nsales.orders\norders\n",
+            &catalogs,
+            Some("prod"),
+        );
+        let table = |c: &str, n: &str, t: &str| (c.to_string(), n.to_string(), t.to_string());
+        assert_eq!(
+            tables,
+            vec![
+                table("prod", "sales", "orders"),
+                table("dev", "sales", "orders"),
+                table("prod", "sales", "customers"),
+            ]
+        );
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].0, "orders");
+    }
+
+    #[test]
+    fn test_table_tab_id_is_unambiguous() {
+        assert_eq!(table_tab_id("prod", "sales", "orders"), "prod.sales.orders");
+        // Dots inside names can't make two tables share a key
+        assert_ne!(
+            table_tab_id("prod", "sales.eu", "orders"),
+            table_tab_id("prod", "sales", "eu.orders")
+        );
+        assert_ne!(
+            table_tab_id("prod", "sales", "Orders"),
+            table_tab_id("prod", "sales", "orders")
+        );
+        assert_ne!(
+            table_tab_id("prod.eu", "sales", "orders"),
+            table_tab_id("prod", "eu.sales", "orders")
+        );
+        // Renames rekey tabs by their catalog prefix
+        assert_eq!(
+            config::rename_catalog_in_table_key(
+                &table_tab_id("prod", "sales.eu", "orders"),
+                "prod",
+                "live"
+            ),
+            Some(table_tab_id("live", "sales.eu", "orders"))
+        );
+    }
+
+    #[test]
+    fn test_find_table_tab_with_case_variants() {
+        let tabs = vec![
+            AppTab::Catalog,
+            loading_tab("prod", "sales", "Orders"),
+            loading_tab("prod", "sales", "orders"),
+        ];
+
+        assert_eq!(
+            find_table_tab(&tabs, &table_tab_id("prod", "sales", "Orders")),
+            Some(1)
+        );
+        assert_eq!(
+            find_table_tab(&tabs, &table_tab_id("prod", "sales", "orders")),
+            Some(2)
+        );
+        assert_eq!(
+            find_table_tab(&tabs, &table_tab_id("prod", "sales", "ORDERS")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_compute_filtered_tree_with_case_variants() {
+        let catalogs = vec![catalog::CatalogConfig {
+            catalog_type: catalog::CatalogType::Glue,
+            name: "prod".to_string(),
+            config: HashMap::new(),
+            group: None,
+            namespace_filter: None,
+            pinned: false,
+        }];
+        let namespaces = HashMap::from([(
+            "prod".to_string(),
+            vec!["Sales".to_string(), "sales".to_string()],
+        )]);
+        let tables = HashMap::from([
+            (
+                "prod::Sales".to_string(),
+                vec![table_reference("Sales", "Orders")],
+            ),
+            (
+                "prod::sales".to_string(),
+                vec![
+                    table_reference("sales", "orders"),
+                    table_reference("sales", "Orders"),
+                    table_reference("sales", "users"),
+                    catalog::TableReference {
+                        table_type: catalog::TableType::View,
+                        ..table_reference("sales", "daily_totals")
+                    },
+                ],
+            ),
+        ]);
+
+        let tree = compute_filtered_tree(&catalogs, &namespaces, &tables, "ORDERS");
+        assert_eq!(tree.len(), 1);
+        let filtered = &tree[0].filtered_namespaces;
+        // Case-variant namespaces keep separate keys and their own tables
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[0].namespace_key, "prod::Sales");
+        assert_eq!(filtered[1].namespace_key, "prod::sales");
+        assert_eq!(filtered[0].filtered_tables.len(), 1);
+        let names: Vec<&str> = filtered[1]
+            .filtered_tables
+            .iter()
+            .map(|table| table.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["orders", "Orders"]);
+        assert_eq!(compute_filter_counts(&tree), (1, 2, 3));
+        assert_eq!(single_table_match(&tree), None);
+
+        let tree = compute_filtered_tree(&catalogs, &namespaces, &tables, "users");
+        assert_eq!(
+            single_table_match(&tree),
+            Some(("prod".to_string(), table_reference("sales", "users")))
+        );
+
+        // Views open from the filter too
+        let tree = compute_filtered_tree(&catalogs, &namespaces, &tables, "daily");
+        let (catalog_name, view) = single_table_match(&tree).unwrap();
+        assert_eq!(catalog_name, "prod");
+        assert_eq!(view.name, "daily_totals");
+        assert_eq!(view.table_type, catalog::TableType::View);
+    }
+}
@@ -0,0 +1,912 @@
+use crate::catalog::{CatalogManager, OperationMetrics};
+use crate::catalog_ui::ProxySettingsFields;
+use crate::config::{
+    DEFAULT_BULK_OPEN_CONFIRM_THRESHOLD, DEFAULT_CLASSIFICATION_PROPERTY_PREFIX,
+    DEFAULT_IDLE_DISCONNECT_MINUTES, DEFAULT_LARGE_METADATA_WARNING_MB,
+    DEFAULT_LARGE_TABLE_SNAPSHOT_LIMIT, DEFAULT_MAX_CONCURRENT_REQUESTS,
+    DEFAULT_SEARCH_RESULT_LIMIT, FreshnessThresholds, LogLevel,
+};
+use crate::data::HealthCategory;
+use crate::logging::{self, LogEntry};
+use crate::strings::{Locale, Msg, t};
+use crate::theme::{ACCENT_PRESETS, DEFAULT_ACCENT, parse_hex};
+use dioxus::prelude::*;
+
+#[component]
+pub fn SettingsModal(
+    catalog_manager: Signal<CatalogManager>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let settings = catalog_manager.read().settings().clone();
+    let proxy = use_signal(|| settings.proxy.clone());
+    let mut check_for_updates = use_signal(|| settings.check_for_updates);
+    let mut max_concurrent_requests = use_signal(|| settings.max_concurrent_requests);
+    let mut search_result_limit = use_signal(|| settings.search_result_limit);
+    let mut bulk_open_confirm_threshold = use_signal(|| settings.bulk_open_confirm_threshold);
+    let mut large_table_snapshot_limit = use_signal(|| settings.large_table_snapshot_limit);
+    let mut large_metadata_warning_mb = use_signal(|| settings.large_metadata_warning_mb);
+    let mut classification_property_prefix =
+        use_signal(|| settings.classification_property_prefix.clone());
+    let mut collect_request_metrics = use_signal(|| settings.collect_request_metrics);
+    let mut show_request_metrics = use_signal(|| false);
+    let mut idle_disconnect_minutes = use_signal(|| settings.idle_disconnect_minutes);
+    let mut aging_hours = use_signal(|| settings.freshness_thresholds.aging_hours);
+    let mut stale_hours = use_signal(|| settings.freshness_thresholds.stale_hours);
+    let mut enabled_health_categories = use_signal(|| settings.enabled_health_categories.clone());
+    // Every table sub-tab in display order, with whether it is shown
+    let mut table_view_tabs = use_signal(|| {
+        let visible = settings.visible_table_view_tabs();
+        settings
+            .ordered_table_view_tabs()
+            .into_iter()
+            .map(|tab| (tab, visible.contains(&tab)))
+            .collect::<Vec<_>>()
+    });
+    let mut log_level = use_signal(|| settings.log_level);
+    let mut log_health_metrics = use_signal(|| settings.log_health_metrics);
+    let mut accent_color = use_signal(|| settings.accent_color.clone());
+    let mut locale = use_signal(|| settings.locale);
+    let mut show_log_viewer = use_signal(|| false);
+    let mut save_error = use_signal(|| Option::<String>::None);
+
+    let save = move |_| {
+        let mut updated = catalog_manager.read().settings().clone();
+        updated.proxy = proxy();
+        updated.check_for_updates = check_for_updates();
+        updated.max_concurrent_requests = max_concurrent_requests();
+        updated.search_result_limit = search_result_limit();
+        updated.bulk_open_confirm_threshold = bulk_open_confirm_threshold();
+        updated.large_table_snapshot_limit = large_table_snapshot_limit();
+        updated.large_metadata_warning_mb = large_metadata_warning_mb();
+        updated.classification_property_prefix =
+            classification_property_prefix().trim().to_string();
+        updated.collect_request_metrics = collect_request_metrics();
+        updated.idle_disconnect_minutes = idle_disconnect_minutes();
+        updated.enabled_health_categories = HealthCategory::ALL
+            .into_iter()
+            .filter(|category| enabled_health_categories.read().contains(category))
+            .collect();
+        updated.table_view_tabs = table_view_tabs.read().iter().map(|(tab, _)| *tab).collect();
+        updated.hidden_table_view_tabs = table_view_tabs
+            .read()
+            .iter()
+            .filter(|(_, shown)| !*shown)
+            .map(|(tab, _)| *tab)
+            .collect();
+        updated.freshness_thresholds = FreshnessThresholds {
+            aging_hours: aging_hours(),
+            stale_hours: stale_hours().max(aging_hours()),
+        };
+        updated.log_level = log_level();
+        updated.log_health_metrics = log_health_metrics();
+        if parse_hex(&accent_color()).is_some() {
+            updated.accent_color = accent_color();
+        }
+        updated.locale = locale();
+
+        match catalog_manager.with_mut(|manager| manager.update_settings(updated)) {
+            Ok(()) => on_close.call(()),
+            Err(e) => save_error.set(Some(e.to_string())),
+        }
+    };
+
+    rsx! {
+        // Modal overlay
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-20",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-2xl w-full mx-4 flex flex-col",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    h3 {
+                        class: "text-lg font-medium text-gray-900",
+                        {format!("⚙️ {}", t(Msg::Settings))}
+                    }
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "text-gray-400 hover:text-gray-600",
+                        "✕"
+                    }
+                }
+
+                div {
+                    class: "p-4 space-y-6 overflow-y-auto",
+
+                    // Network
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Proxy"
+                        }
+                        p {
+                            class: "text-xs text-gray-500 mb-3",
+                            "Applied to all catalog connections unless a catalog sets its own override. When empty, HTTPS_PROXY / HTTP_PROXY / NO_PROXY from the environment are used. Changes apply the next time a catalog connects."
+                        }
+                        ProxySettingsFields { proxy: proxy }
+                    }
+
+                    // Catalog requests
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Catalog Requests"
+                        }
+                        label {
+                            class: "block text-sm text-gray-700",
+                            "Max concurrent catalog requests"
+                        }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "64",
+                            value: "{max_concurrent_requests}",
+                            oninput: move |evt| {
+                                if let Ok(value) = evt.value().parse::<usize>() {
+                                    max_concurrent_requests.set(value.clamp(1, 64));
+                                }
+                            },
+                            class: "mt-1 w-24 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Listing, loading and scanning share this limit; extra requests wait in a queue. Lower it for rate-limited or metered catalogs. Default: {DEFAULT_MAX_CONCURRENT_REQUESTS}."
+                        }
+                        div {
+                            class: "mt-3 flex items-center space-x-3",
+                            label {
+                                class: "flex items-center text-sm text-gray-700",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: collect_request_metrics(),
+                                    onchange: move |evt| collect_request_metrics.set(evt.checked()),
+                                    class: "mr-2"
+                                }
+                                "Record request metrics"
+                            }
+                            button {
+                                onclick: move |_| show_request_metrics.set(true),
+                                class: "px-3 py-1 text-sm font-medium text-gray-700 bg-white border border-gray-300 rounded-md hover:bg-gray-50",
+                                "📈 View metrics"
+                            }
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Counts and times each catalog's list, load and manifest scan requests, excluding time spent queued. Kept in memory only."
+                        }
+                        label {
+                            class: "mt-3 flex items-center text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked: idle_disconnect_minutes().is_some(),
+                                onchange: move |evt| {
+                                    idle_disconnect_minutes.set(
+                                        evt.checked().then_some(DEFAULT_IDLE_DISCONNECT_MINUTES),
+                                    );
+                                },
+                                class: "mr-2"
+                            }
+                            "Disconnect idle catalogs after"
+                            input {
+                                r#type: "number",
+                                min: "1",
+                                max: "10080",
+                                disabled: idle_disconnect_minutes().is_none(),
+                                value: idle_disconnect_minutes()
+                                    .unwrap_or(DEFAULT_IDLE_DISCONNECT_MINUTES)
+                                    .to_string(),
+                                oninput: move |evt| {
+                                    if let Ok(value) = evt.value().parse::<u64>() {
+                                        idle_disconnect_minutes.set(Some(value.clamp(1, 10080)));
+                                    }
+                                },
+                                class: "mx-2 w-20 px-2 py-1 border border-gray-300 rounded-md text-sm disabled:bg-gray-100"
+                            }
+                            "minutes"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Drops the connection and its credentials when a catalog has not been listed or loaded for this long. Saved settings are kept and the catalog reconnects the next time you use it."
+                        }
+                    }
+
+                    // Search
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Search"
+                        }
+                        label {
+                            class: "block text-sm text-gray-700",
+                            "Tables shown per batch in the command palette"
+                        }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "500",
+                            value: "{search_result_limit}",
+                            oninput: move |evt| {
+                                if let Ok(value) = evt.value().parse::<usize>() {
+                                    search_result_limit.set(value.clamp(1, 500));
+                                }
+                            },
+                            class: "mt-1 w-24 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Further matches are revealed in batches of this size with \"Show more\". Default: {DEFAULT_SEARCH_RESULT_LIMIT}."
+                        }
+                        label {
+                            class: "block mt-3 text-sm text-gray-700",
+                            "Ask before opening more than this many tables at once"
+                        }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "500",
+                            value: "{bulk_open_confirm_threshold}",
+                            oninput: move |evt| {
+                                if let Ok(value) = evt.value().parse::<usize>() {
+                                    bulk_open_confirm_threshold.set(value.clamp(1, 500));
+                                }
+                            },
+                            class: "mt-1 w-24 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Each table opens in its own tab and loads from its catalog. Default: {DEFAULT_BULK_OPEN_CONFIRM_THRESHOLD}."
+                        }
+                        label {
+                            class: "block mt-3 text-sm text-gray-700",
+                            "Show only the newest snapshots of tables with more than"
+                        }
+                        input {
+                            r#type: "number",
+                            min: "100",
+                            max: "1000000",
+                            value: "{large_table_snapshot_limit}",
+                            oninput: move |evt| {
+                                if let Ok(value) = evt.value().parse::<usize>() {
+                                    large_table_snapshot_limit.set(value.clamp(100, 1_000_000));
+                                }
+                            },
+                            class: "mt-1 w-32 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Such tables show a warning with a \"Load all snapshots\" option. Default: {DEFAULT_LARGE_TABLE_SNAPSHOT_LIMIT}."
+                        }
+                        label {
+                            class: "block mt-3 text-sm text-gray-700",
+                            "Warn when a table's metadata file is larger than (MB)"
+                        }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "10000",
+                            value: "{large_metadata_warning_mb}",
+                            oninput: move |evt| {
+                                if let Ok(value) = evt.value().parse::<u64>() {
+                                    large_metadata_warning_mb.set(value.clamp(1, 10_000));
+                                }
+                            },
+                            class: "mt-1 w-24 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Default: {DEFAULT_LARGE_METADATA_WARNING_MB} MB."
+                        }
+                        label {
+                            class: "block mt-3 text-sm text-gray-700",
+                            "Column classification property prefix"
+                        }
+                        input {
+                            r#type: "text",
+                            value: "{classification_property_prefix}",
+                            placeholder: DEFAULT_CLASSIFICATION_PROPERTY_PREFIX,
+                            oninput: move |evt| classification_property_prefix.set(evt.value()),
+                            class: "mt-1 w-64 px-3 py-2 border border-gray-300 rounded-md text-sm font-mono focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "A table property such as \"{DEFAULT_CLASSIFICATION_PROPERTY_PREFIX}customer.email\" = \"pii\" badges that column in the schema view. Leave empty to turn badges off."
+                        }
+                    }
+
+                    // Health categories
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Health Categories"
+                        }
+                        p {
+                            class: "text-xs text-gray-500 mb-2",
+                            "Disabled categories are left out of the health score and alerts. The score is rescaled across the categories that remain."
+                        }
+                        div {
+                            class: "grid grid-cols-2 gap-2",
+                            for category in HealthCategory::ALL {
+                                label {
+                                    class: "flex items-center text-sm text-gray-700",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: enabled_health_categories.read().contains(&category),
+                                        onchange: move |evt| {
+                                            enabled_health_categories.with_mut(|enabled| {
+                                                enabled.retain(|c| *c != category);
+                                                if evt.checked() {
+                                                    enabled.push(category);
+                                                }
+                                            });
+                                        },
+                                        class: "mr-2"
+                                    }
+                                    "{category.label()}"
+                                }
+                            }
+                        }
+                    }
+
+                    // Table sub-tabs
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            {t(Msg::TableTabs)}
+                        }
+                        p {
+                            class: "text-xs text-gray-500 mb-2",
+                            {t(Msg::TableTabsHelp)}
+                        }
+                        div {
+                            class: "space-y-1",
+                            {
+                                let tabs = table_view_tabs();
+                                let shown_count = tabs.iter().filter(|(_, shown)| *shown).count();
+                                let last = tabs.len() - 1;
+                                rsx! {
+                                    for (index, (tab, shown)) in tabs.into_iter().enumerate() {
+                                        div {
+                                            key: "{tab:?}",
+                                            class: "flex items-center text-sm text-gray-700",
+                                            label {
+                                                class: "flex items-center flex-1",
+                                                input {
+                                                    r#type: "checkbox",
+                                                    checked: shown,
+                                                    // At least one tab stays visible
+                                                    disabled: shown && shown_count == 1,
+                                                    onchange: move |evt| {
+                                                        table_view_tabs.with_mut(|tabs| tabs[index].1 = evt.checked());
+                                                    },
+                                                    class: "mr-2"
+                                                }
+                                                span {
+                                                    class: if shown { "" } else { "text-gray-400" },
+                                                    {t(tab.label())}
+                                                }
+                                            }
+                                            button {
+                                                onclick: move |_| table_view_tabs.with_mut(|tabs| tabs.swap(index.saturating_sub(1), index)),
+                                                disabled: index == 0,
+                                                class: "px-2 text-gray-500 hover:text-gray-800 disabled:text-gray-300",
+                                                title: t(Msg::MoveUp),
+                                                "↑"
+                                            }
+                                            button {
+                                                onclick: move |_| table_view_tabs.with_mut(|tabs| tabs.swap(index, (index + 1).min(last))),
+                                                disabled: index == last,
+                                                class: "px-2 text-gray-500 hover:text-gray-800 disabled:text-gray-300",
+                                                title: t(Msg::MoveDown),
+                                                "↓"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Table freshness
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Table Freshness"
+                        }
+                        div {
+                            class: "flex items-center space-x-6",
+                            label {
+                                class: "text-sm text-gray-700",
+                                "Aging after (hours)"
+                                input {
+                                    r#type: "number",
+                                    min: "0",
+                                    step: "any",
+                                    value: "{aging_hours}",
+                                    oninput: move |evt| {
+                                        if let Ok(value) = evt.value().parse::<f64>() {
+                                            aging_hours.set(value.max(0.0));
+                                        }
+                                    },
+                                    class: "ml-2 w-24 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                }
+                            }
+                            label {
+                                class: "text-sm text-gray-700",
+                                "Stale after (hours)"
+                                input {
+                                    r#type: "number",
+                                    min: "0",
+                                    step: "any",
+                                    value: "{stale_hours}",
+                                    oninput: move |evt| {
+                                        if let Ok(value) = evt.value().parse::<f64>() {
+                                            stale_hours.set(value.max(0.0));
+                                        }
+                                    },
+                                    class: "ml-2 w-24 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                }
+                            }
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Time since a table's newest snapshot before its freshness badge turns yellow, then red. Individual tables can override these from their badge."
+                        }
+                    }
+
+                    // Appearance
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Accent Color"
+                        }
+                        div {
+                            class: "flex items-center space-x-2",
+                            for (name, color) in ACCENT_PRESETS {
+                                button {
+                                    onclick: move |_| accent_color.set(color.to_string()),
+                                    class: format!("w-6 h-6 rounded-full border-2 {}",
+                                        if accent_color().eq_ignore_ascii_case(color) { "border-gray-900" } else { "border-white" }
+                                    ),
+                                    style: "background-color: {color};",
+                                    title: "{name}",
+                                }
+                            }
+                            input {
+                                r#type: "color",
+                                value: "{accent_color}",
+                                oninput: move |evt| accent_color.set(evt.value()),
+                                class: "w-8 h-6 border border-gray-300 rounded cursor-pointer",
+                                title: "Custom color"
+                            }
+                            button {
+                                onclick: move |_| accent_color.set(DEFAULT_ACCENT.to_string()),
+                                class: "px-2 py-1 text-xs text-gray-600 hover:text-gray-800",
+                                "Reset"
+                            }
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Used for buttons, highlights and the active tab. Lighter and darker shades are derived from it."
+                        }
+                    }
+
+                    // Language
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Language"
+                        }
+                        select {
+                            class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                            onchange: move |evt| {
+                                if let Some(value) = Locale::parse(&evt.value()) {
+                                    locale.set(value);
+                                }
+                            },
+                            for option_locale in Locale::ALL {
+                                option {
+                                    value: option_locale.code(),
+                                    selected: locale() == option_locale,
+                                    "{option_locale.label()}"
+                                }
+                            }
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Headers, buttons and common messages are translated; anything not yet translated stays in English. A new language applies after restarting Hielo."
+                        }
+                    }
+
+                    // Diagnostics
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Diagnostics"
+                        }
+                        div {
+                            class: "flex items-center space-x-3",
+                            label {
+                                class: "text-sm text-gray-700",
+                                "Log level"
+                                select {
+                                    class: "ml-2 px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                                    onchange: move |evt| {
+                                        if let Some(level) = LogLevel::parse(&evt.value()) {
+                                            log_level.set(level);
+                                        }
+                                    },
+                                    for level in LogLevel::ALL {
+                                        option {
+                                            value: level.as_str(),
+                                            selected: log_level() == level,
+                                            "{level.as_str()}"
+                                        }
+                                    }
+                                }
+                            }
+                            button {
+                                onclick: move |_| show_log_viewer.set(true),
+                                class: "px-3 py-1 text-sm font-medium text-gray-700 bg-white border border-gray-300 rounded-md hover:bg-gray-50",
+                                "📜 View logs"
+                            }
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            {
+                                let location = logging::log_dir()
+                                    .map(|dir| dir.display().to_string())
+                                    .unwrap_or_else(|_| "~/.hielo/logs".to_string());
+                                format!("Logs are written as JSON to {}, one file per day, keeping the last week. Attach them to bug reports. A new level applies after restarting Hielo.", location)
+                            }
+                        }
+                        label {
+                            class: "mt-3 flex items-center text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked: log_health_metrics(),
+                                onchange: move |evt| log_health_metrics.set(evt.checked()),
+                                class: "mr-2"
+                            }
+                            "Log health metrics"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "Writes each table's health score, file, activity and storage figures to the log when its snapshots tab computes them."
+                        }
+                    }
+
+                    // Updates
+                    div {
+                        h4 {
+                            class: "text-md font-medium text-gray-900 mb-1",
+                            "Updates"
+                        }
+                        label {
+                            class: "flex items-center text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked: check_for_updates(),
+                                onchange: move |evt| check_for_updates.set(evt.checked()),
+                                class: "mr-2"
+                            }
+                            "Check for new releases on startup"
+                        }
+                        p {
+                            class: "mt-1 text-xs text-gray-500",
+                            "At most once a day, Hielo asks GitHub for the latest release version. No usage data is sent."
+                        }
+                    }
+
+                    if let Some(error) = save_error() {
+                        div {
+                            class: "p-3 bg-red-50 border border-red-200 rounded-md text-sm text-red-700",
+                            "{error}"
+                        }
+                    }
+                }
+
+                // Footer
+                div {
+                    class: "flex justify-end space-x-3 p-4 border-t border-gray-200",
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "px-4 py-2 text-sm font-medium text-gray-700 bg-white border border-gray-300 rounded-md hover:bg-gray-50",
+                        {t(Msg::Cancel)}
+                    }
+                    button {
+                        onclick: save,
+                        class: "px-4 py-2 text-sm font-medium text-white bg-blue-600 rounded-md hover:bg-blue-700",
+                        {t(Msg::Save)}
+                    }
+                }
+            }
+        }
+
+        if show_log_viewer() {
+            LogViewerModal {
+                on_close: move |_| show_log_viewer.set(false)
+            }
+        }
+
+        if show_request_metrics() {
+            RequestMetricsModal {
+                catalog_manager: catalog_manager,
+                on_close: move |_| show_request_metrics.set(false)
+            }
+        }
+    }
+}
+
+/// Entries loaded into the log viewer at a time
+const LOG_VIEWER_LIMIT: usize = 500;
+
+fn log_level_class(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Error => "text-red-600",
+        LogLevel::Warn => "text-yellow-600",
+        LogLevel::Info => "text-blue-600",
+        LogLevel::Debug | LogLevel::Trace => "text-gray-500",
+    }
+}
+
+/// Tail of the current log file, with level and text filters
+#[component]
+pub fn LogViewerModal(on_close: EventHandler<()>) -> Element {
+    let mut entries = use_signal(|| logging::read_recent_entries(LOG_VIEWER_LIMIT));
+    // Entries at or above this severity are shown
+    let mut min_level = use_signal(|| LogLevel::Trace);
+    let mut search = use_signal(String::new);
+
+    let visible: Vec<LogEntry> = match &*entries.read() {
+        Ok(entries) => {
+            let needle = search().to_lowercase();
+            entries
+                .iter()
+                .rev()
+                .filter(|entry| entry.level <= min_level())
+                .filter(|entry| {
+                    needle.is_empty()
+                        || entry.message.to_lowercase().contains(&needle)
+                        || entry.target.to_lowercase().contains(&needle)
+                })
+                .cloned()
+                .collect()
+        }
+        Err(_) => Vec::new(),
+    };
+    let load_error = entries.read().as_ref().err().map(|e| e.to_string());
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-10",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-5xl w-full mx-4 flex flex-col",
+                style: "max-height: 85vh;",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    h3 {
+                        class: "text-lg font-medium text-gray-900",
+                        "📜 Logs"
+                    }
+                    div {
+                        class: "flex items-center space-x-3",
+                        select {
+                            class: "px-2 py-1 text-sm border border-gray-300 rounded-md bg-white",
+                            onchange: move |evt| {
+                                if let Some(level) = LogLevel::parse(&evt.value()) {
+                                    min_level.set(level);
+                                }
+                            },
+                            for level in LogLevel::ALL {
+                                option {
+                                    value: level.as_str(),
+                                    selected: min_level() == level,
+                                    "{level.as_str()} and above"
+                                }
+                            }
+                        }
+                        input {
+                            r#type: "text",
+                            placeholder: "Filter messages...",
+                            value: "{search}",
+                            oninput: move |evt| search.set(evt.value()),
+                            class: "px-3 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        }
+                        button {
+                            onclick: move |_| entries.set(logging::read_recent_entries(LOG_VIEWER_LIMIT)),
+                            class: "px-3 py-1 text-sm font-medium text-gray-700 bg-white border border-gray-300 rounded-md hover:bg-gray-50",
+                            "🔄 Refresh"
+                        }
+                        button {
+                            onclick: move |_| on_close.call(()),
+                            class: "text-gray-400 hover:text-gray-600",
+                            "✕"
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-auto p-4",
+                    if let Some(error) = load_error {
+                        div {
+                            class: "p-3 bg-red-50 border border-red-200 rounded-md text-sm text-red-700",
+                            "Could not read the log file: {error}"
+                        }
+                    } else if visible.is_empty() {
+                        p {
+                            class: "text-sm text-gray-500 text-center py-8",
+                            "No log entries match."
+                        }
+                    } else {
+                        table {
+                            class: "min-w-full text-xs font-mono",
+                            tbody {
+                                for entry in visible {
+                                    tr {
+                                        class: "border-b border-gray-100 align-top",
+                                        td {
+                                            class: "pr-3 py-1 text-gray-400 whitespace-nowrap",
+                                            "{entry.timestamp}"
+                                        }
+                                        td {
+                                            class: format!("pr-3 py-1 font-semibold {}", log_level_class(entry.level)),
+                                            "{entry.level.as_str()}"
+                                        }
+                                        td {
+                                            class: "pr-3 py-1 text-gray-500 whitespace-nowrap",
+                                            "{entry.target}"
+                                        }
+                                        td {
+                                            class: "py-1 text-gray-900 break-all",
+                                            "{entry.message}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "px-4 py-2 border-t border-gray-200 text-xs text-gray-500",
+                    "Newest first, showing up to {LOG_VIEWER_LIMIT} entries from today's log file."
+                }
+            }
+        }
+    }
+}
+
+/// How often the request metrics panel re-reads the recorded samples
+const REQUEST_METRICS_REFRESH_MS: u64 = 1000;
+
+/// Per-catalog request counts, errors and latency percentiles, refreshed while open
+#[component]
+pub fn RequestMetricsModal(
+    catalog_manager: Signal<CatalogManager>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut rows = use_signal(|| catalog_manager.read().request_metrics());
+    let enabled = catalog_manager.read().settings().collect_request_metrics;
+
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_millis(
+                REQUEST_METRICS_REFRESH_MS,
+            ))
+            .await;
+            let latest = match catalog_manager.try_read() {
+                Ok(manager) => manager.request_metrics(),
+                Err(_) => continue,
+            };
+            if latest != *rows.peek() {
+                rows.set(latest);
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-gray-600 bg-opacity-50 overflow-y-auto h-full w-full z-50 flex items-start justify-center pt-10",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "bg-white rounded-lg shadow-xl max-w-3xl w-full mx-4 flex flex-col",
+                style: "max-height: 85vh;",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between p-4 border-b border-gray-200",
+                    h3 {
+                        class: "text-lg font-medium text-gray-900",
+                        "📈 Request Metrics"
+                    }
+                    div {
+                        class: "flex items-center space-x-3",
+                        button {
+                            onclick: move |_| {
+                                catalog_manager.read().reset_request_metrics();
+                                rows.set(Vec::new());
+                            },
+                            class: "px-3 py-1 text-sm font-medium text-gray-700 bg-white border border-gray-300 rounded-md hover:bg-gray-50",
+                            "Reset"
+                        }
+                        button {
+                            onclick: move |_| on_close.call(()),
+                            class: "text-gray-400 hover:text-gray-600",
+                            "✕"
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-auto p-4",
+                    if !enabled {
+                        div {
+                            class: "mb-3 p-3 bg-yellow-50 border border-yellow-200 rounded-md text-sm text-yellow-800",
+                            "Recording is off. Enable \"Record request metrics\" and save the settings to start collecting."
+                        }
+                    }
+                    if rows().is_empty() {
+                        p {
+                            class: "text-sm text-gray-500 text-center py-8",
+                            "No requests recorded yet."
+                        }
+                    } else {
+                        table {
+                            class: "min-w-full divide-y divide-gray-200 text-sm",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Catalog" }
+                                    th { class: "px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", "Request" }
+                                    th { class: "px-3 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Count" }
+                                    th { class: "px-3 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "Errors" }
+                                    th { class: "px-3 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "p50" }
+                                    th { class: "px-3 py-2 text-right text-xs font-medium text-gray-500 uppercase tracking-wider", "p95" }
+                                }
+                            }
+                            tbody {
+                                class: "divide-y divide-gray-100",
+                                for row in rows() {
+                                    RequestMetricsRow { row: row }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "px-4 py-2 border-t border-gray-200 text-xs text-gray-500",
+                    "Percentiles cover each request type's most recent samples. Scans are manifest reads for partition and schema statistics."
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn RequestMetricsRow(row: OperationMetrics) -> Element {
+    rsx! {
+        tr {
+            td { class: "px-3 py-2 text-gray-900", "{row.catalog_name}" }
+            td { class: "px-3 py-2 font-mono text-xs text-gray-700", "{row.operation.label()}" }
+            td { class: "px-3 py-2 text-right text-gray-900", "{row.requests}" }
+            td {
+                class: if row.errors > 0 { "px-3 py-2 text-right text-red-600" } else { "px-3 py-2 text-right text-gray-500" },
+                "{row.errors}"
+            }
+            td { class: "px-3 py-2 text-right text-gray-900", "{row.p50_ms} ms" }
+            td { class: "px-3 py-2 text-right text-gray-900", "{row.p95_ms} ms" }
+        }
+    }
+}
@@ -0,0 +1,479 @@
+//! Translatable UI strings. Components look messages up by key with [`t`] instead of using
+//! English literals, so a translation only has to fill in [`spanish`]-style tables here.
+//! Anything a translation leaves out falls back to English.
+
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::Spanish];
+
+    /// BCP 47 language code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Spanish => "es",
+        }
+    }
+
+    /// Name of the language in that language, as shown in the language picker
+    pub fn label(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Spanish => "Español",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(value.trim()))
+    }
+}
+
+/// Keys for every translated UI string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    // Buttons
+    Cancel,
+    Connect,
+    Delete,
+    Refresh,
+    Retry,
+    Save,
+    Compare,
+    DownloadReport,
+    MoveDown,
+    MoveUp,
+    // Headers and tabs
+    Catalogs,
+    ConnectToCatalog,
+    Settings,
+    SnapshotHistory,
+    SnapshotTimeline,
+    TabOverview,
+    TabPartitions,
+    TabSchema,
+    TabSnapshots,
+    TabViewDefinition,
+    TableOverview,
+    TableProperties,
+    WelcomeTitle,
+    CompareCatalogs,
+    ColumnDifference,
+    ColumnTable,
+    TableTabs,
+    // Loading and empty states
+    Loading,
+    LoadingNamespaces,
+    LoadingSnapshots,
+    LoadingTables,
+    NoNamespacesInCatalog,
+    NoSnapshotsFound,
+    NoSnapshotsInTable,
+    NoTablesFound,
+    NoTablesInNamespace,
+    CatalogsMatch,
+    ConnectAnotherCatalog,
+    PickCatalogToCompare,
+    // Labels and help text
+    AllNamespaces,
+    CompareAgainst,
+    CompareIn,
+    DownloadComparisonHelp,
+    TableTabsHelp,
+    // Messages with `{}` placeholders, filled in by [`tf`]
+    CatalogBusy,
+    ComparisonCancelled,
+    ComparisonProgress,
+    ComparisonSummary,
+    DdlCopied,
+    MissingFrom,
+    SameTableOpen,
+    SavedFile,
+    SchemaCopiedAs,
+    SqlCopied,
+    TablesNotOpened,
+    WarehouseSwitched,
+    // Notifications
+    DbtSourceCopied,
+    FilePathCopied,
+    LocationCopied,
+    ManifestListPathCopied,
+    ReportCopied,
+    SchemaCopiedAsMarkdown,
+    SnapshotSummaryCopied,
+    ValueCopied,
+    // Error prefixes, followed by ": <details>" where there are any
+    Error,
+    FailedToListNamespaces,
+    FailedToListTables,
+    FailedToLoad,
+    ManifestReadFailed,
+    FailedToBuildReport,
+    FailedToDeleteCatalog,
+    FailedToExportReport,
+    FailedToExportSchema,
+    FailedToExportTimelineImage,
+    FailedToListNamespacesOf,
+    FailedToSaveComparison,
+    FailedToSaveSchema,
+    NotCompared,
+}
+
+impl Msg {
+    pub const ALL: [Msg; 78] = [
+        Msg::Cancel,
+        Msg::Connect,
+        Msg::Delete,
+        Msg::Refresh,
+        Msg::Retry,
+        Msg::Save,
+        Msg::Compare,
+        Msg::DownloadReport,
+        Msg::MoveDown,
+        Msg::MoveUp,
+        Msg::Catalogs,
+        Msg::ConnectToCatalog,
+        Msg::Settings,
+        Msg::SnapshotHistory,
+        Msg::SnapshotTimeline,
+        Msg::TabOverview,
+        Msg::TabPartitions,
+        Msg::TabSchema,
+        Msg::TabSnapshots,
+        Msg::TabViewDefinition,
+        Msg::TableOverview,
+        Msg::TableProperties,
+        Msg::WelcomeTitle,
+        Msg::CompareCatalogs,
+        Msg::ColumnDifference,
+        Msg::ColumnTable,
+        Msg::TableTabs,
+        Msg::Loading,
+        Msg::LoadingNamespaces,
+        Msg::LoadingSnapshots,
+        Msg::LoadingTables,
+        Msg::NoNamespacesInCatalog,
+        Msg::NoSnapshotsFound,
+        Msg::NoSnapshotsInTable,
+        Msg::NoTablesFound,
+        Msg::NoTablesInNamespace,
+        Msg::CatalogsMatch,
+        Msg::ConnectAnotherCatalog,
+        Msg::PickCatalogToCompare,
+        Msg::AllNamespaces,
+        Msg::CompareAgainst,
+        Msg::CompareIn,
+        Msg::DownloadComparisonHelp,
+        Msg::TableTabsHelp,
+        Msg::CatalogBusy,
+        Msg::ComparisonCancelled,
+        Msg::ComparisonProgress,
+        Msg::ComparisonSummary,
+        Msg::DdlCopied,
+        Msg::MissingFrom,
+        Msg::SameTableOpen,
+        Msg::SavedFile,
+        Msg::SchemaCopiedAs,
+        Msg::SqlCopied,
+        Msg::TablesNotOpened,
+        Msg::WarehouseSwitched,
+        Msg::DbtSourceCopied,
+        Msg::FilePathCopied,
+        Msg::LocationCopied,
+        Msg::ManifestListPathCopied,
+        Msg::ReportCopied,
+        Msg::SchemaCopiedAsMarkdown,
+        Msg::SnapshotSummaryCopied,
+        Msg::ValueCopied,
+        Msg::Error,
+        Msg::FailedToListNamespaces,
+        Msg::FailedToListTables,
+        Msg::FailedToLoad,
+        Msg::ManifestReadFailed,
+        Msg::FailedToBuildReport,
+        Msg::FailedToDeleteCatalog,
+        Msg::FailedToExportReport,
+        Msg::FailedToExportSchema,
+        Msg::FailedToExportTimelineImage,
+        Msg::FailedToListNamespacesOf,
+        Msg::FailedToSaveComparison,
+        Msg::FailedToSaveSchema,
+        Msg::NotCompared,
+    ];
+}
+
+fn english(msg: Msg) -> &'static str {
+    match msg {
+        Msg::Cancel => "Cancel",
+        Msg::Connect => "Connect",
+        Msg::Delete => "Delete",
+        Msg::Refresh => "Refresh",
+        Msg::Retry => "Retry",
+        Msg::Save => "Save",
+        Msg::Compare => "Compare",
+        Msg::DownloadReport => "Download report",
+        Msg::MoveDown => "Move down",
+        Msg::MoveUp => "Move up",
+        Msg::Catalogs => "Catalogs",
+        Msg::ConnectToCatalog => "Connect to Catalog",
+        Msg::Settings => "Settings",
+        Msg::SnapshotHistory => "Snapshot History",
+        Msg::SnapshotTimeline => "Snapshot Timeline",
+        Msg::TabOverview => "Overview",
+        Msg::TabPartitions => "Partitions",
+        Msg::TabSchema => "Schema",
+        Msg::TabSnapshots => "Snapshots",
+        Msg::TabViewDefinition => "View Definition",
+        Msg::TableOverview => "Table Overview",
+        Msg::TableProperties => "Table Properties",
+        Msg::WelcomeTitle => "Welcome to Hielo!",
+        Msg::CompareCatalogs => "Compare Catalogs",
+        Msg::ColumnDifference => "Difference",
+        Msg::ColumnTable => "Table",
+        Msg::TableTabs => "Table Tabs",
+        Msg::Loading => "Loading...",
+        Msg::LoadingNamespaces => "Loading namespaces...",
+        Msg::LoadingSnapshots => "Loading snapshots...",
+        Msg::LoadingTables => "Loading tables...",
+        Msg::NoNamespacesInCatalog => "No namespaces found in this catalog",
+        Msg::NoSnapshotsFound => "No snapshots found",
+        Msg::NoSnapshotsInTable => "This table has no snapshots to display.",
+        Msg::NoTablesFound => "No tables found",
+        Msg::NoTablesInNamespace => "No tables found in this namespace",
+        Msg::CatalogsMatch => "Both catalogs have the same tables, schemas and partitioning",
+        Msg::ConnectAnotherCatalog => "Connect another catalog to compare against",
+        Msg::PickCatalogToCompare => "Pick a catalog to compare against",
+        Msg::AllNamespaces => "All namespaces",
+        Msg::CompareAgainst => "Against",
+        Msg::CompareIn => "in",
+        Msg::DownloadComparisonHelp => {
+            "Save the comparison as a Markdown report in your downloads folder"
+        }
+        Msg::TableTabsHelp => {
+            "Order and show the sub-tabs of an open table. Tables open on the first shown tab."
+        }
+        Msg::CatalogBusy => "{} is busy; try disconnecting again",
+        Msg::ComparisonCancelled => "Cancelled after comparing {} of {} shared tables with {}",
+        Msg::ComparisonProgress => "Compared {} of {} shared tables with {}...",
+        Msg::ComparisonSummary => "{} tables match {}; {} need attention",
+        Msg::DdlCopied => "{} DDL copied to clipboard",
+        Msg::MissingFrom => "Missing from {}",
+        Msg::SameTableOpen => "'{}' is the same table as an open tab in {}",
+        Msg::SavedFile => "Saved {}",
+        Msg::SchemaCopiedAs => "Schema copied as {}",
+        Msg::SqlCopied => "{} SQL copied to clipboard",
+        Msg::TablesNotOpened => "{} of the listed tables could not be opened: {}",
+        Msg::WarehouseSwitched => "'{}' now uses warehouse '{}'",
+        Msg::DbtSourceCopied => "dbt source copied to clipboard",
+        Msg::FilePathCopied => "File path copied to clipboard",
+        Msg::LocationCopied => "Location copied to clipboard",
+        Msg::ManifestListPathCopied => "Manifest list path copied to clipboard",
+        Msg::ReportCopied => "Report copied to clipboard",
+        Msg::SchemaCopiedAsMarkdown => "Schema copied to clipboard as Markdown",
+        Msg::SnapshotSummaryCopied => "Snapshot summary copied to clipboard",
+        Msg::ValueCopied => "Value copied to clipboard",
+        Msg::Error => "Error",
+        Msg::FailedToListNamespaces => "Failed to list namespaces",
+        Msg::FailedToListTables => "Failed to list tables",
+        Msg::FailedToLoad => "Failed to load",
+        Msg::ManifestReadFailed => "Couldn't read this table's manifests",
+        Msg::FailedToBuildReport => "Failed to build table report",
+        Msg::FailedToDeleteCatalog => "Failed to delete catalog",
+        Msg::FailedToExportReport => "Failed to export table report",
+        Msg::FailedToExportSchema => "Failed to export schema",
+        Msg::FailedToExportTimelineImage => "Failed to export timeline image",
+        Msg::FailedToListNamespacesOf => "Failed to list namespaces of {}",
+        Msg::FailedToSaveComparison => "Failed to save comparison report",
+        Msg::FailedToSaveSchema => "Failed to save schema",
+        Msg::NotCompared => "Not compared",
+    }
+}
+
+fn spanish(msg: Msg) -> Option<&'static str> {
+    Some(match msg {
+        Msg::Cancel => "Cancelar",
+        Msg::Connect => "Conectar",
+        Msg::Delete => "Eliminar",
+        Msg::Refresh => "Actualizar",
+        Msg::Retry => "Reintentar",
+        Msg::Save => "Guardar",
+        Msg::Compare => "Comparar",
+        Msg::DownloadReport => "Descargar informe",
+        Msg::MoveDown => "Bajar",
+        Msg::MoveUp => "Subir",
+        Msg::Catalogs => "Catálogos",
+        Msg::ConnectToCatalog => "Conectar a un catálogo",
+        Msg::Settings => "Ajustes",
+        Msg::SnapshotHistory => "Historial de snapshots",
+        Msg::SnapshotTimeline => "Línea de tiempo de snapshots",
+        Msg::TabOverview => "Resumen",
+        Msg::TabPartitions => "Particiones",
+        Msg::TabSchema => "Esquema",
+        Msg::TabSnapshots => "Snapshots",
+        Msg::TabViewDefinition => "Definición de la vista",
+        Msg::TableOverview => "Resumen de la tabla",
+        Msg::TableProperties => "Propiedades de la tabla",
+        Msg::WelcomeTitle => "¡Bienvenido a Hielo!",
+        Msg::CompareCatalogs => "Comparar catálogos",
+        Msg::ColumnDifference => "Diferencia",
+        Msg::ColumnTable => "Tabla",
+        Msg::TableTabs => "Pestañas de la tabla",
+        Msg::Loading => "Cargando...",
+        Msg::LoadingNamespaces => "Cargando namespaces...",
+        Msg::LoadingSnapshots => "Cargando snapshots...",
+        Msg::LoadingTables => "Cargando tablas...",
+        Msg::NoNamespacesInCatalog => "No hay namespaces en este catálogo",
+        Msg::NoSnapshotsFound => "No se encontraron snapshots",
+        Msg::NoSnapshotsInTable => "Esta tabla no tiene snapshots.",
+        Msg::NoTablesFound => "No se encontraron tablas",
+        Msg::NoTablesInNamespace => "No hay tablas en este namespace",
+        Msg::CatalogsMatch => "Ambos catálogos tienen las mismas tablas, esquemas y particionado",
+        Msg::ConnectAnotherCatalog => "Conecta otro catálogo para comparar",
+        Msg::PickCatalogToCompare => "Elige un catálogo con el que comparar",
+        Msg::AllNamespaces => "Todos los namespaces",
+        Msg::CompareAgainst => "Con",
+        Msg::CompareIn => "en",
+        Msg::DownloadComparisonHelp => {
+            "Guarda la comparación como un informe Markdown en tu carpeta de descargas"
+        }
+        Msg::TableTabsHelp => {
+            "Ordena y muestra las subpestañas de una tabla abierta. Las tablas se abren en la primera pestaña visible."
+        }
+        Msg::CatalogBusy => "{} está ocupado; intenta desconectarlo de nuevo",
+        Msg::ComparisonCancelled => "Cancelado tras comparar {} de {} tablas compartidas con {}",
+        Msg::ComparisonProgress => "Comparadas {} de {} tablas compartidas con {}...",
+        Msg::ComparisonSummary => "{} tablas coinciden con {}; {} requieren atención",
+        Msg::DdlCopied => "DDL de {} copiado al portapapeles",
+        Msg::MissingFrom => "Falta en {}",
+        Msg::SameTableOpen => "'{}' es la misma tabla que una pestaña abierta en {}",
+        Msg::SavedFile => "Guardado en {}",
+        Msg::SchemaCopiedAs => "Esquema copiado como {}",
+        Msg::SqlCopied => "SQL de {} copiado al portapapeles",
+        Msg::TablesNotOpened => "No se pudieron abrir {} de las tablas listadas: {}",
+        Msg::WarehouseSwitched => "'{}' ahora usa el warehouse '{}'",
+        Msg::DbtSourceCopied => "Fuente de dbt copiada al portapapeles",
+        Msg::FilePathCopied => "Ruta del archivo copiada al portapapeles",
+        Msg::LocationCopied => "Ubicación copiada al portapapeles",
+        Msg::ManifestListPathCopied => "Ruta de la lista de manifests copiada al portapapeles",
+        Msg::ReportCopied => "Informe copiado al portapapeles",
+        Msg::SchemaCopiedAsMarkdown => "Esquema copiado al portapapeles como Markdown",
+        Msg::SnapshotSummaryCopied => "Resumen del snapshot copiado al portapapeles",
+        Msg::ValueCopied => "Valor copiado al portapapeles",
+        Msg::Error => "Error",
+        Msg::FailedToListNamespaces => "No se pudieron listar los namespaces",
+        Msg::FailedToListTables => "No se pudieron listar las tablas",
+        Msg::FailedToLoad => "No se pudo cargar",
+        Msg::ManifestReadFailed => "No se pudieron leer los manifests de esta tabla",
+        Msg::FailedToBuildReport => "No se pudo generar el informe de la tabla",
+        Msg::FailedToDeleteCatalog => "No se pudo eliminar el catálogo",
+        Msg::FailedToExportReport => "No se pudo exportar el informe de la tabla",
+        Msg::FailedToExportSchema => "No se pudo exportar el esquema",
+        Msg::FailedToExportTimelineImage => "No se pudo exportar la imagen de la línea de tiempo",
+        Msg::FailedToListNamespacesOf => "No se pudieron listar los namespaces de {}",
+        Msg::FailedToSaveComparison => "No se pudo guardar el informe de la comparación",
+        Msg::FailedToSaveSchema => "No se pudo guardar el esquema",
+        Msg::NotCompared => "No comparada",
+    })
+}
+
+/// `msg` in `locale`, or in English when the locale has no translation for it
+pub fn text(locale: Locale, msg: Msg) -> &'static str {
+    let translated = match locale {
+        Locale::English => None,
+        Locale::Spanish => spanish(msg),
+    };
+    translated.unwrap_or_else(|| english(msg))
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Choose the UI language. Must be called once, before the app is launched; a new locale
+/// applies on the next start.
+pub fn init(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+/// `msg` in the UI language chosen at startup
+pub fn t(msg: Msg) -> &'static str {
+    text(LOCALE.get().copied().unwrap_or_default(), msg)
+}
+
+/// [`t`] with each `{}` in the message replaced by the next of `args`, in order, so a
+/// translation can put the names and counts wherever its grammar wants them
+pub fn tf(msg: Msg, args: &[&dyn std::fmt::Display]) -> String {
+    fill(t(msg), args)
+}
+
+fn fill(template: &str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut pieces = template.split("{}");
+    let mut filled = pieces.next().unwrap_or_default().to_string();
+    let mut args = args.iter();
+    for piece in pieces {
+        if let Some(arg) = args.next() {
+            filled.push_str(&arg.to_string());
+        }
+        filled.push_str(piece);
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_message_has_text_in_every_locale() {
+        for locale in Locale::ALL {
+            for msg in Msg::ALL {
+                assert!(!text(locale, msg).is_empty(), "{:?} {:?}", locale, msg);
+            }
+        }
+        assert_eq!(text(Locale::English, Msg::Save), "Save");
+        assert_eq!(text(Locale::Spanish, Msg::Save), "Guardar");
+    }
+
+    #[test]
+    fn test_placeholders_match_across_locales() {
+        for msg in Msg::ALL {
+            let placeholders = text(Locale::English, msg).matches("{}").count();
+            for locale in Locale::ALL {
+                assert_eq!(
+                    text(locale, msg).matches("{}").count(),
+                    placeholders,
+                    "{:?} {:?}",
+                    locale,
+                    msg
+                );
+            }
+        }
+        assert_eq!(
+            fill(text(Locale::Spanish, Msg::DdlCopied), &[&"Spark"]),
+            "DDL de Spark copiado al portapapeles"
+        );
+        assert_eq!(
+            fill(
+                text(Locale::English, Msg::ComparisonSummary),
+                &[&3, &"prod", &1]
+            ),
+            "3 tables match prod; 1 need attention"
+        );
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        for locale in Locale::ALL {
+            assert_eq!(Locale::parse(locale.code()), Some(locale));
+        }
+        assert_eq!(Locale::parse(" ES "), Some(Locale::Spanish));
+        assert_eq!(Locale::parse("fr"), None);
+    }
+}
@@ -0,0 +1,121 @@
+//! Accent color theming. The Tailwind `blue-*` utilities used for accents across the UI are
+//! re-pointed at CSS variables, so a single configurable base color drives every shade.
+
+/// Accent presets offered in settings, as (name, base color). The base is the 600 shade.
+pub const ACCENT_PRESETS: [(&str, &str); 7] = [
+    ("Blue", "#2563eb"),
+    ("Indigo", "#4f46e5"),
+    ("Violet", "#7c3aed"),
+    ("Teal", "#0d9488"),
+    ("Green", "#16a34a"),
+    ("Orange", "#ea580c"),
+    ("Rose", "#e11d48"),
+];
+
+/// Tailwind's own `blue-600`, so the default theme looks as it always has
+pub const DEFAULT_ACCENT: &str = "#2563eb";
+
+/// Tailwind shade and how far it is mixed from the base: towards white when positive,
+/// towards black when negative
+const SHADES: [(u16, f64); 10] = [
+    (50, 0.95),
+    (100, 0.9),
+    (200, 0.75),
+    (300, 0.6),
+    (400, 0.35),
+    (500, 0.15),
+    (600, 0.0),
+    (700, -0.15),
+    (800, -0.3),
+    (900, -0.45),
+];
+
+/// Parse a `#rrggbb` color
+pub fn parse_hex(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.trim().strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Mix `base` towards white (`amount > 0`) or black (`amount < 0`)
+fn mix((r, g, b): (u8, u8, u8), amount: f64) -> (u8, u8, u8) {
+    let (target, weight) = if amount >= 0.0 {
+        (255.0, amount)
+    } else {
+        (0.0, -amount)
+    };
+    let channel = |value: u8| (value as f64 + (target - value as f64) * weight).round() as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+/// Every accent shade derived from `base`, keyed by Tailwind shade number
+pub fn accent_shades(base: (u8, u8, u8)) -> Vec<(u16, (u8, u8, u8))> {
+    SHADES
+        .iter()
+        .map(|&(shade, amount)| (shade, mix(base, amount)))
+        .collect()
+}
+
+/// Stylesheet defining the accent variables and routing the `blue-*` utilities through them.
+/// Must be placed after Tailwind so it wins over the stock rules. Invalid colors fall back
+/// to the default accent.
+pub fn accent_stylesheet(accent: &str) -> String {
+    let base = parse_hex(accent)
+        .or_else(|| parse_hex(DEFAULT_ACCENT))
+        .unwrap_or((37, 99, 235));
+    let shades = accent_shades(base);
+
+    let variables = shades
+        .iter()
+        .map(|(shade, (r, g, b))| format!("--accent-{}: {}, {}, {};", shade, r, g, b))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut css = format!(":root {{ {} }}\n", variables);
+
+    for (shade, _) in &shades {
+        css.push_str(&format!(
+            ".bg-blue-{s}, .hover\\:bg-blue-{s}:hover {{ background-color: rgba(var(--accent-{s}), var(--tw-bg-opacity, 1)); }}\n\
+             .text-blue-{s}, .hover\\:text-blue-{s}:hover, .group:hover .group-hover\\:text-blue-{s} {{ color: rgba(var(--accent-{s}), var(--tw-text-opacity, 1)); }}\n\
+             .border-blue-{s}, .hover\\:border-blue-{s}:hover, .focus\\:border-blue-{s}:focus {{ border-color: rgba(var(--accent-{s}), var(--tw-border-opacity, 1)); }}\n\
+             .focus\\:ring-blue-{s}:focus {{ --tw-ring-color: rgba(var(--accent-{s}), var(--tw-ring-opacity, 1)); }}\n",
+            s = shade
+        ));
+    }
+    css
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("#2563eb"), Some((37, 99, 235)));
+        assert_eq!(parse_hex(" #FFFFFF "), Some((255, 255, 255)));
+        assert_eq!(parse_hex("2563eb"), None);
+        assert_eq!(parse_hex("#25g3eb"), None);
+        assert_eq!(parse_hex("#fff"), None);
+    }
+
+    #[test]
+    fn test_accent_shades_surround_the_base() {
+        let base = (37, 99, 235);
+        let shades = accent_shades(base);
+        let shade = |n: u16| shades.iter().find(|(s, _)| *s == n).unwrap().1;
+
+        assert_eq!(shade(600), base);
+        // Lighter shades approach white, darker ones black
+        assert!(shade(50).0 > 240 && shade(50).1 > 240);
+        assert!(shade(700).2 < base.2 && shade(900).2 < shade(700).2);
+    }
+
+    #[test]
+    fn test_accent_stylesheet_falls_back_to_default() {
+        let css = accent_stylesheet("not a color");
+        assert!(css.contains("--accent-600: 37, 99, 235;"));
+        assert!(css.contains(".hover\\:bg-blue-700:hover"));
+    }
+}
@@ -0,0 +1,178 @@
+//! Stacked toast notifications. The app root provides a [`Toasts`] context; anything below it
+//! reports errors and confirmations with [`notify`], and [`ToastStack`] renders them.
+
+use dioxus::prelude::*;
+
+/// Toasts shown at once; older ones are dropped first
+const MAX_TOASTS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    /// How long the toast stays up before dismissing itself; errors linger so they can be read
+    pub fn duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(match self {
+            ToastKind::Info | ToastKind::Success => 4,
+            ToastKind::Warning => 6,
+            ToastKind::Error => 10,
+        })
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            ToastKind::Info => "ℹ️",
+            ToastKind::Success => "✅",
+            ToastKind::Warning => "⚠️",
+            ToastKind::Error => "❌",
+        }
+    }
+
+    fn classes(&self) -> &'static str {
+        match self {
+            ToastKind::Info => "bg-white border-gray-200 text-gray-800",
+            ToastKind::Success => "bg-green-50 border-green-200 text-green-800",
+            ToastKind::Warning => "bg-yellow-50 border-yellow-200 text-yellow-800",
+            ToastKind::Error => "bg-red-50 border-red-200 text-red-800",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub id: u64,
+    pub kind: ToastKind,
+    pub message: String,
+}
+
+/// Toasts on screen, oldest first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToastQueue {
+    toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+impl ToastQueue {
+    /// Add a toast and return its id. Repeating the newest toast refreshes it instead of
+    /// stacking a duplicate.
+    pub fn push(&mut self, kind: ToastKind, message: String) -> u64 {
+        if let Some(last) = self.toasts.last_mut() {
+            if last.kind == kind && last.message == message {
+                self.next_id += 1;
+                last.id = self.next_id;
+                return last.id;
+            }
+        }
+        self.next_id += 1;
+        self.toasts.push(Toast {
+            id: self.next_id,
+            kind,
+            message,
+        });
+        if self.toasts.len() > MAX_TOASTS {
+            self.toasts.remove(0);
+        }
+        self.next_id
+    }
+
+    pub fn dismiss(&mut self, id: u64) {
+        self.toasts.retain(|toast| toast.id != id);
+    }
+
+    pub fn toasts(&self) -> &[Toast] {
+        &self.toasts
+    }
+}
+
+/// Handle to the app's toast queue, shared through context
+#[derive(Clone, Copy)]
+pub struct Toasts(Signal<ToastQueue>);
+
+impl Toasts {
+    pub fn notify(mut self, kind: ToastKind, message: impl Into<String>) {
+        let id = self.0.write().push(kind, message.into());
+        // Outlives the component that raised the toast
+        spawn_forever(async move {
+            tokio::time::sleep(kind.duration()).await;
+            self.0.write().dismiss(id);
+        });
+    }
+}
+
+/// Create the toast queue and share it with every component below the caller
+pub fn use_toasts_provider() -> Toasts {
+    use_context_provider(|| Toasts(Signal::new(ToastQueue::default())))
+}
+
+/// Show a toast from any component, event handler or task under the app root
+pub fn notify(kind: ToastKind, message: impl Into<String>) {
+    let message = message.into();
+    match try_consume_context::<Toasts>() {
+        Some(toasts) => toasts.notify(kind, message),
+        None => log::warn!("No toast stack to show: {}", message),
+    }
+}
+
+/// The toast stack, in the bottom-right corner
+#[component]
+pub fn ToastStack() -> Element {
+    let Toasts(mut queue) = use_context::<Toasts>();
+    let toasts = queue.read().toasts().to_vec();
+
+    rsx! {
+        div {
+            class: "fixed bottom-4 right-4 z-50 flex flex-col items-end space-y-2 max-w-md",
+            for toast in toasts {
+                div {
+                    key: "{toast.id}",
+                    class: "flex items-start w-full p-3 border rounded-md shadow-lg text-sm {toast.kind.classes()}",
+                    role: if toast.kind == ToastKind::Error { "alert" } else { "status" },
+                    span { class: "mr-2 flex-shrink-0", "{toast.kind.icon()}" }
+                    span { class: "flex-1 break-words", "{toast.message}" }
+                    button {
+                        onclick: move |_| queue.write().dismiss(toast.id),
+                        class: "ml-3 flex-shrink-0 opacity-60 hover:opacity-100",
+                        title: "Dismiss",
+                        "×"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_queue() {
+        let mut queue = ToastQueue::default();
+        let first = queue.push(ToastKind::Error, "boom".to_string());
+        let second = queue.push(ToastKind::Success, "Saved".to_string());
+        assert_eq!(queue.toasts().len(), 2);
+
+        // A repeat of the newest toast refreshes it rather than stacking
+        let repeated = queue.push(ToastKind::Success, "Saved".to_string());
+        assert_ne!(repeated, second);
+        assert_eq!(queue.toasts().len(), 2);
+        queue.dismiss(second);
+        assert_eq!(queue.toasts().len(), 2);
+        queue.dismiss(repeated);
+        assert_eq!(queue.toasts().len(), 1);
+
+        queue.dismiss(first);
+        assert!(queue.toasts().is_empty());
+
+        for n in 0..MAX_TOASTS + 2 {
+            queue.push(ToastKind::Info, format!("toast {}", n));
+        }
+        assert_eq!(queue.toasts().len(), MAX_TOASTS);
+        assert_eq!(queue.toasts()[0].message, "toast 2");
+    }
+}
@@ -0,0 +1,109 @@
+//! Opt-out check for newer Hielo releases. Only GitHub's public releases API is contacted and
+//! nothing about the user or their catalogs is sent; any failure (e.g. offline) is silent.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+
+use crate::catalog::{TlsOptions, build_http_client};
+use crate::config::ProxySettings;
+
+const LATEST_RELEASE_API: &str = "https://api.github.com/repos/atcol/hielo/releases/latest";
+pub const RELEASES_PAGE: &str = "https://github.com/atcol/hielo/releases/latest";
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Minimum time between two checks against the releases API
+const CHECK_INTERVAL_HOURS: i64 = 24;
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+}
+
+/// Whether enough time has passed since the last check to ask again
+pub fn is_check_due(last_check: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    match last_check {
+        Some(last) => now - last >= Duration::hours(CHECK_INTERVAL_HOURS),
+        None => true,
+    }
+}
+
+/// Fetch the latest published release version through `proxy`, or `None` if it can't be
+/// determined
+pub async fn fetch_latest_version(proxy: Option<ProxySettings>) -> Option<String> {
+    let client = build_http_client(proxy.as_ref(), &TlsOptions::default()).ok()?;
+
+    let response = match client
+        .get(LATEST_RELEASE_API)
+        .header(
+            reqwest::header::USER_AGENT,
+            format!("hielo/{}", CURRENT_VERSION),
+        )
+        .timeout(std::time::Duration::from_secs(10))
+        .send()
+        .await
+    {
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => {
+            log::debug!("Update check returned HTTP {}", response.status());
+            return None;
+        }
+        Err(e) => {
+            log::debug!("Update check failed: {}", e);
+            return None;
+        }
+    };
+
+    let body = response.text().await.ok()?;
+    let release: Release = serde_json::from_str(&body).ok()?;
+    Some(release.tag_name.trim_start_matches('v').to_string())
+}
+
+/// Parse "v1.2.3" / "1.2.3-beta" into numeric components, ignoring pre-release suffixes
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Whether `latest` is a strictly newer release than `current`
+pub fn is_newer_version(latest: &str, current: &str) -> bool {
+    match (parse_version(latest), parse_version(current)) {
+        (Some(mut latest), Some(mut current)) => {
+            let len = latest.len().max(current.len());
+            latest.resize(len, 0);
+            current.resize(len, 0);
+            latest > current
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer_version() {
+        assert!(is_newer_version("v0.6.0", "0.5.0"));
+        assert!(is_newer_version("0.5.10", "0.5.9"));
+        assert!(is_newer_version("1.0", "0.9.9"));
+        assert!(!is_newer_version("0.5.0", "0.5.0"));
+        assert!(!is_newer_version("v0.5", "0.5.0"));
+        assert!(!is_newer_version("0.4.9", "0.5.0"));
+        assert!(!is_newer_version("0.6.0-rc1", "0.6.0"));
+        assert!(!is_newer_version("nightly", "0.5.0"));
+    }
+
+    #[test]
+    fn test_is_check_due() {
+        let now = Utc::now();
+        assert!(is_check_due(None, now));
+        assert!(!is_check_due(Some(now - Duration::hours(1)), now));
+        assert!(is_check_due(Some(now - Duration::hours(25)), now));
+    }
+}
@@ -0,0 +1,371 @@
+//! Iceberg views served by a REST catalog.
+//!
+//! iceberg-rust's `Catalog` trait has no view operations, so views are listed and loaded
+//! straight from the REST catalog's view endpoints, with the same HTTP client and credentials
+//! as the catalog itself. Glue has no Iceberg view API, so Glue catalogs list no views.
+
+use iceberg::spec::ViewMetadata;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tokio::sync::{Mutex, OnceCell};
+use url::Url;
+
+use crate::catalog::{CatalogError, LoadFailureKind, RestAuthMethod, RestAuthOptions};
+
+/// Entry a catalog's config lists when it serves views, for catalogs that list endpoints
+const LIST_VIEWS_ENDPOINT: &str = "GET /v1/{prefix}/namespaces/{namespace}/views";
+
+/// Scope requested with client credentials when none is configured, as the REST client does
+const DEFAULT_OAUTH_SCOPE: &str = "catalog";
+
+#[derive(Debug)]
+pub struct RestViewClient {
+    client: reqwest::Client,
+    uri: String,
+    warehouse: Option<String>,
+    auth: RestAuthOptions,
+    /// Static or exchanged token the catalog connected with
+    token: Option<String>,
+    /// Client credentials token, fetched on first use and again once the catalog rejects it
+    credentials_token: Mutex<Option<String>>,
+    /// Resolved on first use, so connecting costs no extra requests
+    endpoint: OnceCell<ViewEndpoint>,
+}
+
+#[derive(Debug)]
+struct ViewEndpoint {
+    /// Path segments of the catalog's `{prefix}`, from its config overrides
+    prefix: Vec<String>,
+    serves_views: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfigResponse {
+    #[serde(default)]
+    overrides: HashMap<String, String>,
+    /// Only listed by catalogs on spec 1.6 or later
+    #[serde(default)]
+    endpoints: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListViewsResponse {
+    identifiers: Vec<ViewIdentifier>,
+    #[serde(rename = "next-page-token", default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ViewIdentifier {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadViewResponse {
+    metadata: ViewMetadata,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClientCredentialsToken {
+    access_token: String,
+}
+
+impl RestViewClient {
+    pub fn new(
+        client: reqwest::Client,
+        uri: String,
+        warehouse: Option<String>,
+        auth: RestAuthOptions,
+        token: Option<String>,
+    ) -> Self {
+        Self {
+            client,
+            uri,
+            warehouse,
+            auth,
+            token,
+            credentials_token: Mutex::new(None),
+            endpoint: OnceCell::new(),
+        }
+    }
+
+    /// Names of the views in a namespace. Catalogs that don't serve views have none.
+    pub async fn list_views(&self, namespace: &str) -> Result<Vec<String>, CatalogError> {
+        let endpoint = self.endpoint().await?;
+        if !endpoint.serves_views {
+            return Ok(Vec::new());
+        }
+
+        let mut names = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut url = self.url(endpoint, &["namespaces", namespace, "views"])?;
+            if let Some(token) = &page_token {
+                url.query_pairs_mut().append_pair("pageToken", token);
+            }
+            let (status, body) = self.get(url).await?;
+            match status.as_u16() {
+                // Catalogs from before views were in the spec don't know the route
+                400 | 404 | 405 | 501 if page_token.is_none() => {
+                    log::info!("Catalog at {} does not serve views ({})", self.uri, status);
+                    return Ok(Vec::new());
+                }
+                _ => check_status(status, &body, &format!("list views in '{}'", namespace))?,
+            }
+            let page: ListViewsResponse = serde_json::from_str(&body).map_err(|e| {
+                CatalogError::ConnectionFailed(format!("Unexpected list views response: {}", e))
+            })?;
+            names.extend(page.identifiers.into_iter().map(|ident| ident.name));
+            page_token = page.next_page_token.filter(|token| !token.is_empty());
+            if page_token.is_none() {
+                return Ok(names);
+            }
+        }
+    }
+
+    pub async fn load_view(
+        &self,
+        namespace: &str,
+        view_name: &str,
+    ) -> Result<ViewMetadata, CatalogError> {
+        let endpoint = self.endpoint().await?;
+        let url = self.url(endpoint, &["namespaces", namespace, "views", view_name])?;
+        let (status, body) = self.get(url).await?;
+        let view = format!("{}.{}", namespace, view_name);
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(CatalogError::TableNotFound(format!(
+                "View '{}' does not exist",
+                view
+            )));
+        }
+        check_status(status, &body, &format!("load view '{}'", view))?;
+        let response: LoadViewResponse = serde_json::from_str(&body).map_err(|e| {
+            CatalogError::TableLoadFailed(
+                format!("Invalid metadata for view '{}': {}", view, e),
+                LoadFailureKind::ParseError,
+            )
+        })?;
+        Ok(response.metadata)
+    }
+
+    /// Path prefix for view requests, read once from the catalog's config
+    async fn endpoint(&self) -> Result<&ViewEndpoint, CatalogError> {
+        self.endpoint
+            .get_or_try_init(|| async {
+                let unprefixed = ViewEndpoint {
+                    prefix: Vec::new(),
+                    serves_views: true,
+                };
+
+                let mut url = self.url(&unprefixed, &["config"])?;
+                if let Some(warehouse) = &self.warehouse {
+                    url.query_pairs_mut().append_pair("warehouse", warehouse);
+                }
+                let (status, body) = self.get(url).await?;
+                check_status(status, &body, "read the catalog config")?;
+                let config: ConfigResponse = serde_json::from_str(&body).map_err(|e| {
+                    CatalogError::ConnectionFailed(format!("Unexpected config response: {}", e))
+                })?;
+
+                Ok(ViewEndpoint {
+                    prefix: config
+                        .overrides
+                        .get("prefix")
+                        .map(|prefix| {
+                            prefix
+                                .split('/')
+                                .filter(|segment| !segment.is_empty())
+                                .map(str::to_string)
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    serves_views: config.endpoints.is_none_or(|endpoints| {
+                        endpoints
+                            .iter()
+                            .any(|endpoint| endpoint == LIST_VIEWS_ENDPOINT)
+                    }),
+                })
+            })
+            .await
+    }
+
+    /// `{uri}/v1/{prefix}/{segments}`, with each segment percent-encoded
+    fn url(&self, endpoint: &ViewEndpoint, segments: &[&str]) -> Result<Url, CatalogError> {
+        let mut url = Url::parse(&self.uri)
+            .map_err(|e| CatalogError::InvalidConfig(format!("Invalid URI: {}", e)))?;
+        url.path_segments_mut()
+            .map_err(|_| {
+                CatalogError::InvalidConfig(format!("'{}' cannot be a catalog URI", self.uri))
+            })?
+            .pop_if_empty()
+            .push("v1")
+            .extend(&endpoint.prefix)
+            .extend(segments);
+        Ok(url)
+    }
+
+    /// GET with the catalog's token. A client credentials token the catalog rejects has
+    /// likely expired, so it's fetched again and the request retried once.
+    async fn get(&self, url: Url) -> Result<(reqwest::StatusCode, String), CatalogError> {
+        let token = self.bearer_token(false).await?;
+        let (status, body) = self.send(&url, token.as_deref()).await?;
+        if status != reqwest::StatusCode::UNAUTHORIZED || !self.uses_client_credentials() {
+            return Ok((status, body));
+        }
+        log::info!(
+            "View request to {} was unauthorized; fetching a new token",
+            url
+        );
+        let token = self.bearer_token(true).await?;
+        self.send(&url, token.as_deref()).await
+    }
+
+    fn uses_client_credentials(&self) -> bool {
+        self.token.is_none() && self.auth.method == RestAuthMethod::ClientCredentials
+    }
+
+    /// Token to send, fetching a client credentials one when there's none yet or `refresh` is set
+    async fn bearer_token(&self, refresh: bool) -> Result<Option<String>, CatalogError> {
+        if !self.uses_client_credentials() {
+            return Ok(self.token.clone());
+        }
+        let mut cached = self.credentials_token.lock().await;
+        if refresh || cached.is_none() {
+            *cached = Some(self.client_credentials_token().await?);
+        }
+        Ok(cached.clone())
+    }
+
+    async fn send(
+        &self,
+        url: &Url,
+        token: Option<&str>,
+    ) -> Result<(reqwest::StatusCode, String), CatalogError> {
+        let mut request = self
+            .client
+            .get(url.clone())
+            .timeout(std::time::Duration::from_secs(30));
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| CatalogError::NetworkError(format!("Request to {} failed: {}", url, e)))?;
+        let status = response.status();
+        let body = response.text().await.map_err(|e| {
+            CatalogError::NetworkError(format!("Failed to read response from {}: {}", url, e))
+        })?;
+        Ok((status, body))
+    }
+
+    /// Access token for client credentials; the REST catalog client keeps its own to itself
+    async fn client_credentials_token(&self) -> Result<String, CatalogError> {
+        let endpoint = self.auth.token_endpoint(&self.uri);
+        let scope = match self.auth.scope.trim() {
+            "" => DEFAULT_OAUTH_SCOPE,
+            scope => scope,
+        };
+        let form = [
+            ("grant_type", "client_credentials"),
+            ("client_id", self.auth.client_id.trim()),
+            ("client_secret", self.auth.client_secret.trim()),
+            ("scope", scope),
+        ];
+        let response = self
+            .client
+            .post(&endpoint)
+            .timeout(std::time::Duration::from_secs(30))
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| {
+                CatalogError::NetworkError(format!("Token request to {} failed: {}", endpoint, e))
+            })?;
+        let status = response.status();
+        let body = response.text().await.map_err(|e| {
+            CatalogError::ConnectionFailed(format!("Failed to read token response: {}", e))
+        })?;
+        if !status.is_success() {
+            return Err(CatalogError::AuthenticationFailed(format!(
+                "Token request to {} was rejected ({}): {}",
+                endpoint, status, body
+            )));
+        }
+        let token: ClientCredentialsToken = serde_json::from_str(&body).map_err(|e| {
+            CatalogError::ConnectionFailed(format!("Unexpected token response: {}", e))
+        })?;
+        Ok(token.access_token)
+    }
+}
+
+fn check_status(status: reqwest::StatusCode, body: &str, action: &str) -> Result<(), CatalogError> {
+    match status.as_u16() {
+        200..=299 => Ok(()),
+        401 | 403 => Err(CatalogError::AuthenticationFailed(format!(
+            "Not allowed to {} ({}): {}",
+            action, status, body
+        ))),
+        _ => Err(CatalogError::NetworkError(format!(
+            "Failed to {} ({}): {}",
+            action, status, body
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(uri: &str) -> RestViewClient {
+        RestViewClient::new(
+            reqwest::Client::new(),
+            uri.to_string(),
+            None,
+            RestAuthOptions::default(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_view_urls() {
+        let endpoint = ViewEndpoint {
+            prefix: vec!["warehouses".to_string(), "main".to_string()],
+            serves_views: true,
+        };
+        let url = client("http://localhost:8181/api/catalog/")
+            .url(&endpoint, &["namespaces", "sales eu", "views"])
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "http://localhost:8181/api/catalog/v1/warehouses/main/namespaces/sales%20eu/views"
+        );
+
+        let unprefixed = ViewEndpoint {
+            prefix: Vec::new(),
+            serves_views: true,
+        };
+        let url = client("http://localhost:8181")
+            .url(&unprefixed, &["config"])
+            .unwrap();
+        assert_eq!(url.as_str(), "http://localhost:8181/v1/config");
+    }
+
+    #[test]
+    fn test_list_views_response() {
+        let page: ListViewsResponse = serde_json::from_str(
+            r#"{"identifiers": [{"namespace": ["sales"], "name": "daily"}], "next-page-token": "abc"}"#,
+        )
+        .unwrap();
+        assert_eq!(page.identifiers[0].name, "daily");
+        assert_eq!(page.next_page_token.as_deref(), Some("abc"));
+
+        let config: ConfigResponse =
+            serde_json::from_str(r#"{"defaults": {}, "overrides": {"prefix": "main"}}"#).unwrap();
+        assert_eq!(
+            config.overrides.get("prefix").map(String::as_str),
+            Some("main")
+        );
+        assert!(config.endpoints.is_none());
+    }
+}